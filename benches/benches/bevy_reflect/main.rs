@@ -4,6 +4,7 @@ mod function;
 mod list;
 mod map;
 mod path;
+mod serde;
 mod r#struct;
 
 criterion_main!(
@@ -11,5 +12,6 @@ criterion_main!(
     list::benches,
     map::benches,
     path::benches,
+    serde::benches,
     r#struct::benches,
 );
@@ -0,0 +1,188 @@
+use core::{hint::black_box, time::Duration};
+
+use benches::bench;
+use bevy_reflect::{
+    serde::{
+        CompactReflectDeserializer, CompactReflectSerializer, CompactTypeIds, ReflectDeserializer,
+        ReflectSerializer,
+    },
+    Reflect, TypeRegistry,
+};
+use bincode::Options;
+use criterion::{
+    criterion_group, measurement::Measurement, AxisScale, BenchmarkGroup, BenchmarkId, Criterion,
+    PlotConfiguration, Throughput,
+};
+
+criterion_group!(benches, serialize, deserialize);
+
+const WARM_UP_TIME: Duration = Duration::from_millis(500);
+const MEASUREMENT_TIME: Duration = Duration::from_secs(4);
+const SIZES: [usize; 4] = [1, 16, 64, 256];
+
+#[derive(Reflect, Clone)]
+struct Player {
+    name: String,
+    position: Position,
+    health: u32,
+    inventory: Vec<Item>,
+}
+
+#[derive(Reflect, Clone)]
+struct Position {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+#[derive(Reflect, Clone)]
+enum Item {
+    Empty,
+    Potion(u8),
+    Weapon { damage: u32, range: f32 },
+}
+
+/// Creates a [`BenchmarkGroup`] with common configuration shared by all benchmarks within this
+/// module.
+fn create_group<'a, M: Measurement>(c: &'a mut Criterion<M>, name: &str) -> BenchmarkGroup<'a, M> {
+    let mut group = c.benchmark_group(name);
+
+    group
+        .warm_up_time(WARM_UP_TIME)
+        .measurement_time(MEASUREMENT_TIME)
+        // Make the plots logarithmic, matching `SIZES`' scale.
+        .plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+
+    group
+}
+
+fn create_registry() -> TypeRegistry {
+    let mut registry = TypeRegistry::default();
+    registry.register::<Player>();
+    registry.register::<Vec<Player>>();
+    registry
+}
+
+/// Identifies the registered types by their position in the sorted list of type paths, as peers
+/// would after exchanging that list.
+fn negotiated_type_ids(registry: &TypeRegistry) -> CompactTypeIds {
+    let mut type_paths = registry
+        .iter()
+        .map(|registration| registration.type_info().type_path())
+        .collect::<Vec<_>>();
+    type_paths.sort_unstable();
+    CompactTypeIds::from_type_paths(registry, type_paths)
+}
+
+fn create_players(size: usize) -> Vec<Player> {
+    (0..size)
+        .map(|i| Player {
+            name: format!("player_{i}"),
+            position: Position {
+                x: i as f32,
+                y: 0.0,
+                z: -(i as f32),
+            },
+            health: 100,
+            inventory: vec![
+                Item::Empty,
+                Item::Potion(3),
+                Item::Weapon {
+                    damage: 12,
+                    range: 1.5,
+                },
+            ],
+        })
+        .collect()
+}
+
+fn serialize(criterion: &mut Criterion) {
+    let mut group = create_group(criterion, bench!("serialize"));
+
+    let registry = create_registry();
+    let hashed = CompactTypeIds::hashed(&registry);
+    let negotiated = negotiated_type_ids(&registry);
+    let options = bincode::DefaultOptions::new();
+
+    for size in SIZES {
+        let players = create_players(size);
+        group.throughput(Throughput::Elements(size as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("reflect", size),
+            &players,
+            |bencher, players| {
+                bencher.iter(|| {
+                    let serializer = ReflectSerializer::new(black_box(players), &registry);
+                    options.serialize(&serializer).unwrap()
+                });
+            },
+        );
+
+        for (name, type_ids) in [
+            ("compact_hashed", &hashed),
+            ("compact_negotiated", &negotiated),
+        ] {
+            group.bench_with_input(
+                BenchmarkId::new(name, size),
+                &players,
+                |bencher, players| {
+                    bencher.iter(|| {
+                        let serializer =
+                            CompactReflectSerializer::new(black_box(players), &registry, type_ids);
+                        options.serialize(&serializer).unwrap()
+                    });
+                },
+            );
+        }
+    }
+}
+
+fn deserialize(criterion: &mut Criterion) {
+    let mut group = create_group(criterion, bench!("deserialize"));
+
+    let registry = create_registry();
+    let hashed = CompactTypeIds::hashed(&registry);
+    let negotiated = negotiated_type_ids(&registry);
+    let options = bincode::DefaultOptions::new();
+
+    for size in SIZES {
+        let players = create_players(size);
+        group.throughput(Throughput::Elements(size as u64));
+
+        let bytes = options
+            .serialize(&ReflectSerializer::new(&players, &registry))
+            .unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("reflect", size),
+            &bytes,
+            |bencher, bytes| {
+                bencher.iter(|| {
+                    let deserializer = ReflectDeserializer::new(&registry);
+                    options
+                        .deserialize_seed(deserializer, black_box(bytes))
+                        .unwrap()
+                });
+            },
+        );
+
+        for (name, type_ids) in [
+            ("compact_hashed", &hashed),
+            ("compact_negotiated", &negotiated),
+        ] {
+            let bytes = options
+                .serialize(&CompactReflectSerializer::new(
+                    &players, &registry, type_ids,
+                ))
+                .unwrap();
+            group.bench_with_input(BenchmarkId::new(name, size), &bytes, |bencher, bytes| {
+                bencher.iter(|| {
+                    let deserializer = CompactReflectDeserializer::new(&registry, type_ids);
+                    options
+                        .deserialize_seed(deserializer, black_box(bytes))
+                        .unwrap()
+                });
+            });
+        }
+    }
+}
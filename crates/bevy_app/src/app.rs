@@ -13,6 +13,7 @@ use bevy_ecs::{
     event::{event_update_system, EventCursor},
     intern::Interned,
     prelude::*,
+    result::{Error, SystemErrorContext},
     schedule::{ScheduleBuildSettings, ScheduleLabel},
     system::{IntoObserverSystem, SystemId, SystemInput},
 };
@@ -104,6 +105,8 @@ impl Default for App {
         {
             app.init_resource::<AppTypeRegistry>();
             app.register_type::<Name>();
+            app.register_type::<bevy_ecs::entity_disabling::Disabled>();
+            app.register_type::<bevy_ecs::resource_entity::ResourceEntity>();
         }
 
         #[cfg(feature = "reflect_functions")]
@@ -417,6 +420,27 @@ impl App {
         self
     }
 
+    /// Sets the error handler used when systems, observers or commands in the main app return an [`Err`].
+    ///
+    /// This handler is used by every schedule that has not been given its own handler with
+    /// [`Schedule::set_error_handler`]. By default, errors cause a panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::result;
+    /// App::new()
+    ///     .set_system_error_handler(result::warn);
+    /// ```
+    pub fn set_system_error_handler(
+        &mut self,
+        error_handler: fn(Error, SystemErrorContext),
+    ) -> &mut Self {
+        self.main_mut().set_system_error_handler(error_handler);
+        self
+    }
+
     /// Inserts the [`!Send`](Send) resource into the app, overwriting any existing resource
     /// of the same type.
     ///
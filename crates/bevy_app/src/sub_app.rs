@@ -3,6 +3,7 @@ use alloc::{boxed::Box, string::String, vec::Vec};
 use bevy_ecs::{
     event::EventRegistry,
     prelude::*,
+    result::{DefaultSystemErrorHandler, Error, SystemErrorContext},
     schedule::{InternedScheduleLabel, ScheduleBuildSettings, ScheduleLabel},
     system::{SystemId, SystemInput},
 };
@@ -207,6 +208,16 @@ impl SubApp {
         self
     }
 
+    /// See [`App::set_system_error_handler`].
+    pub fn set_system_error_handler(
+        &mut self,
+        error_handler: fn(Error, SystemErrorContext),
+    ) -> &mut Self {
+        self.world
+            .insert_resource(DefaultSystemErrorHandler(error_handler));
+        self
+    }
+
     /// See [`App::add_systems`].
     pub fn add_systems<M>(
         &mut self,
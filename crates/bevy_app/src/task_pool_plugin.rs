@@ -16,6 +16,9 @@ use log::trace;
 #[cfg(not(target_arch = "wasm32"))]
 use {crate::Last, bevy_ecs::prelude::NonSend};
 
+#[cfg(feature = "std")]
+use {crate::First, bevy_ecs::task::apply_task_commands};
+
 #[cfg(feature = "portable-atomic")]
 use portable_atomic_util::Arc;
 
@@ -39,6 +42,9 @@ impl Plugin for TaskPoolPlugin {
 
         #[cfg(not(target_arch = "wasm32"))]
         _app.add_systems(Last, tick_global_task_pools);
+
+        #[cfg(feature = "std")]
+        _app.add_systems(First, apply_task_commands);
     }
 }
 /// A dummy type that is [`!Send`](Send), to force systems to run on the main thread.
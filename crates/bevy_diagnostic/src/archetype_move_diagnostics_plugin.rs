@@ -0,0 +1,134 @@
+use alloc::format;
+
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    component::{ComponentId, Components},
+    prelude::*,
+};
+use bevy_utils::{HashMap, Instant};
+
+use crate::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore, RegisterDiagnostic,
+};
+
+/// Adds diagnostics counting the archetype moves caused by each component with
+/// [migratable storage](bevy_ecs::component::Component::MIGRATABLE_STORAGE) to an App.
+///
+/// Every frame, the diagnostic `archetype_moves/<component name>` measures how many times an
+/// entity moved to another archetype because the component was inserted or removed, and
+/// [`Self::TOTAL`] measures the sum for all components.
+/// The counts are read from [`ComponentInfo::archetype_moves`] and guide the choice of storage,
+/// see [`World::adapt_component_storage`].
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+///
+/// [`ComponentInfo::archetype_moves`]: bevy_ecs::component::ComponentInfo::archetype_moves
+#[derive(Default)]
+pub struct ArchetypeMoveDiagnosticsPlugin;
+
+impl Plugin for ArchetypeMoveDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::TOTAL))
+            .add_systems(Last, Self::diagnostic_system);
+    }
+}
+
+impl ArchetypeMoveDiagnosticsPlugin {
+    pub const TOTAL: DiagnosticPath = DiagnosticPath::const_new("archetype_moves/total");
+
+    /// Returns the path of the diagnostic counting the archetype moves caused by the component
+    /// named `name`.
+    pub fn component_path(name: &str) -> DiagnosticPath {
+        DiagnosticPath::new(format!("archetype_moves/{name}"))
+    }
+
+    /// Measures the archetype moves caused by each component since the last frame.
+    ///
+    /// Components are given a diagnostic the first time they are seen.
+    pub fn diagnostic_system(
+        mut store: ResMut<DiagnosticsStore>,
+        components: &Components,
+        mut counted: Local<HashMap<ComponentId, (DiagnosticPath, u64)>>,
+    ) {
+        let time = Instant::now();
+        let mut total = 0;
+        for info in components.iter().filter(|info| info.migratable_storage()) {
+            let (path, last_moves) = counted
+                .entry(info.id())
+                .or_insert_with(|| (Self::component_path(info.name()), 0));
+            // The counts go down when they are reset, e.g. by `World::adapt_component_storage`.
+            let moves = info
+                .archetype_moves()
+                .checked_sub(*last_moves)
+                .unwrap_or(info.archetype_moves());
+            *last_moves = info.archetype_moves();
+            total += moves;
+
+            if store.get(path).is_none() {
+                store.add(Diagnostic::new(path.clone()));
+            }
+            let diagnostic = store.get_mut(path).unwrap();
+            if diagnostic.is_enabled {
+                diagnostic.add_measurement(DiagnosticMeasurement {
+                    time,
+                    value: moves as f64,
+                });
+            }
+        }
+
+        if let Some(diagnostic) = store
+            .get_mut(&Self::TOTAL)
+            .filter(|diagnostic| diagnostic.is_enabled)
+        {
+            diagnostic.add_measurement(DiagnosticMeasurement {
+                time,
+                value: total as f64,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DiagnosticsPlugin;
+
+    #[derive(Component)]
+    #[component(migratable_storage)]
+    struct Migratable;
+
+    #[test]
+    fn counts_archetype_moves_per_frame() {
+        let mut app = App::new();
+        app.add_plugins((DiagnosticsPlugin, ArchetypeMoveDiagnosticsPlugin));
+        let entity = app.world_mut().spawn_empty().id();
+        let measurement = |app: &App, path: &DiagnosticPath| {
+            app.world()
+                .resource::<DiagnosticsStore>()
+                .get(path)
+                .and_then(Diagnostic::value)
+        };
+
+        app.world_mut().entity_mut(entity).insert(Migratable);
+        app.world_mut().entity_mut(entity).remove::<Migratable>();
+        app.update();
+        let path =
+            ArchetypeMoveDiagnosticsPlugin::component_path(core::any::type_name::<Migratable>());
+        assert_eq!(measurement(&app, &path), Some(2.0));
+        assert_eq!(
+            measurement(&app, &ArchetypeMoveDiagnosticsPlugin::TOTAL),
+            Some(2.0)
+        );
+
+        app.world_mut().entity_mut(entity).insert(Migratable);
+        app.update();
+        assert_eq!(measurement(&app, &path), Some(1.0));
+
+        app.world_mut().reset_archetype_moves();
+        app.world_mut().entity_mut(entity).remove::<Migratable>();
+        app.update();
+        assert_eq!(measurement(&app, &path), Some(1.0));
+    }
+}
@@ -12,20 +12,26 @@
 
 extern crate alloc;
 
+mod archetype_move_diagnostics_plugin;
 mod diagnostic;
 mod entity_count_diagnostics_plugin;
 mod frame_count_diagnostics_plugin;
 mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
+mod storage_diagnostics_plugin;
+mod sync_point_diagnostics_plugin;
 #[cfg(feature = "sysinfo_plugin")]
 mod system_information_diagnostics_plugin;
 
+pub use archetype_move_diagnostics_plugin::ArchetypeMoveDiagnosticsPlugin;
 pub use diagnostic::*;
 
 pub use entity_count_diagnostics_plugin::EntityCountDiagnosticsPlugin;
 pub use frame_count_diagnostics_plugin::{update_frame_count, FrameCount, FrameCountPlugin};
 pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
 pub use log_diagnostics_plugin::LogDiagnosticsPlugin;
+pub use storage_diagnostics_plugin::StorageDiagnosticsPlugin;
+pub use sync_point_diagnostics_plugin::SyncPointDiagnosticsPlugin;
 #[cfg(feature = "sysinfo_plugin")]
 pub use system_information_diagnostics_plugin::{SystemInfo, SystemInformationDiagnosticsPlugin};
 
@@ -0,0 +1,75 @@
+use core::cell::OnceCell;
+
+use bevy_app::prelude::*;
+use bevy_ecs::{storage::MemoryUsage, system::SystemState, world::World};
+
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+
+/// Adds diagnostics about the memory used to store entities and components to an App, from the
+/// report of [`World::memory_usage`].
+///
+/// Use [`World::compact_storage`] to free memory that isn't used anymore.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct StorageDiagnosticsPlugin;
+
+impl Plugin for StorageDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::ARCHETYPE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::EMPTY_ARCHETYPE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::TABLE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::EMPTY_TABLE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::ALLOCATED_MEMORY).with_suffix("MiB"))
+            .register_diagnostic(Diagnostic::new(Self::USED_MEMORY).with_suffix("MiB"))
+            .register_diagnostic(Diagnostic::new(Self::FRAGMENTATION).with_suffix("%"))
+            .add_systems(Last, Self::diagnostic_system);
+    }
+}
+
+impl StorageDiagnosticsPlugin {
+    pub const ARCHETYPE_COUNT: DiagnosticPath = DiagnosticPath::const_new("storage/archetypes");
+    pub const EMPTY_ARCHETYPE_COUNT: DiagnosticPath =
+        DiagnosticPath::const_new("storage/empty_archetypes");
+    pub const TABLE_COUNT: DiagnosticPath = DiagnosticPath::const_new("storage/tables");
+    pub const EMPTY_TABLE_COUNT: DiagnosticPath = DiagnosticPath::const_new("storage/empty_tables");
+    pub const ALLOCATED_MEMORY: DiagnosticPath =
+        DiagnosticPath::const_new("storage/allocated_memory");
+    pub const USED_MEMORY: DiagnosticPath = DiagnosticPath::const_new("storage/used_memory");
+    pub const FRAGMENTATION: DiagnosticPath = DiagnosticPath::const_new("storage/fragmentation");
+
+    /// Measures the memory used by the storages of the world.
+    ///
+    /// This is an exclusive system running in [`Last`], so that reading the storages doesn't
+    /// conflict with the systems changing the world during the frame.
+    pub fn diagnostic_system(world: &mut World, state: &mut SystemState<Diagnostics>) {
+        Self::measure(&mut state.get(world), world);
+        state.apply(world);
+    }
+
+    fn measure(diagnostics: &mut Diagnostics, world: &World) {
+        const BYTES_PER_MIB: f64 = 1024.0 * 1024.0;
+
+        // Only build the report if one of the diagnostics is enabled.
+        let usage = OnceCell::<MemoryUsage>::new();
+        let usage = || usage.get_or_init(|| world.memory_usage());
+
+        diagnostics.add_measurement(&Self::ARCHETYPE_COUNT, || usage().archetypes.len() as f64);
+        diagnostics.add_measurement(&Self::EMPTY_ARCHETYPE_COUNT, || {
+            usage().empty_archetypes().count() as f64
+        });
+        diagnostics.add_measurement(&Self::TABLE_COUNT, || usage().tables.len() as f64);
+        diagnostics.add_measurement(&Self::EMPTY_TABLE_COUNT, || {
+            usage().empty_tables().count() as f64
+        });
+        diagnostics.add_measurement(&Self::ALLOCATED_MEMORY, || {
+            usage().allocated_bytes() as f64 / BYTES_PER_MIB
+        });
+        diagnostics.add_measurement(&Self::USED_MEMORY, || {
+            usage().used_bytes() as f64 / BYTES_PER_MIB
+        });
+        diagnostics.add_measurement(&Self::FRAGMENTATION, || usage().fragmentation() * 100.0);
+    }
+}
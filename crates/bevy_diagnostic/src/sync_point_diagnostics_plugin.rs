@@ -0,0 +1,35 @@
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::SyncPointStats};
+
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+
+/// Adds "sync point" diagnostics to an App, specifically the time spent applying deferred
+/// system buffers such as [`Commands`] and the number of sync points that ran each frame.
+///
+/// This inserts the [`SyncPointStats`] resource, which records the timings of every schedule.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct SyncPointDiagnosticsPlugin;
+
+impl Plugin for SyncPointDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SyncPointStats>()
+            .register_diagnostic(Diagnostic::new(Self::APPLY_TIME).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(Self::COUNT))
+            .add_systems(Update, Self::diagnostic_system);
+    }
+}
+
+impl SyncPointDiagnosticsPlugin {
+    pub const APPLY_TIME: DiagnosticPath = DiagnosticPath::const_new("sync_points/apply_time");
+    pub const COUNT: DiagnosticPath = DiagnosticPath::const_new("sync_points/count");
+
+    pub fn diagnostic_system(mut diagnostics: Diagnostics, mut stats: ResMut<SyncPointStats>) {
+        diagnostics.add_measurement(&Self::APPLY_TIME, || stats.elapsed().as_secs_f64() * 1000.0);
+        diagnostics.add_measurement(&Self::COUNT, || stats.count() as f64);
+        stats.reset();
+    }
+}
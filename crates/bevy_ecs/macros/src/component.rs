@@ -9,7 +9,8 @@ use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
     token::{Comma, Paren},
-    DeriveInput, ExprClosure, ExprPath, Ident, LitStr, Path, Result,
+    Data, DataStruct, DeriveInput, ExprClosure, ExprPath, Fields, Ident, LitStr, Member, Path,
+    Result, Token, Type,
 };
 
 pub fn derive_event(input: TokenStream) -> TokenStream {
@@ -54,12 +55,42 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(input as DeriveInput);
     let bevy_ecs_path: Path = crate::bevy_ecs_path();
 
-    let attrs = match parse_component_attr(&ast) {
+    let mut attrs = match parse_component_attr(&ast) {
         Ok(attrs) => attrs,
         Err(e) => return e.into_compile_error().into(),
     };
 
+    let relationship = match derive_relationship(&ast, &attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let relationship_target = match derive_relationship_target(&ast, &attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let tracked_fields = match derive_tracked_fields(&ast, &attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    if attrs.relationship.is_some() {
+        attrs.on_insert =
+            Some(parse_quote!(<Self as #bevy_ecs_path::relationship::Relationship>::on_insert));
+        attrs.on_replace =
+            Some(parse_quote!(<Self as #bevy_ecs_path::relationship::Relationship>::on_replace));
+    }
+    if attrs.relationship_target.is_some() {
+        attrs.on_replace = Some(
+            parse_quote!(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_replace),
+        );
+    }
+    if attrs.index {
+        attrs.on_insert = Some(parse_quote!(#bevy_ecs_path::index::on_insert_index::<Self>));
+        attrs.on_replace = Some(parse_quote!(#bevy_ecs_path::index::on_replace_index::<Self>));
+    }
+
     let storage = storage_path(&bevy_ecs_path, attrs.storage);
+    let migratable_storage = attrs.migratable_storage;
 
     let on_add = hook_register_function_call(quote! {on_add}, attrs.on_add);
     let on_insert = hook_register_function_call(quote! {on_insert}, attrs.on_insert);
@@ -124,19 +155,51 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
             }
         }
     }
+    if attrs.track_fields {
+        register_required.push(quote! {
+            components.register_required_components_manual::<Self, #bevy_ecs_path::change_detection::FieldTicks<Self>>(
+                storages,
+                required_components,
+                <#bevy_ecs_path::change_detection::FieldTicks<Self> as Default>::default,
+                inheritance_depth,
+                recursion_check_stack
+            );
+        });
+    }
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
-    let mutable_type = attrs
-        .immutable
+    let indexed_component = attrs.index.then(|| {
+        quote! {
+            impl #impl_generics #bevy_ecs_path::index::IndexedComponent for #struct_name #type_generics #where_clause {}
+        }
+    });
+
+    // Relationships are always immutable, so that their hooks see every change of target.
+    let mutable_type = (attrs.immutable || attrs.relationship.is_some())
         .then_some(quote! { #bevy_ecs_path::component::Immutable })
         .unwrap_or(quote! { #bevy_ecs_path::component::Mutable });
 
+    // Cloning either side of a relationship would silently add the clone to (or take it from)
+    // the relationships of other entities, so relationships are not cloned by default.
+    let clone_handler = if attrs.relationship.is_some() || attrs.relationship_target.is_some() {
+        quote! {
+            #bevy_ecs_path::component::ComponentCloneHandler::ignore()
+        }
+    } else {
+        quote! {
+            use #bevy_ecs_path::component::{ComponentCloneViaClone, ComponentCloneBase};
+            (&&&#bevy_ecs_path::component::ComponentCloneSpecializationWrapper::<Self>::default())
+                .get_component_clone_handler()
+        }
+    };
+
     // This puts `register_required` before `register_recursive_requires` to ensure that the constructors of _all_ top
     // level components are initialized first, giving them precedence over recursively defined constructors for the same component type
     TokenStream::from(quote! {
         impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
             const STORAGE_TYPE: #bevy_ecs_path::component::StorageType = #storage;
+            const MIGRATABLE_STORAGE: bool = #migratable_storage;
             type Mutability = #mutable_type;
             fn register_required_components(
                 requiree: #bevy_ecs_path::component::ComponentId,
@@ -163,11 +226,17 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
             }
 
             fn get_component_clone_handler() -> #bevy_ecs_path::component::ComponentCloneHandler {
-                use #bevy_ecs_path::component::{ComponentCloneViaClone, ComponentCloneBase};
-                (&&&#bevy_ecs_path::component::ComponentCloneSpecializationWrapper::<Self>::default())
-                    .get_component_clone_handler()
+                #clone_handler
             }
         }
+
+        #relationship
+
+        #relationship_target
+
+        #tracked_fields
+
+        #indexed_component
     })
 }
 
@@ -209,6 +278,12 @@ pub const ON_REPLACE: &str = "on_replace";
 pub const ON_REMOVE: &str = "on_remove";
 
 pub const IMMUTABLE: &str = "immutable";
+pub const MIGRATABLE_STORAGE: &str = "migratable_storage";
+pub const TRACK_FIELDS: &str = "track_fields";
+pub const INDEX: &str = "index";
+
+pub const RELATIONSHIP: &str = "relationship";
+pub const RELATIONSHIP_TARGET: &str = "relationship_target";
 
 struct Attrs {
     storage: StorageTy,
@@ -218,6 +293,11 @@ struct Attrs {
     on_replace: Option<ExprPath>,
     on_remove: Option<ExprPath>,
     immutable: bool,
+    migratable_storage: bool,
+    track_fields: bool,
+    index: bool,
+    relationship: Option<Relationship>,
+    relationship_target: Option<RelationshipTarget>,
 }
 
 #[derive(Clone, Copy)]
@@ -236,6 +316,14 @@ enum RequireFunc {
     Closure(ExprClosure),
 }
 
+struct Relationship {
+    relationship_target: Ident,
+}
+
+struct RelationshipTarget {
+    relationship: Ident,
+}
+
 // values for `storage` attribute
 const TABLE: &str = "Table";
 const SPARSE_SET: &str = "SparseSet";
@@ -249,6 +337,11 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
         on_remove: None,
         requires: None,
         immutable: false,
+        migratable_storage: false,
+        track_fields: false,
+        index: false,
+        relationship: None,
+        relationship_target: None,
     };
 
     let mut require_paths = HashSet::new();
@@ -281,6 +374,15 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
                 } else if nested.path.is_ident(IMMUTABLE) {
                     attrs.immutable = true;
                     Ok(())
+                } else if nested.path.is_ident(MIGRATABLE_STORAGE) {
+                    attrs.migratable_storage = true;
+                    Ok(())
+                } else if nested.path.is_ident(TRACK_FIELDS) {
+                    attrs.track_fields = true;
+                    Ok(())
+                } else if nested.path.is_ident(INDEX) {
+                    attrs.index = true;
+                    Ok(())
                 } else {
                     Err(nested.error("Unsupported attribute"))
                 }
@@ -301,6 +403,65 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
             } else {
                 attrs.requires = Some(punctuated);
             }
+        } else if attr.path().is_ident(RELATIONSHIP) {
+            let relationship = attr.parse_args::<Relationship>()?;
+            attrs.relationship = Some(relationship);
+        } else if attr.path().is_ident(RELATIONSHIP_TARGET) {
+            let relationship_target = attr.parse_args::<RelationshipTarget>()?;
+            attrs.relationship_target = Some(relationship_target);
+        }
+    }
+
+    if attrs.relationship.is_some() && attrs.relationship_target.is_some() {
+        return Err(syn::Error::new(
+            ast.span(),
+            "A component cannot be both a relationship and a relationship target.",
+        ));
+    }
+    if attrs.relationship.is_some() && (attrs.on_insert.is_some() || attrs.on_replace.is_some()) {
+        return Err(syn::Error::new(
+            ast.span(),
+            "Custom on_insert and on_replace hooks are not supported as relationships already define them.",
+        ));
+    }
+    if attrs.relationship_target.is_some() && attrs.on_replace.is_some() {
+        return Err(syn::Error::new(
+            ast.span(),
+            "Custom on_replace hooks are not supported as relationship targets already define an on_replace hook.",
+        ));
+    }
+    if attrs.relationship_target.is_some() && attrs.immutable {
+        return Err(syn::Error::new(
+            ast.span(),
+            "Relationship targets cannot be immutable, as their collection is updated in place.",
+        ));
+    }
+
+    if attrs.track_fields && attrs.immutable {
+        return Err(syn::Error::new(
+            ast.span(),
+            "Immutable components cannot track fields, as their fields are never changed.",
+        ));
+    }
+
+    if attrs.index {
+        if !attrs.immutable {
+            return Err(syn::Error::new(
+                ast.span(),
+                "Indexed components must be immutable, so that the index sees every change. Add `#[component(immutable)]`.",
+            ));
+        }
+        if attrs.relationship.is_some() || attrs.relationship_target.is_some() {
+            return Err(syn::Error::new(
+                ast.span(),
+                "Relationships cannot be indexed, as they already define their own hooks.",
+            ));
+        }
+        if attrs.on_insert.is_some() || attrs.on_replace.is_some() {
+            return Err(syn::Error::new(
+                ast.span(),
+                "Custom on_insert and on_replace hooks are not supported as indexed components already define them.",
+            ));
         }
     }
 
@@ -341,3 +502,179 @@ fn hook_register_function_call(
 ) -> Option<TokenStream2> {
     function.map(|meta| quote! { hooks. #hook (#meta); })
 }
+
+mod kw {
+    syn::custom_keyword!(relationship_target);
+    syn::custom_keyword!(relationship);
+}
+
+impl Parse for Relationship {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        input.parse::<kw::relationship_target>()?;
+        input.parse::<Token![=]>()?;
+        Ok(Relationship {
+            relationship_target: input.parse::<Ident>()?,
+        })
+    }
+}
+
+impl Parse for RelationshipTarget {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        input.parse::<kw::relationship>()?;
+        input.parse::<Token![=]>()?;
+        Ok(RelationshipTarget {
+            relationship: input.parse::<Ident>()?,
+        })
+    }
+}
+
+fn derive_relationship(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship) = &attrs.relationship else {
+        return Ok(None);
+    };
+    let (member, _) = relationship_field(ast, "Relationship")?;
+
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+    let relationship_target = &relationship.relationship_target;
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::Relationship for #struct_name #type_generics #where_clause {
+            type RelationshipTarget = #relationship_target;
+
+            #[inline(always)]
+            fn get(&self) -> #bevy_ecs_path::entity::Entity {
+                self.#member
+            }
+
+            #[inline]
+            fn from(entity: #bevy_ecs_path::entity::Entity) -> Self {
+                Self {
+                    #member: entity
+                }
+            }
+        }
+    }))
+}
+
+fn derive_tracked_fields(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    if !attrs.track_fields {
+        return Ok(None);
+    }
+    let Data::Struct(DataStruct { fields, .. }) = &ast.data else {
+        return Err(syn::Error::new(
+            ast.span(),
+            "Field tracking can only be derived for structs.",
+        ));
+    };
+    if fields.is_empty() {
+        return Err(syn::Error::new(
+            ast.span(),
+            "Field tracking requires at least one field.",
+        ));
+    }
+
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    let mut names = Vec::with_capacity(fields.len());
+    let mut field_access = Vec::with_capacity(fields.len());
+    for (index, field) in fields.iter().enumerate() {
+        let member = field
+            .ident
+            .clone()
+            .map_or(Member::from(index), Member::Named);
+        names.push(match &member {
+            Member::Named(ident) => ident.to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        });
+        let ty = &field.ty;
+        field_access.push(quote! {
+            impl #impl_generics #bevy_ecs_path::change_detection::FieldAccess<#index> for #struct_name #type_generics #where_clause {
+                type Value = #ty;
+
+                #[inline]
+                fn field_mut(&mut self) -> &mut #ty {
+                    &mut self.#member
+                }
+            }
+        });
+    }
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::change_detection::TrackedFields for #struct_name #type_generics #where_clause {
+            const FIELDS: &'static [&'static str] = &[#(#names),*];
+        }
+
+        #(#field_access)*
+    }))
+}
+
+fn derive_relationship_target(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship_target) = &attrs.relationship_target else {
+        return Ok(None);
+    };
+    let (member, collection) = relationship_field(ast, "RelationshipTarget")?;
+
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+    let relationship = &relationship_target.relationship;
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::RelationshipTarget for #struct_name #type_generics #where_clause {
+            type Relationship = #relationship;
+            type Collection = #collection;
+
+            #[inline]
+            fn collection(&self) -> &Self::Collection {
+                &self.#member
+            }
+
+            #[inline]
+            fn collection_mut_risky(&mut self) -> &mut Self::Collection {
+                &mut self.#member
+            }
+
+            #[inline]
+            fn from_collection_risky(collection: Self::Collection) -> Self {
+                Self {
+                    #member: collection
+                }
+            }
+        }
+    }))
+}
+
+/// Returns the member and type of the single field of a relationship component.
+fn relationship_field<'a>(ast: &'a DeriveInput, derived: &str) -> Result<(Member, &'a Type)> {
+    let Data::Struct(DataStruct { fields, .. }) = &ast.data else {
+        return Err(syn::Error::new(
+            ast.span(),
+            format!("{derived} can only be derived for structs."),
+        ));
+    };
+    if fields.len() != 1 {
+        return Err(syn::Error::new(
+            fields.span(),
+            format!("{derived} components must have exactly one field."),
+        ));
+    }
+    let field = fields.iter().next().unwrap();
+    let member = match fields {
+        Fields::Named(_) => Member::Named(field.ident.clone().unwrap()),
+        _ => Member::Unnamed(0.into()),
+    };
+    Ok((member, &field.ty))
+}
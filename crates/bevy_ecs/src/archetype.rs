@@ -369,6 +369,8 @@ bitflags::bitflags! {
 /// [module level documentation]: crate::archetype
 pub struct Archetype {
     id: ArchetypeId,
+    // The number of times the archetypes were compacted when this archetype got its id
+    compactions: u32,
     table_id: TableId,
     edges: Edges,
     entities: Vec<ArchetypeEntity>,
@@ -431,6 +433,7 @@ impl Archetype {
         }
         Self {
             id,
+            compactions: 0,
             table_id,
             entities: Vec::new(),
             components: archetype_components.into_immutable(),
@@ -445,6 +448,12 @@ impl Archetype {
         self.id
     }
 
+    /// Returns the number of times the archetypes were compacted when this archetype got its id.
+    #[inline]
+    pub(crate) fn compactions(&self) -> u32 {
+        self.compactions
+    }
+
     /// Fetches the flags for the archetype.
     #[inline]
     pub(crate) fn flags(&self) -> ArchetypeFlags {
@@ -583,6 +592,18 @@ impl Archetype {
         self.entities.reserve(additional);
     }
 
+    /// Gets the maximum number of entities the archetype can currently store
+    /// without reallocating the underlying memory.
+    #[inline]
+    pub fn entity_capacity(&self) -> usize {
+        self.entities.capacity()
+    }
+
+    /// Shrinks the capacity of the archetype as much as possible.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.entities.shrink_to_fit();
+    }
+
     /// Removes the entity at `row` by swapping it out. Returns the table row the entity is stored
     /// in.
     ///
@@ -709,14 +730,23 @@ impl Archetype {
 ///
 /// This is used in archetype update methods to limit archetype updates to the
 /// ones added since the last time the method ran.
+///
+/// A generation taken before the archetypes were compacted, which changes their ids, covers all
+/// the archetypes.
 #[derive(Debug, Copy, Clone, PartialEq)]
-pub struct ArchetypeGeneration(pub(crate) ArchetypeId);
+pub struct ArchetypeGeneration {
+    pub(crate) next: ArchetypeId,
+    pub(crate) compactions: u32,
+}
 
 impl ArchetypeGeneration {
     /// The first archetype.
     #[inline]
     pub const fn initial() -> Self {
-        ArchetypeGeneration(ArchetypeId::EMPTY)
+        ArchetypeGeneration {
+            next: ArchetypeId::EMPTY,
+            compactions: 0,
+        }
     }
 }
 
@@ -779,6 +809,8 @@ pub type ComponentIndex = HashMap<ComponentId, HashMap<ArchetypeId, ArchetypeRec
 pub struct Archetypes {
     pub(crate) archetypes: Vec<Archetype>,
     archetype_component_count: usize,
+    /// the number of times archetypes were dropped by [`Archetypes::drop_empty`]
+    compactions: u32,
     /// find the archetype id by the archetype's components
     by_components: HashMap<ArchetypeComponents, ArchetypeId>,
     /// find all the archetypes that contain a component
@@ -803,6 +835,7 @@ impl Archetypes {
             by_components: Default::default(),
             by_component: Default::default(),
             archetype_component_count: 0,
+            compactions: 0,
         };
         // SAFETY: Empty archetype has no components
         unsafe {
@@ -824,8 +857,10 @@ impl Archetypes {
     /// function was called.
     #[inline]
     pub fn generation(&self) -> ArchetypeGeneration {
-        let id = ArchetypeId::new(self.archetypes.len());
-        ArchetypeGeneration(id)
+        ArchetypeGeneration {
+            next: ArchetypeId::new(self.archetypes.len()),
+            compactions: self.compactions,
+        }
     }
 
     /// Fetches the total number of [`Archetype`]s within the world.
@@ -924,6 +959,7 @@ impl Archetypes {
         };
 
         let archetypes = &mut self.archetypes;
+        let compactions = self.compactions;
         let archetype_component_count = &mut self.archetype_component_count;
         let component_index = &mut self.by_component;
         let archetype_id = *self
@@ -943,7 +979,7 @@ impl Archetypes {
                 *archetype_component_count += sparse_set_components.len();
                 let sparse_set_archetype_components =
                     (sparse_start..*archetype_component_count).map(ArchetypeComponentId);
-                archetypes.push(Archetype::new(
+                let mut archetype = Archetype::new(
                     components,
                     component_index,
                     observers,
@@ -957,7 +993,9 @@ impl Archetypes {
                         .iter()
                         .copied()
                         .zip(sparse_set_archetype_components),
-                ));
+                );
+                archetype.compactions = compactions;
+                archetypes.push(archetype);
                 id
             });
         archetype_id
@@ -977,6 +1015,62 @@ impl Archetypes {
         }
     }
 
+    /// Shrinks the capacity of every archetype as much as possible.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        for archetype in &mut self.archetypes {
+            archetype.shrink_to_fit();
+        }
+    }
+
+    /// Drops the archetypes that don't contain any entities, except the empty archetype, and gives
+    /// consecutive ids to the others.
+    ///
+    /// Returns `false` if no archetype was dropped. Otherwise, the ids of the archetypes, their
+    /// [`Edges`] and the [`ArchetypeGeneration`]s taken before are invalidated, and the locations of
+    /// the entities must be updated with the new ids.
+    pub(crate) fn drop_empty(&mut self) -> bool {
+        let is_dropped =
+            |archetype: &Archetype| archetype.is_empty() && archetype.id != ArchetypeId::EMPTY;
+        if !self.archetypes.iter().any(is_dropped) {
+            return false;
+        }
+
+        self.compactions += 1;
+        let mut new_ids = Vec::with_capacity(self.archetypes.len());
+        let mut next = 0;
+        self.archetypes.retain_mut(|archetype| {
+            if is_dropped(archetype) {
+                new_ids.push(None);
+                return false;
+            }
+            let id = ArchetypeId::new(next);
+            next += 1;
+            new_ids.push(Some(id));
+            archetype.id = id;
+            archetype.compactions = self.compactions;
+            // The edges are only a cache, they are computed again when they're traversed.
+            archetype.edges = Edges::default();
+            true
+        });
+        self.archetypes.shrink_to_fit();
+
+        self.by_components
+            .retain(|_, id| match new_ids[id.index()] {
+                Some(new_id) => {
+                    *id = new_id;
+                    true
+                }
+                None => false,
+            });
+        for records in self.by_component.values_mut() {
+            *records = core::mem::take(records)
+                .into_iter()
+                .filter_map(|(id, record)| new_ids[id.index()].map(|new_id| (new_id, record)))
+                .collect();
+        }
+        true
+    }
+
     /// Get the component index
     pub(crate) fn component_index(&self) -> &ComponentIndex {
         &self.by_component
@@ -1006,7 +1100,10 @@ impl Index<RangeFrom<ArchetypeGeneration>> for Archetypes {
 
     #[inline]
     fn index(&self, index: RangeFrom<ArchetypeGeneration>) -> &Self::Output {
-        &self.archetypes[index.start.0.index()..]
+        if index.start.compactions != self.compactions {
+            return &self.archetypes;
+        }
+        &self.archetypes[index.start.next.index()..]
     }
 }
 impl Index<ArchetypeId> for Archetypes {
@@ -184,7 +184,7 @@ pub unsafe trait Bundle: DynamicBundle + Send + Sync + 'static {
 pub trait DynamicBundle {
     // SAFETY:
     // The `StorageType` argument passed into [`Bundle::get_components`] must be correct for the
-    // component being fetched.
+    // component being fetched, unless the component has migratable storage.
     //
     /// Calls `func` on each value, in the order of this bundle's [`Component`]s. This passes
     /// ownership of the component values to `func`.
@@ -384,6 +384,8 @@ pub struct BundleInfo {
     component_ids: Vec<ComponentId>,
     required_components: Vec<RequiredComponentConstructor>,
     explicit_components_len: usize,
+    /// Whether any explicit component of the bundle has [migratable storage](Component::MIGRATABLE_STORAGE).
+    migratable_storage: bool,
 }
 
 impl BundleInfo {
@@ -428,10 +430,12 @@ impl BundleInfo {
 
         let explicit_components_len = component_ids.len();
         let mut required_components = RequiredComponents::default();
+        let mut migratable_storage = false;
         for component_id in component_ids.iter().copied() {
             // SAFETY: caller has verified that all ids are valid
             let info = unsafe { components.get_info_unchecked(component_id) };
             required_components.merge(info.required_components());
+            migratable_storage |= info.migratable_storage();
         }
         required_components.remove_explicit_components(&component_ids);
         let required_components = required_components
@@ -454,6 +458,7 @@ impl BundleInfo {
             component_ids,
             required_components,
             explicit_components_len,
+            migratable_storage,
         }
     }
 
@@ -545,6 +550,15 @@ impl BundleInfo {
         let mut bundle_component = 0;
         bundle.get_components(&mut |storage_type, component_ptr| {
             let component_id = *self.component_ids.get_unchecked(bundle_component);
+            // Components with migratable storage report the storage type they started out with,
+            // so check whether the target table stores them instead.
+            let storage_type = if !self.migratable_storage {
+                storage_type
+            } else if table.has_column(component_id) {
+                StorageType::Table
+            } else {
+                StorageType::SparseSet
+            };
             match storage_type {
                 StorageType::Table => {
                     // SAFETY: bundle_component is a valid index for this bundle
@@ -1101,11 +1115,16 @@ impl<'w> BundleInserter<'w> {
                 let new_archetype = new_archetype.as_mut();
 
                 // SAFETY: Mutable references do not alias and will be dropped after this block
-                let (sparse_sets, entities) = {
+                let (sparse_sets, entities, components) = {
                     let world = self.world.world_mut();
-                    (&mut world.storages.sparse_sets, &mut world.entities)
+                    (
+                        &mut world.storages.sparse_sets,
+                        &mut world.entities,
+                        &mut world.components,
+                    )
                 };
 
+                components.record_archetype_moves(archetype_after_insert.iter_added());
                 let result = archetype.swap_remove(location.archetype_row);
                 if let Some(swapped_entity) = result.swapped_entity {
                     let swapped_location =
@@ -1147,15 +1166,17 @@ impl<'w> BundleInserter<'w> {
                 let new_archetype = new_archetype.as_mut();
 
                 // SAFETY: Mutable references do not alias and will be dropped after this block
-                let (archetypes_ptr, sparse_sets, entities) = {
+                let (archetypes_ptr, sparse_sets, entities, components) = {
                     let world = self.world.world_mut();
                     let archetype_ptr: *mut Archetype = world.archetypes.archetypes.as_mut_ptr();
                     (
                         archetype_ptr,
                         &mut world.storages.sparse_sets,
                         &mut world.entities,
+                        &mut world.components,
                     )
                 };
+                components.record_archetype_moves(archetype_after_insert.iter_added());
                 let result = archetype.swap_remove(location.archetype_row);
                 if let Some(swapped_entity) = result.swapped_entity {
                     let swapped_location =
@@ -1565,6 +1586,29 @@ impl Bundles {
             .debug_checked_unwrap()
     }
 
+    /// Updates the cached storage types of dynamic bundles containing `component_id`, after
+    /// its storage was migrated to `storage_type`.
+    pub(crate) fn set_storage_type(
+        &mut self,
+        component_id: ComponentId,
+        storage_type: StorageType,
+    ) {
+        if let Some(bundle_id) = self.dynamic_component_bundle_ids.get(&component_id) {
+            self.dynamic_component_storages
+                .insert(*bundle_id, storage_type);
+        }
+        for (component_ids, bundle_id) in &self.dynamic_bundle_ids {
+            let Some(storages) = self.dynamic_bundle_storages.get_mut(bundle_id) else {
+                continue;
+            };
+            for (id, storage) in component_ids.iter().zip(storages) {
+                if *id == component_id {
+                    *storage = storage_type;
+                }
+            }
+        }
+    }
+
     /// Initializes a new [`BundleInfo`] for a dynamic [`Bundle`].
     ///
     /// # Panics
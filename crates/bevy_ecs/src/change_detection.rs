@@ -1,13 +1,16 @@
 //! Types that detect when their internal data mutate.
 
 use crate::{
-    component::{Tick, TickCells},
+    self as bevy_ecs,
+    component::{Component, ComponentCloneHandler, Mutable, StorageType, Tick, TickCells},
     ptr::PtrMut,
+    query::QueryData,
     system::Resource,
 };
-use alloc::borrow::ToOwned;
+use alloc::{borrow::ToOwned, boxed::Box, vec};
 use bevy_ptr::{Ptr, UnsafeCellDeref};
 use core::{
+    marker::PhantomData,
     mem,
     ops::{Deref, DerefMut},
 };
@@ -1244,6 +1247,218 @@ pub(crate) type MaybeThinSlicePtrLocation<'w> =
 #[cfg(not(feature = "track_location"))]
 pub(crate) type MaybeThinSlicePtrLocation<'w> = ();
 
+/// A [`Component`] that records when each of its fields was last changed, so that systems can
+/// react to changes of a single field with [`FieldChanged`](crate::query::FieldChanged).
+///
+/// This is implemented by `#[derive(Component)]` when the `#[component(track_fields)]` attribute
+/// is present, which also implements [`FieldAccess`] for every field and makes the component
+/// [require](Component#required-components) [`FieldTicks<Self>`].
+///
+/// Only changes made through [`TrackedMut`] are attributed to a single field.
+/// Any other mutable access, such as through [`Mut`], changes every field.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::change_detection::{field_index, TrackedMut};
+/// # use bevy_ecs::query::FieldChanged;
+/// #[derive(Component, Default)]
+/// #[component(track_fields)]
+/// struct Transform {
+///     translation: [f32; 3],
+///     rotation: [f32; 4],
+/// }
+///
+/// const TRANSLATION: usize = field_index::<Transform>("translation");
+/// const ROTATION: usize = field_index::<Transform>("rotation");
+///
+/// fn move_things(mut query: Query<TrackedMut<Transform>>) {
+///     for mut transform in &mut query {
+///         transform.field_mut::<TRANSLATION>()[0] += 1.0;
+///     }
+/// }
+///
+/// // Not triggered by `move_things`.
+/// fn rotated(query: Query<Entity, FieldChanged<Transform, ROTATION>>) {
+///     for entity in &query {
+///         println!("{entity} was rotated");
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(move_things);
+/// # bevy_ecs::system::assert_is_system(rotated);
+/// ```
+pub trait TrackedFields: Component<Mutability = Mutable> {
+    /// The names of the fields, in declaration order.
+    ///
+    /// The position of a name is the index of that field, as used by [`FieldAccess`].
+    /// Fields of tuple structs are named by their position, like `"0"`.
+    const FIELDS: &'static [&'static str];
+}
+
+/// Provides access to the field with index `FIELD` of a [`TrackedFields`] component.
+pub trait FieldAccess<const FIELD: usize>: TrackedFields {
+    /// The type of the field.
+    type Value;
+
+    /// Returns a mutable reference to the field.
+    fn field_mut(&mut self) -> &mut Self::Value;
+}
+
+/// Returns the index of the field called `name` of the [`TrackedFields`] component `T`.
+///
+/// # Panics
+///
+/// Panics if `T` has no field called `name`.
+/// When evaluated in a const context, such as a const generic argument, this is a compile error instead.
+pub const fn field_index<T: TrackedFields>(name: &str) -> usize {
+    let mut index = 0;
+    while index < T::FIELDS.len() {
+        let field = T::FIELDS[index].as_bytes();
+        let name = name.as_bytes();
+        if field.len() == name.len() {
+            let mut i = 0;
+            while i < field.len() && field[i] == name[i] {
+                i += 1;
+            }
+            if i == field.len() {
+                return index;
+            }
+        }
+        index += 1;
+    }
+    panic!("the component has no tracked field with this name");
+}
+
+/// Stores the tick at which each field of the [`TrackedFields`] component `T` was last changed.
+///
+/// This is a required component of `T`, so it is inserted automatically alongside it,
+/// and it uses the same [`StorageType`].
+pub struct FieldTicks<T: TrackedFields> {
+    ticks: Box<[Tick]>,
+    /// The change tick of `T` when a field was last changed through [`TrackedMut`].
+    /// If `T` has been changed since, it was changed in some other way, which changes every field.
+    synced: Tick,
+    marker: PhantomData<T>,
+}
+
+impl<T: TrackedFields> FieldTicks<T> {
+    /// Returns the tick at which the field with index `field` was last changed through [`TrackedMut`].
+    ///
+    /// This does not include changes made without [`TrackedMut`] that did not yet
+    /// get attributed to every field. Use [`FieldChanged`](crate::query::FieldChanged)
+    /// to check for changes to a field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `field` is not the index of a field of `T`.
+    pub fn last_changed(&self, field: usize) -> Tick {
+        self.ticks[field]
+    }
+
+    /// Returns `true` if the field with index `field` was changed after `last_run`,
+    /// where `changed` is the tick at which the component was last changed.
+    pub(crate) fn is_field_changed(
+        &self,
+        field: usize,
+        changed: Tick,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> bool {
+        changed.is_newer_than(last_run, this_run)
+            && (changed != self.synced || self.ticks[field].is_newer_than(last_run, this_run))
+    }
+}
+
+impl<T: TrackedFields> Default for FieldTicks<T> {
+    fn default() -> Self {
+        Self {
+            ticks: vec![Tick::new(0); T::FIELDS.len()].into_boxed_slice(),
+            synced: Tick::new(0),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: TrackedFields> Clone for FieldTicks<T> {
+    fn clone(&self) -> Self {
+        Self {
+            ticks: self.ticks.clone(),
+            synced: self.synced,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: TrackedFields> core::fmt::Debug for FieldTicks<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FieldTicks")
+            .field("ticks", &self.ticks)
+            .field("synced", &self.synced)
+            .finish()
+    }
+}
+
+impl<T: TrackedFields> Component for FieldTicks<T> {
+    const STORAGE_TYPE: StorageType = T::STORAGE_TYPE;
+    const STORES_TICKS: bool = true;
+    type Mutability = Mutable;
+
+    fn check_change_ticks(&mut self, change_tick: Tick) {
+        // `synced` is compared with the change tick of `T`, which is clamped the same way.
+        self.synced.check_tick(change_tick);
+        for tick in &mut self.ticks {
+            tick.check_tick(change_tick);
+        }
+    }
+
+    fn get_component_clone_handler() -> ComponentCloneHandler {
+        ComponentCloneHandler::clone_handler::<Self>()
+    }
+}
+
+/// [`QueryData`] that changes a [`TrackedFields`] component one field at a time,
+/// so that [`FieldChanged`](crate::query::FieldChanged) filters for other fields are not triggered.
+///
+/// See [`TrackedFields`] for an example.
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct TrackedMut<T: TrackedFields> {
+    value: &'static mut T,
+    ticks: &'static mut FieldTicks<T>,
+}
+
+impl<'w, T: TrackedFields> TrackedMutItem<'w, T> {
+    /// Returns a mutable reference to the field with index `FIELD`, and marks only that field as changed.
+    ///
+    /// The component itself is marked as changed too, like with [`Mut`].
+    pub fn field_mut<const FIELD: usize>(&mut self) -> &mut <T as FieldAccess<FIELD>>::Value
+    where
+        T: FieldAccess<FIELD>,
+    {
+        let changed = *self.value.ticks.changed;
+        let this_run = self.value.ticks.this_run;
+        let field_ticks = self.ticks.bypass_change_detection();
+        if changed != field_ticks.synced {
+            // The component was changed without `TrackedMut`, which changed every field.
+            field_ticks.ticks.fill(changed);
+        }
+        field_ticks.ticks[FIELD] = this_run;
+        field_ticks.synced = this_run;
+        <T as FieldAccess<FIELD>>::field_mut(&mut self.value)
+    }
+
+    /// Returns a reference to the component.
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the [`FieldTicks`] of the component.
+    pub fn field_ticks(&self) -> &FieldTicks<T> {
+        &self.ticks
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bevy_ecs_macros::Resource;
@@ -1256,10 +1471,12 @@ mod tests {
     use crate::{
         self as bevy_ecs,
         change_detection::{
-            Mut, NonSendMut, Ref, ResMut, TicksMut, CHECK_TICK_THRESHOLD, MAX_CHANGE_AGE,
+            field_index, FieldTicks, Mut, NonSendMut, Ref, ResMut, TicksMut, TrackedFields,
+            TrackedMut, CHECK_TICK_THRESHOLD, MAX_CHANGE_AGE,
         },
         component::{Component, ComponentTicks, Tick},
-        system::{IntoSystem, Single, System},
+        query::FieldChanged,
+        system::{IntoSystem, Query, Single, System},
         world::World,
     };
 
@@ -1616,4 +1833,123 @@ mod tests {
         assert_eq!(3, into_mut.ticks.last_run.get());
         assert_eq!(4, into_mut.ticks.this_run.get());
     }
+
+    #[derive(Component, Default)]
+    #[component(track_fields)]
+    struct Tracked {
+        a: u32,
+        b: u32,
+    }
+
+    #[derive(Component)]
+    #[component(track_fields, storage = "SparseSet")]
+    struct TrackedTuple(u8, u16);
+
+    const A: usize = field_index::<Tracked>("a");
+    const B: usize = field_index::<Tracked>("b");
+
+    #[test]
+    fn tracked_fields_derive() {
+        assert_eq!(Tracked::FIELDS, &["a", "b"]);
+        assert_eq!(TrackedTuple::FIELDS, &["0", "1"]);
+        assert_eq!((A, B), (0, 1));
+        assert_eq!(field_index::<TrackedTuple>("1"), 1);
+
+        let mut world = World::new();
+        let entity = world.spawn(TrackedTuple(1, 2)).id();
+        assert!(world.entity(entity).contains::<FieldTicks<TrackedTuple>>());
+    }
+
+    #[test]
+    fn field_changed() {
+        fn changed_a(query: Query<(), FieldChanged<Tracked, A>>) -> usize {
+            query.iter().count()
+        }
+        fn changed_b(query: Query<(), FieldChanged<Tracked, B>>) -> usize {
+            query.iter().count()
+        }
+        fn write_a(mut query: Query<TrackedMut<Tracked>>) {
+            for mut tracked in &mut query {
+                *tracked.field_mut::<A>() += 1;
+            }
+        }
+        fn write_untracked(mut query: Query<&mut Tracked>) {
+            for mut tracked in &mut query {
+                tracked.b += 1;
+            }
+        }
+
+        let mut world = World::new();
+        let mut changed_a = IntoSystem::into_system(changed_a);
+        let mut changed_b = IntoSystem::into_system(changed_b);
+        let mut write_a = IntoSystem::into_system(write_a);
+        let mut write_untracked = IntoSystem::into_system(write_untracked);
+        changed_a.initialize(&mut world);
+        changed_b.initialize(&mut world);
+        write_a.initialize(&mut world);
+        write_untracked.initialize(&mut world);
+
+        // Inserting the component changes every field.
+        world.spawn(Tracked::default());
+        assert_eq!(changed_a.run((), &mut world), 1);
+        assert_eq!(changed_b.run((), &mut world), 1);
+        assert_eq!(changed_a.run((), &mut world), 0);
+        assert_eq!(changed_b.run((), &mut world), 0);
+
+        // Tracked writes only change their own field.
+        write_a.run((), &mut world);
+        assert_eq!(changed_a.run((), &mut world), 1);
+        assert_eq!(changed_b.run((), &mut world), 0);
+
+        // Other writes change every field.
+        write_untracked.run((), &mut world);
+        assert_eq!(changed_a.run((), &mut world), 1);
+        assert_eq!(changed_b.run((), &mut world), 1);
+
+        // An untracked write is not hidden by a later tracked write.
+        write_untracked.run((), &mut world);
+        write_a.run((), &mut world);
+        assert_eq!(changed_a.run((), &mut world), 1);
+        assert_eq!(changed_b.run((), &mut world), 1);
+
+        let (tracked, ticks) = world
+            .query::<(&Tracked, &FieldTicks<Tracked>)>()
+            .single(&world);
+        assert_eq!((tracked.a, tracked.b), (2, 2));
+        assert!(ticks
+            .last_changed(A)
+            .is_newer_than(ticks.last_changed(B), world.change_tick()));
+    }
+
+    #[test]
+    fn field_ticks_scan() {
+        fn write_a(mut query: Query<TrackedMut<Tracked>>) {
+            for mut tracked in &mut query {
+                *tracked.field_mut::<A>() += 1;
+            }
+        }
+
+        let mut world = World::new();
+        world.spawn(Tracked::default());
+        let mut write_a = IntoSystem::into_system(write_a);
+        write_a.initialize(&mut world);
+        write_a.run((), &mut world);
+
+        // a bunch of stuff happens, the field ticks are now older than `MAX_CHANGE_AGE`
+        *world.change_tick.get_mut() += MAX_CHANGE_AGE + CHECK_TICK_THRESHOLD;
+        let change_tick = world.change_tick();
+
+        // scan change ticks and clamp those at risk of overflow, field ticks included
+        world.check_change_ticks();
+
+        let (tracked, ticks) = world
+            .query::<(Ref<Tracked>, &FieldTicks<Tracked>)>()
+            .single(&world);
+        for field in [A, B] {
+            let ticks_since_change = change_tick.relative_to(ticks.last_changed(field)).get();
+            assert_eq!(ticks_since_change, MAX_CHANGE_AGE);
+        }
+        // The last change is still attributed to the field it was made through.
+        assert_eq!(ticks.synced, *tracked.ticks.changed);
+    }
 }
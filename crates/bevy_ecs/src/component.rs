@@ -15,7 +15,7 @@ use crate::{
 use alloc::boxed::Box;
 use alloc::{borrow::Cow, format, vec::Vec};
 pub use bevy_ecs_macros::Component;
-use bevy_ptr::{OwningPtr, UnsafeCellDeref};
+use bevy_ptr::{OwningPtr, PtrMut, UnsafeCellDeref};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
 use bevy_utils::{HashMap, HashSet, TypeIdMap};
@@ -88,6 +88,11 @@ pub use bevy_ecs_macros::require;
 /// attribute when using the derive macro.
 /// See the documentation for [`ComponentMutability`] for more details around this
 /// feature.
+/// Components deriving a [relationship](crate::relationship) are always immutable.
+///
+/// Mutable components can record when each of their fields was changed by adding the
+/// `#[component(track_fields)]` attribute, so that systems can react to a single field.
+/// See [`TrackedFields`] for more details.
 ///
 /// See the [`entity`] module level documentation to learn how to add or remove components from an entity.
 ///
@@ -96,6 +101,7 @@ pub use bevy_ecs_macros::require;
 /// [`entity`]: crate::entity#usage
 /// [`Query`]: crate::system::Query
 /// [`ComponentMutability`]: crate::component::ComponentMutability
+/// [`TrackedFields`]: crate::change_detection::TrackedFields
 ///
 /// # Choosing a storage type
 ///
@@ -113,6 +119,22 @@ pub use bevy_ecs_macros::require;
 /// struct ComponentA;
 /// ```
 ///
+/// When the best choice depends on how the component ends up being used, it can be marked with
+/// `#[component(migratable_storage)]`. Its data can then be moved between storages at runtime with
+/// [`World::migrate_component_storage`], guided by [`ComponentInfo::archetype_moves`]:
+///
+/// ```
+/// # use bevy_ecs::{component::{Component, StorageType}, world::World};
+/// #
+/// #[derive(Component)]
+/// #[component(migratable_storage)]
+/// struct Burning;
+///
+/// let mut world = World::new();
+/// world.spawn(Burning);
+/// world.migrate_component_storage::<Burning>(StorageType::SparseSet);
+/// ```
+///
 /// [`Table`]: crate::storage::Table
 /// [`SparseSet`]: crate::storage::SparseSet
 ///
@@ -395,6 +417,35 @@ pub trait Component: Send + Sync + 'static {
     /// A constant indicating the storage type used for this component.
     const STORAGE_TYPE: StorageType;
 
+    /// Whether [`World::migrate_component_storage`] may move this component to another
+    /// [`StorageType`] at runtime. When `true`, [`Self::STORAGE_TYPE`] is only the storage
+    /// type the component starts out with.
+    ///
+    /// Queries can't know where such a component is stored at compile time, so queries
+    /// accessing it are never iterated densely over tables.
+    ///
+    /// This is controlled through `#[component(migratable_storage)]` when using the derive macro.
+    const MIGRATABLE_STORAGE: bool = false;
+
+    /// Whether this component stores [`Tick`]s that [`Self::check_change_ticks`] needs to clamp.
+    ///
+    /// When `true`, [`World::check_change_ticks`] calls [`Self::check_change_ticks`] on every
+    /// value of this component, in the same pass that clamps the change ticks of components.
+    ///
+    /// This is an implementation detail of [`FieldTicks`](crate::change_detection::FieldTicks)
+    /// and not part of the public API.
+    #[doc(hidden)]
+    const STORES_TICKS: bool = false;
+
+    /// Clamps the [`Tick`]s stored in this component, so that they don't get older than
+    /// [`MAX_CHANGE_AGE`](crate::change_detection::MAX_CHANGE_AGE) relative to `change_tick`,
+    /// like the change ticks of components. Only called if [`Self::STORES_TICKS`] is `true`.
+    ///
+    /// This is an implementation detail of [`FieldTicks`](crate::change_detection::FieldTicks)
+    /// and not part of the public API.
+    #[doc(hidden)]
+    fn check_change_ticks(&mut self, _change_tick: Tick) {}
+
     /// A marker type to assist Bevy with determining if this component is
     /// mutable, or immutable. Mutable components will have [`Component<Mutability = Mutable>`],
     /// while immutable components will instead have [`Component<Mutability = Immutable>`].
@@ -690,6 +741,7 @@ pub struct ComponentInfo {
     hooks: ComponentHooks,
     required_components: RequiredComponents,
     required_by: HashSet<ComponentId>,
+    archetype_moves: u64,
 }
 
 impl ComponentInfo {
@@ -741,6 +793,36 @@ impl ComponentInfo {
         self.descriptor.storage_type
     }
 
+    /// Returns `true` if the storage type of this component can be changed at runtime.
+    ///
+    /// See [`Component::MIGRATABLE_STORAGE`].
+    #[inline]
+    pub fn migratable_storage(&self) -> bool {
+        self.descriptor.migratable_storage
+    }
+
+    /// Returns the function that clamps the ticks stored in values of this component,
+    /// if it [stores any](Component::STORES_TICKS).
+    #[inline]
+    pub(crate) fn check_change_ticks_fn(&self) -> Option<for<'a> unsafe fn(PtrMut<'a>, Tick)> {
+        self.descriptor.check_change_ticks
+    }
+
+    /// Returns how many times an entity moved to another archetype because this component
+    /// was inserted into or removed from it, since the counts were last reset with
+    /// [`World::reset_archetype_moves`].
+    ///
+    /// Components that cause many archetype moves are usually better off in a
+    /// [`StorageType::SparseSet`], which avoids moving the other components of the entity
+    /// to a new table.
+    ///
+    /// Archetype moves are only counted for components with
+    /// [migratable storage](Self::migratable_storage), this is always zero for other components.
+    #[inline]
+    pub fn archetype_moves(&self) -> u64 {
+        self.archetype_moves
+    }
+
     /// Returns `true` if the underlying component type can be freely shared between threads.
     /// If this returns `false`, then extra care must be taken to ensure that components
     /// are not accessed from the wrong thread.
@@ -757,6 +839,7 @@ impl ComponentInfo {
             hooks: Default::default(),
             required_components: Default::default(),
             required_by: Default::default(),
+            archetype_moves: 0,
         }
     }
 
@@ -852,8 +935,11 @@ impl SparseSetIndex for ComponentId {
 pub struct ComponentDescriptor {
     name: Cow<'static, str>,
     // SAFETY: This must remain private. It must match the statically known StorageType of the
-    // associated rust component type if one exists.
+    // associated rust component type if one exists, unless that type has `MIGRATABLE_STORAGE`.
     storage_type: StorageType,
+    // SAFETY: This must remain private. It must only be set to "true" if the associated rust
+    // component type, if one exists, has `MIGRATABLE_STORAGE`.
+    migratable_storage: bool,
     // SAFETY: This must remain private. It must only be set to "true" if this component is
     // actually Send + Sync
     is_send_and_sync: bool,
@@ -863,15 +949,20 @@ pub struct ComponentDescriptor {
     // this descriptor describes.
     // None if the underlying type doesn't need to be dropped
     drop: Option<for<'a> unsafe fn(OwningPtr<'a>)>,
+    // SAFETY: this function must be safe to call with pointers pointing to items of the type
+    // this descriptor describes.
+    // None if the underlying type doesn't store ticks
+    check_change_ticks: Option<for<'a> unsafe fn(PtrMut<'a>, Tick)>,
     mutable: bool,
 }
 
-// We need to ignore the `drop` field in our `Debug` impl
+// We need to ignore the `drop` and `check_change_ticks` fields in our `Debug` impl
 impl Debug for ComponentDescriptor {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("ComponentDescriptor")
             .field("name", &self.name)
             .field("storage_type", &self.storage_type)
+            .field("migratable_storage", &self.migratable_storage)
             .field("is_send_and_sync", &self.is_send_and_sync)
             .field("type_id", &self.type_id)
             .field("layout", &self.layout)
@@ -891,15 +982,27 @@ impl ComponentDescriptor {
         }
     }
 
+    /// # Safety
+    ///
+    /// `x` must point to a valid value of type `T`.
+    unsafe fn check_change_ticks_ptr<T: Component>(x: PtrMut<'_>, change_tick: Tick) {
+        // SAFETY: Contract is required to be upheld by the caller.
+        unsafe {
+            x.deref_mut::<T>().check_change_ticks(change_tick);
+        }
+    }
+
     /// Create a new `ComponentDescriptor` for the type `T`.
     pub fn new<T: Component>() -> Self {
         Self {
             name: Cow::Borrowed(core::any::type_name::<T>()),
             storage_type: T::STORAGE_TYPE,
+            migratable_storage: T::MIGRATABLE_STORAGE,
             is_send_and_sync: true,
             type_id: Some(TypeId::of::<T>()),
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
+            check_change_ticks: T::STORES_TICKS.then_some(Self::check_change_ticks_ptr::<T> as _),
             mutable: T::Mutability::MUTABLE,
         }
     }
@@ -919,10 +1022,12 @@ impl ComponentDescriptor {
         Self {
             name: name.into(),
             storage_type,
+            migratable_storage: false,
             is_send_and_sync: true,
             type_id: None,
             layout,
             drop,
+            check_change_ticks: None,
             mutable,
         }
     }
@@ -936,10 +1041,12 @@ impl ComponentDescriptor {
             // PERF: `SparseStorage` may actually be a more
             // reasonable choice as `storage_type` for resources.
             storage_type: StorageType::Table,
+            migratable_storage: false,
             is_send_and_sync: true,
             type_id: Some(TypeId::of::<T>()),
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
+            check_change_ticks: None,
             mutable: true,
         }
     }
@@ -948,10 +1055,12 @@ impl ComponentDescriptor {
         Self {
             name: Cow::Borrowed(core::any::type_name::<T>()),
             storage_type,
+            migratable_storage: false,
             is_send_and_sync: false,
             type_id: Some(TypeId::of::<T>()),
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
+            check_change_ticks: None,
             mutable: true,
         }
     }
@@ -1095,9 +1204,36 @@ pub struct Components {
     indices: TypeIdMap<ComponentId>,
     resource_indices: TypeIdMap<ComponentId>,
     component_clone_handlers: ComponentCloneHandlers,
+    /// Whether a component with migratable storage was registered, in which case archetype moves
+    /// are counted.
+    has_migratable_storage: bool,
+    // SAFETY: This must remain private. Every component it lists must be stored as a
+    // `Box<dyn PartialReflect>`.
+    #[cfg(feature = "bevy_reflect")]
+    dynamic_components: crate::reflect::DynamicComponents,
 }
 
 impl Components {
+    /// Returns the [`DynamicComponents`](crate::reflect::DynamicComponents) registered with
+    /// [`World::register_dynamic_component`].
+    #[cfg(feature = "bevy_reflect")]
+    #[inline]
+    pub fn dynamic_components(&self) -> &crate::reflect::DynamicComponents {
+        &self.dynamic_components
+    }
+
+    /// # Safety
+    ///
+    /// Every component added to the returned [`DynamicComponents`](crate::reflect::DynamicComponents)
+    /// must be stored as a `Box<dyn PartialReflect>`.
+    #[cfg(feature = "bevy_reflect")]
+    #[inline]
+    pub(crate) unsafe fn dynamic_components_mut(
+        &mut self,
+    ) -> &mut crate::reflect::DynamicComponents {
+        &mut self.dynamic_components
+    }
+
     /// Registers a [`Component`] of type `T` with this instance.
     /// If a component of this type has already been registered, this will return
     /// the ID of the pre-existing component.
@@ -1136,6 +1272,7 @@ impl Components {
             })
         };
         if is_new_registration {
+            self.has_migratable_storage |= T::MIGRATABLE_STORAGE;
             let mut required_components = RequiredComponents::default();
             T::register_required_components(
                 id,
@@ -1171,6 +1308,7 @@ impl Components {
         storages: &mut Storages,
         descriptor: ComponentDescriptor,
     ) -> ComponentId {
+        self.has_migratable_storage |= descriptor.migratable_storage;
         Components::register_component_inner(&mut self.components, storages, descriptor)
     }
 
@@ -1182,7 +1320,11 @@ impl Components {
     ) -> ComponentId {
         let component_id = ComponentId(components.len());
         let info = ComponentInfo::new(component_id, descriptor);
-        if info.descriptor.storage_type == StorageType::SparseSet {
+        // Components with migratable storage always get a sparse set, so that queries
+        // can look them up there no matter where they are currently stored.
+        if info.descriptor.storage_type == StorageType::SparseSet
+            || info.descriptor.migratable_storage
+        {
             storages.sparse_sets.get_or_insert(&info);
         }
         components.push(info);
@@ -1228,6 +1370,54 @@ impl Components {
         unsafe { self.components.get_unchecked(id.0) }
     }
 
+    /// Returns the current [`StorageType`] of the component `T`, registered as `id`.
+    ///
+    /// This is [`Component::STORAGE_TYPE`] unless `T` has [migratable storage](Component::MIGRATABLE_STORAGE).
+    #[inline]
+    pub(crate) fn storage_type_of<T: Component>(&self, id: ComponentId) -> StorageType {
+        if T::MIGRATABLE_STORAGE {
+            self.components[id.0].descriptor.storage_type
+        } else {
+            T::STORAGE_TYPE
+        }
+    }
+
+    /// Changes the recorded [`StorageType`] of a component with migratable storage.
+    ///
+    /// This does not move any component data; see [`World::migrate_component_storage_by_id`].
+    #[inline]
+    pub(crate) fn set_storage_type(&mut self, id: ComponentId, storage_type: StorageType) {
+        let descriptor = &mut self.components[id.0].descriptor;
+        debug_assert!(descriptor.migratable_storage);
+        descriptor.storage_type = storage_type;
+    }
+
+    /// Records that an entity moved to another archetype because the components `ids` were
+    /// inserted into or removed from it.
+    ///
+    /// Only moves caused by components with migratable storage are counted, so this does nothing
+    /// unless such a component was registered.
+    #[inline]
+    pub(crate) fn record_archetype_moves(&mut self, ids: impl IntoIterator<Item = ComponentId>) {
+        if !self.has_migratable_storage {
+            return;
+        }
+        for id in ids {
+            if let Some(info) = self.components.get_mut(id.0) {
+                if info.descriptor.migratable_storage {
+                    info.archetype_moves += 1;
+                }
+            }
+        }
+    }
+
+    /// Resets the [archetype move](ComponentInfo::archetype_moves) counts of all components.
+    pub(crate) fn reset_archetype_moves(&mut self) {
+        for info in &mut self.components {
+            info.archetype_moves = 0;
+        }
+    }
+
     #[inline]
     pub(crate) fn get_hooks_mut(&mut self, id: ComponentId) -> Option<&mut ComponentHooks> {
         self.components.get_mut(id.0).map(|info| &mut info.hooks)
@@ -2073,10 +2263,19 @@ impl RequiredComponents {
                       table_row,
                       entity,
                       #[cfg(feature = "track_location")] caller| {
+                    // Components with migratable storage may have been moved since the constructor
+                    // was created, in which case the target table knows where they live now.
+                    let storage_type = if !C::MIGRATABLE_STORAGE {
+                        C::STORAGE_TYPE
+                    } else if table.has_column(component_id) {
+                        StorageType::Table
+                    } else {
+                        StorageType::SparseSet
+                    };
                     OwningPtr::make(constructor(), |ptr| {
                         // SAFETY: This will only be called in the context of `BundleInfo::write_components`, which will
                         // pass in a valid table_row and entity requiring a C constructor
-                        // C::STORAGE_TYPE is the storage type associated with `component_id` / `C`
+                        // `storage_type` is the storage type associated with `component_id` / `C`
                         // `ptr` points to valid `C` data, which matches the type associated with `component_id`
                         unsafe {
                             BundleInfo::initialize_required_component(
@@ -2086,7 +2285,7 @@ impl RequiredComponents {
                                 table_row,
                                 entity,
                                 component_id,
-                                C::STORAGE_TYPE,
+                                storage_type,
                                 ptr,
                                 #[cfg(feature = "track_location")]
                                 caller,
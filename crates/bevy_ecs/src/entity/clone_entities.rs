@@ -16,12 +16,73 @@ use alloc::sync::Arc;
 
 use crate::{
     bundle::Bundle,
-    component::{Component, ComponentCloneHandler, ComponentId, ComponentInfo, Components},
-    entity::Entity,
+    component::{
+        Component, ComponentCloneFn, ComponentCloneHandler, ComponentId, ComponentInfo, Components,
+    },
+    entity::{Entity, EntityHashMap, EntityHashSet, EntityMapper, MapEntities, VisitEntities},
     query::DebugCheckedUnwrap,
-    world::World,
+    relationship::{Relationship, RelationshipTarget},
+    world::{DeferredWorld, World},
 };
 
+/// Type-erased [`VisitEntities::visit_entities`] for a component behind a [`Ptr`].
+type VisitComponentEntitiesFn = unsafe fn(Ptr, &mut dyn FnMut(Entity));
+
+/// Type-erased [`MapEntities::map_entities`] for a component behind a [`PtrMut`].
+type MapComponentEntitiesFn = unsafe fn(PtrMut, &mut dyn EntityMapper);
+
+/// # Safety
+/// `ptr` must point to a valid `T`.
+unsafe fn visit_component_entities<T: VisitEntities>(ptr: Ptr, f: &mut dyn FnMut(Entity)) {
+    ptr.deref::<T>().visit_entities(f);
+}
+
+/// # Safety
+/// `ptr` must point to a valid `T`.
+unsafe fn visit_relationship_target_entities<T: RelationshipTarget>(
+    ptr: Ptr,
+    f: &mut dyn FnMut(Entity),
+) {
+    ptr.deref::<T>().iter().for_each(f);
+}
+
+/// # Safety
+/// `ptr` must point to a valid `T`.
+unsafe fn map_component_entities<T: MapEntities>(ptr: PtrMut, mut mapper: &mut dyn EntityMapper) {
+    ptr.deref_mut::<T>().map_entities(&mut mapper);
+}
+
+/// A component whose [`Entity`] references are followed by [`EntityCloneBuilder::clone_entities`].
+#[derive(Clone, Copy, Debug)]
+struct LinkedComponent {
+    visit_entities: VisitComponentEntitiesFn,
+    /// The [`Relationship`] of this component and its clone handler, if it is a [`RelationshipTarget`].
+    relationship: Option<(ComponentId, ComponentCloneFn)>,
+}
+
+/// Maps the entities that are being cloned to their clones, leaving every other entity untouched.
+struct ClonedEntityMapper<'a>(&'a EntityHashMap<Entity>);
+
+impl EntityMapper for ClonedEntityMapper<'_> {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.0.get(&entity).copied().unwrap_or(entity)
+    }
+}
+
+/// Clone handler for a [`Relationship`] that is followed by [`EntityCloneBuilder::follow_relationship`].
+/// The relationship is only cloned if it points at an entity that is also being cloned.
+fn component_clone_relationship<R: Relationship>(
+    _world: &mut DeferredWorld,
+    ctx: &mut ComponentCloneCtx,
+) {
+    let Some(target) = ctx.read_source_component::<R>().map(Relationship::get) else {
+        return;
+    };
+    if let Some(target) = ctx.cloned_entity(target) {
+        ctx.write_target_component(R::from(target));
+    }
+}
+
 /// Context for component clone handlers.
 ///
 /// Provides fast access to useful resources like [`AppTypeRegistry`](crate::reflect::AppTypeRegistry)
@@ -87,6 +148,16 @@ impl<'a, 'b> ComponentCloneCtx<'a, 'b> {
         self.entity_cloner.target
     }
 
+    /// Returns the entity that `entity` is cloned into, if it is one of the entities being cloned
+    /// together by [`EntityCloneBuilder::clone_entities`].
+    pub fn cloned_entity(&self, entity: Entity) -> Option<Entity> {
+        self.entity_cloner
+            .entity_map
+            .as_ref()?
+            .get(&entity)
+            .copied()
+    }
+
     /// Returns the [`ComponentId`] of the component being cloned.
     pub fn component_id(&self) -> ComponentId {
         self.component_id
@@ -275,6 +346,8 @@ pub struct EntityCloner {
     filter: Arc<HashSet<ComponentId>>,
     clone_handlers_overrides: Arc<HashMap<ComponentId, ComponentCloneHandler>>,
     move_components: bool,
+    entity_map: Option<Arc<EntityHashMap<Entity>>>,
+    mapped_components: Arc<HashMap<ComponentId, MapComponentEntitiesFn>>,
 }
 
 impl EntityCloner {
@@ -350,6 +423,38 @@ impl EntityCloner {
             }
         }
 
+        if let Some(entity_map) = &self.entity_map {
+            let mut mapper = ClonedEntityMapper(entity_map);
+            for (component, ptr) in component_ids.iter().zip(component_data_ptrs.iter_mut()) {
+                if let Some(map_entities) = self.mapped_components.get(component) {
+                    // SAFETY: `ptr` holds the cloned data of `component`, which `map_entities` was registered for.
+                    unsafe { map_entities(ptr.reborrow(), &mut mapper) };
+                    continue;
+                }
+                #[cfg(feature = "bevy_reflect")]
+                if let Some(registry) = type_registry {
+                    let registry = registry.read();
+                    // SAFETY: `component` is from the same world as `components`.
+                    let type_id = unsafe { components.get_info_unchecked(*component) }.type_id();
+                    let Some((reflect_from_ptr, reflect_map_entities)) =
+                        type_id.and_then(|type_id| {
+                            let from_ptr =
+                                registry.get_type_data::<bevy_reflect::ReflectFromPtr>(type_id)?;
+                            let map_entities = registry
+                                .get_type_data::<crate::reflect::ReflectMapEntities>(type_id)?;
+                            (from_ptr.type_id() == type_id).then_some((from_ptr, map_entities))
+                        })
+                    else {
+                        continue;
+                    };
+                    // SAFETY: `ptr` holds the cloned data of `component`, which `reflect_from_ptr` was registered for.
+                    let reflect = unsafe { reflect_from_ptr.as_reflect_mut(ptr.reborrow()) };
+                    reflect_map_entities
+                        .map_entities(reflect.as_partial_reflect_mut(), &mut mapper);
+                }
+            }
+        }
+
         world.flush();
 
         if !world.entities.contains(self.target) {
@@ -385,6 +490,8 @@ impl EntityCloner {
             target,
             filter: self.filter.clone(),
             clone_handlers_overrides: self.clone_handlers_overrides.clone(),
+            entity_map: self.entity_map.clone(),
+            mapped_components: self.mapped_components.clone(),
             ..*self
         }
     }
@@ -448,6 +555,39 @@ impl EntityCloner {
 /// 3. component-defined handler using [`get_component_clone_handler`](Component::get_component_clone_handler)
 /// 4. default handler override using [`set_default_handler`](crate::component::ComponentCloneHandlers::set_default_handler)
 /// 5. reflect-based or noop default clone handler depending on if `bevy_reflect` feature is enabled or not.
+///
+/// # Cloning linked entities
+/// Components that store references to other entities can be followed using [`follow`](Self::follow)
+/// or [`follow_relationship`](Self::follow_relationship). Every entity reachable through followed components
+/// is cloned along with the source entity, and all [`Entity`] references stored in the cloned components
+/// are remapped to point at the clones instead of the originals. References to entities that are not
+/// cloned are left untouched.
+///
+/// Besides followed components, references are remapped for components registered with
+/// [`map_entities`](Self::map_entities), and, if the `bevy_reflect` feature is enabled, for components
+/// registered with `ReflectMapEntities` in the [`AppTypeRegistry`](crate::reflect::AppTypeRegistry).
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_ecs::entity::{EntityCloneBuilder, VisitEntities, VisitEntitiesMut};
+///
+/// #[derive(Component, Clone, VisitEntities, VisitEntitiesMut)]
+/// struct Linked(Entity);
+///
+/// let mut world = World::default();
+///
+/// let a = world.spawn_empty().id();
+/// let b = world.spawn(Linked(a)).id();
+/// world.entity_mut(a).insert(Linked(b));
+///
+/// let mut builder = EntityCloneBuilder::new(&mut world);
+/// builder.follow::<Linked>();
+/// let clones = builder.clone_entities([a]);
+///
+/// let (a_clone, b_clone) = (clones[&a], clones[&b]);
+/// assert_eq!(world.get::<Linked>(a_clone).unwrap().0, b_clone);
+/// assert_eq!(world.get::<Linked>(b_clone).unwrap().0, a_clone);
+/// ```
 #[derive(Debug)]
 pub struct EntityCloneBuilder<'w> {
     world: &'w mut World,
@@ -456,6 +596,8 @@ pub struct EntityCloneBuilder<'w> {
     clone_handlers_overrides: HashMap<ComponentId, ComponentCloneHandler>,
     attach_required_components: bool,
     move_components: bool,
+    linked_components: HashMap<ComponentId, LinkedComponent>,
+    mapped_components: HashMap<ComponentId, MapComponentEntitiesFn>,
 }
 
 impl<'w> EntityCloneBuilder<'w> {
@@ -468,17 +610,30 @@ impl<'w> EntityCloneBuilder<'w> {
             clone_handlers_overrides: Default::default(),
             attach_required_components: true,
             move_components: false,
+            linked_components: Default::default(),
+            mapped_components: Default::default(),
         }
     }
 
     /// Finishes configuring the builder and clones `source` entity to `target`.
+    ///
+    /// If any components are [followed](Self::follow), all entities linked to `source` are cloned
+    /// into newly spawned entities as well.
     pub fn clone_entity(self, source: Entity, target: Entity) {
+        if !self.linked_components.is_empty() {
+            let mut entity_map = EntityHashMap::default();
+            entity_map.insert(source, target);
+            self.clone_linked(entity_map, [source]);
+            return;
+        }
+
         let EntityCloneBuilder {
             world,
             filter_allows_components,
             filter,
             clone_handlers_overrides,
             move_components,
+            mapped_components,
             ..
         } = self;
 
@@ -489,10 +644,103 @@ impl<'w> EntityCloneBuilder<'w> {
             filter: Arc::new(filter),
             clone_handlers_overrides: Arc::new(clone_handlers_overrides),
             move_components,
+            entity_map: None,
+            mapped_components: Arc::new(mapped_components),
         }
         .clone_entity(world);
     }
 
+    /// Finishes configuring the builder and clones `sources`, along with every entity linked to them
+    /// through [followed](Self::follow) components, into newly spawned entities.
+    ///
+    /// [`Entity`] references between the cloned entities are remapped to point at the clones.
+    /// Returns the map from each cloned entity to its clone.
+    pub fn clone_entities(
+        self,
+        sources: impl IntoIterator<Item = Entity>,
+    ) -> EntityHashMap<Entity> {
+        self.clone_linked(EntityHashMap::default(), sources)
+    }
+
+    /// Collects `sources` and all entities linked to them, spawns clones for the ones that are
+    /// missing from `entity_map` and clones every collected entity.
+    fn clone_linked(
+        self,
+        mut entity_map: EntityHashMap<Entity>,
+        sources: impl IntoIterator<Item = Entity>,
+    ) -> EntityHashMap<Entity> {
+        let EntityCloneBuilder {
+            world,
+            filter_allows_components,
+            filter,
+            clone_handlers_overrides,
+            move_components,
+            linked_components,
+            mapped_components,
+            ..
+        } = self;
+
+        let mut queue: Vec<Entity> = sources.into_iter().collect();
+        let mut visited = EntityHashSet::default();
+        let mut order = Vec::new();
+        let mut next = 0;
+        while let Some(&entity) = queue.get(next) {
+            next += 1;
+            if !visited.insert(entity) {
+                continue;
+            }
+            let Ok(entity_ref) = world.get_entity(entity) else {
+                continue;
+            };
+            order.push(entity);
+            for component in entity_ref.archetype().components() {
+                let Some(linked) = linked_components.get(&component) else {
+                    continue;
+                };
+                // SAFETY: `component` is from the archetype of `entity_ref`.
+                let ptr = unsafe { entity_ref.get_by_id(component).debug_checked_unwrap() };
+                // SAFETY: `visit_entities` was registered for `component`.
+                unsafe { (linked.visit_entities)(ptr, &mut |linked| queue.push(linked)) };
+            }
+        }
+
+        for &source in &order {
+            entity_map
+                .entry(source)
+                .or_insert_with(|| world.spawn_empty().id());
+        }
+
+        // Relationships of followed relationship targets are only cloned between linked entities,
+        // unless their handler is overridden.
+        let mut handlers: HashMap<ComponentId, ComponentCloneHandler> = linked_components
+            .values()
+            .filter_map(|linked| linked.relationship)
+            .map(|(relationship, handler)| {
+                (relationship, ComponentCloneHandler::custom_handler(handler))
+            })
+            .collect();
+        handlers.extend(clone_handlers_overrides);
+
+        let entity_map = Arc::new(entity_map);
+        let mut cloner = EntityCloner {
+            source: Entity::PLACEHOLDER,
+            target: Entity::PLACEHOLDER,
+            filter_allows_components,
+            filter: Arc::new(filter),
+            clone_handlers_overrides: Arc::new(handlers),
+            move_components,
+            entity_map: Some(entity_map.clone()),
+            mapped_components: Arc::new(mapped_components),
+        };
+        for source in order {
+            cloner.source = source;
+            cloner.target = entity_map[&source];
+            cloner.clone_entity(world);
+        }
+
+        entity_map.as_ref().clone()
+    }
+
     /// By default, any components allowed/denied through the filter will automatically
     /// allow/deny all of their required components.
     ///
@@ -621,6 +869,72 @@ impl<'w> EntityCloneBuilder<'w> {
         self
     }
 
+    /// Follows the [`Entity`] references stored in component `T`: every entity referenced by `T`
+    /// is cloned along with the entity holding it, recursively.
+    ///
+    /// References stored in `T` are also remapped to the clones, like with [`map_entities`](Self::map_entities).
+    /// Whether `T` itself is cloned is still decided by the filter and its [`ComponentCloneHandler`].
+    ///
+    /// See [Cloning linked entities](EntityCloneBuilder#cloning-linked-entities) for more details.
+    pub fn follow<T: Component + VisitEntities + MapEntities>(&mut self) -> &mut Self {
+        let id = self.world.register_component::<T>();
+        self.linked_components.insert(
+            id,
+            LinkedComponent {
+                visit_entities: visit_component_entities::<T>,
+                relationship: None,
+            },
+        );
+        self.mapped_components
+            .insert(id, map_component_entities::<T>);
+        self
+    }
+
+    /// Follows the source entities of the [`RelationshipTarget`] `T`, such as the children of an entity.
+    ///
+    /// Every linked entity is cloned, and its [`Relationship`] is cloned to point at the clone of its
+    /// target if that target is cloned as well. The [`RelationshipTarget`] of the clones is rebuilt
+    /// from the cloned relationships.
+    ///
+    /// See [Cloning linked entities](EntityCloneBuilder#cloning-linked-entities) for more details.
+    pub fn follow_relationship<T: RelationshipTarget>(&mut self) -> &mut Self {
+        let id = self.world.register_component::<T>();
+        let relationship = self.world.register_component::<T::Relationship>();
+        self.linked_components.insert(
+            id,
+            LinkedComponent {
+                visit_entities: visit_relationship_target_entities::<T>,
+                relationship: Some((
+                    relationship,
+                    component_clone_relationship::<T::Relationship>,
+                )),
+            },
+        );
+        self
+    }
+
+    /// Stops following component `T` that was previously followed using [`follow`](Self::follow)
+    /// or [`follow_relationship`](Self::follow_relationship).
+    pub fn unfollow<T: Component>(&mut self) -> &mut Self {
+        if let Some(id) = self.world.components().component_id::<T>() {
+            if self.linked_components.remove(&id).is_some() {
+                self.mapped_components.remove(&id);
+            }
+        }
+        self
+    }
+
+    /// Remaps the [`Entity`] references stored in component `T` when cloning linked entities,
+    /// without following them.
+    ///
+    /// See [Cloning linked entities](EntityCloneBuilder#cloning-linked-entities) for more details.
+    pub fn map_entities<T: Component + MapEntities>(&mut self) -> &mut Self {
+        let id = self.world.register_component::<T>();
+        self.mapped_components
+            .insert(id, map_component_entities::<T>);
+        self
+    }
+
     /// Helper function that allows a component through the filter.
     fn filter_allow(&mut self, id: ComponentId) {
         if self.filter_allows_components {
@@ -668,19 +982,20 @@ mod tests {
     use crate::{
         self as bevy_ecs,
         component::{Component, ComponentCloneHandler, ComponentDescriptor, StorageType},
-        entity::EntityCloneBuilder,
+        entity::{Entity, EntityCloneBuilder},
         world::{DeferredWorld, World},
     };
-    use alloc::vec::Vec;
-    use bevy_ecs_macros::require;
+    use alloc::{vec, vec::Vec};
+    use bevy_ecs_macros::{require, VisitEntities, VisitEntitiesMut};
     use bevy_ptr::OwningPtr;
     use core::alloc::Layout;
 
     #[cfg(feature = "bevy_reflect")]
     mod reflect {
         use super::*;
-        use crate::reflect::{AppTypeRegistry, ReflectComponent, ReflectFromWorld};
-        use alloc::vec;
+        use crate::reflect::{
+            AppTypeRegistry, ReflectComponent, ReflectFromWorld, ReflectMapEntities,
+        };
         use bevy_reflect::{std_traits::ReflectDefault, FromType, Reflect, ReflectFromPtr};
 
         #[test]
@@ -879,6 +1194,30 @@ mod tests {
             assert_eq!(world.get::<A>(e_clone), None);
             assert_eq!(world.get::<B>(e_clone), None);
         }
+
+        #[test]
+        fn clone_entities_remaps_reflected_entities() {
+            #[derive(Component, Clone, VisitEntities, VisitEntitiesMut)]
+            struct Linked(Entity);
+
+            #[derive(Component, Reflect, Clone, VisitEntities, VisitEntitiesMut)]
+            #[reflect(Component, MapEntities)]
+            struct Target(Entity);
+
+            let mut world = World::default();
+            world.init_resource::<AppTypeRegistry>();
+            let registry = world.get_resource::<AppTypeRegistry>().unwrap();
+            registry.write().register::<Target>();
+
+            let a = world.spawn_empty().id();
+            let b = world.spawn((Linked(a), Target(a))).id();
+
+            let mut builder = EntityCloneBuilder::new(&mut world);
+            builder.follow::<Linked>();
+            let clones = builder.clone_entities([b]);
+
+            assert_eq!(world.get::<Target>(clones[&b]).unwrap().0, clones[&a]);
+        }
     }
 
     #[test]
@@ -1114,4 +1453,73 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn clone_entities_remaps_linked_entities() {
+        #[derive(Component, Clone, VisitEntities, VisitEntitiesMut)]
+        struct Linked(Entity);
+
+        #[derive(Component, Clone, VisitEntities, VisitEntitiesMut)]
+        struct Target(Entity);
+
+        let mut world = World::default();
+        let outside = world.spawn_empty().id();
+        let a = world.spawn(Target(outside)).id();
+        let b = world.spawn((Linked(a), Target(a))).id();
+        world.entity_mut(a).insert(Linked(b));
+
+        let mut builder = EntityCloneBuilder::new(&mut world);
+        builder.follow::<Linked>().map_entities::<Target>();
+        let clones = builder.clone_entities([a]);
+
+        assert_eq!(clones.len(), 2);
+        let (a_clone, b_clone) = (clones[&a], clones[&b]);
+        assert_eq!(world.get::<Linked>(a_clone).unwrap().0, b_clone);
+        assert_eq!(world.get::<Linked>(b_clone).unwrap().0, a_clone);
+        assert_eq!(world.get::<Target>(a_clone).unwrap().0, outside);
+        assert_eq!(world.get::<Target>(b_clone).unwrap().0, a_clone);
+
+        assert_eq!(world.get::<Linked>(a).unwrap().0, b);
+        assert_eq!(world.get::<Target>(b).unwrap().0, a);
+    }
+
+    #[test]
+    fn clone_entity_follows_relationships() {
+        #[derive(Component)]
+        #[relationship(relationship_target = Children)]
+        struct ChildOf(Entity);
+
+        #[derive(Component)]
+        #[relationship_target(relationship = ChildOf)]
+        struct Children(Vec<Entity>);
+
+        let mut world = World::default();
+        let parent = world.spawn_empty().id();
+        let root = world.spawn(ChildOf(parent)).id();
+        let child_1 = world.spawn(ChildOf(root)).id();
+        let child_2 = world.spawn(ChildOf(root)).id();
+        let grandchild = world.spawn(ChildOf(child_1)).id();
+
+        let root_clone = world.spawn_empty().id();
+        let mut builder = EntityCloneBuilder::new(&mut world);
+        builder.follow_relationship::<Children>();
+        builder.clone_entity(root, root_clone);
+
+        // The relationship to an entity that wasn't cloned is skipped.
+        assert!(world.get::<ChildOf>(root_clone).is_none());
+        assert_eq!(world.get::<Children>(parent).unwrap().0, vec![root]);
+
+        let children = world.get::<Children>(root_clone).unwrap().0.clone();
+        assert_eq!(children.len(), 2);
+        assert!(!children.contains(&child_1) && !children.contains(&child_2));
+        let grandchildren = &world.get::<Children>(children[0]).unwrap().0;
+        assert_eq!(grandchildren.len(), 1);
+        assert_ne!(grandchildren[0], grandchild);
+        assert!(world.get::<Children>(children[1]).is_none());
+
+        assert_eq!(
+            world.get::<Children>(root).unwrap().0,
+            vec![child_1, child_2]
+        );
+    }
 }
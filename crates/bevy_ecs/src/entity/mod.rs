@@ -71,6 +71,8 @@ use crate::{
 };
 use alloc::vec::Vec;
 use core::{fmt, hash::Hash, mem, num::NonZero};
+#[cfg(feature = "bevy_reflect")]
+use fixedbitset::FixedBitSet;
 use log::warn;
 
 #[cfg(feature = "track_location")]
@@ -829,6 +831,62 @@ impl Entities {
         self.len = 0;
     }
 
+    /// Captures the generation of every entity index and the list of free indices,
+    /// so [`restore_allocator`](Self::restore_allocator) can make the same entities available again.
+    ///
+    /// Entities that are reserved but not flushed yet are considered free.
+    #[cfg(feature = "bevy_reflect")]
+    pub(crate) fn snapshot_allocator(&self) -> AllocatorSnapshot {
+        AllocatorSnapshot {
+            generations: self.meta.iter().map(|meta| meta.generation).collect(),
+            pending: self.pending.clone(),
+        }
+    }
+
+    /// Restores the state captured by [`snapshot_allocator`](Self::snapshot_allocator).
+    ///
+    /// The entities alive in the snapshot must be alive with the same generation. Entities that are
+    /// free in the snapshot but still alive are kept allocated, and the free indices are restored
+    /// around them.
+    #[cfg(feature = "bevy_reflect")]
+    pub(crate) fn restore_allocator(&mut self, snapshot: &AllocatorSnapshot) {
+        self.verify_flushed();
+        debug_assert!(
+            snapshot.alive().all(|entity| self.get(entity).is_some()),
+            "entities that are alive in the snapshot must be alive before restoring it"
+        );
+
+        let is_alive = |meta: &EntityMeta| meta.location.archetype_id != ArchetypeId::INVALID;
+        let len = self
+            .meta
+            .iter()
+            .rposition(is_alive)
+            .map_or(0, |index| index + 1)
+            .max(snapshot.generations.len());
+        // Indices past the end of the snapshot are only allocated once its free indices run out.
+        let mut pending = (snapshot.generations.len()..len)
+            .filter(|&index| !is_alive(&self.meta[index]))
+            .map(|index| index as u32)
+            .collect::<Vec<_>>();
+        pending.extend(
+            snapshot
+                .pending
+                .iter()
+                .copied()
+                .filter(|&index| !self.meta.get(index as usize).is_some_and(is_alive)),
+        );
+
+        self.meta.resize(len, EntityMeta::EMPTY);
+        for (meta, &generation) in self.meta.iter_mut().zip(&snapshot.generations) {
+            if !is_alive(meta) {
+                meta.generation = generation;
+            }
+        }
+        self.pending = pending;
+        *self.free_cursor.get_mut() = self.pending.len() as IdCursor;
+        self.len = (self.meta.len() - self.pending.len()) as u32;
+    }
+
     /// Returns the location of an [`Entity`].
     /// Note: for pending entities, returns `Some(EntityLocation::INVALID)`.
     #[inline]
@@ -1049,6 +1107,34 @@ impl fmt::Display for EntityDoesNotExistDetails {
     }
 }
 
+/// The allocation state of [`Entities`], captured by [`Entities::snapshot_allocator`].
+#[cfg(feature = "bevy_reflect")]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AllocatorSnapshot {
+    generations: Vec<NonZero<u32>>,
+    pending: Vec<u32>,
+}
+
+#[cfg(feature = "bevy_reflect")]
+impl AllocatorSnapshot {
+    /// Returns the entities that were alive when the snapshot was taken.
+    pub(crate) fn alive(&self) -> impl Iterator<Item = Entity> + '_ {
+        let mut free = FixedBitSet::with_capacity(self.generations.len());
+        free.extend(self.pending.iter().map(|&index| index as usize));
+        self.generations
+            .iter()
+            .enumerate()
+            .filter(move |(index, _)| !free.contains(*index))
+            .map(|(index, &generation)| Entity::from_raw_and_generation(index as u32, generation))
+    }
+
+    /// Returns the entity that was allocated at `index` when the snapshot was taken, if any.
+    pub(crate) fn entity_at(&self, index: u32) -> Option<Entity> {
+        let generation = *self.generations.get(index as usize)?;
+        Some(Entity::from_raw_and_generation(index, generation))
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 struct EntityMeta {
     /// The current generation of the [`Entity`].
@@ -0,0 +1,253 @@
+//! Types for disabling entities without despawning them.
+//!
+//! Disabling an entity hides it from queries, while keeping all of its components (and its [`Entity`] id) intact.
+//! This is useful for things like pooled projectiles, or level chunks that are temporarily hidden:
+//! re-enabling the entity is just a matter of removing the [`Disabled`] component again.
+//!
+//! Filtering is done through the [`DefaultQueryFilters`] resource, which every [`World`] starts with.
+//! When a [`QueryState`](crate::query::QueryState) is created, every "disabling" component registered in
+//! [`DefaultQueryFilters`] is added to the query as a [`Without`](crate::query::Without) filter,
+//! unless the query already mentions that component itself.
+//!
+//! To include disabled entities in a query, mention the disabling component explicitly,
+//! for example with [`Has<Disabled>`](crate::query::Has), [`With<Disabled>`](crate::query::With)
+//! or `Option<&Disabled>`.
+//! Accessing all components through [`EntityRef`](crate::world::EntityRef) or [`EntityMut`](crate::world::EntityMut)
+//! does not count as mentioning the disabling components.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::entity_disabling::Disabled;
+//! #[derive(Component)]
+//! struct Projectile;
+//!
+//! let mut world = World::new();
+//! let active = world.spawn(Projectile).id();
+//! let pooled = world.spawn((Projectile, Disabled)).id();
+//!
+//! // Disabled entities are skipped by default...
+//! let mut query = world.query_filtered::<Entity, With<Projectile>>();
+//! assert_eq!(query.iter(&world).collect::<Vec<_>>(), [active]);
+//!
+//! // ...unless the query explicitly asks for them.
+//! let mut query = world.query_filtered::<(Entity, Has<Disabled>), With<Projectile>>();
+//! assert_eq!(query.iter(&world).count(), 2);
+//!
+//! // Re-enabling an entity is just removing the marker.
+//! world.entity_mut(pooled).remove::<Disabled>();
+//! let mut query = world.query_filtered::<Entity, With<Projectile>>();
+//! assert_eq!(query.iter(&world).count(), 2);
+//! ```
+//!
+//! Note that the default filters are captured when a query state is created, so changing
+//! [`DefaultQueryFilters`] will not affect queries that already exist.
+
+use crate::{
+    self as bevy_ecs,
+    component::{ComponentId, Components, StorageType},
+    query::FilteredAccess,
+    world::{FromWorld, World},
+};
+use bevy_ecs_macros::{Component, Resource};
+use smallvec::SmallVec;
+
+#[cfg(feature = "bevy_reflect")]
+use {
+    crate::reflect::ReflectComponent,
+    bevy_reflect::{std_traits::ReflectDefault, Reflect},
+};
+
+#[cfg(doc)]
+use crate::entity::Entity;
+
+/// A marker component for disabled entities.
+///
+/// Entities with this component are excluded from queries by default.
+/// See the [module docs](crate::entity_disabling) for more information.
+#[derive(Component, Clone, Debug, Default)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, Debug)
+)]
+pub struct Disabled;
+
+/// The default filters applied to every query created from a [`World`].
+///
+/// Each registered "disabling" component is added to new queries as a
+/// [`Without`](crate::query::Without) filter, unless the query already mentions that component.
+/// By default, only [`Disabled`] is registered.
+///
+/// See the [module docs](crate::entity_disabling) for more information.
+#[derive(Resource, Debug)]
+pub struct DefaultQueryFilters {
+    disabling: SmallVec<[ComponentId; 4]>,
+}
+
+impl FromWorld for DefaultQueryFilters {
+    fn from_world(world: &mut World) -> Self {
+        let mut filters = DefaultQueryFilters::empty();
+        let disabled_component_id = world.register_component::<Disabled>();
+        filters.register_disabling_component(disabled_component_id);
+        filters
+    }
+}
+
+impl DefaultQueryFilters {
+    /// Creates a new, completely empty [`DefaultQueryFilters`].
+    ///
+    /// This is provided as an escape hatch; in most cases you should initialize this using [`FromWorld`],
+    /// which is automatically called when creating a new [`World`].
+    pub fn empty() -> Self {
+        DefaultQueryFilters {
+            disabling: SmallVec::new(),
+        }
+    }
+
+    /// Adds this [`ComponentId`] to the set of disabling components.
+    ///
+    /// Entities with this component will be excluded from queries created afterwards,
+    /// unless the query explicitly mentions the component.
+    pub fn register_disabling_component(&mut self, component_id: ComponentId) {
+        if !self.disabling.contains(&component_id) {
+            self.disabling.push(component_id);
+        }
+    }
+
+    /// Returns an iterator over the [`ComponentId`]s of all registered disabling components.
+    pub fn disabling_ids(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.disabling.iter().copied()
+    }
+
+    /// Adds a `Without` filter for every disabling component not already mentioned by `component_access`.
+    pub(crate) fn modify_access(&self, component_access: &mut FilteredAccess<ComponentId>) {
+        for component_id in self.disabling_ids() {
+            if !component_access.contains(component_id) {
+                component_access.and_without(component_id);
+            }
+        }
+    }
+
+    /// Returns `true` if all disabling components are stored in tables,
+    /// meaning that the filters added by [`Self::modify_access`] keep a dense query dense.
+    pub(crate) fn is_dense(&self, components: &Components) -> bool {
+        self.disabling_ids().all(|component_id| {
+            components.get_info(component_id).is_some_and(|info| {
+                info.storage_type() == StorageType::Table && !info.migratable_storage()
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::{Component, Entity, EntityRef, Has, QueryBuilder, With},
+        query::Without,
+        world::FilteredEntityRef,
+    };
+    use alloc::vec::Vec;
+
+    #[derive(Component)]
+    struct A;
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct CustomDisabled;
+
+    #[test]
+    fn disabled_entities_are_filtered_by_default() {
+        let mut world = World::new();
+        let enabled = world.spawn(A).id();
+        let disabled = world.spawn((A, Disabled)).id();
+
+        let mut query = world.query::<(Entity, &A)>();
+        assert_eq!(
+            query.iter(&world).map(|(e, _)| e).collect::<Vec<_>>(),
+            [enabled]
+        );
+        assert!(query.get(&world, disabled).is_err());
+
+        let mut query = world.query::<EntityRef>();
+        assert!(query.get(&world, disabled).is_err());
+
+        let mut query = world.query_filtered::<Entity, Without<A>>();
+        assert_eq!(query.iter(&world).count(), 0);
+    }
+
+    #[test]
+    fn queries_can_opt_into_disabled_entities() {
+        let mut world = World::new();
+        world.spawn(A);
+        let disabled = world.spawn((A, Disabled)).id();
+
+        let mut query = world.query::<(&A, Has<Disabled>)>();
+        assert_eq!(query.iter(&world).count(), 2);
+
+        let mut query = world.query_filtered::<Entity, With<Disabled>>();
+        assert_eq!(query.iter(&world).collect::<Vec<_>>(), [disabled]);
+
+        let mut query = world.query::<(&A, Option<&Disabled>)>();
+        assert_eq!(query.iter(&world).count(), 2);
+    }
+
+    #[test]
+    fn query_builder_uses_default_filters() {
+        let mut world = World::new();
+        world.spawn(A);
+        world.spawn((A, Disabled));
+
+        let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+            .data::<&A>()
+            .build();
+        assert_eq!(query.iter(&world).count(), 1);
+
+        let disabled_id = world.register_component::<Disabled>();
+        let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+            .data::<&A>()
+            .optional(|builder| {
+                builder.ref_id(disabled_id);
+            })
+            .build();
+        let entities = query.iter(&world).collect::<Vec<_>>();
+        assert_eq!(entities.len(), 2);
+        assert_eq!(
+            entities
+                .iter()
+                .filter(|entity| entity.contains::<Disabled>())
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn custom_disabling_components() {
+        let mut world = World::new();
+        let custom_id = world.register_component::<CustomDisabled>();
+        world
+            .resource_mut::<DefaultQueryFilters>()
+            .register_disabling_component(custom_id);
+
+        world.spawn(A);
+        world.spawn((A, Disabled));
+        world.spawn((A, CustomDisabled));
+
+        let mut query = world.query::<&A>();
+        assert_eq!(query.iter(&world).count(), 1);
+
+        let mut query = world.query::<(&A, Has<CustomDisabled>)>();
+        assert_eq!(query.iter(&world).count(), 2);
+    }
+
+    #[test]
+    fn default_filters_can_be_removed() {
+        let mut world = World::new();
+        world.spawn(A);
+        world.spawn((A, Disabled));
+
+        world.insert_resource(DefaultQueryFilters::empty());
+        let mut query = world.query::<&A>();
+        assert_eq!(query.iter(&world).count(), 2);
+    }
+}
@@ -4,12 +4,27 @@ use bevy_ecs::{
     event::{Event, EventCursor, EventId, EventInstance},
     system::Resource,
 };
+use concurrent_queue::ConcurrentQueue;
 #[cfg(feature = "track_location")]
 use core::panic::Location;
 use core::{
+    any::type_name,
     marker::PhantomData,
     ops::{Deref, DerefMut},
 };
+use log::warn;
+
+#[cfg(feature = "portable-atomic")]
+use portable_atomic_util::Arc;
+
+#[cfg(not(feature = "portable-atomic"))]
+use alloc::sync::Arc;
+
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::{AtomicUsize, Ordering};
 #[cfg(feature = "bevy_reflect")]
 use {
     bevy_ecs::reflect::ReflectResource,
@@ -85,10 +100,49 @@ use {
 /// but can be done by adding your event as a resource instead of using
 /// [`add_event`](https://docs.rs/bevy/*/bevy/app/struct.App.html#method.add_event).
 ///
+/// # Retention
+///
+/// Readers that don't run every frame, like systems gated behind a run condition or running in
+/// `FixedUpdate`, can miss events with the default double buffering. Events created with
+/// [`EventRetention::UntilRead`] are instead kept until every registered reader has read them,
+/// up to a maximum number of events:
+///
+/// ```
+/// use bevy_ecs::event::{Event, EventOverflow, EventRetention, Events};
+///
+/// #[derive(Event)]
+/// struct LevelUp;
+///
+/// let mut events = Events::<LevelUp>::with_retention(EventRetention::UntilRead {
+///     capacity: 64,
+///     overflow: EventOverflow::DropOldestWithWarning,
+/// });
+/// let mut fast = events.get_cursor();
+/// let mut slow = events.get_cursor();
+/// // Readers register themselves when they first read.
+/// assert_eq!(fast.read(&events).count(), 0);
+/// assert_eq!(slow.read(&events).count(), 0);
+///
+/// events.send(LevelUp);
+/// assert_eq!(fast.read(&events).count(), 1);
+/// events.update();
+/// events.update();
+///
+/// // The event is kept until the slow reader has read it.
+/// assert_eq!(slow.read(&events).count(), 1);
+/// events.update();
+/// assert!(events.is_empty());
+/// ```
+///
+/// [`EventReader`]s and [`EventMutator`]s are registered when their system is initialized, so
+/// they don't miss events sent before they first run. Other [`EventCursor`]s are registered the
+/// first time they read events, and stop counting as readers once they are dropped.
+///
 /// [Example usage.](https://github.com/bevyengine/bevy/blob/latest/examples/ecs/event.rs)
 /// [Example usage standalone.](https://github.com/bevyengine/bevy/blob/latest/crates/bevy_ecs/examples/events.rs)
 ///
 /// [`EventReader`]: super::EventReader
+/// [`EventMutator`]: super::EventMutator
 /// [`EventWriter`]: super::EventWriter
 /// [`event_update_system`]: super::event_update_system
 #[derive(Debug, Resource)]
@@ -100,6 +154,15 @@ pub struct Events<E: Event> {
     /// Holds the newer events.
     pub(crate) events_b: EventSequence<E>,
     pub(crate) event_count: usize,
+    /// How long events are kept.
+    retention: EventRetention,
+    /// The readers of the events, if they are kept until read.
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    readers: EventReaders,
+    /// The number of events dropped because of [`EventRetention::UntilRead::capacity`] since
+    /// the last update.
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    overflowed: usize,
 }
 
 // Derived Default impl would incorrectly require E: Default
@@ -109,11 +172,49 @@ impl<E: Event> Default for Events<E> {
             events_a: Default::default(),
             events_b: Default::default(),
             event_count: Default::default(),
+            retention: Default::default(),
+            readers: Default::default(),
+            overflowed: Default::default(),
         }
     }
 }
 
 impl<E: Event> Events<E> {
+    /// Creates an empty event collection that keeps its events according to `retention`.
+    pub fn with_retention(retention: EventRetention) -> Self {
+        Self {
+            retention,
+            ..Default::default()
+        }
+    }
+
+    /// Returns how long events are kept.
+    pub fn retention(&self) -> EventRetention {
+        self.retention
+    }
+
+    /// Changes how long events are kept, starting with the next [`Events::update`].
+    pub fn set_retention(&mut self, retention: EventRetention) {
+        self.retention = retention;
+    }
+
+    /// Registers `cursor` as a reader of these events, if they are kept
+    /// [until read](EventRetention::UntilRead).
+    ///
+    /// Events are then kept until `cursor` has read them, as long as it isn't dropped.
+    /// This is done automatically when a cursor first reads events, so this only needs to be
+    /// called for cursors that should keep events before they read for the first time.
+    pub fn register_cursor(&self, cursor: &mut EventCursor<E>) {
+        if !matches!(self.retention, EventRetention::UntilRead { .. }) || cursor.progress.is_some()
+        {
+            return;
+        }
+        let progress = Arc::new(AtomicUsize::new(cursor.last_event_count));
+        // An unbounded queue can only fail to push when closed, which never happens.
+        let _ = self.readers.registered.push(progress.clone());
+        cursor.progress = Some(progress);
+    }
+
     /// Returns the index of the oldest event stored in the event buffer.
     pub fn oldest_event_count(&self) -> usize {
         self.events_a.start_event_count
@@ -147,6 +248,7 @@ impl<E: Event> Events<E> {
 
         let event_instance = EventInstance { event_id, event };
 
+        self.make_room(1);
         self.events_b.push(event_instance);
         self.event_count += 1;
 
@@ -196,8 +298,16 @@ impl<E: Event> Events<E> {
     /// Swaps the event buffers and clears the oldest event buffer. In general, this should be
     /// called once per frame/update.
     ///
+    /// If the events are kept [until read](EventRetention::UntilRead), only the events that
+    /// every registered reader has read are removed instead.
+    ///
     /// If you need access to the events that were removed, consider using [`Events::update_drain`].
     pub fn update(&mut self) {
+        if let EventRetention::UntilRead { .. } = self.retention {
+            let read = self.retain_unread();
+            self.events_a.drain(..read);
+            return;
+        }
         core::mem::swap(&mut self.events_a, &mut self.events_b);
         self.events_b.clear();
         self.events_b.start_event_count = self.event_count;
@@ -210,18 +320,97 @@ impl<E: Event> Events<E> {
     /// Swaps the event buffers and drains the oldest event buffer, returning an iterator
     /// of all events that were removed. In general, this should be called once per frame/update.
     ///
+    /// If the events are kept [until read](EventRetention::UntilRead), only the events that
+    /// every registered reader has read are removed instead.
+    ///
     /// If you do not need to take ownership of the removed events, use [`Events::update`] instead.
     #[must_use = "If you do not need the returned events, call .update() instead."]
     pub fn update_drain(&mut self) -> impl Iterator<Item = E> + '_ {
-        core::mem::swap(&mut self.events_a, &mut self.events_b);
-        let iter = self.events_b.events.drain(..);
+        let iter = if let EventRetention::UntilRead { .. } = self.retention {
+            let read = self.retain_unread();
+            self.events_a.events.drain(..read)
+        } else {
+            core::mem::swap(&mut self.events_a, &mut self.events_b);
+            let iter = self.events_b.events.drain(..);
+            self.events_b.start_event_count = self.event_count;
+            debug_assert_eq!(
+                self.events_a.start_event_count + self.events_a.len(),
+                self.events_b.start_event_count
+            );
+            iter
+        };
+
+        iter.map(|e| e.event)
+    }
+
+    /// Moves all events to the oldest buffer and returns how many of them every registered reader
+    /// has read, advancing the start of the oldest buffer past them.
+    ///
+    /// The caller is expected to remove that many events from the front of the oldest buffer.
+    fn retain_unread(&mut self) -> usize {
+        if self.overflowed > 0 {
+            if let EventRetention::UntilRead {
+                capacity,
+                overflow: EventOverflow::DropOldestWithWarning,
+            } = self.retention
+            {
+                warn!(
+                    "{} events of type `{}` were dropped because more than {capacity} events were waiting to be read",
+                    self.overflowed,
+                    type_name::<E>(),
+                );
+            }
+            self.overflowed = 0;
+        }
+
+        let events_b = core::mem::take(&mut self.events_b.events);
+        self.events_a.extend(events_b);
         self.events_b.start_event_count = self.event_count;
+
+        // Events are kept until a reader registers, so that they aren't lost before any reader
+        // had a chance to run.
+        let read = self
+            .readers
+            .oldest_read()
+            .map_or(0, |read| {
+                read.saturating_sub(self.events_a.start_event_count)
+            })
+            .min(self.events_a.len());
+        self.events_a.start_event_count += read;
         debug_assert_eq!(
-            self.events_a.start_event_count + self.events_a.len(),
+            self.events_a.start_event_count + self.events_a.len() - read,
             self.events_b.start_event_count
         );
+        read
+    }
 
-        iter.map(|e| e.event)
+    /// Drops the oldest events as needed so that `additional` events can be sent without
+    /// exceeding [`EventRetention::UntilRead::capacity`].
+    fn make_room(&mut self, additional: usize) {
+        let EventRetention::UntilRead { capacity, overflow } = self.retention else {
+            return;
+        };
+        let excess = (self.len() + additional).saturating_sub(capacity);
+        if excess == 0 {
+            return;
+        }
+        if overflow == EventOverflow::Panic {
+            panic!(
+                "Events of type `{}` exceeded their capacity of {capacity} unread events",
+                type_name::<E>()
+            );
+        }
+        self.overflowed += excess;
+
+        let from_a = excess.min(self.events_a.len());
+        self.events_a.drain(..from_a);
+        self.events_a.start_event_count += from_a;
+        let from_b = (excess - from_a).min(self.events_b.len());
+        self.events_b.drain(..from_b);
+        self.events_b.start_event_count += from_b;
+        if self.events_a.is_empty() {
+            self.events_a.start_event_count = self.events_b.start_event_count;
+        }
     }
 
     #[inline]
@@ -305,7 +494,21 @@ impl<E: Event> Extend<E> for Events<E> {
     {
         let old_count = self.event_count;
         let mut event_count = self.event_count;
-        let events = iter.into_iter().map(|event| {
+        let iter = iter.into_iter();
+        if let EventRetention::UntilRead { .. } = self.retention {
+            // Make room for events one at a time, as the number of events isn't known up front.
+            #[cfg(feature = "track_location")]
+            let caller = Location::caller();
+            for event in iter {
+                self.send_with_caller(
+                    event,
+                    #[cfg(feature = "track_location")]
+                    caller,
+                );
+            }
+            return;
+        }
+        let events = iter.map(|event| {
             let event_id = EventId {
                 id: event_count,
                 #[cfg(feature = "track_location")]
@@ -331,6 +534,81 @@ impl<E: Event> Extend<E> for Events<E> {
     }
 }
 
+/// How long [`Events`] keep the events sent to them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, PartialEq)
+)]
+pub enum EventRetention {
+    /// Events are kept for two [`Events::update`] calls, so readers must read at least once
+    /// every other update to not miss any events.
+    #[default]
+    DoubleBuffered,
+    /// Events are kept until every registered reader has read them.
+    ///
+    /// See the [`Events`] documentation for how readers are registered.
+    UntilRead {
+        /// The maximum number of events kept at once.
+        capacity: usize,
+        /// What happens when an event is sent while `capacity` events are kept.
+        overflow: EventOverflow,
+    },
+}
+
+/// What happens when an event is sent to [`Events`] that are kept
+/// [until read](EventRetention::UntilRead) and are already at capacity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, PartialEq)
+)]
+pub enum EventOverflow {
+    /// The oldest event is dropped, and a warning with the number of dropped events is logged on
+    /// the next [`Events::update`].
+    #[default]
+    DropOldestWithWarning,
+    /// The oldest event is dropped silently.
+    DropOldest,
+    /// Panics.
+    Panic,
+}
+
+/// The registered readers of [`Events`] that are kept [until read](EventRetention::UntilRead).
+#[derive(Debug)]
+struct EventReaders {
+    /// Readers registered since the last update, which can happen during
+    /// [`EventReader::read`](super::EventReader::read) without mutable access to the [`Events`].
+    registered: ConcurrentQueue<Arc<AtomicUsize>>,
+    /// The number of events each reader has seen.
+    readers: Vec<Arc<AtomicUsize>>,
+}
+
+impl Default for EventReaders {
+    fn default() -> Self {
+        Self {
+            registered: ConcurrentQueue::unbounded(),
+            readers: Vec::new(),
+        }
+    }
+}
+
+impl EventReaders {
+    /// Returns the number of events every reader has seen, or `None` if there are no readers.
+    fn oldest_read(&mut self) -> Option<usize> {
+        self.readers.extend(self.registered.try_iter());
+        // Readers whose cursor was dropped no longer hold on to events.
+        self.readers
+            .retain(|progress| Arc::strong_count(progress) > 1);
+        self.readers
+            .iter()
+            .map(|progress| progress.load(Ordering::Relaxed))
+            .min()
+    }
+}
+
 #[derive(Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
 pub(crate) struct EventSequence<E: Event> {
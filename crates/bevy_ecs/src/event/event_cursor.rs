@@ -1,10 +1,31 @@
 use crate as bevy_ecs;
-use bevy_ecs::event::{
-    Event, EventIterator, EventIteratorWithId, EventMutIterator, EventMutIteratorWithId, Events,
-};
 #[cfg(feature = "multi_threaded")]
 use bevy_ecs::event::{EventMutParIter, EventParIter};
-use core::marker::PhantomData;
+use bevy_ecs::{
+    component::Tick,
+    event::{
+        Event, EventIterator, EventIteratorWithId, EventMutIterator, EventMutIteratorWithId, Events,
+    },
+    system::{ReadOnlySystemParam, SystemMeta, SystemParam},
+    world::{unsafe_world_cell::UnsafeWorldCell, World},
+};
+use bevy_utils::synccell::SyncCell;
+use core::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+#[cfg(feature = "portable-atomic")]
+use portable_atomic_util::Arc;
+
+#[cfg(not(feature = "portable-atomic"))]
+use alloc::sync::Arc;
+
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::{AtomicUsize, Ordering};
 
 /// Stores the state for an [`EventReader`] or [`EventMutator`].
 ///
@@ -53,6 +74,9 @@ use core::marker::PhantomData;
 #[derive(Debug)]
 pub struct EventCursor<E: Event> {
     pub(super) last_event_count: usize,
+    /// Shares `last_event_count` with the [`Events`] this cursor is registered with, if they are
+    /// kept [until read](super::EventRetention::UntilRead).
+    pub(super) progress: Option<Arc<AtomicUsize>>,
     pub(super) _marker: PhantomData<E>,
 }
 
@@ -60,21 +84,39 @@ impl<E: Event> Default for EventCursor<E> {
     fn default() -> Self {
         EventCursor {
             last_event_count: 0,
+            progress: None,
             _marker: Default::default(),
         }
     }
 }
 
+/// The clone is not registered as a reader of any [`Events`] until it first reads them.
 impl<E: Event> Clone for EventCursor<E> {
     fn clone(&self) -> Self {
         EventCursor {
             last_event_count: self.last_event_count,
+            progress: None,
             _marker: PhantomData,
         }
     }
 }
 
 impl<E: Event> EventCursor<E> {
+    /// Sets the number of events this cursor has seen.
+    #[inline]
+    pub(super) fn set_last_event_count(&mut self, last_event_count: usize) {
+        self.last_event_count = last_event_count;
+        if let Some(progress) = &self.progress {
+            progress.store(last_event_count, Ordering::Relaxed);
+        }
+    }
+
+    /// Marks `count` more events as seen by this cursor.
+    #[inline]
+    pub(super) fn advance(&mut self, count: usize) {
+        self.set_last_event_count(self.last_event_count + count);
+    }
+
     /// See [`EventReader::read`](super::EventReader::read)
     pub fn read<'a>(&'a mut self, events: &'a Events<E>) -> EventIterator<'a, E> {
         self.read_with_id(events).without_id()
@@ -87,6 +129,7 @@ impl<E: Event> EventCursor<E> {
 
     /// See [`EventReader::read_with_id`](super::EventReader::read_with_id)
     pub fn read_with_id<'a>(&'a mut self, events: &'a Events<E>) -> EventIteratorWithId<'a, E> {
+        events.register_cursor(self);
         EventIteratorWithId::new(self, events)
     }
 
@@ -95,18 +138,21 @@ impl<E: Event> EventCursor<E> {
         &'a mut self,
         events: &'a mut Events<E>,
     ) -> EventMutIteratorWithId<'a, E> {
+        events.register_cursor(self);
         EventMutIteratorWithId::new(self, events)
     }
 
     /// See [`EventReader::par_read`](super::EventReader::par_read)
     #[cfg(feature = "multi_threaded")]
     pub fn par_read<'a>(&'a mut self, events: &'a Events<E>) -> EventParIter<'a, E> {
+        events.register_cursor(self);
         EventParIter::new(self, events)
     }
 
     /// See [`EventMutator::par_read`](super::EventMutator::par_read)
     #[cfg(feature = "multi_threaded")]
     pub fn par_read_mut<'a>(&'a mut self, events: &'a mut Events<E>) -> EventMutParIter<'a, E> {
+        events.register_cursor(self);
         EventMutParIter::new(self, events)
     }
 
@@ -136,6 +182,59 @@ impl<E: Event> EventCursor<E> {
 
     /// See [`EventReader::clear()`](super::EventReader::clear)
     pub fn clear(&mut self, events: &Events<E>) {
-        self.last_event_count = events.event_count;
+        events.register_cursor(self);
+        self.set_last_event_count(events.event_count);
+    }
+}
+
+/// A system-local [`EventCursor`], like a [`Local<EventCursor<E>>`](crate::system::Local), that
+/// is registered as a reader of the [`Events<E>`] resource when its system is initialized.
+///
+/// This is what [`EventReader`](super::EventReader) and [`EventMutator`](super::EventMutator)
+/// use to keep track of the events they have read. It makes sure that events kept
+/// [until read](super::EventRetention::UntilRead) are also kept for systems that haven't run yet.
+#[derive(Debug)]
+pub struct LocalEventCursor<'s, E: Event>(&'s mut EventCursor<E>);
+
+impl<'s, E: Event> Deref for LocalEventCursor<'s, E> {
+    type Target = EventCursor<E>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl<'s, E: Event> DerefMut for LocalEventCursor<'s, E> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0
+    }
+}
+
+// SAFETY: Only accesses system-local state.
+unsafe impl<'s, E: Event> ReadOnlySystemParam for LocalEventCursor<'s, E> {}
+
+// SAFETY: Only accesses system-local state.
+unsafe impl<E: Event> SystemParam for LocalEventCursor<'_, E> {
+    type State = SyncCell<EventCursor<E>>;
+    type Item<'w, 's> = LocalEventCursor<'s, E>;
+
+    fn init_state(world: &mut World, _system_meta: &mut SystemMeta) -> Self::State {
+        let mut cursor = EventCursor::default();
+        if let Some(events) = world.get_resource::<Events<E>>() {
+            events.register_cursor(&mut cursor);
+        }
+        SyncCell::new(cursor)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        _system_meta: &SystemMeta,
+        _world: UnsafeWorldCell<'w>,
+        _change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        LocalEventCursor(state.get())
     }
 }
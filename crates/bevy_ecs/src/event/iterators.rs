@@ -65,7 +65,7 @@ impl<'a, E: Event> EventIteratorWithId<'a, E> {
         let unread_count = a.len() + b.len();
         // Ensure `len` is implemented correctly
         debug_assert_eq!(unread_count, reader.len(events));
-        reader.last_event_count = events.event_count - unread_count;
+        reader.set_last_event_count(events.event_count - unread_count);
         // Iterate the oldest first, then the newer events
         let chain = a.iter().chain(b.iter());
 
@@ -93,7 +93,7 @@ impl<'a, E: Event> Iterator for EventIteratorWithId<'a, E> {
             Some(item) => {
                 #[cfg(feature = "detailed_trace")]
                 tracing::trace!("EventReader::iter() -> {}", item.1);
-                self.reader.last_event_count += 1;
+                self.reader.advance(1);
                 self.unread -= 1;
                 Some(item)
             }
@@ -106,7 +106,7 @@ impl<'a, E: Event> Iterator for EventIteratorWithId<'a, E> {
     }
 
     fn count(self) -> usize {
-        self.reader.last_event_count += self.unread;
+        self.reader.advance(self.unread);
         self.unread
     }
 
@@ -115,17 +115,17 @@ impl<'a, E: Event> Iterator for EventIteratorWithId<'a, E> {
         Self: Sized,
     {
         let EventInstance { event_id, event } = self.chain.last()?;
-        self.reader.last_event_count += self.unread;
+        self.reader.advance(self.unread);
         Some((event, *event_id))
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         if let Some(EventInstance { event_id, event }) = self.chain.nth(n) {
-            self.reader.last_event_count += n + 1;
+            self.reader.advance(n + 1);
             self.unread -= n + 1;
             Some((event, *event_id))
         } else {
-            self.reader.last_event_count += self.unread;
+            self.reader.advance(self.unread);
             self.unread = 0;
             None
         }
@@ -165,7 +165,7 @@ impl<'a, E: Event> EventParIter<'a, E> {
         let unread_count = a.len() + b.len();
         // Ensure `len` is implemented correctly
         debug_assert_eq!(unread_count, reader.len(events));
-        reader.last_event_count = events.event_count - unread_count;
+        reader.set_last_event_count(events.event_count - unread_count);
 
         Self {
             reader,
@@ -244,7 +244,7 @@ impl<'a, E: Event> EventParIter<'a, E> {
             });
 
             // Events are guaranteed to be read at this point.
-            self.reader.last_event_count += self.unread;
+            self.reader.advance(self.unread);
             self.unread = 0;
         }
     }
@@ -13,8 +13,8 @@ mod writer;
 pub(crate) use base::EventInstance;
 pub use base::{Event, EventId};
 pub use bevy_ecs_macros::Event;
-pub use collections::{Events, SendBatchIds};
-pub use event_cursor::EventCursor;
+pub use collections::{EventOverflow, EventRetention, Events, SendBatchIds};
+pub use event_cursor::{EventCursor, LocalEventCursor};
 #[cfg(feature = "multi_threaded")]
 pub use iterators::EventParIter;
 pub use iterators::{EventIterator, EventIteratorWithId};
@@ -619,4 +619,143 @@ mod tests {
         });
         schedule.run(&mut world);
     }
+
+    fn until_read(capacity: usize, overflow: EventOverflow) -> Events<TestEvent> {
+        Events::with_retention(EventRetention::UntilRead { capacity, overflow })
+    }
+
+    #[test]
+    fn test_events_kept_until_read() {
+        let mut events = until_read(16, EventOverflow::Panic);
+        let mut fast = events.get_cursor();
+        let mut slow = events.get_cursor();
+        events.register_cursor(&mut fast);
+        events.register_cursor(&mut slow);
+
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+        assert_eq!(
+            get_events(&events, &mut fast),
+            [TestEvent { i: 0 }, TestEvent { i: 1 }]
+        );
+        for _ in 0..3 {
+            events.update();
+        }
+        assert_eq!(events.len(), 2);
+
+        assert_eq!(slow.read(&events).next(), Some(&TestEvent { i: 0 }));
+        events.update();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events.oldest_event_count(), 1);
+
+        events.send(TestEvent { i: 2 });
+        assert_eq!(
+            get_events(&events, &mut slow),
+            [TestEvent { i: 1 }, TestEvent { i: 2 }]
+        );
+        events.update();
+        assert_eq!(events.len(), 1);
+        assert_eq!(get_events(&events, &mut fast), [TestEvent { i: 2 }]);
+        assert_eq!(
+            events.update_drain().collect::<Vec<_>>(),
+            [TestEvent { i: 2 }]
+        );
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_events_kept_until_read_dropped_cursor() {
+        let mut events = until_read(16, EventOverflow::Panic);
+        let mut reader = events.get_cursor();
+        events.send(TestEvent { i: 0 });
+
+        // Events are kept while there are no readers.
+        events.update();
+        events.update();
+        assert_eq!(events.len(), 1);
+
+        let mut stalled = events.get_cursor();
+        events.register_cursor(&mut stalled);
+        assert_eq!(get_events(&events, &mut reader), [TestEvent { i: 0 }]);
+        events.update();
+        assert_eq!(events.len(), 1);
+
+        drop(stalled);
+        events.update();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_events_kept_until_read_overflow() {
+        let mut events = until_read(2, EventOverflow::DropOldest);
+        let mut reader = events.get_cursor();
+        events.register_cursor(&mut reader);
+
+        events.send(TestEvent { i: 0 });
+        events.update();
+        events.extend([TestEvent { i: 1 }, TestEvent { i: 2 }]);
+        assert_eq!(events.len(), 2);
+        assert_eq!(reader.missed_events(&events), 1);
+        assert_eq!(
+            get_events(&events, &mut reader),
+            [TestEvent { i: 1 }, TestEvent { i: 2 }]
+        );
+
+        events.send(TestEvent { i: 3 });
+        events.send(TestEvent { i: 4 });
+        events.send(TestEvent { i: 5 });
+        assert_eq!(
+            get_events(&events, &mut reader),
+            [TestEvent { i: 4 }, TestEvent { i: 5 }]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_events_kept_until_read_overflow_panic() {
+        let mut events = until_read(1, EventOverflow::Panic);
+        events.send(TestEvent { i: 0 });
+        events.send(TestEvent { i: 1 });
+    }
+
+    #[test]
+    fn test_event_reader_kept_until_read() {
+        use bevy_ecs::prelude::*;
+
+        #[derive(Resource, Default)]
+        struct Received(Vec<usize>);
+
+        #[derive(Resource, Default)]
+        struct Frame(usize);
+
+        let mut world = World::new();
+        world.insert_resource(until_read(64, EventOverflow::Panic));
+        world.init_resource::<Received>();
+        world.init_resource::<Frame>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                (|mut frame: ResMut<Frame>, mut writer: EventWriter<TestEvent>| {
+                    writer.send(TestEvent { i: frame.0 });
+                    frame.0 += 1;
+                }),
+                (|mut reader: EventReader<TestEvent>, mut received: ResMut<Received>| {
+                    received.0.extend(reader.read().map(|event| event.i));
+                })
+                .run_if(|frame: Res<Frame>| frame.0 % 4 == 0),
+                (|mut reader: EventReader<TestEvent>| {
+                    reader.clear();
+                }),
+            )
+                .chain(),
+        );
+
+        for _ in 0..10 {
+            schedule.run(&mut world);
+            world.resource_mut::<Events<TestEvent>>().update();
+        }
+        assert_eq!(world.resource::<Received>().0, [0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(world.resource::<Events<TestEvent>>().len(), 2);
+    }
 }
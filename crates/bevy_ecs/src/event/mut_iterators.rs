@@ -68,7 +68,7 @@ impl<'a, E: Event> EventMutIteratorWithId<'a, E> {
 
         let unread_count = a.len() + b.len();
 
-        mutator.last_event_count = events.event_count - unread_count;
+        mutator.set_last_event_count(events.event_count - unread_count);
         // Iterate the oldest first, then the newer events
         let chain = a.iter_mut().chain(b.iter_mut());
 
@@ -96,7 +96,7 @@ impl<'a, E: Event> Iterator for EventMutIteratorWithId<'a, E> {
             Some(item) => {
                 #[cfg(feature = "detailed_trace")]
                 tracing::trace!("EventMutator::iter() -> {}", item.1);
-                self.mutator.last_event_count += 1;
+                self.mutator.advance(1);
                 self.unread -= 1;
                 Some(item)
             }
@@ -109,7 +109,7 @@ impl<'a, E: Event> Iterator for EventMutIteratorWithId<'a, E> {
     }
 
     fn count(self) -> usize {
-        self.mutator.last_event_count += self.unread;
+        self.mutator.advance(self.unread);
         self.unread
     }
 
@@ -118,17 +118,17 @@ impl<'a, E: Event> Iterator for EventMutIteratorWithId<'a, E> {
         Self: Sized,
     {
         let EventInstance { event_id, event } = self.chain.last()?;
-        self.mutator.last_event_count += self.unread;
+        self.mutator.advance(self.unread);
         Some((event, *event_id))
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         if let Some(EventInstance { event_id, event }) = self.chain.nth(n) {
-            self.mutator.last_event_count += n + 1;
+            self.mutator.advance(n + 1);
             self.unread -= n + 1;
             Some((event, *event_id))
         } else {
-            self.mutator.last_event_count += self.unread;
+            self.mutator.advance(self.unread);
             self.unread = 0;
             None
         }
@@ -166,7 +166,7 @@ impl<'a, E: Event> EventMutParIter<'a, E> {
         let b = events.events_b.get_mut(b_index..).unwrap_or_default();
 
         let unread_count = a.len() + b.len();
-        mutator.last_event_count = events.event_count - unread_count;
+        mutator.set_last_event_count(events.event_count - unread_count);
 
         Self {
             mutator,
@@ -247,7 +247,7 @@ impl<'a, E: Event> EventMutParIter<'a, E> {
             });
 
             // Events are guaranteed to be read at this point.
-            self.mutator.last_event_count += self.unread;
+            self.mutator.advance(self.unread);
             self.unread = 0;
         }
     }
@@ -2,8 +2,8 @@ use crate as bevy_ecs;
 #[cfg(feature = "multi_threaded")]
 use bevy_ecs::event::EventMutParIter;
 use bevy_ecs::{
-    event::{Event, EventCursor, EventMutIterator, EventMutIteratorWithId, Events},
-    system::{Local, ResMut, SystemParam},
+    event::{Event, EventMutIterator, EventMutIteratorWithId, Events, LocalEventCursor},
+    system::{ResMut, SystemParam},
 };
 
 /// Mutably reads events of type `T` keeping track of which events have already been read
@@ -44,7 +44,7 @@ use bevy_ecs::{
 /// [`EventWriter`]: super::EventWriter
 #[derive(SystemParam, Debug)]
 pub struct EventMutator<'w, 's, E: Event> {
-    pub(super) reader: Local<'s, EventCursor<E>>,
+    pub(super) reader: LocalEventCursor<'s, E>,
     events: ResMut<'w, Events<E>>,
 }
 
@@ -2,8 +2,8 @@ use crate as bevy_ecs;
 #[cfg(feature = "multi_threaded")]
 use bevy_ecs::event::EventParIter;
 use bevy_ecs::{
-    event::{Event, EventCursor, EventIterator, EventIteratorWithId, Events},
-    system::{Local, Res, SystemParam},
+    event::{Event, EventIterator, EventIteratorWithId, Events, LocalEventCursor},
+    system::{Res, SystemParam},
 };
 
 /// Reads events of type `T` in order and tracks which events have already been read.
@@ -16,7 +16,7 @@ use bevy_ecs::{
 /// [`EventWriter<T>`]: super::EventWriter
 #[derive(SystemParam, Debug)]
 pub struct EventReader<'w, 's, E: Event> {
-    pub(super) reader: Local<'s, EventCursor<E>>,
+    pub(super) reader: LocalEventCursor<'s, E>,
     events: Res<'w, Events<E>>,
 }
 
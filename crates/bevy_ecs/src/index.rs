@@ -0,0 +1,292 @@
+//! Lookup tables from component values to the entities that have them.
+//!
+//! Deriving [`Component`] with the `#[component(index)]` attribute makes the ECS maintain a
+//! [`ComponentIndex`] of that component: a map from each value of the component to the entities
+//! holding that value. It is updated by the component's hooks whenever the component is inserted,
+//! replaced or removed, including when an entity is despawned, so it never goes stale.
+//!
+//! Indexed components must be [immutable](crate::component::Immutable), so their value can only
+//! change by inserting a new one, and implement [`Hash`], [`Eq`] and [`Clone`].
+//! Systems read the index through the [`Index`] system param.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::index::Index;
+//! # use bevy_ecs::system::RunSystemOnce;
+//! #[derive(Component, Hash, PartialEq, Eq, Clone)]
+//! #[component(immutable, index)]
+//! struct GridCell(i32, i32);
+//!
+//! fn explode(cells: Index<GridCell>, mut commands: Commands) {
+//!     for entity in cells.get(&GridCell(0, 0)) {
+//!         commands.entity(entity).despawn();
+//!     }
+//! }
+//!
+//! let mut world = World::new();
+//! world.spawn(GridCell(0, 0));
+//! world.spawn(GridCell(0, 0));
+//! world.spawn(GridCell(1, 0));
+//!
+//! world.run_system_once(explode).unwrap();
+//! assert_eq!(world.query::<&GridCell>().iter(&world).len(), 1);
+//! ```
+//!
+//! The index of a component is created the first time it is needed, by scanning the world.
+
+use core::{hash::Hash, ops::Deref};
+
+use bevy_utils::HashMap;
+
+use crate::{
+    self as bevy_ecs,
+    component::{Component, ComponentId, Immutable, Tick},
+    entity::{Entity, EntityHashSet},
+    system::{ReadOnlySystemParam, Res, Resource, SystemMeta, SystemParam},
+    world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, FromWorld, World},
+};
+
+/// A [`Component`] whose values are indexed in a [`ComponentIndex`].
+///
+/// This is implemented by `#[derive(Component)]` when the `#[component(index)]` attribute is present,
+/// which also registers the hooks that keep the index up to date.
+/// Implementing it by hand without those hooks results in an index that is never updated.
+///
+/// See the [module docs](crate::index) for an example.
+pub trait IndexedComponent: Component<Mutability = Immutable> + Hash + Eq + Clone {}
+
+/// Resource that maps each value of the [`IndexedComponent`] `C` to the entities that have it.
+///
+/// Prefer the [`Index`] system param, which creates this resource if it doesn't exist yet.
+#[derive(Resource, Debug)]
+pub struct ComponentIndex<C: IndexedComponent> {
+    entities: HashMap<C, EntityHashSet>,
+}
+
+impl<C: IndexedComponent> ComponentIndex<C> {
+    /// Returns an iterator over the entities that have a component equal to `key`, in no particular order.
+    pub fn get(&self, key: &C) -> impl Iterator<Item = Entity> + '_ {
+        self.entities
+            .get(key)
+            .into_iter()
+            .flat_map(|entities| entities.iter().copied())
+    }
+
+    /// Returns `true` if any entity has a component equal to `key`.
+    pub fn contains(&self, key: &C) -> bool {
+        self.entities.contains_key(key)
+    }
+
+    /// Returns the number of entities that have a component equal to `key`.
+    pub fn count(&self, key: &C) -> usize {
+        self.entities.get(key).map_or(0, |entities| entities.len())
+    }
+
+    /// Returns an iterator over all distinct component values, and the entities that have them.
+    pub fn iter(&self) -> impl Iterator<Item = (&C, impl Iterator<Item = Entity> + '_)> {
+        self.entities
+            .iter()
+            .map(|(key, entities)| (key, entities.iter().copied()))
+    }
+
+    /// Returns the number of distinct component values.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns `true` if no entity has the component.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    fn add(&mut self, key: C, entity: Entity) {
+        self.entities.entry(key).or_default().insert(entity);
+    }
+
+    fn remove(&mut self, key: &C, entity: Entity) {
+        if let Some(entities) = self.entities.get_mut(key) {
+            entities.remove(&entity);
+            if entities.is_empty() {
+                self.entities.remove(key);
+            }
+        }
+    }
+}
+
+impl<C: IndexedComponent> FromWorld for ComponentIndex<C> {
+    fn from_world(world: &mut World) -> Self {
+        let mut index = Self {
+            entities: HashMap::default(),
+        };
+        let Some(component_id) = world.component_id::<C>() else {
+            return index;
+        };
+        // Archetypes are scanned directly, as queries would skip disabled entities.
+        for archetype in world.archetypes().iter() {
+            if !archetype.contains(component_id) {
+                continue;
+            }
+            for entity in archetype.entities() {
+                let entity = entity.id();
+                if let Some(component) = world.get::<C>(entity) {
+                    index.add(component.clone(), entity);
+                }
+            }
+        }
+        index
+    }
+}
+
+/// The `on_insert` hook of indexed components, which adds the entity to the [`ComponentIndex`].
+#[doc(hidden)]
+pub fn on_insert_index<C: IndexedComponent>(
+    mut world: DeferredWorld,
+    entity: Entity,
+    _: ComponentId,
+) {
+    let Some(key) = world.get::<C>(entity).cloned() else {
+        return;
+    };
+    // If the index doesn't exist yet, it will see the component when it is built.
+    if let Some(mut index) = world.get_resource_mut::<ComponentIndex<C>>() {
+        index.add(key, entity);
+    }
+}
+
+/// The `on_replace` hook of indexed components, which removes the entity from the [`ComponentIndex`].
+#[doc(hidden)]
+pub fn on_replace_index<C: IndexedComponent>(
+    mut world: DeferredWorld,
+    entity: Entity,
+    _: ComponentId,
+) {
+    let Some(key) = world.get::<C>(entity).cloned() else {
+        return;
+    };
+    if let Some(mut index) = world.get_resource_mut::<ComponentIndex<C>>() {
+        index.remove(&key, entity);
+    }
+}
+
+/// [`SystemParam`] that looks up entities by the value of their [`IndexedComponent`] `C`.
+///
+/// This reads the [`ComponentIndex<C>`] resource, which is created when the system is initialized.
+///
+/// See the [module docs](crate::index) for an example.
+pub struct Index<'w, C: IndexedComponent> {
+    index: Res<'w, ComponentIndex<C>>,
+}
+
+impl<'w, C: IndexedComponent> Index<'w, C> {
+    /// Returns an iterator over the entities that have a component equal to `key`, in no particular order.
+    pub fn get(&self, key: &C) -> impl Iterator<Item = Entity> + 'w {
+        Res::clone(&self.index).into_inner().get(key)
+    }
+}
+
+impl<'w, C: IndexedComponent> Deref for Index<'w, C> {
+    type Target = ComponentIndex<C>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.index
+    }
+}
+
+// SAFETY: This only reads the `ComponentIndex<C>` resource, through `Res`.
+unsafe impl<'a, C: IndexedComponent> SystemParam for Index<'a, C> {
+    type State = <Res<'a, ComponentIndex<C>> as SystemParam>::State;
+    type Item<'w, 's> = Index<'w, C>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        world.init_resource::<ComponentIndex<C>>();
+        <Res<'a, ComponentIndex<C>> as SystemParam>::init_state(world, system_meta)
+    }
+
+    #[inline]
+    unsafe fn validate_param(
+        state: &Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell,
+    ) -> bool {
+        // SAFETY: The caller upholds the requirements of `Res`.
+        unsafe {
+            <Res<'a, ComponentIndex<C>> as SystemParam>::validate_param(state, system_meta, world)
+        }
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        Index {
+            // SAFETY: The caller upholds the requirements of `Res`.
+            index: unsafe {
+                <Res<'a, ComponentIndex<C>> as SystemParam>::get_param(
+                    state,
+                    system_meta,
+                    world,
+                    change_tick,
+                )
+            },
+        }
+    }
+}
+
+// SAFETY: `Res` is read-only.
+unsafe impl<'a, C: IndexedComponent> ReadOnlySystemParam for Index<'a, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{entity_disabling::Disabled, system::RunSystemOnce};
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component, Hash, PartialEq, Eq, Clone, Debug)]
+    #[component(immutable, index)]
+    struct Name(&'static str);
+
+    fn lookup(world: &mut World, name: &'static str) -> Vec<Entity> {
+        let mut entities = world
+            .run_system_once(move |index: Index<Name>| index.get(&Name(name)).collect::<Vec<_>>())
+            .unwrap();
+        entities.sort();
+        entities
+    }
+
+    #[test]
+    fn index_includes_disabled_entities() {
+        let mut world = World::new();
+        let a = world.spawn((Name("a"), Disabled)).id();
+        assert_eq!(lookup(&mut world, "a"), vec![a]);
+    }
+
+    #[test]
+    fn index_tracks_insert_replace_and_remove() {
+        let mut world = World::new();
+        let a = world.spawn(Name("a")).id();
+        // Entities spawned before the index exists are picked up when it is built.
+        assert_eq!(lookup(&mut world, "a"), vec![a]);
+
+        let b = world.spawn(Name("b")).id();
+        let c = world.spawn(Name("a")).id();
+        assert_eq!(lookup(&mut world, "a"), vec![a, c]);
+        assert_eq!(lookup(&mut world, "b"), vec![b]);
+
+        world.entity_mut(a).insert(Name("b"));
+        assert_eq!(lookup(&mut world, "a"), vec![c]);
+        assert_eq!(lookup(&mut world, "b"), vec![a, b]);
+
+        world.entity_mut(b).remove::<Name>();
+        world.despawn(c);
+        assert_eq!(lookup(&mut world, "a"), vec![]);
+        assert_eq!(lookup(&mut world, "b"), vec![a]);
+
+        let index = world.resource::<ComponentIndex<Name>>();
+        assert_eq!(index.len(), 1);
+        assert!(!index.contains(&Name("a")));
+        assert_eq!(index.count(&Name("b")), 1);
+    }
+}
@@ -39,8 +39,10 @@ pub mod bundle;
 pub mod change_detection;
 pub mod component;
 pub mod entity;
+pub mod entity_disabling;
 pub mod event;
 pub mod identifier;
+pub mod index;
 pub mod intern;
 pub mod label;
 pub mod name;
@@ -48,11 +50,15 @@ pub mod observer;
 pub mod query;
 #[cfg(feature = "bevy_reflect")]
 pub mod reflect;
+pub mod relationship;
 pub mod removal_detection;
+pub mod resource_entity;
 pub mod result;
 pub mod schedule;
 pub mod storage;
 pub mod system;
+#[cfg(feature = "std")]
+pub mod task;
 pub mod traversal;
 pub mod world;
 
@@ -128,6 +134,7 @@ mod tests {
         change_detection::Ref,
         component::{require, Component, ComponentId, RequiredComponents, RequiredComponentsError},
         entity::Entity,
+        entity_disabling::Disabled,
         prelude::Or,
         query::{Added, Changed, FilteredAccess, QueryFilter, With, Without},
         system::Resource,
@@ -1529,8 +1536,10 @@ mod tests {
         let mut expected = FilteredAccess::<ComponentId>::default();
         let a_id = world.components.get_id(TypeId::of::<A>()).unwrap();
         let b_id = world.components.get_id(TypeId::of::<B>()).unwrap();
+        let disabled_id = world.components.get_id(TypeId::of::<Disabled>()).unwrap();
         expected.add_component_write(a_id);
         expected.add_component_read(b_id);
+        expected.and_without(disabled_id);
         assert!(
             query.component_access.eq(&expected),
             "ComponentId access from query fetch and query filter should be combined"
@@ -9,19 +9,23 @@ pub use runner::*;
 use crate::{
     archetype::ArchetypeFlags,
     component::ComponentId,
-    entity::EntityHashMap,
+    entity::{EntityHash, EntityHashMap},
     prelude::*,
+    schedule::InternedSystemSet,
     system::IntoObserverSystem,
     world::{DeferredWorld, *},
 };
-use alloc::vec::Vec;
+use alloc::{collections::BinaryHeap, vec::Vec};
 use bevy_ptr::Ptr;
 use bevy_utils::HashMap;
 use core::{
+    cmp::Reverse,
     fmt::Debug,
     marker::PhantomData,
     ops::{Deref, DerefMut},
 };
+use indexmap::IndexMap;
+use log::warn;
 use smallvec::SmallVec;
 
 /// Type containing triggered [`Event`] information for a given run of an [`Observer`]. This contains the
@@ -29,17 +33,21 @@ use smallvec::SmallVec;
 /// contains event propagation information. See [`Trigger::propagate`] for more information.
 pub struct Trigger<'w, E, B: Bundle = ()> {
     event: &'w mut E,
-    propagate: &'w mut bool,
+    propagation: &'w mut Propagation,
     trigger: ObserverTrigger,
     _marker: PhantomData<B>,
 }
 
 impl<'w, E, B: Bundle> Trigger<'w, E, B> {
     /// Creates a new trigger for the given event and observer information.
-    pub fn new(event: &'w mut E, propagate: &'w mut bool, trigger: ObserverTrigger) -> Self {
+    pub fn new(
+        event: &'w mut E,
+        propagation: &'w mut Propagation,
+        trigger: ObserverTrigger,
+    ) -> Self {
         Self {
             event,
-            propagate,
+            propagation,
             trigger,
             _marker: PhantomData,
         }
@@ -129,14 +137,52 @@ impl<'w, E, B: Bundle> Trigger<'w, E, B> {
     ///
     /// [`Traversal`]: crate::traversal::Traversal
     pub fn propagate(&mut self, should_propagate: bool) {
-        *self.propagate = should_propagate;
+        self.propagation.propagate = should_propagate;
     }
 
     /// Returns the value of the flag that controls event propagation. See [`propagate`] for more information.
     ///
     /// [`propagate`]: Trigger::propagate
     pub fn get_propagate(&self) -> bool {
-        *self.propagate
+        self.propagation.propagate
+    }
+
+    /// Stops this trigger: the observers that would run after this one, on this target or on the targets
+    /// the event would [propagate](Trigger::propagate) to, are skipped.
+    ///
+    /// Observers run in the order set with [`Observer::before`] and [`Observer::after`], so an observer
+    /// validating an event can run first and stop it:
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # let mut world = World::default();
+    /// #[derive(Event)]
+    /// struct PlaySound(&'static str);
+    ///
+    /// fn validate(mut trigger: Trigger<PlaySound>) {
+    ///     if trigger.event().0.is_empty() {
+    ///         trigger.stop();
+    ///     }
+    /// }
+    ///
+    /// fn play(trigger: Trigger<PlaySound>) {
+    ///     println!("Playing {}", trigger.event().0);
+    /// }
+    ///
+    /// world.spawn(Observer::new(play));
+    /// world.spawn(Observer::new(validate).before(play));
+    /// world.flush();
+    ///
+    /// // Only `validate` runs.
+    /// world.trigger(PlaySound(""));
+    /// ```
+    pub fn stop(&mut self) {
+        self.propagation.stopped = true;
+    }
+
+    /// Returns `true` if an observer [stopped](Trigger::stop) this trigger.
+    pub fn is_stopped(&self) -> bool {
+        self.propagation.stopped
     }
 }
 
@@ -144,7 +190,7 @@ impl<'w, E: Debug, B: Bundle> Debug for Trigger<'w, E, B> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Trigger")
             .field("event", &self.event)
-            .field("propagate", &self.propagate)
+            .field("propagation", &self.propagation)
             .field("trigger", &self.trigger)
             .field("_marker", &self._marker)
             .finish()
@@ -165,6 +211,17 @@ impl<'w, E, B: Bundle> DerefMut for Trigger<'w, E, B> {
     }
 }
 
+/// The state of a triggered event that is shared by the observers it runs.
+///
+/// Observers change it through [`Trigger::propagate`] and [`Trigger::stop`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Propagation {
+    /// Whether the event propagates to the next target along its [`Traversal`](crate::traversal::Traversal).
+    pub propagate: bool,
+    /// Whether an observer stopped the trigger, skipping the remaining observers and targets.
+    pub stopped: bool,
+}
+
 /// Represents a collection of targets for a specific [`Trigger`] of an [`Event`]. Targets can be of type [`Entity`] or [`ComponentId`].
 ///
 /// When a trigger occurs for a given event and [`TriggerTargets`], any [`Observer`] that watches for that specific event-target combination
@@ -258,7 +315,7 @@ impl TriggerTargets for &Vec<Entity> {
 }
 
 /// A description of what an [`Observer`] observes.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Debug)]
 pub struct ObserverDescriptor {
     /// The events the observer is watching.
     events: Vec<ComponentId>,
@@ -268,6 +325,15 @@ pub struct ObserverDescriptor {
 
     /// The entities the observer is watching.
     entities: Vec<Entity>,
+
+    /// The sets the observer belongs to, for ordering.
+    sets: Vec<InternedSystemSet>,
+
+    /// The sets the observer runs before.
+    before: Vec<InternedSystemSet>,
+
+    /// The sets the observer runs after.
+    after: Vec<InternedSystemSet>,
 }
 
 impl ObserverDescriptor {
@@ -297,6 +363,20 @@ impl ObserverDescriptor {
         self.components
             .extend(descriptor.components.iter().copied());
         self.entities.extend(descriptor.entities.iter().copied());
+        self.sets.extend(descriptor.sets.iter().copied());
+        self.before.extend(descriptor.before.iter().copied());
+        self.after.extend(descriptor.after.iter().copied());
+    }
+
+    /// Returns the position of observers with this descriptor in the default order:
+    /// global observers run first, then entity, component and component entity observers.
+    fn default_order(&self) -> u8 {
+        match (self.components.is_empty(), self.entities.is_empty()) {
+            (true, true) => 0,
+            (true, false) => 1,
+            (false, true) => 2,
+            (false, false) => 3,
+        }
     }
 }
 
@@ -320,8 +400,8 @@ impl ObserverTrigger {
     }
 }
 
-// Map between an observer entity and its runner
-type ObserverMap = EntityHashMap<ObserverRunner>;
+// Map between an observer entity and its runner, in the order the observers were registered in
+type ObserverMap = IndexMap<Entity, ObserverRunner, EntityHash>;
 
 /// Collection of [`ObserverRunner`] for [`Observer`] registered to a particular trigger targeted at a specific component.
 #[derive(Default, Debug)]
@@ -341,6 +421,197 @@ pub struct CachedObservers {
     component_observers: HashMap<ComponentId, CachedComponentObservers>,
     // Observers listening for this trigger fired at a specific entity
     entity_observers: EntityHashMap<ObserverMap>,
+    // Ordering constraints between the observers of this trigger
+    order: ObserverOrder,
+}
+
+/// The ordering constraints of an observer that runs before or after some sets.
+#[derive(Debug)]
+struct ObserverConstraints {
+    default_order: u8,
+    sequence: u64,
+    before: Vec<InternedSystemSet>,
+    after: Vec<InternedSystemSet>,
+}
+
+/// Tracks the ordering constraints between the observers of a trigger.
+///
+/// Only observers that run before or after some sets, and the observers in those sets, are ranked.
+/// All other observers run in their [default order](ObserverDescriptor::default_order), after the
+/// ranked observers with the same default order, and in the order they were registered in.
+#[derive(Default, Debug)]
+struct ObserverOrder {
+    // The number of observers registered so far, used to keep the registration order
+    next_sequence: u64,
+    // The observers in each set, with their default order and sequence number
+    set_members: HashMap<InternedSystemSet, EntityHashMap<(u8, u64)>>,
+    // The observers that run before or after some sets
+    constrained: EntityHashMap<ObserverConstraints>,
+    // The number of constraints referring to each set
+    referenced: HashMap<InternedSystemSet, usize>,
+    // The position of each ranked observer in the order they run in, after its effective default order
+    ranks: EntityHashMap<(u8, usize)>,
+}
+
+impl ObserverOrder {
+    fn insert(&mut self, observer: Entity, descriptor: &ObserverDescriptor) {
+        let default_order = descriptor.default_order();
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+
+        let mut ranked = false;
+        for set in &descriptor.sets {
+            self.set_members
+                .entry(*set)
+                .or_default()
+                .insert(observer, (default_order, sequence));
+            ranked |= self.referenced.contains_key(set);
+        }
+
+        if !descriptor.before.is_empty() || !descriptor.after.is_empty() {
+            for set in descriptor.before.iter().chain(&descriptor.after) {
+                *self.referenced.entry(*set).or_default() += 1;
+            }
+            self.constrained.insert(
+                observer,
+                ObserverConstraints {
+                    default_order,
+                    sequence,
+                    before: descriptor.before.clone(),
+                    after: descriptor.after.clone(),
+                },
+            );
+            ranked = true;
+        }
+
+        if ranked {
+            self.update_ranks();
+        }
+    }
+
+    fn remove(&mut self, observer: Entity, descriptor: &ObserverDescriptor) {
+        for set in &descriptor.sets {
+            if let Some(members) = self.set_members.get_mut(set) {
+                members.remove(&observer);
+                if members.is_empty() {
+                    self.set_members.remove(set);
+                }
+            }
+        }
+
+        if let Some(constraints) = self.constrained.remove(&observer) {
+            for set in constraints.before.iter().chain(&constraints.after) {
+                if let Some(count) = self.referenced.get_mut(set) {
+                    *count -= 1;
+                    if *count == 0 {
+                        self.referenced.remove(set);
+                    }
+                }
+            }
+        }
+
+        if self.ranks.contains_key(&observer) {
+            self.update_ranks();
+        }
+    }
+
+    /// Sorts the ranked observers topologically by their ordering constraints. Observers that aren't
+    /// ordered relative to each other keep their [default order](ObserverDescriptor::default_order),
+    /// then the order they were registered in.
+    ///
+    /// An observer that must run after observers with a later default order takes on their default order,
+    /// so that sorting by default order, then rank, respects the constraints.
+    fn update_ranks(&mut self) {
+        self.ranks.clear();
+        if self.constrained.is_empty() {
+            return;
+        }
+
+        // Collect the ranked observers and the edges between them.
+        let mut nodes = EntityHashMap::<(u8, u64)>::default();
+        for (&observer, constraints) in &self.constrained {
+            nodes.insert(observer, (constraints.default_order, constraints.sequence));
+        }
+        for set in self.referenced.keys() {
+            if let Some(members) = self.set_members.get(set) {
+                nodes.extend(members.iter().map(|(&observer, &key)| (observer, key)));
+            }
+        }
+        let mut successors = EntityHashMap::<Vec<Entity>>::default();
+        let mut dependencies = EntityHashMap::<usize>::default();
+        let mut add_edge = |from: Entity, to: Entity| {
+            if from != to {
+                successors.entry(from).or_default().push(to);
+                *dependencies.entry(to).or_default() += 1;
+            }
+        };
+        for (&observer, constraints) in &self.constrained {
+            for set in &constraints.before {
+                for &other in self
+                    .set_members
+                    .get(set)
+                    .into_iter()
+                    .flat_map(EntityHashMap::keys)
+                {
+                    add_edge(observer, other);
+                }
+            }
+            for set in &constraints.after {
+                for &other in self
+                    .set_members
+                    .get(set)
+                    .into_iter()
+                    .flat_map(EntityHashMap::keys)
+                {
+                    add_edge(other, observer);
+                }
+            }
+        }
+
+        let mut effective_orders: EntityHashMap<u8> = nodes
+            .iter()
+            .map(|(&observer, &(default_order, _))| (observer, default_order))
+            .collect();
+        let mut ready: BinaryHeap<_> = nodes
+            .iter()
+            .filter(|(observer, _)| !dependencies.contains_key(*observer))
+            .map(|(&observer, &(default_order, sequence))| {
+                Reverse((default_order, sequence, observer))
+            })
+            .collect();
+        while let Some(Reverse((_, _, observer))) = ready.pop() {
+            let effective_order = effective_orders[&observer];
+            let rank = self.ranks.len();
+            self.ranks.insert(observer, (effective_order, rank));
+            for &next in successors.get(&observer).into_iter().flatten() {
+                let order = effective_orders.get_mut(&next).unwrap();
+                *order = (*order).max(effective_order);
+                let count = dependencies.get_mut(&next).unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    let (default_order, sequence) = nodes[&next];
+                    ready.push(Reverse((default_order, sequence, next)));
+                }
+            }
+        }
+
+        if self.ranks.len() < nodes.len() {
+            let mut cycle: Vec<_> = nodes
+                .iter()
+                .filter(|(observer, _)| !self.ranks.contains_key(*observer))
+                .map(|(&observer, &(default_order, sequence))| (sequence, default_order, observer))
+                .collect();
+            cycle.sort_unstable();
+            warn!(
+                "Observers {:?} have cyclic ordering constraints, so they run in the order they were added.",
+                cycle.iter().map(|(_, _, observer)| *observer).collect::<Vec<_>>()
+            );
+            for (_, default_order, observer) in cycle {
+                let rank = self.ranks.len();
+                self.ranks.insert(observer, (default_order, rank));
+            }
+        }
+    }
 }
 
 /// Metadata for observers. Stores a cache mapping trigger ids to the registered observers.
@@ -383,7 +654,7 @@ impl Observers {
         target: Entity,
         components: impl Iterator<Item = ComponentId> + Clone,
         data: &mut T,
-        propagate: &mut bool,
+        propagation: &mut Propagation,
     ) {
         // SAFETY: You cannot get a mutable reference to `observers` from `DeferredWorld`
         let (mut world, observers) = unsafe {
@@ -398,9 +669,7 @@ impl Observers {
             (world.into_deferred(), observers)
         };
 
-        let trigger_for_components = components.clone();
-
-        let mut trigger_observer = |(&observer, runner): (&Entity, &ObserverRunner)| {
+        let mut trigger_observer = |observer: Entity, runner: ObserverRunner| {
             (runner)(
                 world.reborrow(),
                 ObserverTrigger {
@@ -410,34 +679,64 @@ impl Observers {
                     target,
                 },
                 data.into(),
-                propagate,
+                propagation,
             );
+            propagation.stopped
         };
-        // Trigger observers listening for any kind of this trigger
-        observers.map.iter().for_each(&mut trigger_observer);
 
-        // Trigger entity observers listening for this kind of trigger
-        if target != Entity::PLACEHOLDER {
-            if let Some(map) = observers.entity_observers.get(&target) {
-                map.iter().for_each(&mut trigger_observer);
-            }
-        }
+        // Observers listening for any kind of this trigger, then entity observers listening for this kind
+        // of trigger, then observers listening to this trigger targeting a specific component,
+        // along with their default order.
+        let entity_observers = (target != Entity::PLACEHOLDER)
+            .then(|| observers.entity_observers.get(&target))
+            .flatten();
+        let maps = core::iter::once((0, &observers.map))
+            .chain(entity_observers.map(|map| (1, map)))
+            .chain(
+                components
+                    .clone()
+                    .filter_map(|id| observers.component_observers.get(&id))
+                    .flat_map(|component_observers| {
+                        let entity_map = (target != Entity::PLACEHOLDER)
+                            .then(|| component_observers.entity_map.get(&target))
+                            .flatten();
+                        core::iter::once((2, &component_observers.map))
+                            .chain(entity_map.map(|map| (3, map)))
+                    }),
+            );
 
-        // Trigger observers listening to this trigger targeting a specific component
-        trigger_for_components.for_each(|id| {
-            if let Some(component_observers) = observers.component_observers.get(&id) {
-                component_observers
-                    .map
-                    .iter()
-                    .for_each(&mut trigger_observer);
-
-                if target != Entity::PLACEHOLDER {
-                    if let Some(map) = component_observers.entity_map.get(&target) {
-                        map.iter().for_each(&mut trigger_observer);
+        let ranks = &observers.order.ranks;
+        if ranks.is_empty() {
+            for (_, map) in maps {
+                for (&observer, &runner) in map {
+                    if trigger_observer(observer, runner) {
+                        return;
                     }
                 }
             }
-        });
+            return;
+        }
+
+        let mut triggered = SmallVec::<[((u8, usize), Entity, ObserverRunner); 8]>::new();
+        for (default_order, map) in maps {
+            triggered.extend(map.iter().map(|(&observer, &runner)| {
+                let rank = ranks
+                    .get(&observer)
+                    .copied()
+                    .unwrap_or((default_order, usize::MAX));
+                (rank, observer, runner)
+            }));
+        }
+
+        // The sort is stable, so unranked observers keep the order they were collected in.
+        // An observer watching several of the components is collected once per component,
+        // but its runner only runs it once per trigger.
+        triggered.sort_by_key(|(rank, _, _)| *rank);
+        for (_, observer, runner) in triggered {
+            if trigger_observer(observer, runner) {
+                return;
+            }
+        }
     }
 
     pub(crate) fn is_archetype_cached(event_type: ComponentId) -> Option<ArchetypeFlags> {
@@ -640,6 +939,7 @@ impl World {
 
         for &event_type in &descriptor.events {
             let cache = observers.get_observers(event_type);
+            cache.order.insert(observer_entity, descriptor);
 
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
                 cache.map.insert(observer_entity, observer_state.runner);
@@ -684,15 +984,16 @@ impl World {
 
         for &event_type in &descriptor.events {
             let cache = observers.get_observers(event_type);
+            cache.order.remove(entity, &descriptor);
             if descriptor.components.is_empty() && descriptor.entities.is_empty() {
-                cache.map.remove(&entity);
+                cache.map.shift_remove(&entity);
             } else if descriptor.components.is_empty() {
                 for watched_entity in &descriptor.entities {
                     // This check should be unnecessary since this observer hasn't been unregistered yet
                     let Some(observers) = cache.entity_observers.get_mut(watched_entity) else {
                         continue;
                     };
-                    observers.remove(&entity);
+                    observers.shift_remove(&entity);
                     if observers.is_empty() {
                         cache.entity_observers.remove(watched_entity);
                     }
@@ -703,13 +1004,13 @@ impl World {
                         continue;
                     };
                     if descriptor.entities.is_empty() {
-                        observers.map.remove(&entity);
+                        observers.map.shift_remove(&entity);
                     } else {
                         for watched_entity in &descriptor.entities {
                             let Some(map) = observers.entity_map.get_mut(watched_entity) else {
                                 continue;
                             };
-                            map.remove(&entity);
+                            map.shift_remove(&entity);
                             if map.is_empty() {
                                 observers.entity_map.remove(watched_entity);
                             }
@@ -1159,7 +1460,7 @@ mod tests {
         world.spawn(ObserverState {
             // SAFETY: we registered `event_a` above and it matches the type of EventA
             descriptor: unsafe { ObserverDescriptor::default().with_events(vec![event_a]) },
-            runner: |mut world, _trigger, _ptr, _propagate| {
+            runner: |mut world, _trigger, _ptr, _propagation| {
                 world.resource_mut::<Order>().observed("event_a");
             },
             ..Default::default()
@@ -1472,9 +1773,12 @@ mod tests {
 
         let mut world = World::new();
         // This fails because `ResA` is not present in the world
-        world.add_observer(|_: Trigger<EventA>, _: Res<ResA>, mut commands: Commands| {
-            commands.insert_resource(ResB);
-        });
+        world.add_observer(
+            (|_: Trigger<EventA>, _: Res<ResA>, mut commands: Commands| {
+                commands.insert_resource(ResB);
+            })
+            .ignore_param_missing(),
+        );
         world.trigger(EventA);
 
         assert!(world.get_resource::<ResB>().is_none());
@@ -1530,4 +1834,210 @@ mod tests {
         assert_eq!(4, *counter.0.get(&a_id).unwrap());
         assert_eq!(3, *counter.0.get(&b_id).unwrap());
     }
+
+    #[test]
+    fn observer_ordering() {
+        #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+        struct Late;
+
+        fn first(_: Trigger<EventA>, mut res: ResMut<Order>) {
+            res.observed("first");
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("late"))
+                .in_set(Late),
+        );
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("middle"))
+                .after(first)
+                .before(Late),
+        );
+        world.spawn(Observer::new(first));
+        world.flush();
+
+        world.trigger(EventA);
+        assert_eq!(vec!["first", "middle", "late"], world.resource::<Order>().0);
+    }
+
+    #[test]
+    fn observer_ordering_mixes_global_and_entity_observers() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        fn global(_: Trigger<EventA>, mut res: ResMut<Order>) {
+            res.observed("global");
+        }
+
+        let entity = world
+            .spawn_empty()
+            .observe(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("entity"))
+            .id();
+        world.spawn(Observer::new(global));
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("before"))
+                .before(global),
+        );
+        world.flush();
+
+        world.trigger_targets(EventA, entity);
+        // Global observers run before entity observers unless they are ordered otherwise.
+        assert_eq!(
+            vec!["before", "global", "entity"],
+            world.resource::<Order>().0
+        );
+    }
+
+    #[test]
+    fn observer_ordering_ranks_only_ordered_observers() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        fn global(_: Trigger<EventA>, mut res: ResMut<Order>) {
+            res.observed("global");
+        }
+
+        let entity = world.spawn_empty().id();
+        for _ in 0..3 {
+            world.spawn(Observer::new(|_: Trigger<EventA>| {}).with_entity(entity));
+        }
+        world.spawn(Observer::new(global));
+        world.flush();
+        let ranks = |world: &World| {
+            world
+                .observers
+                .try_get_observers(EventA::component_id(world).unwrap())
+                .unwrap()
+                .order
+                .ranks
+                .len()
+        };
+        assert_eq!(0, ranks(&world));
+
+        // An entity observer ordered before a global observer runs first.
+        let before = world
+            .spawn(
+                Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("entity"))
+                    .with_entity(entity)
+                    .before(global),
+            )
+            .id();
+        world.flush();
+        assert_eq!(2, ranks(&world));
+
+        world.trigger_targets(EventA, entity);
+        assert_eq!(vec!["entity", "global"], world.resource::<Order>().0);
+
+        world.despawn(before);
+        assert_eq!(0, ranks(&world));
+    }
+
+    #[test]
+    fn observer_ordering_follows_registration() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        fn constrained(_: Trigger<EventA>, mut res: ResMut<Order>) {
+            res.observed("constrained");
+        }
+
+        for name in ["a", "b", "c", "d", "e", "f", "g", "h"] {
+            world.spawn(Observer::new(
+                move |_: Trigger<EventA>, mut res: ResMut<Order>| res.observed(name),
+            ));
+        }
+        let last = world.spawn(Observer::new(constrained)).id();
+        world.flush();
+        world.trigger(EventA);
+        assert_eq!(
+            vec!["a", "b", "c", "d", "e", "f", "g", "h", "constrained"],
+            world.resource::<Order>().0
+        );
+
+        // Ranked observers run first, and the unordered ones keep their registration order.
+        world.despawn(last);
+        world.resource_mut::<Order>().0.clear();
+        world.spawn(Observer::new(constrained));
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("first"))
+                .before(constrained),
+        );
+        world.flush();
+        world.trigger(EventA);
+        assert_eq!(
+            vec![
+                "first",
+                "constrained",
+                "a",
+                "b",
+                "c",
+                "d",
+                "e",
+                "f",
+                "g",
+                "h"
+            ],
+            world.resource::<Order>().0
+        );
+    }
+
+    #[test]
+    fn observer_stop() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        fn stop(mut trigger: Trigger<EventPropagating>, mut res: ResMut<Order>) {
+            res.observed("stop");
+            trigger.stop();
+            assert!(trigger.is_stopped());
+        }
+
+        let parent = world
+            .spawn_empty()
+            .observe(|_: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                res.observed("parent");
+            })
+            .id();
+        let child = world.spawn(Parent(parent)).id();
+        world.spawn(Observer::new(stop).with_entity(child));
+        world.spawn(
+            Observer::new(|_: Trigger<EventPropagating>, mut res: ResMut<Order>| {
+                res.observed("after");
+            })
+            .with_entity(child)
+            .after(stop),
+        );
+        world.flush();
+
+        world.trigger_targets(EventPropagating, child);
+        world.flush();
+        assert_eq!(vec!["stop"], world.resource::<Order>().0);
+    }
+
+    #[test]
+    fn observer_run_conditions() {
+        #[derive(Resource)]
+        struct Enabled(bool);
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        world.insert_resource(Enabled(false));
+
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut res: ResMut<Order>| res.observed("observer"))
+                .run_if(|enabled: Res<Enabled>| enabled.0)
+                .run_if(resource_exists::<Order>),
+        );
+        world.flush();
+
+        world.trigger(EventA);
+        assert!(world.resource::<Order>().0.is_empty());
+
+        world.resource_mut::<Enabled>().0 = true;
+        world.trigger(EventA);
+        assert_eq!(vec!["observer"], world.resource::<Order>().0);
+    }
 }
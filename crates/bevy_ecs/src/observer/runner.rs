@@ -3,10 +3,12 @@ use core::any::Any;
 
 use crate::{
     component::{ComponentHook, ComponentHooks, ComponentId, Mutable, StorageType},
-    observer::{ObserverDescriptor, ObserverTrigger},
+    observer::{ObserverDescriptor, ObserverTrigger, Propagation},
     prelude::*,
     query::DebugCheckedUnwrap,
-    system::{IntoObserverSystem, ObserverSystem},
+    result::{self, DefaultSystemErrorHandler, Error, Result, SystemErrorContext},
+    schedule::{BoxedCondition, Condition, IntoSystemSet, SystemSet},
+    system::{IntoObserverSystem, IntoSystem, ObserverSystem},
     world::DeferredWorld,
 };
 use bevy_ptr::PtrMut;
@@ -91,7 +93,7 @@ impl Component for ObserverState {
 ///
 /// Typically refers to the default runner that runs the system stored in the associated [`Observer`] component,
 /// but can be overridden for custom behavior.
-pub type ObserverRunner = fn(DeferredWorld, ObserverTrigger, PtrMut, propagate: &mut bool);
+pub type ObserverRunner = fn(DeferredWorld, ObserverTrigger, PtrMut, propagation: &mut Propagation);
 
 /// An [`Observer`] system. Add this [`Component`] to an [`Entity`] to turn it into an "observer".
 ///
@@ -257,6 +259,74 @@ pub type ObserverRunner = fn(DeferredWorld, ObserverTrigger, PtrMut, propagate:
 /// world.spawn(observer);
 /// ```
 ///
+/// Observer systems can also return a [`Result`]. Errors are passed to the observer's error handler, which can be set
+/// with [`Observer::with_error_handler`] and otherwise defaults to the world's
+/// [`DefaultSystemErrorHandler`](crate::result::DefaultSystemErrorHandler):
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::result;
+/// # let mut world = World::default();
+/// # #[derive(Component)]
+/// # struct Health(u32);
+/// # #[derive(Event)]
+/// # struct Explode;
+/// fn report_health(_trigger: Trigger<Explode>, query: Query<&Health>) -> Result {
+///     let health = query.get_single()?;
+///     println!("The explosion hit an entity with {} health", health.0);
+///     Ok(())
+/// }
+///
+/// world.spawn(Observer::new(report_health).with_error_handler(result::warn));
+/// ```
+///
+/// When an event is triggered, global observers run first, then the observers watching its target
+/// entity, then the ones watching its components, and last the ones watching its components on
+/// that entity. Within each of these groups, observers run in the order they were added, component
+/// by component for the observers watching components.
+///
+/// Observers can be ordered otherwise with [`Observer::before`] and [`Observer::after`], and then
+/// run before the unordered observers of their group. Like systems, observers can be grouped with
+/// [`Observer::in_set`] and ordered relative to whole sets, and an observer's system is in the set
+/// of its function, so other observers can be ordered relative to it directly. An observer can skip
+/// the observers that run after it with [`Trigger::stop`]:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # let mut world = World::default();
+/// #[derive(Event)]
+/// struct Damage(u32);
+///
+/// #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+/// struct Armor;
+///
+/// fn shield(mut trigger: Trigger<Damage>) {
+///     trigger.stop();
+/// }
+///
+/// fn apply_damage(trigger: Trigger<Damage>) {
+///     println!("Took {} damage", trigger.event().0);
+/// }
+///
+/// world.spawn(Observer::new(apply_damage).after(Armor));
+/// world.spawn(Observer::new(shield).in_set(Armor));
+/// ```
+///
+/// Observers can also have run conditions, which are evaluated each time the observer is triggered:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # let mut world = World::default();
+/// # #[derive(Event)]
+/// # struct Damage(u32);
+/// #[derive(Resource)]
+/// struct Invincible;
+///
+/// world.spawn(
+///     Observer::new(|trigger: Trigger<Damage>| {}).run_if(not(resource_exists::<Invincible>)),
+/// );
+/// ```
+///
 /// Note that the [`Observer`] component is not added to the entity it is observing. Observers should always be their own entities!
 ///
 /// You can call [`Observer::watch_entity`] more than once, which allows you to watch multiple entities with the same [`Observer`].
@@ -269,6 +339,8 @@ pub struct Observer {
     system: Box<dyn Any + Send + Sync + 'static>,
     descriptor: ObserverDescriptor,
     hook_on_add: ComponentHook,
+    error_handler: Option<fn(Error, SystemErrorContext)>,
+    conditions: Vec<BoxedCondition>,
 }
 
 impl Observer {
@@ -279,6 +351,8 @@ impl Observer {
             system: Box::new(IntoObserverSystem::into_system(system)),
             descriptor: Default::default(),
             hook_on_add: hook_on_add::<E, B, I::System>,
+            error_handler: None,
+            conditions: Vec::new(),
         }
     }
 
@@ -312,6 +386,64 @@ impl Observer {
         self.descriptor.events.push(event);
         self
     }
+
+    /// Set the error handler to use for this observer.
+    ///
+    /// If no handler is set, the world's [`DefaultSystemErrorHandler`] is used when the observer is spawned.
+    /// See the [`result`](crate::result) module for the built-in handlers.
+    pub fn with_error_handler(mut self, error_handler: fn(Error, SystemErrorContext)) -> Self {
+        self.error_handler = Some(error_handler);
+        self
+    }
+
+    /// Adds the [`Observer`] to the given system `set`, so other observers of the same event can be
+    /// ordered relative to it with [`before`](Self::before) and [`after`](Self::after).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `set` is the set of a system type.
+    pub fn in_set(mut self, set: impl SystemSet) -> Self {
+        assert!(
+            set.system_type().is_none(),
+            "adding arbitrary observers to a system type set is not allowed"
+        );
+        self.descriptor.sets.push(set.intern());
+        self
+    }
+
+    /// Runs the [`Observer`] before the other observers of the same trigger that are in `set`.
+    ///
+    /// Passing an observer's function orders it relative to the observers running that function.
+    pub fn before<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.descriptor.before.push(set.into_system_set().intern());
+        self
+    }
+
+    /// Runs the [`Observer`] after the other observers of the same trigger that are in `set`.
+    ///
+    /// Passing an observer's function orders it relative to the observers running that function.
+    pub fn after<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.descriptor.after.push(set.into_system_set().intern());
+        self
+    }
+
+    /// Only runs the [`Observer`] if the `condition` is `true` when it is triggered.
+    ///
+    /// All the conditions of an observer are evaluated, even if one of them is `false`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `condition` accesses `NonSend` resources.
+    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
+        let condition_system = IntoSystem::into_system(condition);
+        assert!(
+            condition_system.is_send(),
+            "Condition `{}` accesses `NonSend` resources. This is not currently supported.",
+            condition_system.name()
+        );
+        self.conditions.push(Box::new(condition_system));
+        self
+    }
 }
 
 impl Component for Observer {
@@ -328,11 +460,11 @@ impl Component for Observer {
     }
 }
 
-fn observer_system_runner<E: Event, B: Bundle, S: ObserverSystem<E, B>>(
+fn observer_system_runner<E: Event, B: Bundle, S: ObserverSystem<E, B, Result>>(
     mut world: DeferredWorld,
     observer_trigger: ObserverTrigger,
     ptr: PtrMut,
-    propagate: &mut bool,
+    propagation: &mut Propagation,
 ) {
     let world = world.as_unsafe_world_cell();
     // SAFETY: Observer was triggered so must still exist in world
@@ -358,17 +490,43 @@ fn observer_system_runner<E: Event, B: Bundle, S: ObserverSystem<E, B>>(
     let trigger: Trigger<E, B> = Trigger::new(
         // SAFETY: Caller ensures `ptr` is castable to `&mut T`
         unsafe { ptr.deref_mut() },
-        propagate,
+        propagation,
         observer_trigger,
     );
     // SAFETY:
     // - observer was triggered so must have an `Observer` component.
     // - observer cannot be dropped or mutated until after the system pointer is already dropped.
-    let system: *mut dyn ObserverSystem<E, B> = unsafe {
-        let mut observe = observer_cell.get_mut::<Observer>().debug_checked_unwrap();
+    let (system, conditions, error_handler): (
+        *mut dyn ObserverSystem<E, B, Result>,
+        *mut [BoxedCondition],
+        _,
+    ) = unsafe {
+        let observe = observer_cell.get_mut::<Observer>().debug_checked_unwrap();
+        let observe = observe.into_inner();
+        let error_handler = observe.error_handler.unwrap_or(result::panic);
         let system = observe.system.downcast_mut::<S>().unwrap();
-        &mut *system
+        (&mut *system, &mut observe.conditions[..], error_handler)
+    };
+
+    // SAFETY:
+    // - `update_archetype_component_access` is called first
+    // - there are no outstanding references to world except a private component
+    // - conditions are read-only systems
+    // - conditions are the initialized conditions of the observer from above
+    let should_run = unsafe {
+        (*conditions)
+            .iter_mut()
+            .fold(true, |should_run, condition| {
+                condition.update_archetype_component_access(world);
+                // Conditions with invalid parameters count as `false`, like in schedules.
+                condition.validate_param_unsafe(world)
+                    && condition.run_unsafe((), world)
+                    && should_run
+            })
     };
+    if !should_run {
+        return;
+    }
 
     // SAFETY:
     // - `update_archetype_component_access` is called first
@@ -378,7 +536,15 @@ fn observer_system_runner<E: Event, B: Bundle, S: ObserverSystem<E, B>>(
     unsafe {
         (*system).update_archetype_component_access(world);
         if (*system).validate_param_unsafe(world) {
-            (*system).run_unsafe(trigger, world);
+            if let Err(err) = (*system).run_unsafe(trigger, world) {
+                error_handler(
+                    err,
+                    SystemErrorContext {
+                        name: (*system).name(),
+                        last_run: (*system).get_last_run(),
+                    },
+                );
+            };
             (*system).queue_deferred(world.into_deferred());
         }
     }
@@ -392,7 +558,7 @@ fn observer_system_runner<E: Event, B: Bundle, S: ObserverSystem<E, B>>(
 /// The type parameters of this function _must_ match those used to create the [`Observer`].
 /// As such, it is recommended to only use this function within the [`Observer::new`] method to
 /// ensure type parameters match.
-fn hook_on_add<E: Event, B: Bundle, S: ObserverSystem<E, B>>(
+fn hook_on_add<E: Event, B: Bundle, S: ObserverSystem<E, B, Result>>(
     mut world: DeferredWorld<'_>,
     entity: Entity,
     _: ComponentId,
@@ -409,18 +575,32 @@ fn hook_on_add<E: Event, B: Bundle, S: ObserverSystem<E, B>>(
             ..Default::default()
         };
 
+        let default_error_handler = world
+            .get_resource::<DefaultSystemErrorHandler>()
+            .copied()
+            .unwrap_or_default();
+
         // Initialize System
-        let system: *mut dyn ObserverSystem<E, B> =
-            if let Some(mut observe) = world.get_mut::<Observer>(entity) {
+        let (system, conditions): (*mut dyn ObserverSystem<E, B, Result>, *mut [BoxedCondition]) =
+            if let Some(observe) = world.get_mut::<Observer>(entity) {
+                let observe = observe.into_inner();
                 descriptor.merge(&observe.descriptor);
+                if observe.error_handler.is_none() {
+                    observe.error_handler = Some(default_error_handler.0);
+                }
                 let system = observe.system.downcast_mut::<S>().unwrap();
-                &mut *system
+                (&mut *system, &mut observe.conditions[..])
             } else {
                 return;
             };
-        // SAFETY: World reference is exclusive and initialize does not touch system, so references do not alias
+        // SAFETY: World reference is exclusive and initialize does not touch system or conditions,
+        // so references do not alias
         unsafe {
             (*system).initialize(world);
+            descriptor.sets.extend((*system).default_system_sets());
+            for condition in &mut *conditions {
+                condition.initialize(world);
+            }
         }
 
         {
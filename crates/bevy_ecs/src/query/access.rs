@@ -818,6 +818,11 @@ impl<T: SparseSetIndex> Access<T> {
 /// - `Query<Option<&T>>` accesses nothing
 ///
 /// See comments the [`WorldQuery`](super::WorldQuery) impls of [`AnyOf`](super::AnyOf)/`Option`/[`Or`](super::Or) for more information.
+///
+/// Accesses to *other* entities, reached by following an entity reference stored on the matched
+/// entities (like [`Related`](super::Related) does), are tracked separately as "related" accesses.
+/// They are also part of the unfiltered access, but filters cannot make them disjoint from anything,
+/// as the filters only describe the matched entities.
 #[derive(Debug, Eq, PartialEq)]
 pub struct FilteredAccess<T: SparseSetIndex> {
     pub(crate) access: Access<T>,
@@ -825,6 +830,8 @@ pub struct FilteredAccess<T: SparseSetIndex> {
     // An array of filter sets to express `With` or `Without` clauses in disjunctive normal form, for example: `Or<(With<A>, With<B>)>`.
     // Filters like `(With<A>, Or<(With<B>, Without<C>)>` are expanded into `Or<((With<A>, With<B>), (With<A>, Without<C>))>`.
     pub(crate) filter_sets: Vec<AccessFilters<T>>,
+    // The subset of `access` that may touch entities which are not matched by `filter_sets`.
+    pub(crate) related: Access<T>,
 }
 
 // This is needed since `#[derive(Clone)]` does not generate optimized `clone_from`.
@@ -834,6 +841,7 @@ impl<T: SparseSetIndex> Clone for FilteredAccess<T> {
             access: self.access.clone(),
             required: self.required.clone(),
             filter_sets: self.filter_sets.clone(),
+            related: self.related.clone(),
         }
     }
 
@@ -841,6 +849,7 @@ impl<T: SparseSetIndex> Clone for FilteredAccess<T> {
         self.access.clone_from(&source.access);
         self.required.clone_from(&source.required);
         self.filter_sets.clone_from(&source.filter_sets);
+        self.related.clone_from(&source.related);
     }
 }
 
@@ -929,6 +938,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             access: Access::default(),
             required: FixedBitSet::default(),
             filter_sets: vec![AccessFilters::default()],
+            related: Access::default(),
         }
     }
 
@@ -939,6 +949,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             access: Access::default(),
             required: FixedBitSet::default(),
             filter_sets: Vec::new(),
+            related: Access::default(),
         }
     }
 
@@ -954,6 +965,23 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
         &mut self.access
     }
 
+    /// Returns a reference to the access to entities other than the matched ones.
+    ///
+    /// This is a subset of [`Self::access`].
+    #[inline]
+    pub fn related_access(&self) -> &Access<T> {
+        &self.related
+    }
+
+    /// Adds all accesses from `other` as accesses to entities other than the matched ones.
+    ///
+    /// The filters of `other` are ignored, and no filters are added to `self`:
+    /// the accessed entities are not the ones being matched.
+    pub fn add_related_access(&mut self, other: &Access<T>) {
+        self.access.extend(other);
+        self.related.extend(other);
+    }
+
     /// Adds access to the component given by `index`.
     pub fn add_component_read(&mut self, index: T) {
         self.access.add_component_read(index.clone());
@@ -1014,6 +1042,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
     /// Adds all of the accesses from `other` to `self`.
     pub fn extend_access(&mut self, other: &FilteredAccess<T>) {
         self.access.extend(&other.access);
+        self.related.extend(&other.related);
     }
 
     /// Returns `true` if this and `other` can be active at the same time.
@@ -1028,6 +1057,13 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             return true;
         }
 
+        // Related accesses can touch any entity, so filters can't rule out conflicts involving them.
+        if !self.related.is_components_compatible(&other.access)
+            || !other.related.is_components_compatible(&self.access)
+        {
+            return false;
+        }
+
         // If the access instances are incompatible, we want to check that whether filters can
         // guarantee that queries are disjoint.
         // Since the `filter_sets` array represents a Disjunctive Normal Form formula ("ORs of ANDs"),
@@ -1061,6 +1097,7 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
     pub fn extend(&mut self, other: &FilteredAccess<T>) {
         self.access.extend(&other.access);
         self.required.union_with(&other.required);
+        self.related.extend(&other.related);
 
         // We can avoid allocating a new array of bitsets if `other` contains just a single set of filters:
         // in this case we can short-circuit by performing an in-place union for each bitset.
@@ -1107,7 +1144,9 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
     /// Returns `true` if the set is a subset of another, i.e. `other` contains
     /// at least all the values in `self`.
     pub fn is_subset(&self, other: &FilteredAccess<T>) -> bool {
-        self.required.is_subset(&other.required) && self.access().is_subset(other.access())
+        self.required.is_subset(&other.required)
+            && self.access().is_subset(other.access())
+            && self.related.is_subset(&other.related)
     }
 
     /// Returns the indices of the elements that this access filters for.
@@ -1123,6 +1162,23 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             .iter()
             .flat_map(|f| f.without.ones().map(T::get_sparse_set_index))
     }
+
+    /// Returns `true` if this access explicitly mentions the element given by `index`,
+    /// through a read or write, an archetypal access, or a `With` or `Without` filter.
+    ///
+    /// Accessing all components at once (like [`EntityRef`] does) does not count as mentioning them.
+    ///
+    /// [`EntityRef`]: crate::world::EntityRef
+    pub fn contains(&self, index: T) -> bool {
+        let index = index.sparse_set_index();
+        (!self.access.component_read_and_writes_inverted
+            && self.access.component_read_and_writes.contains(index))
+            || self.access.archetypal.contains(index)
+            || self
+                .filter_sets
+                .iter()
+                .any(|f| f.with.contains(index) || f.without.contains(index))
+    }
 }
 
 #[derive(Eq, PartialEq)]
@@ -1542,6 +1598,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn filtered_access_related_ignores_filters() {
+        let mut related = Access::<usize>::default();
+        related.add_component_read(0);
+
+        let mut access_a = FilteredAccess::<usize>::default();
+        access_a.add_component_read(1);
+        access_a.and_with(2);
+        access_a.add_related_access(&related);
+        assert!(access_a.access().has_component_read(0));
+        assert!(access_a.related_access().has_component_read(0));
+
+        let mut access_b = FilteredAccess::<usize>::default();
+        access_b.add_component_write(1);
+        access_b.and_without(2);
+        assert!(access_a.is_compatible(&access_b));
+
+        access_b.add_component_write(0);
+        assert!(!access_a.is_compatible(&access_b));
+        assert!(!access_b.is_compatible(&access_a));
+        assert_eq!(
+            access_a.get_conflicts(&access_b),
+            AccessConflicts::from(vec![0_usize, 1])
+        );
+    }
+
     #[test]
     fn filtered_access_extend() {
         let mut access_a = FilteredAccess::<usize>::default();
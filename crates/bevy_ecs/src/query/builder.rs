@@ -78,7 +78,9 @@ impl<'w, D: QueryData, F: QueryFilter> QueryBuilder<'w, D, F> {
             self.world()
                 .components()
                 .get_info(component_id)
-                .is_some_and(|info| info.storage_type() == StorageType::Table)
+                .is_some_and(|info| {
+                    info.storage_type() == StorageType::Table && !info.migratable_storage()
+                })
         };
 
         let (mut component_reads_and_writes, component_reads_and_writes_inverted) =
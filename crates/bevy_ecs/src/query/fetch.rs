@@ -5,14 +5,16 @@ use crate::{
     component::{Component, ComponentId, Components, Mutable, StorageType, Tick},
     entity::{Entities, Entity, EntityLocation},
     query::{Access, DebugCheckedUnwrap, FilteredAccess, WorldQuery},
+    relationship::Relationship,
     storage::{ComponentSparseSet, Table, TableRow},
     world::{
         unsafe_world_cell::UnsafeWorldCell, EntityMut, EntityMutExcept, EntityRef, EntityRefExcept,
         FilteredEntityMut, FilteredEntityRef, Mut, Ref, World,
     },
 };
+use alloc::sync::Arc;
 use bevy_ptr::{ThinSlicePtr, UnsafeCellDeref};
-use core::{cell::UnsafeCell, marker::PhantomData};
+use core::{cell::UnsafeCell, marker::PhantomData, mem::MaybeUninit};
 use smallvec::SmallVec;
 use variadics_please::all_tuples;
 
@@ -47,6 +49,8 @@ use variadics_please::all_tuples;
 ///   It exposes methods to check for changes to the wrapped component.
 /// - **[`Has`].**
 ///   Returns a bool indicating whether the entity has the specified component.
+/// - **[`Related`].**
+///   Read-only access to the data of the entity targeted by a [`Relationship`] component.
 ///
 /// Implementing the trait manually can allow for a fundamentally new type of behavior.
 ///
@@ -1123,7 +1127,7 @@ unsafe impl<T: Component> WorldQuery for &T {
 
     const IS_DENSE: bool = {
         match T::STORAGE_TYPE {
-            StorageType::Table => true,
+            StorageType::Table => !T::MIGRATABLE_STORAGE,
             StorageType::SparseSet => false,
         }
     };
@@ -1132,10 +1136,10 @@ unsafe impl<T: Component> WorldQuery for &T {
     unsafe fn set_archetype<'w>(
         fetch: &mut ReadFetch<'w, T>,
         component_id: &ComponentId,
-        _archetype: &'w Archetype,
+        archetype: &'w Archetype,
         table: &'w Table,
     ) {
-        if Self::IS_DENSE {
+        if fetch.components.set_archetype(archetype, *component_id) {
             // SAFETY: `set_archetype`'s safety rules are a super set of the `set_table`'s ones.
             unsafe {
                 Self::set_table(fetch, component_id, table);
@@ -1155,7 +1159,7 @@ unsafe impl<T: Component> WorldQuery for &T {
                 .debug_checked_unwrap()
                 .into(),
         );
-        // SAFETY: set_table is only called when T::STORAGE_TYPE = StorageType::Table
+        // SAFETY: set_table is only called when `T` is stored in `table`
         unsafe { fetch.components.set_table(table_data) };
     }
 
@@ -1291,7 +1295,7 @@ unsafe impl<'__w, T: Component> WorldQuery for Ref<'__w, T> {
 
     const IS_DENSE: bool = {
         match T::STORAGE_TYPE {
-            StorageType::Table => true,
+            StorageType::Table => !T::MIGRATABLE_STORAGE,
             StorageType::SparseSet => false,
         }
     };
@@ -1300,10 +1304,10 @@ unsafe impl<'__w, T: Component> WorldQuery for Ref<'__w, T> {
     unsafe fn set_archetype<'w>(
         fetch: &mut RefFetch<'w, T>,
         component_id: &ComponentId,
-        _archetype: &'w Archetype,
+        archetype: &'w Archetype,
         table: &'w Table,
     ) {
-        if Self::IS_DENSE {
+        if fetch.components.set_archetype(archetype, *component_id) {
             // SAFETY: `set_archetype`'s safety rules are a super set of the `set_table`'s ones.
             unsafe {
                 Self::set_table(fetch, component_id, table);
@@ -1327,7 +1331,7 @@ unsafe impl<'__w, T: Component> WorldQuery for Ref<'__w, T> {
             #[cfg(not(feature = "track_location"))]
             (),
         ));
-        // SAFETY: set_table is only called when T::STORAGE_TYPE = StorageType::Table
+        // SAFETY: set_table is only called when `T` is stored in `table`
         unsafe { fetch.components.set_table(table_data) };
     }
 
@@ -1490,7 +1494,7 @@ unsafe impl<'__w, T: Component> WorldQuery for &'__w mut T {
 
     const IS_DENSE: bool = {
         match T::STORAGE_TYPE {
-            StorageType::Table => true,
+            StorageType::Table => !T::MIGRATABLE_STORAGE,
             StorageType::SparseSet => false,
         }
     };
@@ -1499,10 +1503,10 @@ unsafe impl<'__w, T: Component> WorldQuery for &'__w mut T {
     unsafe fn set_archetype<'w>(
         fetch: &mut WriteFetch<'w, T>,
         component_id: &ComponentId,
-        _archetype: &'w Archetype,
+        archetype: &'w Archetype,
         table: &'w Table,
     ) {
-        if Self::IS_DENSE {
+        if fetch.components.set_archetype(archetype, *component_id) {
             // SAFETY: `set_archetype`'s safety rules are a super set of the `set_table`'s ones.
             unsafe {
                 Self::set_table(fetch, component_id, table);
@@ -1526,7 +1530,7 @@ unsafe impl<'__w, T: Component> WorldQuery for &'__w mut T {
             #[cfg(not(feature = "track_location"))]
             (),
         ));
-        // SAFETY: set_table is only called when T::STORAGE_TYPE = StorageType::Table
+        // SAFETY: set_table is only called when `T` is stored in `table`
         unsafe { fetch.components.set_table(table_data) };
     }
 
@@ -1945,7 +1949,7 @@ unsafe impl<T: Component> WorldQuery for Has<T> {
 
     const IS_DENSE: bool = {
         match T::STORAGE_TYPE {
-            StorageType::Table => true,
+            StorageType::Table => !T::MIGRATABLE_STORAGE,
             StorageType::SparseSet => false,
         }
     };
@@ -2006,6 +2010,226 @@ unsafe impl<T: Component> QueryData for Has<T> {
 /// SAFETY: [`Has`] is read only
 unsafe impl<T: Component> ReadOnlyQueryData for Has<T> {}
 
+/// Fetches read-only data `D` from the entity targeted by the [`Relationship`] `R` of each queried entity.
+///
+/// This "joins" the queried entities with their related entities without needing a separate
+/// [`Query::get`](crate::system::Query::get) call for each of them.
+/// Only entities with an `R` component are matched.
+/// The item is `None` if the targeted entity doesn't exist or doesn't match `D`.
+///
+/// `D` is accessed on entities that are not matched by the query's filters,
+/// so `Related` conflicts with any mutable access to the components in `D`, regardless of filters.
+/// Note that the default query filters (such as [`Disabled`](crate::entity_disabling::Disabled))
+/// are not applied to the related entity.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::query::Related;
+/// #[derive(Component)]
+/// #[relationship(relationship_target = Holding)]
+/// struct Holder(Entity);
+///
+/// #[derive(Component)]
+/// #[relationship_target(relationship = Holder)]
+/// struct Holding(Vec<Entity>);
+///
+/// #[derive(Component)]
+/// struct Weapon;
+///
+/// #[derive(Component)]
+/// struct Player {
+///     name: String,
+/// }
+///
+/// fn player_weapons(weapons: Query<(Entity, Related<Holder, &Player>), With<Weapon>>) {
+///     for (weapon, player) in &weapons {
+///         if let Some(player) = player {
+///             println!("{weapon} is held by {}", player.name);
+///         }
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(player_weapons);
+/// ```
+pub struct Related<R, D>(PhantomData<(R, D)>);
+
+impl<R, D> core::fmt::Debug for Related<R, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(
+            f,
+            "Related<{}, {}>",
+            core::any::type_name::<R>(),
+            core::any::type_name::<D>()
+        )
+    }
+}
+
+/// The [`WorldQuery::State`] of [`Related`].
+#[doc(hidden)]
+pub struct RelatedState<D: WorldQuery> {
+    relationship: ComponentId,
+    data: Arc<D::State>,
+}
+
+/// The [`WorldQuery::Fetch`] of [`Related`].
+#[doc(hidden)]
+pub struct RelatedFetch<'w, R: Relationship, D: WorldQuery> {
+    relationship: <&'static R as WorldQuery>::Fetch<'w>,
+    data: D::Fetch<'w>,
+    data_state: Arc<D::State>,
+    world: UnsafeWorldCell<'w>,
+}
+
+impl<R: Relationship, D: WorldQuery> Clone for RelatedFetch<'_, R, D> {
+    fn clone(&self) -> Self {
+        Self {
+            relationship: self.relationship,
+            data: self.data.clone(),
+            data_state: self.data_state.clone(),
+            world: self.world,
+        }
+    }
+}
+
+/// SAFETY:
+/// `fetch` reads `R` on the queried entity, which is added to the access by `&R`'s `update_component_access`.
+/// `D` is only used to read the targeted entity, and its access is added as related access,
+/// which applies to every archetype rather than only the matched ones.
+/// This is sound because `D` is read only.
+unsafe impl<R: Relationship, D: ReadOnlyQueryData> WorldQuery for Related<R, D> {
+    type Item<'w> = Option<D::Item<'w>>;
+    type Fetch<'w> = RelatedFetch<'w, R, D>;
+    type State = RelatedState<D>;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::Item<'wlong>) -> Self::Item<'wshort> {
+        item.map(D::shrink)
+    }
+
+    fn shrink_fetch<'wlong: 'wshort, 'wshort>(fetch: Self::Fetch<'wlong>) -> Self::Fetch<'wshort> {
+        RelatedFetch {
+            relationship: <&R>::shrink_fetch(fetch.relationship),
+            data: D::shrink_fetch(fetch.data),
+            data_state: fetch.data_state,
+            world: fetch.world,
+        }
+    }
+
+    #[inline]
+    unsafe fn init_fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        state: &Self::State,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self::Fetch<'w> {
+        RelatedFetch {
+            // SAFETY: The invariants are upheld by the caller.
+            relationship: unsafe {
+                <&R>::init_fetch(world, &state.relationship, last_run, this_run)
+            },
+            // SAFETY: The invariants are upheld by the caller.
+            data: unsafe { D::init_fetch(world, &state.data, last_run, this_run) },
+            data_state: state.data.clone(),
+            world,
+        }
+    }
+
+    const IS_DENSE: bool = <&R>::IS_DENSE;
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        state: &Self::State,
+        archetype: &'w Archetype,
+        table: &'w Table,
+    ) {
+        // SAFETY: The invariants are upheld by the caller.
+        unsafe {
+            <&R>::set_archetype(
+                &mut fetch.relationship,
+                &state.relationship,
+                archetype,
+                table,
+            );
+        }
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(fetch: &mut Self::Fetch<'w>, state: &Self::State, table: &'w Table) {
+        // SAFETY: The invariants are upheld by the caller.
+        unsafe { <&R>::set_table(&mut fetch.relationship, &state.relationship, table) };
+    }
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> Self::Item<'w> {
+        // SAFETY: The invariants are upheld by the caller.
+        let target = unsafe { <&R>::fetch(&mut fetch.relationship, entity, table_row) }.get();
+        let location = fetch.world.entities().get(target)?;
+        let archetype = fetch.world.archetypes().get(location.archetype_id)?;
+        if !D::matches_component_set(&fetch.data_state, &|id| archetype.contains(id)) {
+            return None;
+        }
+        // SAFETY: The table is only used to read the components in `D`, which are part of the related access.
+        let table = unsafe { fetch.world.storages() }
+            .tables
+            .get(location.table_id)?;
+        // SAFETY:
+        // - `archetype` and `table` belong to the world `fetch.data` was initialized with.
+        // - the related access covers every archetype, so `D` may read from the target entity.
+        unsafe {
+            D::set_archetype(&mut fetch.data, &fetch.data_state, archetype, table);
+            Some(D::fetch(&mut fetch.data, target, location.table_row))
+        }
+    }
+
+    fn update_component_access(state: &Self::State, access: &mut FilteredAccess<ComponentId>) {
+        <&R>::update_component_access(&state.relationship, access);
+
+        let mut data_access = FilteredAccess::default();
+        D::update_component_access(&state.data, &mut data_access);
+        assert!(
+            access.access().is_compatible(data_access.access()),
+            "Related<{}, {}> conflicts with a previous access in this query. Shared access cannot coincide with exclusive access.",
+            core::any::type_name::<R>(),
+            core::any::type_name::<D>(),
+        );
+        access.add_related_access(data_access.access());
+    }
+
+    fn init_state(world: &mut World) -> Self::State {
+        RelatedState {
+            relationship: <&R>::init_state(world),
+            data: Arc::new(D::init_state(world)),
+        }
+    }
+
+    fn get_state(components: &Components) -> Option<Self::State> {
+        Some(RelatedState {
+            relationship: <&R>::get_state(components)?,
+            data: Arc::new(D::get_state(components)?),
+        })
+    }
+
+    fn matches_component_set(
+        state: &Self::State,
+        set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        <&R>::matches_component_set(&state.relationship, set_contains_id)
+    }
+}
+
+/// SAFETY: `Self` is the same as `Self::ReadOnly`
+unsafe impl<R: Relationship, D: ReadOnlyQueryData> QueryData for Related<R, D> {
+    type ReadOnly = Self;
+}
+
+/// SAFETY: [`Related`] only reads `R` and `D`, which is read only
+unsafe impl<R: Relationship, D: ReadOnlyQueryData> ReadOnlyQueryData for Related<R, D> {}
+
 /// The `AnyOf` query parameter fetches entities with any of the component types included in T.
 ///
 /// `Query<AnyOf<(&A, &B, &mut C)>>` is equivalent to `Query<(Option<&A>, Option<&B>, Option<&mut C>), Or<(With<A>, With<B>, With<C>)>>`.
@@ -2335,18 +2559,43 @@ unsafe impl<T: ?Sized> ReadOnlyQueryData for PhantomData<T> {}
 
 /// A compile-time checked union of two different types that differs based on the
 /// [`StorageType`] of a given component.
+///
+/// For components with [migratable storage](Component::MIGRATABLE_STORAGE) the storage type is
+/// only known per archetype, so the [`migratable`](Self::migratable) variant keeps both types and
+/// the switch is made at runtime.
 pub(super) union StorageSwitch<C: Component, T: Copy, S: Copy> {
     /// The table variant. Requires the component to be a table component.
     table: T,
     /// The sparse set variant. Requires the component to be a sparse set component.
     sparse_set: S,
+    /// The runtime checked variant. Requires the component to have migratable storage.
+    migratable: MigratableStorageSwitch<T, S>,
     _marker: PhantomData<C>,
 }
 
+/// Both variants of a [`StorageSwitch`] for a component with migratable storage.
+#[derive(Clone, Copy)]
+struct MigratableStorageSwitch<T: Copy, S: Copy> {
+    /// Only initialized while the component is stored in a table in the current archetype.
+    table: MaybeUninit<T>,
+    sparse_set: S,
+    /// The storage type of the component in the current archetype.
+    storage_type: StorageType,
+}
+
 impl<C: Component, T: Copy, S: Copy> StorageSwitch<C, T, S> {
     /// Creates a new [`StorageSwitch`] using the given closures to initialize
     /// the variant corresponding to the component's [`StorageType`].
     pub fn new(table: impl FnOnce() -> T, sparse_set: impl FnOnce() -> S) -> Self {
+        if C::MIGRATABLE_STORAGE {
+            return Self {
+                migratable: MigratableStorageSwitch {
+                    table: MaybeUninit::uninit(),
+                    sparse_set: sparse_set(),
+                    storage_type: StorageType::SparseSet,
+                },
+            };
+        }
         match C::STORAGE_TYPE {
             StorageType::Table => Self { table: table() },
             StorageType::SparseSet => Self {
@@ -2355,7 +2604,28 @@ impl<C: Component, T: Copy, S: Copy> StorageSwitch<C, T, S> {
         }
     }
 
-    /// Creates a new [`StorageSwitch`] using a table variant.
+    /// Switches to the variant for the storage of the component in `archetype`.
+    ///
+    /// Returns `true` if the component is stored in the archetype's table, in which case
+    /// [`Self::set_table`] must be called before extracting a value.
+    #[inline]
+    pub fn set_archetype(&mut self, archetype: &Archetype, component_id: ComponentId) -> bool {
+        if C::MIGRATABLE_STORAGE {
+            // SAFETY: Archetypes passed to `set_archetype` always contain the queried component
+            let storage_type = unsafe {
+                archetype
+                    .get_storage_type(component_id)
+                    .debug_checked_unwrap()
+            };
+            // The migratable variant is the one initialized for components with migratable storage.
+            self.migratable.storage_type = storage_type;
+            storage_type == StorageType::Table
+        } else {
+            C::STORAGE_TYPE == StorageType::Table
+        }
+    }
+
+    /// Sets the table variant.
     ///
     /// # Panics
     ///
@@ -2363,9 +2633,16 @@ impl<C: Component, T: Copy, S: Copy> StorageSwitch<C, T, S> {
     ///
     /// # Safety
     ///
-    /// `C` must be a table component.
+    /// `C` must be a table component, or have migratable storage and be stored in a table
+    /// in the current archetype.
     #[inline]
     pub unsafe fn set_table(&mut self, table: T) {
+        if C::MIGRATABLE_STORAGE {
+            // The migratable variant is the one initialized for components with migratable storage.
+            self.migratable.table = MaybeUninit::new(table);
+            self.migratable.storage_type = StorageType::Table;
+            return;
+        }
         match C::STORAGE_TYPE {
             StorageType::Table => self.table = table,
             _ => {
@@ -2380,6 +2657,19 @@ impl<C: Component, T: Copy, S: Copy> StorageSwitch<C, T, S> {
     /// Fetches the internal value from the variant that corresponds to the
     /// component's [`StorageType`].
     pub fn extract<R>(&self, table: impl FnOnce(T) -> R, sparse_set: impl FnOnce(S) -> R) -> R {
+        if C::MIGRATABLE_STORAGE {
+            // SAFETY: The migratable variant is the one initialized for components with
+            // migratable storage.
+            let switch = unsafe { self.migratable };
+            return match switch.storage_type {
+                StorageType::Table => table(
+                    // SAFETY: The storage type is only set to `Table` by `set_table`, which
+                    // initializes the table variant.
+                    unsafe { switch.table.assume_init() },
+                ),
+                StorageType::SparseSet => sparse_set(switch.sparse_set),
+            };
+        }
         match C::STORAGE_TYPE {
             StorageType::Table => table(
                 // SAFETY: C::STORAGE_TYPE == StorageType::Table
@@ -2503,4 +2793,80 @@ mod tests {
 
         assert_is_system(client_system);
     }
+
+    #[derive(Component)]
+    #[relationship(relationship_target = Holding)]
+    struct Holder(Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = Holder)]
+    struct Holding(alloc::vec::Vec<Entity>);
+
+    #[derive(Component, PartialEq, Debug)]
+    struct Health(u32);
+
+    #[test]
+    fn related_fetches_data_from_target() {
+        use crate::query::With;
+        use alloc::vec::Vec;
+
+        let mut world = World::new();
+        let player = world.spawn(Health(10)).id();
+        let crate_entity = world.spawn(B).id();
+        let held = world.spawn((A, Holder(player))).id();
+        let held_by_crate = world.spawn((A, Holder(crate_entity))).id();
+        world.spawn(A);
+
+        let mut query = world.query_filtered::<(Entity, Related<Holder, &Health>), With<A>>();
+        let mut results = query.iter(&world).collect::<Vec<_>>();
+        results.sort_by_key(|(entity, _)| *entity);
+        assert_eq!(results, [(held, Some(&Health(10))), (held_by_crate, None)]);
+
+        world.despawn(player);
+        let mut query = world.query::<Related<Holder, &Health>>();
+        assert_eq!(query.iter(&world).flatten().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "B0001")]
+    fn related_access_ignores_filters() {
+        use crate::{
+            query::{With, Without},
+            system::{IntoSystem, System},
+        };
+
+        fn incompatible(
+            _: Query<Related<Holder, &Health>, With<A>>,
+            _: Query<&mut Health, Without<A>>,
+        ) {
+        }
+
+        let mut world = World::new();
+        let mut system = IntoSystem::into_system(incompatible);
+        system.initialize(&mut world);
+    }
+
+    #[test]
+    fn related_archetype_access_covers_unmatched_archetypes() {
+        use crate::{archetype::ArchetypeComponentId, query::QueryState};
+
+        let mut world = World::new();
+        world.spawn((A, Holder(Entity::PLACEHOLDER)));
+        world.spawn(Health(1));
+
+        let mut access = Access::<ArchetypeComponentId>::default();
+        let state =
+            QueryState::<Related<Holder, &Health>>::new_with_access(&mut world, &mut access);
+        let health_archetype = world
+            .archetypes()
+            .iter()
+            .find(|archetype| archetype.contains(world.component_id::<Health>().unwrap()))
+            .unwrap();
+        let health_id = health_archetype
+            .get_archetype_component_id(world.component_id::<Health>().unwrap())
+            .unwrap();
+        assert!(access.has_component_read(health_id));
+        assert!(!access.has_component_write(health_id));
+        drop(state);
+    }
 }
@@ -1,5 +1,6 @@
 use crate::{
     archetype::Archetype,
+    change_detection::{DetectChanges, FieldAccess, FieldTicks, Ref},
     component::{Component, ComponentId, Components, StorageType, Tick},
     entity::Entity,
     query::{DebugCheckedUnwrap, FilteredAccess, StorageSwitch, WorldQuery},
@@ -16,7 +17,8 @@ use variadics_please::all_tuples;
 /// - **Component filters.**
 ///   [`With`] and [`Without`] filters can be applied to check if the queried entity does or does not contain a particular component.
 /// - **Change detection filters.**
-///   [`Added`] and [`Changed`] filters can be applied to detect component changes to an entity.
+///   [`Added`] and [`Changed`] filters can be applied to detect component changes to an entity,
+///   and [`FieldChanged`] filters detect changes to a single field of a component.
 /// - **`QueryFilter` tuples.**
 ///   If every element of a tuple implements `QueryFilter`, then the tuple itself also implements the same trait.
 ///   This enables a single `Query` to filter over multiple conditions.
@@ -159,7 +161,7 @@ unsafe impl<T: Component> WorldQuery for With<T> {
 
     const IS_DENSE: bool = {
         match T::STORAGE_TYPE {
-            StorageType::Table => true,
+            StorageType::Table => !T::MIGRATABLE_STORAGE,
             StorageType::SparseSet => false,
         }
     };
@@ -270,7 +272,7 @@ unsafe impl<T: Component> WorldQuery for Without<T> {
 
     const IS_DENSE: bool = {
         match T::STORAGE_TYPE {
-            StorageType::Table => true,
+            StorageType::Table => !T::MIGRATABLE_STORAGE,
             StorageType::SparseSet => false,
         }
     };
@@ -710,7 +712,7 @@ unsafe impl<T: Component> WorldQuery for Added<T> {
 
     const IS_DENSE: bool = {
         match T::STORAGE_TYPE {
-            StorageType::Table => true,
+            StorageType::Table => !T::MIGRATABLE_STORAGE,
             StorageType::SparseSet => false,
         }
     };
@@ -719,10 +721,10 @@ unsafe impl<T: Component> WorldQuery for Added<T> {
     unsafe fn set_archetype<'w>(
         fetch: &mut Self::Fetch<'w>,
         component_id: &ComponentId,
-        _archetype: &'w Archetype,
+        archetype: &'w Archetype,
         table: &'w Table,
     ) {
-        if Self::IS_DENSE {
+        if fetch.ticks.set_archetype(archetype, *component_id) {
             // SAFETY: `set_archetype`'s safety rules are a super set of the `set_table`'s ones.
             unsafe {
                 Self::set_table(fetch, component_id, table);
@@ -742,7 +744,7 @@ unsafe impl<T: Component> WorldQuery for Added<T> {
                 .debug_checked_unwrap()
                 .into(),
         );
-        // SAFETY: set_table is only called when T::STORAGE_TYPE = StorageType::Table
+        // SAFETY: set_table is only called when `T` is stored in `table`
         unsafe { fetch.ticks.set_table(table_ticks) };
     }
 
@@ -943,7 +945,7 @@ unsafe impl<T: Component> WorldQuery for Changed<T> {
 
     const IS_DENSE: bool = {
         match T::STORAGE_TYPE {
-            StorageType::Table => true,
+            StorageType::Table => !T::MIGRATABLE_STORAGE,
             StorageType::SparseSet => false,
         }
     };
@@ -952,10 +954,10 @@ unsafe impl<T: Component> WorldQuery for Changed<T> {
     unsafe fn set_archetype<'w>(
         fetch: &mut Self::Fetch<'w>,
         component_id: &ComponentId,
-        _archetype: &'w Archetype,
+        archetype: &'w Archetype,
         table: &'w Table,
     ) {
-        if Self::IS_DENSE {
+        if fetch.ticks.set_archetype(archetype, *component_id) {
             // SAFETY: `set_archetype`'s safety rules are a super set of the `set_table`'s ones.
             unsafe {
                 Self::set_table(fetch, component_id, table);
@@ -975,7 +977,7 @@ unsafe impl<T: Component> WorldQuery for Changed<T> {
                 .debug_checked_unwrap()
                 .into(),
         );
-        // SAFETY: set_table is only called when T::STORAGE_TYPE = StorageType::Table
+        // SAFETY: set_table is only called when `T` is stored in `table`
         unsafe { fetch.ticks.set_table(table_ticks) };
     }
 
@@ -1044,6 +1046,142 @@ unsafe impl<T: Component> QueryFilter for Changed<T> {
     }
 }
 
+/// A filter on a [`TrackedFields`](crate::change_detection::TrackedFields) component `T` that retrieves entities where the field with index
+/// `FIELD` has changed since the last time the system ran.
+///
+/// Use [`field_index`](crate::change_detection::field_index) to look up the index of a field by name.
+/// Changes made through [`TrackedMut`](crate::change_detection::TrackedMut) only affect the changed field,
+/// while any other mutable access to `T`, as well as inserting it, changes every field.
+///
+/// Like [`Changed`], this is not an [`ArchetypeFilter`].
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::change_detection::field_index;
+/// # use bevy_ecs::query::FieldChanged;
+/// #[derive(Component)]
+/// #[component(track_fields)]
+/// struct Stats {
+///     health: u32,
+///     mana: u32,
+/// }
+///
+/// fn redraw_health_bar(query: Query<&Stats, FieldChanged<Stats, { field_index::<Stats>("health") }>>) {
+///     for stats in &query {
+///         println!("Health is now {}", stats.health);
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(redraw_health_bar);
+/// ```
+pub struct FieldChanged<T, const FIELD: usize>(PhantomData<T>);
+
+type FieldChangedQuery<T> = (Ref<'static, T>, &'static FieldTicks<T>);
+
+// SAFETY:
+// This delegates to `(Ref<T>, &FieldTicks<T>)`, which reads `T` and `FieldTicks<T>`.
+// `update_component_access` and `matches_component_set` are delegated as well.
+unsafe impl<T: FieldAccess<FIELD>, const FIELD: usize> WorldQuery for FieldChanged<T, FIELD> {
+    type Item<'w> = bool;
+    type Fetch<'w> = <FieldChangedQuery<T> as WorldQuery>::Fetch<'w>;
+    type State = <FieldChangedQuery<T> as WorldQuery>::State;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::Item<'wlong>) -> Self::Item<'wshort> {
+        item
+    }
+
+    fn shrink_fetch<'wlong: 'wshort, 'wshort>(fetch: Self::Fetch<'wlong>) -> Self::Fetch<'wshort> {
+        <FieldChangedQuery<T> as WorldQuery>::shrink_fetch(fetch)
+    }
+
+    #[inline]
+    unsafe fn init_fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        state: &Self::State,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self::Fetch<'w> {
+        // SAFETY: The invariants are uphold by the caller.
+        unsafe {
+            <FieldChangedQuery<T> as WorldQuery>::init_fetch(world, state, last_run, this_run)
+        }
+    }
+
+    const IS_DENSE: bool = <FieldChangedQuery<T> as WorldQuery>::IS_DENSE;
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        state: &Self::State,
+        archetype: &'w Archetype,
+        table: &'w Table,
+    ) {
+        // SAFETY: The invariants are uphold by the caller.
+        unsafe {
+            <FieldChangedQuery<T> as WorldQuery>::set_archetype(fetch, state, archetype, table);
+        }
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(fetch: &mut Self::Fetch<'w>, state: &Self::State, table: &'w Table) {
+        // SAFETY: The invariants are uphold by the caller.
+        unsafe { <FieldChangedQuery<T> as WorldQuery>::set_table(fetch, state, table) }
+    }
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> Self::Item<'w> {
+        // SAFETY: The invariants are uphold by the caller.
+        let (value, field_ticks) =
+            unsafe { <FieldChangedQuery<T> as WorldQuery>::fetch(fetch, entity, table_row) };
+        field_ticks.is_field_changed(
+            FIELD,
+            value.last_changed(),
+            value.ticks.last_run,
+            value.ticks.this_run,
+        )
+    }
+
+    #[inline]
+    fn update_component_access(state: &Self::State, access: &mut FilteredAccess<ComponentId>) {
+        <FieldChangedQuery<T> as WorldQuery>::update_component_access(state, access);
+    }
+
+    fn init_state(world: &mut World) -> Self::State {
+        <FieldChangedQuery<T> as WorldQuery>::init_state(world)
+    }
+
+    fn get_state(components: &Components) -> Option<Self::State> {
+        <FieldChangedQuery<T> as WorldQuery>::get_state(components)
+    }
+
+    fn matches_component_set(
+        state: &Self::State,
+        set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        <FieldChangedQuery<T> as WorldQuery>::matches_component_set(state, set_contains_id)
+    }
+}
+
+// SAFETY: WorldQuery impl performs only read access on `T` and `FieldTicks<T>`
+unsafe impl<T: FieldAccess<FIELD>, const FIELD: usize> QueryFilter for FieldChanged<T, FIELD> {
+    const IS_ARCHETYPAL: bool = false;
+
+    #[inline(always)]
+    unsafe fn filter_fetch(
+        fetch: &mut Self::Fetch<'_>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> bool {
+        // SAFETY: The invariants are uphold by the caller.
+        unsafe { Self::fetch(fetch, entity, table_row) }
+    }
+}
+
 /// A marker trait to indicate that the filter works at an archetype level.
 ///
 /// This is needed to implement [`ExactSizeIterator`] for
@@ -3,6 +3,7 @@ use crate::{
     batching::BatchingStrategy,
     component::{ComponentId, Tick},
     entity::{Entity, EntityBorrow, EntitySet},
+    entity_disabling::DefaultQueryFilters,
     prelude::FromWorld,
     query::{
         Access, DebugCheckedUnwrap, FilteredAccess, QueryCombinationIter, QueryIter, QueryParIter,
@@ -186,6 +187,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
                 if state.new_archetype_internal(archetype) {
                     state.update_archetype_component_access(archetype, access);
                 }
+                state.update_related_archetype_component_access(archetype, access);
             }
         }
         state.archetype_generation = world.archetypes.generation();
@@ -214,7 +216,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     fn new_uninitialized(world: &mut World) -> Self {
         let fetch_state = D::init_state(world);
         let filter_state = F::init_state(world);
-        Self::from_states_uninitialized(world.id(), fetch_state, filter_state)
+        Self::from_states_uninitialized(world, fetch_state, filter_state)
     }
 
     /// Creates a new [`QueryState`] but does not populate it with the matched results from the World yet
@@ -225,7 +227,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         let fetch_state = D::get_state(world.components())?;
         let filter_state = F::get_state(world.components())?;
         Some(Self::from_states_uninitialized(
-            world.id(),
+            world,
             fetch_state,
             filter_state,
         ))
@@ -236,7 +238,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     /// `new_archetype` and its variants must be called on all of the World's archetypes before the
     /// state can return valid query results.
     fn from_states_uninitialized(
-        world_id: WorldId,
+        world: &World,
         fetch_state: <D as WorldQuery>::State,
         filter_state: <F as WorldQuery>::State,
     ) -> Self {
@@ -255,10 +257,15 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
 
         // For queries without dynamic filters the dense-ness of the query is equal to the dense-ness
         // of its static type parameters.
-        let is_dense = D::IS_DENSE && F::IS_DENSE;
+        let mut is_dense = D::IS_DENSE && F::IS_DENSE;
+
+        if let Some(default_filters) = world.get_resource::<DefaultQueryFilters>() {
+            default_filters.modify_access(&mut component_access);
+            is_dense &= default_filters.is_dense(world.components());
+        }
 
         Self {
-            world_id,
+            world_id: world.id(),
             archetype_generation: ArchetypeGeneration::initial(),
             matched_storage_ids: Vec::new(),
             is_dense,
@@ -282,15 +289,24 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         let filter_state = F::init_state(builder.world_mut());
         D::set_access(&mut fetch_state, builder.access());
 
+        let mut component_access = builder.access().clone();
+
+        // For dynamic queries the dense-ness is given by the query builder.
+        let mut is_dense = builder.is_dense();
+
+        if let Some(default_filters) = builder.world().get_resource::<DefaultQueryFilters>() {
+            default_filters.modify_access(&mut component_access);
+            is_dense &= default_filters.is_dense(builder.world().components());
+        }
+
         let mut state = Self {
             world_id: builder.world().id(),
             archetype_generation: ArchetypeGeneration::initial(),
             matched_storage_ids: Vec::new(),
-            // For dynamic queries the dense-ness is given by the query builder.
-            is_dense: builder.is_dense(),
+            is_dense,
             fetch_state,
             filter_state,
-            component_access: builder.access().clone(),
+            component_access,
             matched_tables: Default::default(),
             matched_archetypes: Default::default(),
             #[cfg(feature = "trace")]
@@ -407,6 +423,10 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     /// If `world` does not match the one used to call `QueryState::new` for this instance.
     pub fn update_archetypes_unsafe_world_cell(&mut self, world: UnsafeWorldCell) {
         self.validate_world(world.id());
+        let compactions = world.archetypes().generation().compactions;
+        if self.archetype_generation.compactions != compactions {
+            self.reset_archetypes(compactions);
+        }
         if self.component_access.required.is_empty() {
             let archetypes = world.archetypes();
             let old_generation =
@@ -443,7 +463,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             if let Some(archetypes) = potential_archetypes {
                 for archetype_id in archetypes {
                     // exclude archetypes that have already been processed
-                    if archetype_id < &self.archetype_generation.0 {
+                    if archetype_id < &self.archetype_generation.next {
                         continue;
                     }
                     // SAFETY: get_potential_archetypes only returns archetype ids that are valid for the world
@@ -498,6 +518,8 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             // SAFETY: The caller ensures that `archetype` is from the World the state was initialized from.
             unsafe { self.update_archetype_component_access(archetype, access) };
         }
+        // SAFETY: The caller ensures that `archetype` is from the World the state was initialized from.
+        unsafe { self.update_related_archetype_component_access(archetype, access) };
     }
 
     /// Process the given [`Archetype`] to update internal metadata about the [`Table`](crate::storage::Table)s
@@ -509,6 +531,9 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     /// # Safety
     /// `archetype` must be from the `World` this state was initialized from.
     unsafe fn new_archetype_internal(&mut self, archetype: &Archetype) -> bool {
+        if archetype.compactions() != self.archetype_generation.compactions {
+            self.reset_archetypes(archetype.compactions());
+        }
         if D::matches_component_set(&self.fetch_state, &|id| archetype.contains(id))
             && F::matches_component_set(&self.filter_state, &|id| archetype.contains(id))
             && self.matches_component_set(&|id| archetype.contains(id))
@@ -537,6 +562,31 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         }
     }
 
+    /// Forgets the matched archetypes and tables, after the ids of the archetypes were changed by
+    /// [`World::compact_storage`].
+    fn reset_archetypes(&mut self, compactions: u32) {
+        self.matched_tables.clear();
+        self.matched_archetypes.clear();
+        self.matched_storage_ids.clear();
+        self.archetype_generation = ArchetypeGeneration {
+            compactions,
+            ..ArchetypeGeneration::initial()
+        };
+    }
+
+    /// Panics if the archetypes matched by this query were invalidated by
+    /// [`World::compact_storage`] since it was last updated.
+    #[inline]
+    #[track_caller]
+    fn validate_archetypes(&self, world: &World) {
+        assert_eq!(
+            self.archetype_generation.compactions,
+            world.archetypes().generation().compactions,
+            "The archetypes of the World were compacted since this QueryState was last updated. \
+            Call `QueryState::update_archetypes` before this method."
+        );
+    }
+
     /// Returns `true` if this query matches a set of components. Otherwise, returns `false`.
     pub fn matches_component_set(&self, set_contains_id: &impl Fn(ComponentId) -> bool) -> bool {
         self.component_access.filter_sets.iter().any(|set| {
@@ -561,45 +611,23 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         archetype: &Archetype,
         access: &mut Access<ArchetypeComponentId>,
     ) {
-        // As a fast path, we can iterate directly over the components involved
-        // if the `access` isn't inverted.
-        let (component_reads_and_writes, component_reads_and_writes_inverted) =
-            self.component_access.access.component_reads_and_writes();
-        let (component_writes, component_writes_inverted) =
-            self.component_access.access.component_writes();
-
-        if !component_reads_and_writes_inverted && !component_writes_inverted {
-            component_reads_and_writes.for_each(|id| {
-                if let Some(id) = archetype.get_archetype_component_id(id) {
-                    access.add_component_read(id);
-                }
-            });
-            component_writes.for_each(|id| {
-                if let Some(id) = archetype.get_archetype_component_id(id) {
-                    access.add_component_write(id);
-                }
-            });
-            return;
-        }
+        add_archetype_component_access(&self.component_access.access, archetype, access);
+    }
 
-        for (component_id, archetype_component_id) in
-            archetype.components_with_archetype_component_id()
-        {
-            if self
-                .component_access
-                .access
-                .has_component_read(component_id)
-            {
-                access.add_component_read(archetype_component_id);
-            }
-            if self
-                .component_access
-                .access
-                .has_component_write(component_id)
-            {
-                access.add_component_write(archetype_component_id);
-            }
-        }
+    /// For the given `archetype`, adds the components this query accesses on entities other than
+    /// the matched ones (see [`FilteredAccess::related_access`]) to `access`.
+    ///
+    /// Unlike [`Self::update_archetype_component_access`], this applies to every archetype,
+    /// whether or not it is matched by the query.
+    ///
+    /// # Safety
+    /// `archetype` must be from the `World` this state was initialized from.
+    pub unsafe fn update_related_archetype_component_access(
+        &self,
+        archetype: &Archetype,
+        access: &mut Access<ArchetypeComponentId>,
+    ) {
+        add_archetype_component_access(&self.component_access.related, archetype, access);
     }
 
     /// Use this to transform a [`QueryState`] into a more generic [`QueryState`].
@@ -965,6 +993,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         entity: Entity,
     ) -> Result<ROQueryItem<'w, D>, QueryEntityError<'w>> {
         self.validate_world(world.id());
+        self.validate_archetypes(world);
         // SAFETY: query is read only and world is validated
         unsafe {
             self.as_readonly().get_unchecked_manual(
@@ -1159,6 +1188,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     #[inline]
     pub fn iter_manual<'w, 's>(&'s self, world: &'w World) -> QueryIter<'w, 's, D::ReadOnly, F> {
         self.validate_world(world.id());
+        self.validate_archetypes(world);
         // SAFETY: query is read only and world is validated
         unsafe {
             self.as_readonly().iter_unchecked_manual(
@@ -1291,6 +1321,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         entities: EntityList,
     ) -> QueryManyIter<'w, 's, D::ReadOnly, F, EntityList::IntoIter> {
         self.validate_world(world.id());
+        self.validate_archetypes(world);
         // SAFETY: query is read only, world id is validated
         unsafe {
             self.as_readonly().iter_many_unchecked_manual(
@@ -1374,6 +1405,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         entities: EntityList,
     ) -> QueryManyUniqueIter<'w, 's, D::ReadOnly, F, EntityList::IntoIter> {
         self.validate_world(world.id());
+        self.validate_archetypes(world);
         // SAFETY: query is read only, world id is validated
         unsafe {
             self.as_readonly().iter_many_unique_unchecked_manual(
@@ -1876,6 +1908,43 @@ impl<D: QueryData, F: QueryFilter> From<QueryBuilder<'_, D, F>> for QueryState<D
     }
 }
 
+/// Adds the [`ArchetypeComponentId`]s of `archetype` corresponding to the components in `component_access` to `access`.
+fn add_archetype_component_access(
+    component_access: &Access<ComponentId>,
+    archetype: &Archetype,
+    access: &mut Access<ArchetypeComponentId>,
+) {
+    // As a fast path, we can iterate directly over the components involved
+    // if the `access` isn't inverted.
+    let (component_reads_and_writes, component_reads_and_writes_inverted) =
+        component_access.component_reads_and_writes();
+    let (component_writes, component_writes_inverted) = component_access.component_writes();
+
+    if !component_reads_and_writes_inverted && !component_writes_inverted {
+        component_reads_and_writes.for_each(|id| {
+            if let Some(id) = archetype.get_archetype_component_id(id) {
+                access.add_component_read(id);
+            }
+        });
+        component_writes.for_each(|id| {
+            if let Some(id) = archetype.get_archetype_component_id(id) {
+                access.add_component_write(id);
+            }
+        });
+        return;
+    }
+
+    for (component_id, archetype_component_id) in archetype.components_with_archetype_component_id()
+    {
+        if component_access.has_component_read(component_id) {
+            access.add_component_read(archetype_component_id);
+        }
+        if component_access.has_component_write(component_id) {
+            access.add_component_write(archetype_component_id);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate as bevy_ecs;
@@ -76,7 +76,10 @@ use disqualified::ShortName;
 /// A [`ReflectComponent`] for type `T` can be obtained via
 /// [`bevy_reflect::TypeRegistration::data`].
 #[derive(Clone)]
-pub struct ReflectComponent(ReflectComponentFns);
+pub struct ReflectComponent {
+    fns: ReflectComponentFns,
+    is_mutable: bool,
+}
 
 /// The raw function pointers needed to make up a [`ReflectComponent`].
 ///
@@ -132,7 +135,7 @@ impl ReflectComponentFns {
     /// This is useful if you want to start with the default implementation before overriding some
     /// of the functions to create a custom implementation.
     pub fn new<T: Component + FromReflect + TypePath>() -> Self {
-        <ReflectComponent as FromType<T>>::from_type().0
+        <ReflectComponent as FromType<T>>::from_type().fns
     }
 }
 
@@ -144,7 +147,7 @@ impl ReflectComponent {
         component: &dyn PartialReflect,
         registry: &TypeRegistry,
     ) {
-        (self.0.insert)(entity, component, registry);
+        (self.fns.insert)(entity, component, registry);
     }
 
     /// Uses reflection to set the value of this [`Component`] type in the entity to the given value.
@@ -155,36 +158,38 @@ impl ReflectComponent {
     ///
     /// Will also panic if [`Component`] is immutable.
     pub fn apply<'a>(&self, entity: impl Into<EntityMut<'a>>, component: &dyn PartialReflect) {
-        (self.0.apply)(entity.into(), component);
+        (self.fns.apply)(entity.into(), component);
     }
 
     /// Uses reflection to set the value of this [`Component`] type in the entity to the given value or insert a new one if it does not exist.
     ///
-    /// # Panics
-    ///
-    /// Panics if [`Component`] is immutable.
+    /// If the [`Component`] is [immutable](Self::is_mutable), a new value is always inserted,
+    /// replacing the existing one like [`insert()`](Self::insert), so that its hooks and observers
+    /// see the change. This lets scenes be written again over the entities they spawned, even
+    /// when those have immutable components such as relationships.
+    /// Use [`apply()`](Self::apply) instead to panic for immutable components.
     pub fn apply_or_insert(
         &self,
         entity: &mut EntityWorldMut,
         component: &dyn PartialReflect,
         registry: &TypeRegistry,
     ) {
-        (self.0.apply_or_insert)(entity, component, registry);
+        (self.fns.apply_or_insert)(entity, component, registry);
     }
 
     /// Removes this [`Component`] type from the entity. Does nothing if it doesn't exist.
     pub fn remove(&self, entity: &mut EntityWorldMut) {
-        (self.0.remove)(entity);
+        (self.fns.remove)(entity);
     }
 
     /// Returns whether entity contains this [`Component`]
     pub fn contains<'a>(&self, entity: impl Into<FilteredEntityRef<'a>>) -> bool {
-        (self.0.contains)(entity.into())
+        (self.fns.contains)(entity.into())
     }
 
     /// Gets the value of this [`Component`] type from the entity as a reflected reference.
     pub fn reflect<'a>(&self, entity: impl Into<FilteredEntityRef<'a>>) -> Option<&'a dyn Reflect> {
-        (self.0.reflect)(entity.into())
+        (self.fns.reflect)(entity.into())
     }
 
     /// Gets the value of this [`Component`] type from the entity as a mutable reflected reference.
@@ -196,7 +201,7 @@ impl ReflectComponent {
         &self,
         entity: impl Into<FilteredEntityMut<'a>>,
     ) -> Option<Mut<'a, dyn Reflect>> {
-        (self.0.reflect_mut)(entity.into())
+        (self.fns.reflect_mut)(entity.into())
     }
 
     /// # Safety
@@ -213,7 +218,7 @@ impl ReflectComponent {
         entity: UnsafeEntityCell<'a>,
     ) -> Option<Mut<'a, dyn Reflect>> {
         // SAFETY: safety requirements deferred to caller
-        unsafe { (self.0.reflect_unchecked_mut)(entity) }
+        unsafe { (self.fns.reflect_unchecked_mut)(entity) }
     }
 
     /// Gets the value of this [`Component`] type from entity from `source_world` and [applies](Self::apply()) it to the value of this [`Component`] type in entity in `destination_world`.
@@ -229,7 +234,7 @@ impl ReflectComponent {
         destination_entity: Entity,
         registry: &TypeRegistry,
     ) {
-        (self.0.copy)(
+        (self.fns.copy)(
             source_world,
             destination_world,
             source_entity,
@@ -238,9 +243,18 @@ impl ReflectComponent {
         );
     }
 
+    /// Returns `true` if this [`Component`] can be mutated in place.
+    ///
+    /// Immutable components can only be replaced, through [`insert()`](Self::insert) or
+    /// [`apply_or_insert()`](Self::apply_or_insert): [`apply()`](Self::apply) and
+    /// [`reflect_mut()`](Self::reflect_mut) panic for them, so check this first when the type is not known.
+    pub fn is_mutable(&self) -> bool {
+        self.is_mutable
+    }
+
     /// Register the type of this [`Component`] in [`World`], returning its [`ComponentId`].
     pub fn register_component(&self, world: &mut World) -> ComponentId {
-        (self.0.register_component)(world)
+        (self.fns.register_component)(world)
     }
 
     /// Create a custom implementation of [`ReflectComponent`].
@@ -253,9 +267,25 @@ impl ReflectComponent {
     /// Usually you should derive [`Reflect`] and add the `#[reflect(Component)]` component
     /// to generate a [`ReflectComponent`] implementation automatically.
     ///
+    /// The component is assumed to be [mutable](Self::is_mutable), use [`Self::new_immutable()`]
+    /// for immutable components.
+    ///
     /// See [`ReflectComponentFns`] for more information.
     pub fn new(fns: ReflectComponentFns) -> Self {
-        Self(fns)
+        Self {
+            fns,
+            is_mutable: true,
+        }
+    }
+
+    /// Create a custom implementation of [`ReflectComponent`] for an immutable component.
+    ///
+    /// See [`Self::new()`] for more information.
+    pub fn new_immutable(fns: ReflectComponentFns) -> Self {
+        Self {
+            fns,
+            is_mutable: false,
+        }
     }
 
     /// The underlying function pointers implementing methods on `ReflectComponent`.
@@ -275,88 +305,99 @@ impl ReflectComponent {
     /// [`TypeRegistration::data::<ReflectComponent>`]: bevy_reflect::TypeRegistration::data
     /// [`TypeRegistry::get`]: bevy_reflect::TypeRegistry::get
     pub fn fn_pointers(&self) -> &ReflectComponentFns {
-        &self.0
+        &self.fns
     }
 }
 
 impl<C: Component + Reflect + TypePath> FromType<C> for ReflectComponent {
     fn from_type() -> Self {
-        // TODO: Currently we panic if a component is immutable and you use
-        // reflection to mutate it. Perhaps the mutation methods should be fallible?
-        ReflectComponent(ReflectComponentFns {
-            insert: |entity, reflected_component, registry| {
-                let component = entity.world_scope(|world| {
-                    from_reflect_with_fallback::<C>(reflected_component, world, registry)
-                });
-                entity.insert(component);
-            },
-            apply: |mut entity, reflected_component| {
-                if !C::Mutability::MUTABLE {
-                    let name = ShortName::of::<C>();
-                    panic!("Cannot call `ReflectComponent::apply` on component {name}. It is immutable, and cannot modified through reflection");
-                }
-
-                // SAFETY: guard ensures `C` is a mutable component
-                let mut component = unsafe { entity.get_mut_assume_mutable::<C>() }.unwrap();
-                component.apply(reflected_component);
-            },
-            apply_or_insert: |entity, reflected_component, registry| {
-                if !C::Mutability::MUTABLE {
-                    let name = ShortName::of::<C>();
-                    panic!("Cannot call `ReflectComponent::apply_or_insert` on component {name}. It is immutable, and cannot modified through reflection");
-                }
-
-                // SAFETY: guard ensures `C` is a mutable component
-                if let Some(mut component) = unsafe { entity.get_mut_assume_mutable::<C>() } {
-                    component.apply(reflected_component.as_partial_reflect());
-                } else {
+        ReflectComponent {
+            fns: ReflectComponentFns {
+                insert: |entity, reflected_component, registry| {
                     let component = entity.world_scope(|world| {
                         from_reflect_with_fallback::<C>(reflected_component, world, registry)
                     });
                     entity.insert(component);
-                }
-            },
-            remove: |entity| {
-                entity.remove::<C>();
-            },
-            contains: |entity| entity.contains::<C>(),
-            copy: |source_world, destination_world, source_entity, destination_entity, registry| {
-                let source_component = source_world.get::<C>(source_entity).unwrap();
-                let destination_component =
-                    from_reflect_with_fallback::<C>(source_component, destination_world, registry);
-                destination_world
-                    .entity_mut(destination_entity)
-                    .insert(destination_component);
-            },
-            reflect: |entity| entity.get::<C>().map(|c| c as &dyn Reflect),
-            reflect_mut: |entity| {
-                if !C::Mutability::MUTABLE {
-                    let name = ShortName::of::<C>();
-                    panic!("Cannot call `ReflectComponent::reflect_mut` on component {name}. It is immutable, and cannot modified through reflection");
-                }
+                },
+                apply: |mut entity, reflected_component| {
+                    if !C::Mutability::MUTABLE {
+                        let name = ShortName::of::<C>();
+                        panic!("Cannot call `ReflectComponent::apply` on component {name}. It is immutable, and cannot modified through reflection");
+                    }
 
-                // SAFETY: guard ensures `C` is a mutable component
-                unsafe {
-                    entity
-                        .into_mut_assume_mutable::<C>()
-                        .map(|c| c.map_unchanged(|value| value as &mut dyn Reflect))
-                }
-            },
-            reflect_unchecked_mut: |entity| {
-                if !C::Mutability::MUTABLE {
-                    let name = ShortName::of::<C>();
-                    panic!("Cannot call `ReflectComponent::reflect_unchecked_mut` on component {name}. It is immutable, and cannot modified through reflection");
-                }
+                    // SAFETY: guard ensures `C` is a mutable component
+                    let mut component = unsafe { entity.get_mut_assume_mutable::<C>() }.unwrap();
+                    component.apply(reflected_component);
+                },
+                apply_or_insert: |entity, reflected_component, registry| {
+                    if !C::Mutability::MUTABLE {
+                        // Immutable components are replaced instead, so their hooks see the change.
+                        let component = entity.world_scope(|world| {
+                            from_reflect_with_fallback::<C>(reflected_component, world, registry)
+                        });
+                        entity.insert(component);
+                    } else if let Some(mut component) =
+                        // SAFETY: guard ensures `C` is a mutable component
+                        unsafe { entity.get_mut_assume_mutable::<C>() }
+                    {
+                        component.apply(reflected_component.as_partial_reflect());
+                    } else {
+                        let component = entity.world_scope(|world| {
+                            from_reflect_with_fallback::<C>(reflected_component, world, registry)
+                        });
+                        entity.insert(component);
+                    }
+                },
+                remove: |entity| {
+                    entity.remove::<C>();
+                },
+                contains: |entity| entity.contains::<C>(),
+                copy: |source_world,
+                       destination_world,
+                       source_entity,
+                       destination_entity,
+                       registry| {
+                    let source_component = source_world.get::<C>(source_entity).unwrap();
+                    let destination_component = from_reflect_with_fallback::<C>(
+                        source_component,
+                        destination_world,
+                        registry,
+                    );
+                    destination_world
+                        .entity_mut(destination_entity)
+                        .insert(destination_component);
+                },
+                reflect: |entity| entity.get::<C>().map(|c| c as &dyn Reflect),
+                reflect_mut: |entity| {
+                    if !C::Mutability::MUTABLE {
+                        let name = ShortName::of::<C>();
+                        panic!("Cannot call `ReflectComponent::reflect_mut` on component {name}. It is immutable, and cannot modified through reflection");
+                    }
 
-                // SAFETY: reflect_unchecked_mut is an unsafe function pointer used by
-                // `reflect_unchecked_mut` which must be called with an UnsafeEntityCell with access to the component `C` on the `entity`
-                // guard ensures `C` is a mutable component
-                let c = unsafe { entity.get_mut_assume_mutable::<C>() };
-                c.map(|c| c.map_unchanged(|value| value as &mut dyn Reflect))
-            },
-            register_component: |world: &mut World| -> ComponentId {
-                world.register_component::<C>()
+                    // SAFETY: guard ensures `C` is a mutable component
+                    unsafe {
+                        entity
+                            .into_mut_assume_mutable::<C>()
+                            .map(|c| c.map_unchanged(|value| value as &mut dyn Reflect))
+                    }
+                },
+                reflect_unchecked_mut: |entity| {
+                    if !C::Mutability::MUTABLE {
+                        let name = ShortName::of::<C>();
+                        panic!("Cannot call `ReflectComponent::reflect_unchecked_mut` on component {name}. It is immutable, and cannot modified through reflection");
+                    }
+
+                    // SAFETY: reflect_unchecked_mut is an unsafe function pointer used by
+                    // `reflect_unchecked_mut` which must be called with an UnsafeEntityCell with access to the component `C` on the `entity`
+                    // guard ensures `C` is a mutable component
+                    let c = unsafe { entity.get_mut_assume_mutable::<C>() };
+                    c.map(|c| c.map_unchanged(|value| value as &mut dyn Reflect))
+                },
+                register_component: |world: &mut World| -> ComponentId {
+                    world.register_component::<C>()
+                },
             },
-        })
+            is_mutable: C::Mutability::MUTABLE,
+        }
     }
 }
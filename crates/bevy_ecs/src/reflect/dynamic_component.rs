@@ -0,0 +1,1260 @@
+//! Definitions for dynamic components: components that are described at runtime and whose
+//! values are stored as [`PartialReflect`] trait objects.
+//!
+//! Dynamic components let scripting languages and other runtime code define and query components
+//! without any Rust type or `unsafe` code.
+//! A dynamic component is registered with [`World::register_dynamic_component`], which accepts a
+//! [`DynamicComponentDescriptor`] describing the shape of its values, either with the [`TypeInfo`]
+//! of a reflected type or with a default value to use as a prototype.
+//!
+//! Values are inserted with [`EntityWorldMut::insert_dynamic`], which checks them against that
+//! shape, and are read back with [`EntityWorldMut::get_dynamic`].
+//! [`DynamicQueryBuilder`] builds queries over dynamic components, as well as over reflected Rust
+//! components, that yield reflected views of the matched components.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::reflect::{DynamicComponentDescriptor, DynamicQueryBuilder};
+//! # use bevy_reflect::{DynamicStruct, PartialReflect};
+//! let mut world = World::new();
+//!
+//! let mut health = DynamicStruct::default();
+//! health.insert("current", 100.0f32);
+//! health.insert("max", 100.0f32);
+//! let health_id = world
+//!     .register_dynamic_component(DynamicComponentDescriptor::new("Health").with_default(health))
+//!     .unwrap();
+//!
+//! let mut damaged = DynamicStruct::default();
+//! damaged.insert("current", 40.0f32);
+//! damaged.insert("max", 100.0f32);
+//! let entity = world
+//!     .spawn_empty()
+//!     .insert_dynamic(health_id, Box::new(damaged))
+//!     .unwrap()
+//!     .id();
+//!
+//! let mut query = DynamicQueryBuilder::new(&mut world)
+//!     .read(health_id)
+//!     .build()
+//!     .unwrap();
+//! for item in query.iter(&world) {
+//!     let health = item.get(health_id).unwrap();
+//!     let bevy_reflect::ReflectRef::Struct(health) = health.reflect_ref() else {
+//!         unreachable!()
+//!     };
+//!     assert_eq!(item.entity(), entity);
+//!     assert_eq!(health.field("current").unwrap().try_downcast_ref(), Some(&40.0f32));
+//! }
+//! ```
+
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::alloc::Layout;
+
+use bevy_ptr::{OwningPtr, Ptr, PtrMut};
+use bevy_reflect::{
+    PartialReflect, ReflectFromPtr, ReflectKind, ReflectRef, TypeInfo, UnnamedField, VariantInfo,
+    VariantType,
+};
+use bevy_utils::HashMap;
+use thiserror::Error;
+
+use crate::{
+    change_detection::Mut,
+    component::{
+        ComponentCloneHandler, ComponentDescriptor, ComponentId, ComponentInfo, StorageType,
+    },
+    entity::{ComponentCloneCtx, Entity},
+    query::{QueryBuilder, QueryState},
+    reflect::AppTypeRegistry,
+    world::{DeferredWorld, EntityWorldMut, FilteredEntityMut, FilteredEntityRef, World},
+};
+
+/// The type every dynamic component value is stored as.
+type DynamicValue = Box<dyn PartialReflect>;
+
+/// Describes a dynamic component to register with [`World::register_dynamic_component`].
+///
+/// The shape of the component's values is taken from its [`TypeInfo`] if one is given, or from
+/// its default value otherwise. Without either, any value is accepted.
+pub struct DynamicComponentDescriptor {
+    name: Cow<'static, str>,
+    storage_type: StorageType,
+    type_info: Option<&'static TypeInfo>,
+    default: Option<DynamicValue>,
+}
+
+impl DynamicComponentDescriptor {
+    /// Creates a descriptor for a dynamic component with the given unique name, stored in a table.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            storage_type: StorageType::Table,
+            type_info: None,
+            default: None,
+        }
+    }
+
+    /// Sets the [`StorageType`] of the component.
+    pub fn with_storage_type(mut self, storage_type: StorageType) -> Self {
+        self.storage_type = storage_type;
+        self
+    }
+
+    /// Requires values of the component to match the given [`TypeInfo`].
+    pub fn with_type_info(mut self, type_info: &'static TypeInfo) -> Self {
+        self.type_info = Some(type_info);
+        self
+    }
+
+    /// Sets the default value of the component.
+    ///
+    /// Unless a [`TypeInfo`] is given, values of the component must have the same shape as this
+    /// value: the same kind, the same field names for structs, and the same number of fields for
+    /// tuples and tuple structs.
+    pub fn with_default(mut self, default: impl PartialReflect) -> Self {
+        self.default = Some(Box::new(default));
+        self
+    }
+}
+
+/// Information about a registered dynamic component.
+#[derive(Debug)]
+pub struct DynamicComponentInfo {
+    id: ComponentId,
+    name: Cow<'static, str>,
+    type_info: Option<&'static TypeInfo>,
+    default: Option<DynamicValue>,
+}
+
+impl DynamicComponentInfo {
+    /// Returns the [`ComponentId`] of the component.
+    pub fn id(&self) -> ComponentId {
+        self.id
+    }
+
+    /// Returns the name of the component.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [`TypeInfo`] values of the component must match, if any.
+    pub fn type_info(&self) -> Option<&'static TypeInfo> {
+        self.type_info
+    }
+
+    /// Returns the default value of the component, if any.
+    pub fn default_value(&self) -> Option<&dyn PartialReflect> {
+        self.default.as_deref()
+    }
+
+    /// Checks that `value` has the shape required by this component.
+    pub fn validate(&self, value: &dyn PartialReflect) -> Result<(), DynamicComponentError> {
+        if let Some(type_info) = self.type_info {
+            self.validate_type_info(value, type_info)
+        } else if let Some(default) = &self.default {
+            self.validate_prototype(value, default.as_ref())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_type_info(
+        &self,
+        value: &dyn PartialReflect,
+        type_info: &TypeInfo,
+    ) -> Result<(), DynamicComponentError> {
+        if value
+            .get_represented_type_info()
+            .is_some_and(|info| info.type_path() == type_info.type_path())
+        {
+            return Ok(());
+        }
+        self.validate_kind(value, type_info.kind())?;
+        let validate = |value: &dyn PartialReflect, type_info: &Option<&'static TypeInfo>| {
+            type_info.map_or(Ok(()), |type_info| {
+                self.validate_type_info(value, type_info)
+            })
+        };
+        match (value.reflect_ref(), type_info) {
+            (ReflectRef::Struct(_), TypeInfo::Struct(info)) => self.validate_named_fields(
+                &info
+                    .iter()
+                    .map(|field| (field.name(), field.type_info()))
+                    .collect::<Vec<_>>(),
+                value,
+                validate,
+            ),
+            (ReflectRef::TupleStruct(_), TypeInfo::TupleStruct(info)) => self
+                .validate_unnamed_fields(
+                    &info.iter().map(UnnamedField::type_info).collect::<Vec<_>>(),
+                    value,
+                    validate,
+                ),
+            (ReflectRef::Tuple(_), TypeInfo::Tuple(info)) => self.validate_unnamed_fields(
+                &info.iter().map(UnnamedField::type_info).collect::<Vec<_>>(),
+                value,
+                validate,
+            ),
+            (ReflectRef::Enum(enum_value), TypeInfo::Enum(info)) => {
+                match info.variant(enum_value.variant_name()) {
+                    Some(VariantInfo::Struct(variant)) => self.validate_named_fields(
+                        &variant
+                            .iter()
+                            .map(|field| (field.name(), field.type_info()))
+                            .collect::<Vec<_>>(),
+                        value,
+                        validate,
+                    ),
+                    Some(VariantInfo::Tuple(variant)) => self.validate_unnamed_fields(
+                        &variant
+                            .iter()
+                            .map(UnnamedField::type_info)
+                            .collect::<Vec<_>>(),
+                        value,
+                        validate,
+                    ),
+                    Some(VariantInfo::Unit(_)) => {
+                        self.validate_field_count(enum_value.field_len(), 0)
+                    }
+                    None => Err(self.unknown_variant(enum_value.variant_name())),
+                }
+            }
+            (ReflectRef::List(list), TypeInfo::List(info)) => list
+                .iter()
+                .try_for_each(|item| validate(item, &info.item_info())),
+            (ReflectRef::Array(array), TypeInfo::Array(info)) => {
+                self.validate_field_count(array.len(), info.capacity())?;
+                array
+                    .iter()
+                    .try_for_each(|item| validate(item, &info.item_info()))
+            }
+            (ReflectRef::Map(map), TypeInfo::Map(info)) => {
+                map.iter().try_for_each(|(key, value)| {
+                    validate(key, &info.key_info())?;
+                    validate(value, &info.value_info())
+                })
+            }
+            (ReflectRef::Opaque(value), _) => {
+                Err(self.type_mismatch(type_info.type_path(), value.reflect_type_path()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_prototype(
+        &self,
+        value: &dyn PartialReflect,
+        prototype: &dyn PartialReflect,
+    ) -> Result<(), DynamicComponentError> {
+        self.validate_kind(value, prototype.reflect_kind())?;
+        let validate = |value: &dyn PartialReflect, prototype: &&dyn PartialReflect| {
+            self.validate_prototype(value, *prototype)
+        };
+        match (value.reflect_ref(), prototype.reflect_ref()) {
+            (ReflectRef::Enum(value_enum), ReflectRef::Enum(prototype_enum))
+                if value_enum.variant_name() != prototype_enum.variant_name() =>
+            {
+                // The prototype doesn't tell the shape of other variants.
+                Ok(())
+            }
+            (ReflectRef::List(list), ReflectRef::List(prototype)) => match prototype.get(0) {
+                Some(prototype) => list
+                    .iter()
+                    .try_for_each(|item| self.validate_prototype(item, prototype)),
+                None => Ok(()),
+            },
+            (ReflectRef::Array(array), ReflectRef::Array(prototype)) => {
+                self.validate_field_count(array.len(), prototype.len())?;
+                array
+                    .iter()
+                    .zip(prototype.iter())
+                    .try_for_each(|(item, prototype)| self.validate_prototype(item, prototype))
+            }
+            (ReflectRef::Opaque(value), ReflectRef::Opaque(prototype)) => {
+                if value.reflect_type_path() == prototype.reflect_type_path() {
+                    Ok(())
+                } else {
+                    Err(self
+                        .type_mismatch(prototype.reflect_type_path(), value.reflect_type_path()))
+                }
+            }
+            _ => match fields(prototype) {
+                Some(Fields::Named(expected)) => {
+                    self.validate_named_fields(&expected, value, validate)
+                }
+                Some(Fields::Unnamed(expected)) => {
+                    self.validate_unnamed_fields(&expected, value, validate)
+                }
+                None => Ok(()),
+            },
+        }
+    }
+
+    /// Checks that the named fields of `value` are exactly the `expected` ones, then checks each
+    /// field with `validate`.
+    fn validate_named_fields<T>(
+        &self,
+        expected: &[(&str, T)],
+        value: &dyn PartialReflect,
+        validate: impl Fn(&dyn PartialReflect, &T) -> Result<(), DynamicComponentError>,
+    ) -> Result<(), DynamicComponentError> {
+        let Some(Fields::Named(found)) = fields(value) else {
+            return Ok(());
+        };
+        for (name, _) in expected {
+            if !found.iter().any(|(field, _)| field == name) {
+                return Err(self.missing_field(name));
+            }
+        }
+        for (name, _) in &found {
+            if !expected.iter().any(|(field, _)| field == name) {
+                return Err(self.unknown_field(name));
+            }
+        }
+        for (name, expected) in expected {
+            let (_, field) = found.iter().find(|(field, _)| field == name).unwrap();
+            validate(*field, expected)?;
+        }
+        Ok(())
+    }
+
+    /// Checks that `value` has as many unnamed fields as `expected`, then checks each field with
+    /// `validate`.
+    fn validate_unnamed_fields<T>(
+        &self,
+        expected: &[T],
+        value: &dyn PartialReflect,
+        validate: impl Fn(&dyn PartialReflect, &T) -> Result<(), DynamicComponentError>,
+    ) -> Result<(), DynamicComponentError> {
+        let Some(Fields::Unnamed(found)) = fields(value) else {
+            return Ok(());
+        };
+        self.validate_field_count(found.len(), expected.len())?;
+        found
+            .into_iter()
+            .zip(expected)
+            .try_for_each(|(field, expected)| validate(field, expected))
+    }
+
+    fn validate_kind(
+        &self,
+        value: &dyn PartialReflect,
+        expected: ReflectKind,
+    ) -> Result<(), DynamicComponentError> {
+        let found = value.reflect_kind();
+        if found == expected {
+            Ok(())
+        } else {
+            Err(DynamicComponentError::KindMismatch {
+                component: self.name.to_string(),
+                expected,
+                found,
+            })
+        }
+    }
+
+    fn validate_field_count(
+        &self,
+        found: usize,
+        expected: usize,
+    ) -> Result<(), DynamicComponentError> {
+        if found == expected {
+            Ok(())
+        } else {
+            Err(DynamicComponentError::FieldCountMismatch {
+                component: self.name.to_string(),
+                expected,
+                found,
+            })
+        }
+    }
+
+    fn missing_field(&self, field: &str) -> DynamicComponentError {
+        DynamicComponentError::MissingField {
+            component: self.name.to_string(),
+            field: field.to_string(),
+        }
+    }
+
+    fn unknown_field(&self, field: &str) -> DynamicComponentError {
+        DynamicComponentError::UnknownField {
+            component: self.name.to_string(),
+            field: field.to_string(),
+        }
+    }
+
+    fn unknown_variant(&self, variant: &str) -> DynamicComponentError {
+        DynamicComponentError::UnknownVariant {
+            component: self.name.to_string(),
+            variant: variant.to_string(),
+        }
+    }
+
+    fn type_mismatch(&self, expected: &str, found: &str) -> DynamicComponentError {
+        DynamicComponentError::TypeMismatch {
+            component: self.name.to_string(),
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+}
+
+/// The fields of a struct, tuple struct, tuple or enum value.
+enum Fields<'a> {
+    Named(Vec<(&'a str, &'a dyn PartialReflect)>),
+    Unnamed(Vec<&'a dyn PartialReflect>),
+}
+
+/// Returns the fields of `value`, if it's a struct, tuple struct, tuple or enum.
+fn fields(value: &dyn PartialReflect) -> Option<Fields<'_>> {
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => Some(Fields::Named(
+            (0..value.field_len())
+                .filter_map(|index| Some((value.name_at(index)?, value.field_at(index)?)))
+                .collect(),
+        )),
+        ReflectRef::Enum(value) if value.variant_type() == VariantType::Struct => {
+            Some(Fields::Named(
+                (0..value.field_len())
+                    .filter_map(|index| Some((value.name_at(index)?, value.field_at(index)?)))
+                    .collect(),
+            ))
+        }
+        ReflectRef::TupleStruct(value) => Some(Fields::Unnamed(value.iter_fields().collect())),
+        ReflectRef::Tuple(value) => Some(Fields::Unnamed(value.iter_fields().collect())),
+        ReflectRef::Enum(value) => Some(Fields::Unnamed(
+            (0..value.field_len())
+                .filter_map(|index| value.field_at(index))
+                .collect(),
+        )),
+        _ => None,
+    }
+}
+
+/// The [`DynamicComponentInfo`] of every dynamic component registered with
+/// [`World::register_dynamic_component`].
+///
+/// It's owned by the [`Components`](crate::component::Components) of the world, see
+/// [`Components::dynamic_components`](crate::component::Components::dynamic_components).
+#[derive(Debug, Default)]
+pub struct DynamicComponents {
+    infos: HashMap<ComponentId, DynamicComponentInfo>,
+    ids: HashMap<Cow<'static, str>, ComponentId>,
+}
+
+impl DynamicComponents {
+    /// Returns the [`DynamicComponentInfo`] of the dynamic component with the given id.
+    pub fn get(&self, id: ComponentId) -> Option<&DynamicComponentInfo> {
+        self.infos.get(&id)
+    }
+
+    /// Returns the id of the dynamic component with the given name.
+    pub fn get_id(&self, name: &str) -> Option<ComponentId> {
+        self.ids.get(name).copied()
+    }
+
+    /// Returns an iterator over the [`DynamicComponentInfo`] of every dynamic component.
+    pub fn iter(&self) -> impl Iterator<Item = &DynamicComponentInfo> {
+        self.infos.values()
+    }
+}
+
+/// An error returned when registering, inserting or querying dynamic components.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DynamicComponentError {
+    /// A dynamic component with the same name is already registered.
+    #[error("A dynamic component named `{0}` is already registered")]
+    AlreadyRegistered(String),
+    /// The component is not a dynamic component.
+    #[error("The component {0:?} is not a dynamic component")]
+    NotDynamic(ComponentId),
+    /// The component is neither a dynamic component nor a component registered with
+    /// [`ReflectFromPtr`] type data.
+    #[error("The component {0:?} can't be reflected")]
+    NotReflectable(ComponentId),
+    /// The component can't be accessed mutably.
+    #[error("The component {0:?} is immutable")]
+    Immutable(ComponentId),
+    /// The value has a different [`ReflectKind`] than the component expects.
+    #[error("Expected a {expected} value for `{component}`, found a {found}")]
+    KindMismatch {
+        /// The name of the component.
+        component: String,
+        /// The expected kind.
+        expected: ReflectKind,
+        /// The kind of the value.
+        found: ReflectKind,
+    },
+    /// The value has a different type than the component expects.
+    #[error("Expected a value of type `{expected}` for `{component}`, found `{found}`")]
+    TypeMismatch {
+        /// The name of the component.
+        component: String,
+        /// The expected type path.
+        expected: String,
+        /// The type path of the value.
+        found: String,
+    },
+    /// The value is missing a field the component expects.
+    #[error("The value for `{component}` is missing the field `{field}`")]
+    MissingField {
+        /// The name of the component.
+        component: String,
+        /// The name of the missing field.
+        field: String,
+    },
+    /// The value has a field the component doesn't expect.
+    #[error("The value for `{component}` has the unknown field `{field}`")]
+    UnknownField {
+        /// The name of the component.
+        component: String,
+        /// The name of the unknown field.
+        field: String,
+    },
+    /// The value is an enum variant the component doesn't have.
+    #[error("The value for `{component}` has the unknown variant `{variant}`")]
+    UnknownVariant {
+        /// The name of the component.
+        component: String,
+        /// The name of the unknown variant.
+        variant: String,
+    },
+    /// The value has a different number of fields than the component expects.
+    #[error("Expected {expected} fields for `{component}`, found {found}")]
+    FieldCountMismatch {
+        /// The name of the component.
+        component: String,
+        /// The expected number of fields.
+        expected: usize,
+        /// The number of fields of the value.
+        found: usize,
+    },
+}
+
+/// # Safety
+///
+/// `ptr` must point to a [`DynamicValue`].
+unsafe fn drop_dynamic_value(ptr: OwningPtr<'_>) {
+    // SAFETY: Ensured by the caller.
+    unsafe { ptr.drop_as::<DynamicValue>() }
+}
+
+/// Clones a dynamic component with [`PartialReflect::clone_value`].
+fn clone_dynamic_value(_world: &mut DeferredWorld, ctx: &mut ComponentCloneCtx) {
+    // SAFETY: Dynamic components are always stored as a `DynamicValue`,
+    // and the target pointer has its layout.
+    unsafe {
+        ctx.write_target_component_ptr(|source, target| {
+            let value = source.deref::<DynamicValue>().clone_value();
+            target.cast::<DynamicValue>().write(value);
+            true
+        });
+    }
+}
+
+impl World {
+    /// Registers a dynamic component described by `descriptor`, returning its [`ComponentId`].
+    ///
+    /// See the [module-level documentation](crate::reflect::dynamic_component) for an example.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a dynamic component with the same name is already registered, or if
+    /// the default value doesn't match the [`TypeInfo`] of the descriptor.
+    pub fn register_dynamic_component(
+        &mut self,
+        descriptor: DynamicComponentDescriptor,
+    ) -> Result<ComponentId, DynamicComponentError> {
+        let DynamicComponentDescriptor {
+            name,
+            storage_type,
+            type_info,
+            default,
+        } = descriptor;
+
+        if self.components.dynamic_components().ids.contains_key(&name) {
+            return Err(DynamicComponentError::AlreadyRegistered(name.into_owned()));
+        }
+
+        let mut info = DynamicComponentInfo {
+            id: ComponentId::new(0),
+            name,
+            type_info,
+            default: None,
+        };
+        if let Some(default) = &default {
+            info.validate(default.as_ref())?;
+        }
+        info.default = default;
+
+        // SAFETY:
+        // - `drop_dynamic_value` drops a value with the layout of `DynamicValue`.
+        // - `DynamicValue` is `Send + Sync`.
+        let component_descriptor = unsafe {
+            ComponentDescriptor::new_with_layout(
+                info.name.clone(),
+                storage_type,
+                Layout::new::<DynamicValue>(),
+                Some(drop_dynamic_value),
+                true,
+            )
+        };
+        info.id = self.register_component_with_descriptor(component_descriptor);
+        self.get_component_clone_handlers_mut()
+            .set_component_handler(
+                info.id,
+                ComponentCloneHandler::custom_handler(clone_dynamic_value),
+            );
+
+        let id = info.id;
+        // SAFETY: The component was just registered with the layout and drop fn of a `DynamicValue`.
+        let components = unsafe { self.components.dynamic_components_mut() };
+        components.ids.insert(info.name.clone(), id);
+        components.infos.insert(id, info);
+        Ok(id)
+    }
+
+    /// Returns the id of the dynamic component with the given name.
+    pub fn dynamic_component_id(&self, name: &str) -> Option<ComponentId> {
+        self.components.dynamic_components().get_id(name)
+    }
+
+    /// Returns the [`DynamicComponentInfo`] of the dynamic component with the given id.
+    pub fn dynamic_component_info(&self, id: ComponentId) -> Option<&DynamicComponentInfo> {
+        self.components.dynamic_components().get(id)
+    }
+}
+
+impl EntityWorldMut<'_> {
+    /// Inserts a value of the dynamic component `id` into the entity, replacing the previous one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `id` isn't a dynamic component or `value` doesn't have the shape
+    /// required by the component. See [`DynamicComponentInfo::validate`].
+    ///
+    /// # Panics
+    ///
+    /// If the entity has been despawned while this `EntityWorldMut` is still alive.
+    #[track_caller]
+    pub fn insert_dynamic(
+        &mut self,
+        id: ComponentId,
+        value: Box<dyn PartialReflect>,
+    ) -> Result<&mut Self, DynamicComponentError> {
+        self.world()
+            .dynamic_component_info(id)
+            .ok_or(DynamicComponentError::NotDynamic(id))?
+            .validate(value.as_ref())?;
+        OwningPtr::make(value, |ptr| {
+            // SAFETY: `id` is a dynamic component, which is stored as a `DynamicValue`.
+            unsafe { self.insert_by_id(id, ptr) }
+        });
+        Ok(self)
+    }
+
+    /// Returns the value of the dynamic component `id`, if the entity has it.
+    ///
+    /// # Panics
+    ///
+    /// If the entity has been despawned while this `EntityWorldMut` is still alive.
+    pub fn get_dynamic(&self, id: ComponentId) -> Option<&dyn PartialReflect> {
+        self.world().dynamic_component_info(id)?;
+        let ptr = self.get_by_id(id).ok()?;
+        // SAFETY: `id` is a dynamic component, which is stored as a `DynamicValue`.
+        Some(unsafe { ptr.deref::<DynamicValue>() }.as_ref())
+    }
+
+    /// Returns the value of the dynamic component `id` mutably, if the entity has it.
+    ///
+    /// The value isn't checked again against the shape of the component after it's modified.
+    ///
+    /// # Panics
+    ///
+    /// If the entity has been despawned while this `EntityWorldMut` is still alive.
+    pub fn get_dynamic_mut(&mut self, id: ComponentId) -> Option<Mut<'_, dyn PartialReflect>> {
+        self.world().dynamic_component_info(id)?;
+        let value = self.get_mut_by_id(id).ok()?;
+        // SAFETY: `id` is a dynamic component, which is stored as a `DynamicValue`.
+        Some(value.map_unchanged(|ptr| unsafe { ptr.deref_mut::<DynamicValue>() }.as_mut()))
+    }
+}
+
+/// Turns pointers to a component into reflected values.
+#[derive(Clone)]
+enum ReflectAccessor {
+    Dynamic,
+    Typed(ReflectFromPtr),
+}
+
+impl ReflectAccessor {
+    fn new(world: &World, id: ComponentId) -> Result<Self, DynamicComponentError> {
+        if world.dynamic_component_info(id).is_some() {
+            return Ok(Self::Dynamic);
+        }
+        world
+            .components()
+            .get_info(id)
+            .and_then(ComponentInfo::type_id)
+            .and_then(|type_id| {
+                world
+                    .get_resource::<AppTypeRegistry>()?
+                    .read()
+                    .get_type_data::<ReflectFromPtr>(type_id)
+                    .filter(|reflect_from_ptr| reflect_from_ptr.type_id() == type_id)
+                    .cloned()
+            })
+            .map(Self::Typed)
+            .ok_or(DynamicComponentError::NotReflectable(id))
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must point to a value of the component this accessor was created for.
+    unsafe fn as_reflect<'a>(&self, ptr: Ptr<'a>) -> &'a dyn PartialReflect {
+        match self {
+            // SAFETY: Ensured by the caller.
+            Self::Dynamic => unsafe { ptr.deref::<DynamicValue>() }.as_ref(),
+            Self::Typed(reflect_from_ptr) => {
+                // SAFETY: Ensured by the caller.
+                unsafe { reflect_from_ptr.as_reflect(ptr) }.as_partial_reflect()
+            }
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must point to a value of the component this accessor was created for.
+    unsafe fn as_reflect_mut<'a>(&self, ptr: PtrMut<'a>) -> &'a mut dyn PartialReflect {
+        match self {
+            // SAFETY: Ensured by the caller.
+            Self::Dynamic => unsafe { ptr.deref_mut::<DynamicValue>() }.as_mut(),
+            Self::Typed(reflect_from_ptr) => {
+                // SAFETY: Ensured by the caller.
+                unsafe { reflect_from_ptr.as_reflect_mut(ptr) }.as_partial_reflect_mut()
+            }
+        }
+    }
+}
+
+/// A component fetched by a [`DynamicQuery`].
+#[derive(Clone)]
+struct DynamicFetch {
+    id: ComponentId,
+    accessor: ReflectAccessor,
+}
+
+/// Builds a [`DynamicQuery`].
+///
+/// Components can be fetched if they are dynamic components, or Rust components registered with
+/// [`ReflectFromPtr`] type data in the [`AppTypeRegistry`].
+pub struct DynamicQueryBuilder<'w> {
+    world: &'w mut World,
+    reads: Vec<ComponentId>,
+    writes: Vec<ComponentId>,
+    with: Vec<ComponentId>,
+    without: Vec<ComponentId>,
+}
+
+impl<'w> DynamicQueryBuilder<'w> {
+    /// Creates a builder for a query that matches every entity.
+    pub fn new(world: &'w mut World) -> Self {
+        Self {
+            world,
+            reads: Vec::new(),
+            writes: Vec::new(),
+            with: Vec::new(),
+            without: Vec::new(),
+        }
+    }
+
+    /// Fetches the component `id` immutably, only matching entities that have it.
+    pub fn read(&mut self, id: ComponentId) -> &mut Self {
+        self.reads.push(id);
+        self
+    }
+
+    /// Fetches the component `id` mutably, only matching entities that have it.
+    pub fn write(&mut self, id: ComponentId) -> &mut Self {
+        self.writes.push(id);
+        self
+    }
+
+    /// Only matches entities that have the component `id`, without fetching it.
+    pub fn with(&mut self, id: ComponentId) -> &mut Self {
+        self.with.push(id);
+        self
+    }
+
+    /// Only matches entities that don't have the component `id`.
+    pub fn without(&mut self, id: ComponentId) -> &mut Self {
+        self.without.push(id);
+        self
+    }
+
+    /// Builds the [`DynamicQuery`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a fetched component can't be reflected, or a component fetched
+    /// mutably is immutable.
+    pub fn build(&mut self) -> Result<DynamicQuery, DynamicComponentError> {
+        let mut fetches = Vec::with_capacity(self.reads.len() + self.writes.len());
+        for &id in self.reads.iter().chain(&self.writes) {
+            fetches.push(DynamicFetch {
+                id,
+                accessor: ReflectAccessor::new(self.world, id)?,
+            });
+        }
+        for &id in &self.writes {
+            if self
+                .world
+                .components()
+                .get_info(id)
+                .is_some_and(|info| !info.mutable())
+            {
+                return Err(DynamicComponentError::Immutable(id));
+            }
+        }
+
+        let mut builder = QueryBuilder::<FilteredEntityMut>::new(self.world);
+        for &id in &self.reads {
+            builder.ref_id(id);
+        }
+        for &id in &self.writes {
+            builder.mut_id(id);
+        }
+        for &id in &self.with {
+            builder.with_id(id);
+        }
+        for &id in &self.without {
+            builder.without_id(id);
+        }
+        Ok(DynamicQuery {
+            state: builder.build(),
+            fetches,
+        })
+    }
+}
+
+/// A query over dynamic and reflected components, built with a [`DynamicQueryBuilder`].
+pub struct DynamicQuery {
+    state: QueryState<FilteredEntityMut<'static>>,
+    fetches: Vec<DynamicFetch>,
+}
+
+impl DynamicQuery {
+    /// Iterates over the matched entities, yielding reflected views of their components.
+    pub fn iter<'w, 's>(
+        &'s mut self,
+        world: &'w World,
+    ) -> impl Iterator<Item = DynamicQueryItem<'w, 's>> {
+        let fetches = &self.fetches;
+        self.state
+            .iter(world)
+            .map(move |entity| DynamicQueryItem { entity, fetches })
+    }
+
+    /// Iterates over the matched entities, yielding mutable reflected views of their components.
+    pub fn iter_mut<'w, 's>(
+        &'s mut self,
+        world: &'w mut World,
+    ) -> impl Iterator<Item = DynamicQueryItemMut<'w, 's>> {
+        let fetches = &self.fetches;
+        self.state
+            .iter_mut(world)
+            .map(move |entity| DynamicQueryItemMut { entity, fetches })
+    }
+
+    /// Returns the reflected view of `entity`, if the query matches it.
+    pub fn get<'w, 's>(
+        &'s mut self,
+        world: &'w World,
+        entity: Entity,
+    ) -> Option<DynamicQueryItem<'w, 's>> {
+        let entity = self.state.get(world, entity).ok()?;
+        Some(DynamicQueryItem {
+            entity,
+            fetches: &self.fetches,
+        })
+    }
+
+    /// Returns the mutable reflected view of `entity`, if the query matches it.
+    pub fn get_mut<'w, 's>(
+        &'s mut self,
+        world: &'w mut World,
+        entity: Entity,
+    ) -> Option<DynamicQueryItemMut<'w, 's>> {
+        let entity = self.state.get_mut(world, entity).ok()?;
+        Some(DynamicQueryItemMut {
+            entity,
+            fetches: &self.fetches,
+        })
+    }
+}
+
+/// A reflected view of an entity matched by a [`DynamicQuery`].
+pub struct DynamicQueryItem<'w, 's> {
+    entity: FilteredEntityRef<'w>,
+    fetches: &'s [DynamicFetch],
+}
+
+impl<'w> DynamicQueryItem<'w, '_> {
+    /// Returns the matched entity.
+    pub fn entity(&self) -> Entity {
+        self.entity.id()
+    }
+
+    /// Returns the value of the fetched component `id`.
+    pub fn get(&self, id: ComponentId) -> Option<&'w dyn PartialReflect> {
+        let fetch = self.fetches.iter().find(|fetch| fetch.id == id)?;
+        let ptr = self.entity.get_by_id(id)?;
+        // SAFETY: `ptr` points to a value of the component the accessor was created for.
+        Some(unsafe { fetch.accessor.as_reflect(ptr) })
+    }
+
+    /// Returns an iterator over the fetched components and their values.
+    pub fn iter(&self) -> impl Iterator<Item = (ComponentId, &'w dyn PartialReflect)> + '_ {
+        self.fetches
+            .iter()
+            .filter_map(|fetch| Some((fetch.id, self.get(fetch.id)?)))
+    }
+}
+
+/// A mutable reflected view of an entity matched by a [`DynamicQuery`].
+pub struct DynamicQueryItemMut<'w, 's> {
+    entity: FilteredEntityMut<'w>,
+    fetches: &'s [DynamicFetch],
+}
+
+impl DynamicQueryItemMut<'_, '_> {
+    /// Returns the matched entity.
+    pub fn entity(&self) -> Entity {
+        self.entity.id()
+    }
+
+    /// Returns the value of the fetched component `id`.
+    pub fn get(&self, id: ComponentId) -> Option<&dyn PartialReflect> {
+        let fetch = self.fetches.iter().find(|fetch| fetch.id == id)?;
+        let ptr = self.entity.get_by_id(id)?;
+        // SAFETY: `ptr` points to a value of the component the accessor was created for.
+        Some(unsafe { fetch.accessor.as_reflect(ptr) })
+    }
+
+    /// Returns the value of the component `id`, fetched mutably.
+    pub fn get_mut(&mut self, id: ComponentId) -> Option<Mut<'_, dyn PartialReflect>> {
+        let fetch = self.fetches.iter().find(|fetch| fetch.id == id)?;
+        let value = self.entity.get_mut_by_id(id)?;
+        // SAFETY: `ptr` points to a value of the component the accessor was created for.
+        Some(value.map_unchanged(|ptr| unsafe { fetch.accessor.as_reflect_mut(ptr) }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynamicComponentDescriptor, DynamicComponentError, DynamicQueryBuilder};
+    use crate::{self as bevy_ecs, component::Component, reflect::AppTypeRegistry, world::World};
+    use alloc::{boxed::Box, vec, vec::Vec};
+    use bevy_reflect::{
+        DynamicStruct, DynamicTupleStruct, PartialReflect, Reflect, ReflectKind, ReflectRef, Typed,
+    };
+
+    #[derive(Reflect)]
+    struct Health {
+        current: f32,
+        max: f32,
+    }
+
+    #[derive(Component, Reflect, PartialEq, Debug)]
+    struct Speed(f32);
+
+    fn field(value: &dyn PartialReflect, name: &str) -> f32 {
+        let ReflectRef::Struct(value) = value.reflect_ref() else {
+            panic!("not a struct");
+        };
+        *value
+            .field(name)
+            .unwrap()
+            .try_downcast_ref::<f32>()
+            .unwrap()
+    }
+
+    fn health(current: f32) -> DynamicStruct {
+        let mut value = DynamicStruct::default();
+        value.insert("current", current);
+        value.insert("max", 100.0f32);
+        value
+    }
+
+    #[test]
+    fn register_and_insert() {
+        let mut world = World::new();
+        let id = world
+            .register_dynamic_component(DynamicComponentDescriptor::new("Health"))
+            .unwrap();
+        assert_eq!(world.dynamic_component_id("Health"), Some(id));
+        assert_eq!(world.dynamic_component_info(id).unwrap().name(), "Health");
+        assert_eq!(
+            world
+                .register_dynamic_component(DynamicComponentDescriptor::new("Health"))
+                .err(),
+            Some(DynamicComponentError::AlreadyRegistered("Health".into()))
+        );
+
+        let mut entity = world.spawn_empty();
+        entity.insert_dynamic(id, Box::new(health(50.0))).unwrap();
+        assert_eq!(field(entity.get_dynamic(id).unwrap(), "current"), 50.0);
+
+        let mut value = entity.get_dynamic_mut(id).unwrap();
+        value.apply(&health(25.0));
+        assert_eq!(field(entity.get_dynamic(id).unwrap(), "current"), 25.0);
+
+        entity.insert_dynamic(id, Box::new(health(75.0))).unwrap();
+        assert_eq!(field(entity.get_dynamic(id).unwrap(), "current"), 75.0);
+
+        let clone = entity.clone_and_spawn();
+        let entity = entity.id();
+        assert_eq!(
+            field(world.entity_mut(clone).get_dynamic(id).unwrap(), "current"),
+            75.0
+        );
+
+        world.entity_mut(entity).remove_by_id(id);
+        assert!(world.entity_mut(entity).get_dynamic(id).is_none());
+    }
+
+    #[test]
+    fn validate_values() {
+        let mut world = World::new();
+        let prototype = world
+            .register_dynamic_component(
+                DynamicComponentDescriptor::new("Prototype").with_default(health(100.0)),
+            )
+            .unwrap();
+        let typed = world
+            .register_dynamic_component(
+                DynamicComponentDescriptor::new("Typed").with_type_info(Health::type_info()),
+            )
+            .unwrap();
+        let not_dynamic = world.register_component::<Speed>();
+
+        let mut entity = world.spawn_empty();
+        for id in [prototype, typed] {
+            entity.insert_dynamic(id, Box::new(health(1.0))).unwrap();
+
+            let mut missing = DynamicStruct::default();
+            missing.insert("current", 1.0f32);
+            assert!(matches!(
+                entity.insert_dynamic(id, Box::new(missing)),
+                Err(DynamicComponentError::MissingField { field, .. }) if field == "max"
+            ));
+
+            let mut unknown = health(1.0);
+            unknown.insert("min", 0.0f32);
+            assert!(matches!(
+                entity.insert_dynamic(id, Box::new(unknown)),
+                Err(DynamicComponentError::UnknownField { field, .. }) if field == "min"
+            ));
+
+            assert!(matches!(
+                entity.insert_dynamic(id, Box::new(DynamicTupleStruct::default())),
+                Err(DynamicComponentError::KindMismatch {
+                    expected: ReflectKind::Struct,
+                    found: ReflectKind::TupleStruct,
+                    ..
+                })
+            ));
+        }
+        entity
+            .insert_dynamic(
+                typed,
+                Box::new(Health {
+                    current: 1.0,
+                    max: 2.0,
+                }),
+            )
+            .unwrap();
+
+        assert_eq!(
+            entity
+                .insert_dynamic(not_dynamic, Box::new(Speed(1.0)))
+                .err(),
+            Some(DynamicComponentError::NotDynamic(not_dynamic))
+        );
+        assert!(entity.get_dynamic(not_dynamic).is_none());
+
+        assert!(matches!(
+            world.register_dynamic_component(
+                DynamicComponentDescriptor::new("Invalid")
+                    .with_type_info(Health::type_info())
+                    .with_default(1.0f32)
+            ),
+            Err(DynamicComponentError::KindMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn validate_nested_values() {
+        #[derive(Reflect)]
+        struct Stats {
+            health: Health,
+            levels: Vec<u32>,
+        }
+
+        fn stats(health: DynamicStruct, levels: Vec<u32>) -> DynamicStruct {
+            let mut value = DynamicStruct::default();
+            value.insert("health", health);
+            value.insert("levels", levels);
+            value
+        }
+
+        let mut world = World::new();
+        let prototype = world
+            .register_dynamic_component(
+                DynamicComponentDescriptor::new("Prototype")
+                    .with_default(stats(health(100.0), vec![1])),
+            )
+            .unwrap();
+        let typed = world
+            .register_dynamic_component(
+                DynamicComponentDescriptor::new("Typed").with_type_info(Stats::type_info()),
+            )
+            .unwrap();
+
+        let mut entity = world.spawn_empty();
+        for id in [prototype, typed] {
+            entity
+                .insert_dynamic(id, Box::new(stats(health(1.0), vec![2, 3])))
+                .unwrap();
+
+            let mut missing = DynamicStruct::default();
+            missing.insert("current", 1.0f32);
+            assert!(matches!(
+                entity.insert_dynamic(id, Box::new(stats(missing, vec![]))),
+                Err(DynamicComponentError::MissingField { field, .. }) if field == "max"
+            ));
+
+            let mut wrong_type = DynamicStruct::default();
+            wrong_type.insert("current", 1u32);
+            wrong_type.insert("max", 1.0f32);
+            assert!(matches!(
+                entity.insert_dynamic(id, Box::new(stats(wrong_type, vec![]))),
+                Err(DynamicComponentError::TypeMismatch { .. })
+            ));
+
+            let mut wrong_items = stats(health(1.0), vec![]);
+            wrong_items.insert("levels", vec![1.0f32]);
+            assert!(matches!(
+                entity.insert_dynamic(id, Box::new(wrong_items)),
+                Err(DynamicComponentError::TypeMismatch { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn registry_is_owned_by_the_world() {
+        let mut world = World::new();
+        let id = world
+            .register_dynamic_component(DynamicComponentDescriptor::new("Health"))
+            .unwrap();
+        assert!(world.components().dynamic_components().get(id).is_some());
+
+        // Components registered in another world aren't dynamic there.
+        let mut other = World::new();
+        let speed = other.register_component::<Speed>();
+        assert_eq!(speed, id);
+        let mut entity = other.spawn(Speed(1.0));
+        assert!(entity.get_dynamic(speed).is_none());
+        assert_eq!(
+            entity.insert_dynamic(speed, Box::new(health(1.0))).err(),
+            Some(DynamicComponentError::NotDynamic(speed))
+        );
+    }
+
+    #[test]
+    fn dynamic_query() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Speed>();
+        let health_id = world
+            .register_dynamic_component(DynamicComponentDescriptor::new("Health"))
+            .unwrap();
+        let marker_id = world
+            .register_dynamic_component(DynamicComponentDescriptor::new("Marker"))
+            .unwrap();
+        let speed_id = world.register_component::<Speed>();
+
+        let a = world
+            .spawn(Speed(1.0))
+            .insert_dynamic(health_id, Box::new(health(10.0)))
+            .unwrap()
+            .id();
+        let b = world
+            .spawn(Speed(2.0))
+            .insert_dynamic(health_id, Box::new(health(20.0)))
+            .unwrap()
+            .insert_dynamic(marker_id, Box::new(()))
+            .unwrap()
+            .id();
+        world.spawn(Speed(3.0));
+
+        let mut query = DynamicQueryBuilder::new(&mut world)
+            .read(speed_id)
+            .write(health_id)
+            .build()
+            .unwrap();
+        for mut item in query.iter_mut(&mut world) {
+            let speed = item
+                .get(speed_id)
+                .unwrap()
+                .try_downcast_ref::<Speed>()
+                .unwrap()
+                .0;
+            let mut health = item.get_mut(health_id).unwrap();
+            health.apply(&self::health(speed * 100.0));
+        }
+
+        let mut results = query
+            .iter(&world)
+            .map(|item| {
+                (
+                    item.entity(),
+                    field(item.get(health_id).unwrap(), "current"),
+                )
+            })
+            .collect::<Vec<_>>();
+        results.sort_by_key(|(entity, _)| *entity);
+        assert_eq!(results, [(a, 100.0), (b, 200.0)]);
+
+        let mut filtered = DynamicQueryBuilder::new(&mut world)
+            .read(health_id)
+            .without(marker_id)
+            .build()
+            .unwrap();
+        let items = filtered.iter(&world).collect::<Vec<_>>();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].entity(), a);
+        assert_eq!(items[0].iter().count(), 1);
+        assert!(filtered.get(&world, b).is_none());
+
+        #[derive(Component)]
+        struct NotReflected;
+        let not_reflected = world.register_component::<NotReflected>();
+        assert_eq!(
+            DynamicQueryBuilder::new(&mut world)
+                .read(not_reflected)
+                .build()
+                .err(),
+            Some(DynamicComponentError::NotReflectable(not_reflected))
+        );
+    }
+}
@@ -391,7 +391,7 @@ mod tests {
         bundle::Bundle,
         component::Component,
         prelude::{AppTypeRegistry, ReflectComponent},
-        reflect::{ReflectBundle, ReflectCommandExt},
+        reflect::{ReflectBundle, ReflectCommandExt, ReflectComponentFns},
         system::{Commands, SystemState},
         world::World,
     };
@@ -685,4 +685,66 @@ mod tests {
         assert_eq!(world.entity(entity).get::<ComponentA>(), None);
         assert_eq!(world.entity(entity).get::<ComponentB>(), None);
     }
+
+    #[derive(Component, Reflect, Default, PartialEq, Eq, Debug)]
+    #[component(immutable, on_replace = count_replacements)]
+    #[reflect(Component)]
+    struct Immutable(u32);
+
+    #[derive(Resource, Default)]
+    struct Replacements(u32);
+
+    fn count_replacements(
+        mut world: crate::world::DeferredWorld,
+        _: crate::entity::Entity,
+        _: crate::component::ComponentId,
+    ) {
+        world.resource_mut::<Replacements>().0 += 1;
+    }
+
+    #[test]
+    fn apply_or_insert_replaces_immutable_component() {
+        let mut world = World::new();
+        world.init_resource::<Replacements>();
+        let mut registry = TypeRegistry::default();
+        registry.register::<Immutable>();
+        let reflect_component = registry
+            .get_type_data::<ReflectComponent>(core::any::TypeId::of::<Immutable>())
+            .unwrap()
+            .clone();
+        assert!(!reflect_component.is_mutable());
+
+        let mut entity = world.spawn(Immutable(1));
+        reflect_component.apply_or_insert(&mut entity, &Immutable(2), &registry);
+        assert_eq!(entity.get::<Immutable>(), Some(&Immutable(2)));
+        // The old value was replaced rather than mutated, so the hook saw the change.
+        assert_eq!(world.resource::<Replacements>().0, 1);
+
+        // A missing component is inserted as usual.
+        let mut entity = world.spawn_empty();
+        reflect_component.apply_or_insert(&mut entity, &Immutable(3), &registry);
+        assert_eq!(entity.get::<Immutable>(), Some(&Immutable(3)));
+        assert_eq!(world.resource::<Replacements>().0, 1);
+    }
+
+    #[test]
+    fn custom_reflect_component_mutability() {
+        let fns = ReflectComponentFns::new::<Immutable>();
+        assert!(ReflectComponent::new(fns.clone()).is_mutable());
+        assert!(!ReflectComponent::new_immutable(fns).is_mutable());
+    }
+
+    #[test]
+    #[should_panic]
+    fn reflect_mut_panics_for_immutable_component() {
+        let mut world = World::new();
+        let mut registry = TypeRegistry::default();
+        registry.register::<Immutable>();
+        let reflect_component = registry
+            .get_type_data::<ReflectComponent>(core::any::TypeId::of::<Immutable>())
+            .unwrap();
+
+        let entity = world.spawn(Immutable(1)).id();
+        reflect_component.reflect_mut(world.entity_mut(entity));
+    }
 }
@@ -14,6 +14,7 @@ use bevy_reflect::{
 
 mod bundle;
 mod component;
+pub mod dynamic_component;
 mod entity_commands;
 mod from_world;
 mod map_entities;
@@ -22,6 +23,10 @@ mod visit_entities;
 
 pub use bundle::{ReflectBundle, ReflectBundleFns};
 pub use component::{ReflectComponent, ReflectComponentFns};
+pub use dynamic_component::{
+    DynamicComponentDescriptor, DynamicComponentError, DynamicComponentInfo, DynamicComponents,
+    DynamicQuery, DynamicQueryBuilder, DynamicQueryItem, DynamicQueryItemMut,
+};
 pub use entity_commands::ReflectCommandExt;
 pub use from_world::{ReflectFromWorld, ReflectFromWorldFns};
 pub use map_entities::ReflectMapEntities;
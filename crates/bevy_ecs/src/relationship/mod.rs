@@ -0,0 +1,312 @@
+//! This module provides functionality to link entities to each other using specialized components called "relationships". See the [`Relationship`] trait for more info.
+
+mod related_methods;
+mod relationship_query;
+mod relationship_source_collection;
+
+pub use related_methods::*;
+pub use relationship_query::*;
+pub use relationship_source_collection::*;
+
+use crate::{
+    component::{Component, ComponentId, Immutable, Mutable},
+    entity::Entity,
+    traversal::Traversal,
+    world::{DeferredWorld, World},
+};
+use disqualified::ShortName;
+use log::warn;
+
+/// A [`Component`] on a "source" [`Entity`] that references another target [`Entity`], creating a "relationship" between them. Every [`Relationship`]
+/// has a corresponding [`RelationshipTarget`] type (and vice-versa), which exists on the "target" entity of a relationship and contains the list of all
+/// "source" entities that relate to the given "target"
+///
+/// The [`Relationship`] component is the "source of truth" and the [`RelationshipTarget`] component reflects that source of truth. When a [`Relationship`]
+/// component is inserted on an [`Entity`], the corresponding [`RelationshipTarget`] component is immediately inserted on the target component if it does
+/// not already exist, and the "source" entity is automatically added to the [`RelationshipTarget`] collection (this is done via "component hooks").
+///
+/// A common example of a [`Relationship`] is the parent / child relationship. Bevy's `Parent` component implements [`Relationship`], and its
+/// [`RelationshipTarget`] is `Children`.
+///
+/// [`Relationship`] and [`RelationshipTarget`] should always be derived via the [`Component`] trait to ensure the hooks are set up properly.
+///
+/// ```
+/// # use bevy_ecs::component::Component;
+/// # use bevy_ecs::entity::Entity;
+/// #[derive(Component)]
+/// #[relationship(relationship_target = Children)]
+/// pub struct Parent(pub Entity);
+///
+/// #[derive(Component)]
+/// #[relationship_target(relationship = Parent)]
+/// pub struct Children(Vec<Entity>);
+/// ```
+///
+/// Relationships are kept in sync automatically: inserting, replacing or removing the [`Relationship`] component updates the [`RelationshipTarget`]
+/// collection of the old and new targets, and removing (or despawning) the [`RelationshipTarget`] removes the [`Relationship`] component from every
+/// source entity. The [`RelationshipTarget`] component is removed from the target entity once its last source is removed.
+///
+/// [`Relationship`] components are [immutable](crate::component::Immutable), so that these hooks see every change:
+/// to change the target of a relationship, insert a new value instead.
+///
+/// By default, neither side of a relationship is cloned by [`EntityCloneBuilder`](crate::entity::EntityCloneBuilder),
+/// as doing so would silently alter the relationships of unrelated entities.
+pub trait Relationship: Component<Mutability = Immutable> + Sized {
+    /// The [`Component`] added to the "target" entities of this [`Relationship`], which contains the list of all "source"
+    /// entities that relate to the "target".
+    type RelationshipTarget: RelationshipTarget<Relationship = Self>;
+
+    /// Gets the [`Entity`] ID of the related entity.
+    fn get(&self) -> Entity;
+
+    /// Creates this [`Relationship`] from the given `entity`.
+    fn from(entity: Entity) -> Self;
+
+    /// The `on_insert` component hook that maintains the [`Relationship`] / [`RelationshipTarget`] connection.
+    fn on_insert(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target_entity = world.entity(entity).get::<Self>().unwrap().get();
+        if target_entity == entity {
+            warn!(
+                "The {}({target_entity:?}) relationship on entity {entity:?} points to itself. The invalid {} relationship has been removed.",
+                ShortName::of::<Self>(),
+                ShortName::of::<Self>()
+            );
+            world.commands().entity(entity).try_remove::<Self>();
+            return;
+        }
+        if let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) {
+            if let Some(mut relationship_target) =
+                target_entity_mut.get_mut::<Self::RelationshipTarget>()
+            {
+                relationship_target.collection_mut_risky().add(entity);
+            } else {
+                // The target component can't be inserted from a hook, so this is deferred. Other sources
+                // may be added to the same target before the command is applied (for example when spawning
+                // in batches), so the command must not blindly overwrite an existing collection.
+                world.commands().queue(move |world: &mut World| {
+                    if world
+                        .get::<Self>(entity)
+                        .is_none_or(|relationship| relationship.get() != target_entity)
+                    {
+                        return;
+                    }
+                    let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) else {
+                        return;
+                    };
+                    if let Some(mut relationship_target) =
+                        target_entity_mut.get_mut::<Self::RelationshipTarget>()
+                    {
+                        relationship_target.collection_mut_risky().add(entity);
+                    } else {
+                        let mut target =
+                            <Self::RelationshipTarget as RelationshipTarget>::with_capacity(1);
+                        target.collection_mut_risky().add(entity);
+                        target_entity_mut.insert(target);
+                    }
+                });
+            }
+        } else {
+            warn!(
+                "The {}({target_entity:?}) relationship on entity {entity:?} relates to an entity that does not exist. The invalid {} relationship has been removed.",
+                ShortName::of::<Self>(),
+                ShortName::of::<Self>()
+            );
+            world.commands().entity(entity).try_remove::<Self>();
+        }
+    }
+
+    /// The `on_replace` component hook that maintains the [`Relationship`] / [`RelationshipTarget`] connection.
+    // note: think of this as "on_drop"
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target_entity = world.entity(entity).get::<Self>().unwrap().get();
+        if let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) {
+            if let Some(mut relationship_target) =
+                target_entity_mut.get_mut::<Self::RelationshipTarget>()
+            {
+                relationship_target.collection_mut_risky().remove(entity);
+                if relationship_target.is_empty() {
+                    world.commands().queue(move |world: &mut World| {
+                        // The command may run after the target has gained new sources or was despawned,
+                        // so the emptiness check is repeated here.
+                        if let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) {
+                            if target_entity_mut
+                                .get::<Self::RelationshipTarget>()
+                                .is_some_and(RelationshipTarget::is_empty)
+                            {
+                                target_entity_mut.remove::<Self::RelationshipTarget>();
+                            }
+                        }
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// A [`Component`] containing the collection of entities that relate to this [`Entity`] via the associated `Relationship` type.
+/// See the [`Relationship`] documentation for more information.
+pub trait RelationshipTarget: Component<Mutability = Mutable> + Sized {
+    /// The [`Relationship`] that populates this [`RelationshipTarget`] collection.
+    type Relationship: Relationship<RelationshipTarget = Self>;
+    /// The collection type that stores the "source" entities for this [`RelationshipTarget`] component.
+    type Collection: RelationshipSourceCollection;
+
+    /// Returns a reference to the stored [`RelationshipTarget::Collection`].
+    fn collection(&self) -> &Self::Collection;
+
+    /// Returns a mutable reference to the stored [`RelationshipTarget::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called by user code, as modifying the internal collection could invalidate the relationship.
+    fn collection_mut_risky(&mut self) -> &mut Self::Collection;
+
+    /// Creates a new [`RelationshipTarget`] from the given [`RelationshipTarget::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called by user code, as constructing the internal collection could invalidate the relationship.
+    fn from_collection_risky(collection: Self::Collection) -> Self;
+
+    /// The `on_replace` component hook that maintains the [`Relationship`] / [`RelationshipTarget`] connection.
+    // note: think of this as "on_drop"
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let sources = world
+            .entity(entity)
+            .get::<Self>()
+            .unwrap()
+            .iter()
+            .collect::<alloc::vec::Vec<_>>();
+        let mut commands = world.commands();
+        for source_entity in sources {
+            commands.queue(move |world: &mut World| {
+                // Only remove the relationship if it still points at this target, as the source may
+                // have been despawned or related to another entity in the meantime.
+                if let Ok(mut source_entity_mut) = world.get_entity_mut(source_entity) {
+                    if source_entity_mut
+                        .get::<Self::Relationship>()
+                        .is_some_and(|relationship| relationship.get() == entity)
+                    {
+                        source_entity_mut.remove::<Self::Relationship>();
+                    }
+                }
+            });
+        }
+    }
+
+    /// Creates this [`RelationshipTarget`] with the given pre-allocated entity capacity.
+    fn with_capacity(capacity: usize) -> Self {
+        let collection =
+            <Self::Collection as RelationshipSourceCollection>::with_capacity(capacity);
+        Self::from_collection_risky(collection)
+    }
+
+    /// Iterates the entities stored in this collection.
+    #[inline]
+    fn iter(&self) -> SourceIter<'_, Self> {
+        self.collection().iter()
+    }
+
+    /// Returns the number of entities in this collection.
+    #[inline]
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns true if this entity collection is empty.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+}
+
+/// The iterator type for the source entities in a [`RelationshipTarget`] collection,
+/// as defined in the [`RelationshipSourceCollection`] trait.
+pub type SourceIter<'w, R> =
+    <<R as RelationshipTarget>::Collection as RelationshipSourceCollection>::SourceIter<'w>;
+
+/// Follows a [`Relationship`] from a source entity to its target entity.
+///
+/// `Relationship` traversals will never form loops in properly-constructed relationship graphs.
+///
+/// [event propagation]: crate::observer::Trigger::propagate
+impl<R: Relationship, D> Traversal<D> for &R {
+    fn traverse(item: Self::Item<'_>, _data: &D) -> Option<Entity> {
+        Some(item.get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{entity::Entity, prelude::Component, world::World};
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component)]
+    #[relationship(relationship_target = LikedBy)]
+    struct Likes(pub Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = Likes)]
+    struct LikedBy(Vec<Entity>);
+
+    #[test]
+    fn relationship_target_is_kept_in_sync() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(Likes(a)).id();
+        let c = world.spawn(Likes(a)).id();
+        assert_eq!(world.entity(a).get::<LikedBy>().unwrap().0, &[b, c]);
+
+        world.entity_mut(b).remove::<Likes>();
+        assert_eq!(world.entity(a).get::<LikedBy>().unwrap().0, &[c]);
+
+        world.entity_mut(c).despawn();
+        assert!(!world.entity(a).contains::<LikedBy>());
+    }
+
+    #[test]
+    fn replacing_relationship_moves_source() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn(Likes(a)).id();
+
+        world.entity_mut(c).insert(Likes(b));
+        assert!(!world.entity(a).contains::<LikedBy>());
+        assert_eq!(world.entity(b).get::<LikedBy>().unwrap().0, &[c]);
+    }
+
+    #[test]
+    fn removing_target_removes_relationships() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(Likes(a)).id();
+        let c = world.spawn(Likes(a)).id();
+
+        world.entity_mut(a).despawn();
+        assert!(!world.entity(b).contains::<Likes>());
+        assert!(!world.entity(c).contains::<Likes>());
+    }
+
+    #[test]
+    fn invalid_relationships_are_removed() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        world.entity_mut(a).insert(Likes(a));
+        assert!(!world.entity(a).contains::<Likes>());
+        assert!(!world.entity(a).contains::<LikedBy>());
+
+        let b = world.spawn_empty().id();
+        world.entity_mut(b).despawn();
+        let c = world.spawn(Likes(b)).id();
+        assert!(!world.entity(c).contains::<Likes>());
+    }
+
+    #[test]
+    fn relationship_sources_are_not_duplicated() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(Likes(a)).id();
+        world.entity_mut(b).insert(Likes(a));
+        assert_eq!(world.entity(a).get::<LikedBy>().unwrap().0, vec![b]);
+    }
+}
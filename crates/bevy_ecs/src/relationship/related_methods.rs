@@ -0,0 +1,251 @@
+use crate::{
+    bundle::Bundle,
+    entity::Entity,
+    relationship::{Relationship, RelationshipTarget},
+    system::{Commands, EntityCommands},
+    world::{EntityWorldMut, World},
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+impl<'w> EntityWorldMut<'w> {
+    /// Spawns entities related to this entity (with the `R` relationship) by taking a function that operates on a [`RelatedSpawner`].
+    pub fn with_related<R: Relationship>(
+        &mut self,
+        func: impl FnOnce(&mut RelatedSpawner<R>),
+    ) -> &mut Self {
+        let parent = self.id();
+        self.world_scope(|world| {
+            func(&mut RelatedSpawner::new(world, parent));
+        });
+        self
+    }
+
+    /// Relates the given entities to this entity with the relation `R`
+    pub fn add_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let id = self.id();
+        self.world_scope(|world| {
+            for related in related {
+                world.entity_mut(*related).insert(R::from(id));
+            }
+        });
+        self
+    }
+
+    /// Removes the relation `R` from the given entities, if they are related to this entity.
+    pub fn remove_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let id = self.id();
+        self.world_scope(|world| {
+            for related in related {
+                if let Ok(mut related) = world.get_entity_mut(*related) {
+                    if related
+                        .get::<R>()
+                        .is_some_and(|relationship| relationship.get() == id)
+                    {
+                        related.remove::<R>();
+                    }
+                }
+            }
+        });
+        self
+    }
+
+    /// Despawns entities that relate to this one via the given [`RelationshipTarget`].
+    /// This entity will not be despawned.
+    pub fn despawn_related<S: RelationshipTarget>(&mut self) -> &mut Self {
+        if let Some(sources) = self.take::<S>() {
+            self.world_scope(|world| {
+                for entity in sources.iter() {
+                    if let Ok(entity_mut) = world.get_entity_mut(entity) {
+                        entity_mut.despawn();
+                    }
+                }
+            });
+        }
+        self
+    }
+}
+
+impl<'a> EntityCommands<'a> {
+    /// Spawns entities related to this entity (with the `R` relationship) by taking a function that operates on a [`RelatedSpawnerCommands`].
+    pub fn with_related<R: Relationship>(
+        &mut self,
+        func: impl FnOnce(&mut RelatedSpawnerCommands<R>),
+    ) -> &mut Self {
+        let id = self.id();
+        func(&mut RelatedSpawnerCommands::new(self.commands(), id));
+        self
+    }
+
+    /// Relates the given entities to this entity with the relation `R`
+    pub fn add_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let related: Vec<Entity> = related.to_vec();
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.add_related::<R>(&related);
+        })
+    }
+
+    /// Removes the relation `R` from the given entities, if they are related to this entity.
+    pub fn remove_related<R: Relationship>(&mut self, related: &[Entity]) -> &mut Self {
+        let related: Vec<Entity> = related.to_vec();
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.remove_related::<R>(&related);
+        })
+    }
+
+    /// Despawns entities that relate to this one via the given [`RelationshipTarget`].
+    /// This entity will not be despawned.
+    pub fn despawn_related<S: RelationshipTarget>(&mut self) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.despawn_related::<S>();
+        })
+    }
+}
+
+/// Directly spawns related "source" entities with the given [`Relationship`], targeting
+/// a specific entity.
+pub struct RelatedSpawner<'w, R: Relationship> {
+    target: Entity,
+    world: &'w mut World,
+    _marker: PhantomData<R>,
+}
+
+impl<'w, R: Relationship> RelatedSpawner<'w, R> {
+    /// Creates a new instance that will spawn entities targeting the `target` entity.
+    pub fn new(world: &'w mut World, target: Entity) -> Self {
+        Self {
+            world,
+            target,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Spawns an entity with the given `bundle` and an `R` relationship targeting the `target`
+    /// entity this spawner was initialized with.
+    pub fn spawn(&mut self, bundle: impl Bundle) -> EntityWorldMut<'_> {
+        self.world.spawn((R::from(self.target), bundle))
+    }
+
+    /// Spawns an entity with an `R` relationship targeting the `target`
+    /// entity this spawner was initialized with.
+    pub fn spawn_empty(&mut self) -> EntityWorldMut<'_> {
+        self.world.spawn(R::from(self.target))
+    }
+
+    /// Returns the "target entity" used when spawning entities with an `R` [`Relationship`].
+    pub fn target_entity(&self) -> Entity {
+        self.target
+    }
+}
+
+/// Uses commands to spawn related "source" entities with the given [`Relationship`], targeting
+/// a specific entity.
+pub struct RelatedSpawnerCommands<'w, R: Relationship> {
+    target: Entity,
+    commands: Commands<'w, 'w>,
+    _marker: PhantomData<R>,
+}
+
+impl<'w, R: Relationship> RelatedSpawnerCommands<'w, R> {
+    /// Creates a new instance that will spawn entities targeting the `target` entity.
+    pub fn new(commands: Commands<'w, 'w>, target: Entity) -> Self {
+        Self {
+            commands,
+            target,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Spawns an entity with the given `bundle` and an `R` relationship targeting the `target`
+    /// entity this spawner was initialized with.
+    pub fn spawn(&mut self, bundle: impl Bundle) -> EntityCommands<'_> {
+        self.commands.spawn((R::from(self.target), bundle))
+    }
+
+    /// Spawns an entity with an `R` relationship targeting the `target`
+    /// entity this spawner was initialized with.
+    pub fn spawn_empty(&mut self) -> EntityCommands<'_> {
+        self.commands.spawn(R::from(self.target))
+    }
+
+    /// Returns the "target entity" used when spawning entities with an `R` [`Relationship`].
+    pub fn target_entity(&self) -> Entity {
+        self.target
+    }
+
+    /// Returns the underlying [`Commands`].
+    pub fn commands(&mut self) -> Commands<'w, '_> {
+        self.commands.reborrow()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        entity::Entity,
+        prelude::Component,
+        relationship::RelationshipTarget,
+        system::Commands,
+        world::{CommandQueue, World},
+    };
+    use alloc::vec::Vec;
+
+    #[derive(Component)]
+    #[relationship(relationship_target = Holds)]
+    struct HeldBy(Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = HeldBy)]
+    struct Holds(Vec<Entity>);
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn with_related_and_despawn_related() {
+        let mut world = World::new();
+        let mut items = Vec::new();
+        let holder = world
+            .spawn_empty()
+            .with_related::<HeldBy>(|spawner| {
+                items.push(spawner.spawn(Marker).id());
+                items.push(spawner.spawn_empty().id());
+            })
+            .id();
+
+        assert_eq!(
+            world
+                .get::<Holds>(holder)
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            items
+        );
+
+        world.entity_mut(holder).despawn_related::<Holds>();
+        assert!(!world.entity(holder).contains::<Holds>());
+        assert!(items.iter().all(|item| world.get_entity(*item).is_err()));
+    }
+
+    #[test]
+    fn add_and_remove_related_commands() {
+        let mut world = World::new();
+        let mut queue = CommandQueue::default();
+        let holder = world.spawn_empty().id();
+        let items: Vec<Entity> = (0..3).map(|_| world.spawn_empty().id()).collect();
+
+        Commands::new(&mut queue, &world)
+            .entity(holder)
+            .add_related::<HeldBy>(&items);
+        queue.apply(&mut world);
+        assert_eq!(world.get::<Holds>(holder).unwrap().0, items);
+
+        Commands::new(&mut queue, &world)
+            .entity(holder)
+            .remove_related::<HeldBy>(&items[..2]);
+        queue.apply(&mut world);
+        assert_eq!(world.get::<Holds>(holder).unwrap().0, &items[2..]);
+        assert!(!world.entity(items[0]).contains::<HeldBy>());
+    }
+}
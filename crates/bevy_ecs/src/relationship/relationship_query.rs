@@ -0,0 +1,341 @@
+use crate::{
+    entity::Entity,
+    query::{QueryData, QueryFilter, WorldQuery},
+    relationship::{Relationship, RelationshipTarget},
+    system::Query,
+};
+use alloc::collections::VecDeque;
+use smallvec::SmallVec;
+
+impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
+    /// If the given `entity` contains the `R` [`Relationship`] component, returns the
+    /// target entity of that relationship.
+    pub fn related<R: Relationship>(&'w self, entity: Entity) -> Option<Entity>
+    where
+        <D as QueryData>::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+    {
+        self.get(entity).map(R::get).ok()
+    }
+
+    /// If the given `entity` contains the `S` [`RelationshipTarget`] component, returns the
+    /// source entities stored on that component.
+    pub fn relationship_sources<S: RelationshipTarget>(
+        &'w self,
+        entity: Entity,
+    ) -> impl Iterator<Item = Entity> + 'w
+    where
+        <D as QueryData>::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+    {
+        self.get(entity)
+            .into_iter()
+            .flat_map(RelationshipTarget::iter)
+    }
+
+    /// Recursively walks up the tree defined by the given `R` [`Relationship`] until
+    /// there are no more related entities, returning the "root entity" of the relationship hierarchy.
+    ///
+    /// # Warning
+    ///
+    /// For relationship graphs that contain loops, this could loop infinitely.
+    /// If your relationship is not a tree (like Bevy's hierarchy), be sure to stop if you encounter a duplicate entity.
+    pub fn root_ancestor<R: Relationship>(&'w self, entity: Entity) -> Entity
+    where
+        <D as QueryData>::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+    {
+        // Recursively search up the tree until we're out of parents
+        match self.get(entity) {
+            Ok(parent) => self.root_ancestor(parent.get()),
+            Err(_) => entity,
+        }
+    }
+
+    /// Iterates all "leaf entities" as defined by the [`RelationshipTarget`] hierarchy.
+    ///
+    /// Only entities which have no related sources are considered leaves.
+    /// This will not include the entity itself, and will not include any entities which are not descendants of the entity,
+    /// even if they are leaves in the same relationship tree.
+    ///
+    /// Traverses the hierarchy depth-first.
+    ///
+    /// # Warning
+    ///
+    /// For relationship graphs that contain loops, this could loop infinitely.
+    /// If your relationship is not a tree (like Bevy's hierarchy), be sure to stop if you encounter a duplicate entity.
+    pub fn iter_leaves<S: RelationshipTarget>(
+        &'w self,
+        entity: Entity,
+    ) -> impl Iterator<Item = Entity> + 'w
+    where
+        <D as QueryData>::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+    {
+        self.iter_descendants_depth_first(entity).filter(|entity| {
+            self.get(*entity)
+                // These are leaf nodes if they have the `Children` component but it's empty
+                .map(|children| children.len() == 0)
+                // Or if they don't have the `Children` component at all
+                .unwrap_or(true)
+        })
+    }
+
+    /// Iterates all sibling entities that also have the `R` [`Relationship`] with the same target entity.
+    ///
+    /// The entity itself is not included in the iterator.
+    pub fn iter_siblings<R: Relationship>(
+        &'w self,
+        entity: Entity,
+    ) -> impl Iterator<Item = Entity> + 'w
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = (Option<&'w R>, Option<&'w R::RelationshipTarget>)>,
+    {
+        self.get(entity)
+            .ok()
+            .and_then(|(maybe_parent, _)| maybe_parent.map(R::get))
+            .and_then(|parent| self.get(parent).ok())
+            .and_then(|(_, maybe_children)| maybe_children)
+            .into_iter()
+            .flat_map(move |children| children.iter().filter(move |child| *child != entity))
+    }
+
+    /// Iterates all descendant entities as defined by the given `entity`'s [`RelationshipTarget`] and their recursive
+    /// [`RelationshipTarget`].
+    ///
+    /// Traverses the hierarchy breadth-first and does not include the entity itself.
+    ///
+    /// # Warning
+    ///
+    /// For relationship graphs that contain loops, this could loop infinitely.
+    /// If your relationship is not a tree (like Bevy's hierarchy), be sure to stop if you encounter a duplicate entity.
+    pub fn iter_descendants<S: RelationshipTarget>(
+        &'w self,
+        entity: Entity,
+    ) -> DescendantIter<'w, 's, D, F, S>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+    {
+        DescendantIter::new(self, entity)
+    }
+
+    /// Iterates all descendant entities as defined by the given `entity`'s [`RelationshipTarget`] and their recursive
+    /// [`RelationshipTarget`] in depth-first order.
+    ///
+    /// This is a depth-first alternative to [`Query::iter_descendants`].
+    ///
+    /// # Warning
+    ///
+    /// For relationship graphs that contain loops, this could loop infinitely.
+    /// If your relationship is not a tree (like Bevy's hierarchy), be sure to stop if you encounter a duplicate entity.
+    pub fn iter_descendants_depth_first<S: RelationshipTarget>(
+        &'w self,
+        entity: Entity,
+    ) -> DescendantDepthFirstIter<'w, 's, D, F, S>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+    {
+        DescendantDepthFirstIter::new(self, entity)
+    }
+
+    /// Iterates all ancestors of the given `entity` as defined by the `R` [`Relationship`].
+    ///
+    /// Does not include the entity itself.
+    ///
+    /// # Warning
+    ///
+    /// For relationship graphs that contain loops, this could loop infinitely.
+    /// If your relationship is not a tree (like Bevy's hierarchy), be sure to stop if you encounter a duplicate entity.
+    pub fn iter_ancestors<R: Relationship>(
+        &'w self,
+        entity: Entity,
+    ) -> AncestorIter<'w, 's, D, F, R>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+    {
+        AncestorIter::new(self, entity)
+    }
+}
+
+/// An [`Iterator`] of [`Entity`]s over the descendants of an [`Entity`].
+///
+/// Traverses the hierarchy breadth-first.
+pub struct DescendantIter<'w, 's, D: QueryData, F: QueryFilter, S: RelationshipTarget>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+{
+    children_query: &'w Query<'w, 's, D, F>,
+    vecdeque: VecDeque<Entity>,
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, S: RelationshipTarget> DescendantIter<'w, 's, D, F, S>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+{
+    /// Returns a new [`DescendantIter`].
+    pub fn new(children_query: &'w Query<'w, 's, D, F>, entity: Entity) -> Self {
+        DescendantIter {
+            children_query,
+            vecdeque: children_query
+                .get(entity)
+                .into_iter()
+                .flat_map(RelationshipTarget::iter)
+                .collect(),
+        }
+    }
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, S: RelationshipTarget> Iterator
+    for DescendantIter<'w, 's, D, F, S>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+{
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entity = self.vecdeque.pop_front()?;
+
+        if let Ok(children) = self.children_query.get(entity) {
+            self.vecdeque.extend(children.iter());
+        }
+
+        Some(entity)
+    }
+}
+
+/// An [`Iterator`] of [`Entity`]s over the descendants of an [`Entity`].
+///
+/// Traverses the hierarchy depth-first.
+pub struct DescendantDepthFirstIter<'w, 's, D: QueryData, F: QueryFilter, S: RelationshipTarget>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+{
+    children_query: &'w Query<'w, 's, D, F>,
+    stack: SmallVec<[Entity; 8]>,
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, S: RelationshipTarget>
+    DescendantDepthFirstIter<'w, 's, D, F, S>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+{
+    /// Returns a new [`DescendantDepthFirstIter`].
+    pub fn new(children_query: &'w Query<'w, 's, D, F>, entity: Entity) -> Self {
+        let mut stack: SmallVec<[Entity; 8]> = children_query
+            .get(entity)
+            .into_iter()
+            .flat_map(RelationshipTarget::iter)
+            .collect();
+        stack.reverse();
+        DescendantDepthFirstIter {
+            children_query,
+            stack,
+        }
+    }
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, S: RelationshipTarget> Iterator
+    for DescendantDepthFirstIter<'w, 's, D, F, S>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w S>,
+{
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entity = self.stack.pop()?;
+
+        if let Ok(children) = self.children_query.get(entity) {
+            let start = self.stack.len();
+            self.stack.extend(children.iter());
+            self.stack[start..].reverse();
+        }
+
+        Some(entity)
+    }
+}
+
+/// An [`Iterator`] of [`Entity`]s over the ancestors of an [`Entity`].
+pub struct AncestorIter<'w, 's, D: QueryData, F: QueryFilter, R: Relationship>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+{
+    parent_query: &'w Query<'w, 's, D, F>,
+    next: Option<Entity>,
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, R: Relationship> AncestorIter<'w, 's, D, F, R>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+{
+    /// Returns a new [`AncestorIter`].
+    pub fn new(parent_query: &'w Query<'w, 's, D, F>, entity: Entity) -> Self {
+        AncestorIter {
+            parent_query,
+            next: Some(entity),
+        }
+    }
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, R: Relationship> Iterator
+    for AncestorIter<'w, 's, D, F, R>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w R>,
+{
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next = self.parent_query.get(self.next?).ok().map(R::get);
+        self.next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        entity::Entity,
+        prelude::Component,
+        system::{Query, SystemState},
+        world::World,
+    };
+    use alloc::vec::Vec;
+
+    #[derive(Component)]
+    #[relationship(relationship_target = Contains)]
+    struct ContainedIn(Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = ContainedIn)]
+    struct Contains(Vec<Entity>);
+
+    #[test]
+    fn relationship_traversal() {
+        let world = &mut World::new();
+
+        let a0 = world.spawn_empty().id();
+        let a1 = world.spawn(ContainedIn(a0)).id();
+        let a2 = world.spawn(ContainedIn(a0)).id();
+        let a3 = world.spawn(ContainedIn(a1)).id();
+
+        let mut system_state = SystemState::<(Query<&Contains>, Query<&ContainedIn>)>::new(world);
+        let (contains, contained_in) = system_state.get(world);
+
+        assert_eq!(contained_in.related(a3), Some(a1));
+        assert_eq!(
+            contains.relationship_sources(a0).collect::<Vec<_>>(),
+            [a1, a2]
+        );
+        assert_eq!(contained_in.root_ancestor(a3), a0);
+        assert_eq!(
+            contained_in.iter_ancestors(a3).collect::<Vec<_>>(),
+            [a1, a0]
+        );
+        assert_eq!(
+            contains.iter_descendants(a0).collect::<Vec<_>>(),
+            [a1, a2, a3]
+        );
+        assert_eq!(
+            contains
+                .iter_descendants_depth_first(a0)
+                .collect::<Vec<_>>(),
+            [a1, a3, a2]
+        );
+        assert_eq!(contains.iter_leaves(a0).collect::<Vec<_>>(), [a3, a2]);
+    }
+}
@@ -0,0 +1,180 @@
+use crate::entity::{Entity, EntityHashSet};
+use alloc::vec::Vec;
+use smallvec::SmallVec;
+
+/// The internal [`Entity`] collection used by a [`RelationshipTarget`](crate::relationship::RelationshipTarget) component.
+/// This is not intended to be modified directly by users, as it could invalidate the correctness of relationships.
+pub trait RelationshipSourceCollection {
+    /// The type of iterator returned by the `iter` method.
+    type SourceIter<'a>: Iterator<Item = Entity>
+    where
+        Self: 'a;
+
+    /// Returns an instance with the given pre-allocated entity `capacity`.
+    fn with_capacity(capacity: usize) -> Self;
+
+    /// Adds the given `entity` to the collection, if it is not already present.
+    ///
+    /// Returns whether the entity was added to the collection.
+    fn add(&mut self, entity: Entity) -> bool;
+
+    /// Removes the given `entity` from the collection.
+    ///
+    /// Returns whether the collection actually contained the entity.
+    fn remove(&mut self, entity: Entity) -> bool;
+
+    /// Iterates all entities in the collection.
+    fn iter(&self) -> Self::SourceIter<'_>;
+
+    /// Returns the current length of the collection.
+    fn len(&self) -> usize;
+
+    /// Returns true if the collection contains no entities.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl RelationshipSourceCollection for Vec<Entity> {
+    type SourceIter<'a> = core::iter::Copied<core::slice::Iter<'a, Entity>>;
+
+    fn with_capacity(capacity: usize) -> Self {
+        Vec::with_capacity(capacity)
+    }
+
+    fn add(&mut self, entity: Entity) -> bool {
+        if self.contains(&entity) {
+            return false;
+        }
+        Vec::push(self, entity);
+        true
+    }
+
+    fn remove(&mut self, entity: Entity) -> bool {
+        if let Some(index) = <[Entity]>::iter(self).position(|e| *e == entity) {
+            Vec::remove(self, index);
+            return true;
+        }
+        false
+    }
+
+    fn iter(&self) -> Self::SourceIter<'_> {
+        <[Entity]>::iter(self).copied()
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+}
+
+impl<A: smallvec::Array<Item = Entity>> RelationshipSourceCollection for SmallVec<A> {
+    type SourceIter<'a>
+        = core::iter::Copied<core::slice::Iter<'a, Entity>>
+    where
+        A: 'a;
+
+    fn with_capacity(capacity: usize) -> Self {
+        SmallVec::with_capacity(capacity)
+    }
+
+    fn add(&mut self, entity: Entity) -> bool {
+        if self.contains(&entity) {
+            return false;
+        }
+        SmallVec::push(self, entity);
+        true
+    }
+
+    fn remove(&mut self, entity: Entity) -> bool {
+        if let Some(index) = <[Entity]>::iter(self).position(|e| *e == entity) {
+            SmallVec::remove(self, index);
+            return true;
+        }
+        false
+    }
+
+    fn iter(&self) -> Self::SourceIter<'_> {
+        <[Entity]>::iter(self).copied()
+    }
+
+    fn len(&self) -> usize {
+        SmallVec::len(self)
+    }
+}
+
+impl RelationshipSourceCollection for EntityHashSet {
+    type SourceIter<'a> = core::iter::Copied<bevy_utils::hashbrown::hash_set::Iter<'a, Entity>>;
+
+    fn with_capacity(capacity: usize) -> Self {
+        EntityHashSet::with_capacity(capacity)
+    }
+
+    fn add(&mut self, entity: Entity) -> bool {
+        self.insert(entity)
+    }
+
+    fn remove(&mut self, entity: Entity) -> bool {
+        // We need to call the remove method on the underlying hash set,
+        // which takes its argument by reference
+        self.0.remove(&entity)
+    }
+
+    fn iter(&self) -> Self::SourceIter<'_> {
+        self.0.iter().copied()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_ecs;
+    use crate::{prelude::Component, relationship::RelationshipTarget, world::World};
+
+    #[derive(Component)]
+    #[relationship(relationship_target = RelTarget)]
+    struct Rel(Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = Rel)]
+    struct RelTarget(EntityHashSet);
+
+    #[derive(Component)]
+    #[relationship(relationship_target = SmallRelTarget)]
+    struct SmallRel(Entity);
+
+    #[derive(Component)]
+    #[relationship_target(relationship = SmallRel)]
+    struct SmallRelTarget(SmallVec<[Entity; 4]>);
+
+    #[test]
+    fn entity_hash_set_relationship_source_collection() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+
+        world.entity_mut(a).insert(Rel(b));
+
+        let rel_target = world.get::<RelTarget>(b).unwrap();
+        let collection = rel_target.collection();
+        assert_eq!(collection, &EntityHashSet::from([a]));
+    }
+
+    #[test]
+    fn smallvec_relationship_source_collection() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn_empty().id();
+
+        world.entity_mut(a).insert(SmallRel(c));
+        world.entity_mut(b).insert(SmallRel(c));
+
+        let rel_target = world.get::<SmallRelTarget>(c).unwrap();
+        assert_eq!(rel_target.iter().collect::<Vec<_>>(), [a, b]);
+    }
+}
@@ -0,0 +1,610 @@
+//! Resources stored on singleton entities.
+//!
+//! A [`Resource`] that also implements [`Component`] can be stored on an entity marked with
+//! [`ResourceEntity`] instead of in the world's resource storage.
+//! Such a resource behaves like any other component: it can have hooks and observers, it can be
+//! read and changed through queries (including change detection with
+//! [`Changed`](crate::query::Changed)), and it is picked up by tooling that works with entities,
+//! such as scenes and the remote protocol.
+//!
+//! Resource entities are managed with [`World::insert_resource_entity`],
+//! [`World::init_resource_entity`] and [`World::remove_resource_entity`], or the matching
+//! [`Commands`](crate::system::Commands) methods.
+//! Systems access them with [`EntityRes`] and [`EntityResMut`]. Like [`Res`](crate::system::Res),
+//! these fail validation when the resource entity doesn't exist; wrap them in an [`Option`] to
+//! handle that case.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::resource_entity::{EntityRes, EntityResMut};
+//! #[derive(Resource, Component, Default)]
+//! struct Score(u32);
+//!
+//! fn add_points(mut score: EntityResMut<Score>) {
+//!     score.0 += 10;
+//! }
+//!
+//! fn announce(score: Query<&Score, Changed<Score>>) {
+//!     for score in &score {
+//!         println!("New score: {}", score.0);
+//!     }
+//! }
+//!
+//! let mut world = World::new();
+//! let entity = world.init_resource_entity::<Score>();
+//! world.run_system_cached(add_points).unwrap();
+//! world.run_system_cached(announce).unwrap();
+//!
+//! assert_eq!(world.resource_entity::<Score>(), Some(entity));
+//! assert_eq!(world.get::<Score>(entity).unwrap().0, 10);
+//! ```
+//!
+//! Only one entity is expected to hold a given resource. When several do, for example after
+//! spawning the same scene twice, the world methods and the system parameters all use the same
+//! one: the entity that compares lowest.
+//!
+//! Like queries, these methods and system parameters ignore entities excluded by the
+//! [`DefaultQueryFilters`], such as [`Disabled`](crate::entity_disabling::Disabled) ones.
+
+use core::ops::{Deref, DerefMut};
+
+use crate::{
+    self as bevy_ecs,
+    archetype::{Archetype, ArchetypeEntity},
+    change_detection::Mut,
+    component::{Component, Mutable, Tick},
+    entity::Entity,
+    entity_disabling::DefaultQueryFilters,
+    query::{QueryData, QueryState, With},
+    system::{Query, ReadOnlySystemParam, Resource, SystemMeta, SystemParam},
+    world::{unsafe_world_cell::UnsafeWorldCell, FromWorld, World},
+};
+
+#[cfg(feature = "bevy_reflect")]
+use {
+    crate::reflect::ReflectComponent,
+    bevy_reflect::{std_traits::ReflectDefault, Reflect},
+};
+
+/// A marker component for entities that hold a resource.
+///
+/// See the [module docs](crate::resource_entity) for more information.
+#[derive(Component, Clone, Debug, Default)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, Debug)
+)]
+pub struct ResourceEntity;
+
+/// A [`SystemParam`] that reads the resource `R` stored on a resource entity.
+///
+/// Validation fails when there is no such entity, unless it's wrapped in an [`Option`].
+/// See the [module docs](crate::resource_entity) for more information.
+pub struct EntityRes<'w, R: Resource + Component> {
+    value: &'w R,
+}
+
+impl<'w, R: Resource + Component> EntityRes<'w, R> {
+    /// Returns the reference to the resource, with the lifetime of the world.
+    pub fn into_inner(self) -> &'w R {
+        self.value
+    }
+}
+
+impl<R: Resource + Component> Deref for EntityRes<'_, R> {
+    type Target = R;
+
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+/// A [`SystemParam`] that mutably accesses the resource `R` stored on a resource entity.
+///
+/// Validation fails when there is no such entity, unless it's wrapped in an [`Option`].
+/// See the [module docs](crate::resource_entity) for more information.
+pub struct EntityResMut<'w, R: Resource + Component<Mutability = Mutable>> {
+    value: Mut<'w, R>,
+}
+
+impl<'w, R: Resource + Component<Mutability = Mutable>> EntityResMut<'w, R> {
+    /// Returns the change-detecting reference to the resource, with the lifetime of the world.
+    pub fn into_inner(self) -> Mut<'w, R> {
+        self.value
+    }
+}
+
+impl<R: Resource + Component<Mutability = Mutable>> Deref for EntityResMut<'_, R> {
+    type Target = R;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<R: Resource + Component<Mutability = Mutable>> DerefMut for EntityResMut<'_, R> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+type ResourceEntityState<D> = QueryState<(Entity, D), With<ResourceEntity>>;
+
+/// Returns the item of the resource entity that compares lowest, as [`World::resource_entity`]
+/// picks it.
+///
+/// # Safety
+///
+/// The caller must have the access of `state` to the world, and `state` must belong to it.
+unsafe fn lowest_resource_entity<'w, D: QueryData>(
+    state: &ResourceEntityState<D>,
+    world: UnsafeWorldCell<'w>,
+    last_run: Tick,
+    this_run: Tick,
+) -> Option<D::Item<'w>> {
+    // SAFETY: Upheld by the caller.
+    unsafe { state.iter_unchecked_manual(world, last_run, this_run) }
+        .min_by_key(|(entity, _)| *entity)
+        .map(|(_, item)| item)
+}
+
+/// Returns whether `state` matches a resource entity, warning if it doesn't.
+///
+/// # Safety
+///
+/// The caller must have read access to the components of `state`.
+unsafe fn validate_resource_entity<P: SystemParam, D: QueryData>(
+    state: &ResourceEntityState<D>,
+    system_meta: &SystemMeta,
+    world: UnsafeWorldCell,
+) -> bool {
+    state.validate_world(world.id());
+    // SAFETY: Upheld by the caller, and the query is read only.
+    let is_valid = unsafe {
+        state
+            .as_readonly()
+            .iter_unchecked_manual(world, system_meta.last_run, world.change_tick())
+    }
+    .next()
+    .is_some();
+    if !is_valid {
+        system_meta.try_warn_param::<P>();
+    }
+    is_valid
+}
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta. If
+// this Query conflicts with any prior access, a panic will occur.
+unsafe impl<'a, R: Resource + Component> SystemParam for EntityRes<'a, R> {
+    type State = ResourceEntityState<&'static R>;
+    type Item<'w, 's> = EntityRes<'w, R>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        Query::init_state(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        // SAFETY: Delegate to existing `SystemParam` implementations.
+        unsafe { Query::new_archetype(state, archetype, system_meta) };
+    }
+
+    #[inline]
+    unsafe fn validate_param(
+        state: &Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell,
+    ) -> bool {
+        // SAFETY: State ensures that the components it accesses are not mutably accessible elsewhere.
+        unsafe { validate_resource_entity::<Self, _>(state, system_meta, world) }
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        state.validate_world(world.id());
+        // SAFETY: State ensures that the components it accesses are not mutably accessible elsewhere.
+        let value =
+            unsafe { lowest_resource_entity(state, world, system_meta.last_run, change_tick) };
+        EntityRes {
+            value: value.expect("The resource entity was expected to exist."),
+        }
+    }
+}
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta. If
+// this Query conflicts with any prior access, a panic will occur.
+unsafe impl<'a, R: Resource + Component> SystemParam for Option<EntityRes<'a, R>> {
+    type State = ResourceEntityState<&'static R>;
+    type Item<'w, 's> = Option<EntityRes<'w, R>>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        EntityRes::<R>::init_state(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        // SAFETY: Delegate to existing `SystemParam` implementations.
+        unsafe { EntityRes::<R>::new_archetype(state, archetype, system_meta) };
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        state.validate_world(world.id());
+        // SAFETY: State ensures that the components it accesses are not mutably accessible elsewhere.
+        let value =
+            unsafe { lowest_resource_entity(state, world, system_meta.last_run, change_tick) };
+        value.map(|value| EntityRes { value })
+    }
+}
+
+// SAFETY: QueryState is constrained to read-only fetches, so it only reads World.
+unsafe impl<'a, R: Resource + Component> ReadOnlySystemParam for EntityRes<'a, R> {}
+
+// SAFETY: QueryState is constrained to read-only fetches, so it only reads World.
+unsafe impl<'a, R: Resource + Component> ReadOnlySystemParam for Option<EntityRes<'a, R>> {}
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta. If
+// this Query conflicts with any prior access, a panic will occur.
+unsafe impl<'a, R: Resource + Component<Mutability = Mutable>> SystemParam for EntityResMut<'a, R> {
+    type State = ResourceEntityState<&'static mut R>;
+    type Item<'w, 's> = EntityResMut<'w, R>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        Query::init_state(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        // SAFETY: Delegate to existing `SystemParam` implementations.
+        unsafe { Query::new_archetype(state, archetype, system_meta) };
+    }
+
+    #[inline]
+    unsafe fn validate_param(
+        state: &Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell,
+    ) -> bool {
+        // SAFETY: State ensures that the components it accesses are not accessible elsewhere.
+        unsafe { validate_resource_entity::<Self, _>(state, system_meta, world) }
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        state.validate_world(world.id());
+        // SAFETY: State ensures that the components it accesses are not accessible elsewhere.
+        let value =
+            unsafe { lowest_resource_entity(state, world, system_meta.last_run, change_tick) };
+        EntityResMut {
+            value: value.expect("The resource entity was expected to exist."),
+        }
+    }
+}
+
+// SAFETY: Relevant query ComponentId and ArchetypeComponentId access is applied to SystemMeta. If
+// this Query conflicts with any prior access, a panic will occur.
+unsafe impl<'a, R: Resource + Component<Mutability = Mutable>> SystemParam
+    for Option<EntityResMut<'a, R>>
+{
+    type State = ResourceEntityState<&'static mut R>;
+    type Item<'w, 's> = Option<EntityResMut<'w, R>>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        EntityResMut::<R>::init_state(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        // SAFETY: Delegate to existing `SystemParam` implementations.
+        unsafe { EntityResMut::<R>::new_archetype(state, archetype, system_meta) };
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        state.validate_world(world.id());
+        // SAFETY: State ensures that the components it accesses are not accessible elsewhere.
+        let value =
+            unsafe { lowest_resource_entity(state, world, system_meta.last_run, change_tick) };
+        value.map(|value| EntityResMut { value })
+    }
+}
+
+impl World {
+    /// Returns the entity holding the resource `R`, if any.
+    ///
+    /// Entities excluded by the [`DefaultQueryFilters`] are ignored, as they are by [`EntityRes`]
+    /// and [`EntityResMut`]. When several entities hold `R`, the one that compares lowest is
+    /// returned.
+    pub fn resource_entity<R: Resource + Component>(&self) -> Option<Entity> {
+        let component_id = self.component_id::<R>()?;
+        let marker_id = self.component_id::<ResourceEntity>()?;
+        let filters = self.get_resource::<DefaultQueryFilters>();
+        // Like queries, the filters don't apply to the components they access.
+        let is_filtered = |archetype: &Archetype| {
+            filters.is_some_and(|filters| {
+                filters
+                    .disabling_ids()
+                    .any(|id| id != component_id && id != marker_id && archetype.contains(id))
+            })
+        };
+        self.archetypes()
+            .component_index()
+            .get(&component_id)?
+            .keys()
+            .filter_map(|&archetype_id| self.archetypes().get(archetype_id))
+            .filter(|archetype| archetype.contains(marker_id) && !is_filtered(archetype))
+            .flat_map(Archetype::entities)
+            .map(ArchetypeEntity::id)
+            .min()
+    }
+
+    /// Inserts the resource `R` on its resource entity, spawning the entity if it doesn't exist,
+    /// and returns the entity.
+    ///
+    /// An existing value is replaced, triggering the component hooks and observers of `R`.
+    #[track_caller]
+    pub fn insert_resource_entity<R: Resource + Component>(&mut self, value: R) -> Entity {
+        match self.resource_entity::<R>() {
+            Some(entity) => {
+                self.entity_mut(entity).insert(value);
+                entity
+            }
+            None => self.spawn((value, ResourceEntity)).id(),
+        }
+    }
+
+    /// Spawns a resource entity holding the resource `R` created with [`FromWorld`], unless one
+    /// already exists, and returns the entity.
+    #[track_caller]
+    pub fn init_resource_entity<R: Resource + Component + FromWorld>(&mut self) -> Entity {
+        match self.resource_entity::<R>() {
+            Some(entity) => entity,
+            None => {
+                let value = R::from_world(self);
+                self.spawn((value, ResourceEntity)).id()
+            }
+        }
+    }
+
+    /// Removes the resource `R` from the entity holding it, returning the resource.
+    ///
+    /// Other components of the entity are left untouched. The entity is despawned only if it's left
+    /// with nothing but its [`ResourceEntity`] marker.
+    pub fn remove_resource_entity<R: Resource + Component>(&mut self) -> Option<R> {
+        let entity = self.resource_entity::<R>()?;
+        let mut entity = self.entity_mut(entity);
+        let value = entity.take::<R>();
+        // Hooks and observers of `R` may have despawned the entity.
+        if !entity.is_despawned() && entity.archetype().component_count() == 1 {
+            entity.despawn();
+        }
+        value
+    }
+
+    /// Returns the resource `R` stored on its resource entity, if any.
+    pub fn get_resource_entity<R: Resource + Component>(&self) -> Option<&R> {
+        self.get::<R>(self.resource_entity::<R>()?)
+    }
+
+    /// Returns the resource `R` stored on its resource entity mutably, if any.
+    pub fn get_resource_entity_mut<R: Resource + Component<Mutability = Mutable>>(
+        &mut self,
+    ) -> Option<Mut<'_, R>> {
+        let entity = self.resource_entity::<R>()?;
+        self.get_mut::<R>(entity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EntityRes, EntityResMut, ResourceEntity};
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        entity_disabling::Disabled,
+        observer::Trigger,
+        query::{Changed, With, Without},
+        schedule::Schedule,
+        system::{Commands, Query, ResMut, Resource, RunSystemOnce},
+        world::{OnInsert, OnReplace, World},
+    };
+    use alloc::vec::Vec;
+
+    #[derive(Resource, Component, Default, PartialEq, Debug)]
+    struct Score(u32);
+
+    #[derive(Resource, Component, Default)]
+    struct Counts {
+        inserted: u32,
+        replaced: u32,
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut world = World::new();
+        assert_eq!(world.resource_entity::<Score>(), None);
+        assert_eq!(world.get_resource_entity::<Score>(), None);
+
+        let entity = world.insert_resource_entity(Score(1));
+        assert_eq!(world.resource_entity::<Score>(), Some(entity));
+        assert!(world.entity(entity).contains::<ResourceEntity>());
+        assert_eq!(world.get_resource_entity::<Score>(), Some(&Score(1)));
+
+        // An entity that merely has the component isn't the resource entity.
+        world.spawn(Score(5));
+        assert_eq!(world.resource_entity::<Score>(), Some(entity));
+
+        assert_eq!(world.insert_resource_entity(Score(2)), entity);
+        assert_eq!(world.init_resource_entity::<Score>(), entity);
+        world.get_resource_entity_mut::<Score>().unwrap().0 += 1;
+        assert_eq!(world.get_resource_entity::<Score>(), Some(&Score(3)));
+
+        assert_eq!(world.remove_resource_entity::<Score>(), Some(Score(3)));
+        assert!(world.get_entity(entity).is_err());
+        assert_eq!(world.resource_entity::<Score>(), None);
+        assert_eq!(world.remove_resource_entity::<Score>(), None);
+
+        // Removing the resource keeps the other components of its entity.
+        let shared = world
+            .spawn((Score(4), Counts::default(), ResourceEntity))
+            .id();
+        assert_eq!(world.remove_resource_entity::<Score>(), Some(Score(4)));
+        assert!(world.entity(shared).contains::<Counts>());
+        assert!(world.entity(shared).contains::<ResourceEntity>());
+        world.despawn(shared);
+
+        // Entities spawned by other means, such as scenes, are found too.
+        let spawned = world.spawn((Score(7), ResourceEntity)).id();
+        assert_eq!(world.resource_entity::<Score>(), Some(spawned));
+        let initialized = world.init_resource_entity::<Score>();
+        assert_eq!(initialized, spawned);
+    }
+
+    #[test]
+    fn duplicate_resource_entities() {
+        let mut world = World::new();
+        let first = world
+            .spawn((Score(1), Counts::default(), ResourceEntity))
+            .id();
+        let second = world.spawn((Score(2), ResourceEntity)).id();
+        assert_eq!(world.resource_entity::<Score>(), Some(first));
+
+        // The system parameters use the same entity as the world methods.
+        assert_eq!(
+            world
+                .run_system_once(|score: EntityRes<Score>| score.0)
+                .unwrap(),
+            1
+        );
+        world
+            .run_system_once(|mut score: EntityResMut<Score>| score.0 += 10)
+            .unwrap();
+        assert_eq!(world.get::<Score>(first), Some(&Score(11)));
+        assert_eq!(world.get::<Score>(second), Some(&Score(2)));
+        assert_eq!(world.insert_resource_entity(Score(3)), first);
+
+        assert_eq!(world.remove_resource_entity::<Score>(), Some(Score(3)));
+        assert_eq!(world.resource_entity::<Score>(), Some(second));
+        assert_eq!(
+            world
+                .run_system_once(|score: Option<EntityRes<Score>>| score.map(|score| score.0))
+                .unwrap(),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn disabled_resource_entity() {
+        let mut world = World::new();
+        let entity = world.insert_resource_entity(Score(1));
+        world.entity_mut(entity).insert(Disabled);
+        assert_eq!(world.resource_entity::<Score>(), None);
+        assert!(world
+            .run_system_once(|score: Option<EntityRes<Score>>| score.is_none())
+            .unwrap());
+
+        world.entity_mut(entity).remove::<Disabled>();
+        assert_eq!(world.resource_entity::<Score>(), Some(entity));
+    }
+
+    #[test]
+    fn observers_and_change_detection() {
+        let mut world = World::new();
+        world.init_resource::<Counts>();
+        world.add_observer(|_: Trigger<OnInsert, Score>, mut counts: ResMut<Counts>| {
+            counts.inserted += 1;
+        });
+        world.add_observer(|_: Trigger<OnReplace, Score>, mut counts: ResMut<Counts>| {
+            counts.replaced += 1;
+        });
+
+        world.init_resource_entity::<Score>();
+        world.insert_resource_entity(Score(1));
+        assert_eq!(world.resource::<Counts>().inserted, 2);
+        assert_eq!(world.resource::<Counts>().replaced, 1);
+
+        let mut changed = world.query_filtered::<&Score, (Changed<Score>, With<ResourceEntity>)>();
+        assert_eq!(changed.iter(&world).count(), 1);
+        world.clear_trackers();
+        assert_eq!(changed.iter(&world).count(), 0);
+
+        world
+            .run_system_once(|mut score: EntityResMut<Score>| score.0 += 1)
+            .unwrap();
+        assert_eq!(changed.iter(&world).collect::<Vec<_>>(), [&Score(2)]);
+    }
+
+    #[test]
+    fn system_params_and_commands() {
+        let mut world = World::new();
+        fn read(score: EntityRes<Score>, mut others: Query<&mut Score, Without<ResourceEntity>>) {
+            for mut other in &mut others {
+                other.0 = score.0;
+            }
+        }
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(read);
+
+        let other = world.spawn(Score(0)).id();
+        assert!(world
+            .run_system_once(|score: Option<EntityRes<Score>>| score.is_none())
+            .unwrap());
+        assert_eq!(world.get::<Score>(other), Some(&Score(0)));
+
+        world
+            .run_system_once(|mut commands: Commands| {
+                commands.insert_resource_entity(Score(4));
+            })
+            .unwrap();
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Score>(other), Some(&Score(4)));
+
+        world
+            .run_system_once(|mut commands: Commands| {
+                commands.remove_resource_entity::<Score>();
+            })
+            .unwrap();
+        assert_eq!(world.resource_entity::<Score>(), None);
+
+        world
+            .run_system_once(|mut commands: Commands| {
+                commands.init_resource_entity::<Score>();
+            })
+            .unwrap();
+        assert_eq!(world.get_resource_entity::<Score>(), Some(&Score(0)));
+    }
+}
@@ -1,9 +1,245 @@
 //! Contains error and result helpers for use in fallible systems.
+//!
+//! Systems, observers and commands can return a [`Result`]. When a system or observer returns an
+//! [`Err`], the error is passed to an error handler: a function taking the [`Error`] and a
+//! [`SystemErrorContext`] describing the system that failed.
+//!
+//! The error handler used by a [`Schedule`](crate::schedule::Schedule) can be set with
+//! [`Schedule::set_error_handler`](crate::schedule::Schedule::set_error_handler).
+//! Schedules without their own handler (as well as observers and commands) use the handler stored in the
+//! [`DefaultSystemErrorHandler`] resource, which panics unless configured otherwise.
+//!
+//! This module provides a handler for each common behavior: [`panic`], [`error`], [`warn`], [`info`],
+//! [`debug`], [`trace`] and [`ignore`]. Any function with the same signature can be used as a custom handler.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::result;
+//! # #[derive(Component)]
+//! # struct Player;
+//! fn fallible_system(query: Query<Entity, With<Player>>) -> Result {
+//!     let player = query.get_single()?;
+//!     // ...
+//!     # let _ = player;
+//!     Ok(())
+//! }
+//!
+//! let mut world = World::new();
+//! let mut schedule = Schedule::default();
+//! schedule.add_systems(fallible_system);
+//! // There is no `Player`, so `fallible_system` fails. The error is logged instead of panicking.
+//! schedule.set_error_handler(result::warn);
+//! schedule.run(&mut world);
+//! ```
 
-use alloc::boxed::Box;
+use crate::{self as bevy_ecs, component::Tick, system::Resource};
+use alloc::{borrow::Cow, boxed::Box};
 
 /// A dynamic error type for use in fallible systems.
 pub type Error = Box<dyn core::error::Error + Send + Sync + 'static>;
 
 /// A result type for use in fallible systems.
 pub type Result<T = (), E = Error> = core::result::Result<T, E>;
+
+/// Additional context for a failed system run.
+#[derive(Clone, Debug)]
+pub struct SystemErrorContext {
+    /// The name of the system (or command) that failed.
+    pub name: Cow<'static, str>,
+    /// The last tick that the system was run.
+    ///
+    /// For commands, this is the last change tick of the [`World`](crate::world::World) the command was applied to.
+    pub last_run: Tick,
+}
+
+/// The error handler used by schedules, observers and commands that have not been given a handler of their own.
+///
+/// If this resource is not present in the [`World`](crate::world::World), errors are handled with [`panic`].
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct DefaultSystemErrorHandler(pub fn(Error, SystemErrorContext));
+
+impl Default for DefaultSystemErrorHandler {
+    fn default() -> Self {
+        Self(panic)
+    }
+}
+
+macro_rules! inner {
+    ($call:path, $e:ident, $c:ident) => {
+        $call!("Encountered an error in system `{}`: {:?}", $c.name, $e);
+    };
+}
+
+/// Error handler that panics with the system error.
+#[track_caller]
+#[inline]
+pub fn panic(error: Error, ctx: SystemErrorContext) {
+    inner!(panic, error, ctx);
+}
+
+/// Error handler that logs the system error at the `error` level.
+#[track_caller]
+#[inline]
+pub fn error(error: Error, ctx: SystemErrorContext) {
+    inner!(log::error, error, ctx);
+}
+
+/// Error handler that logs the system error at the `warn` level.
+#[track_caller]
+#[inline]
+pub fn warn(error: Error, ctx: SystemErrorContext) {
+    inner!(log::warn, error, ctx);
+}
+
+/// Error handler that logs the system error at the `info` level.
+#[track_caller]
+#[inline]
+pub fn info(error: Error, ctx: SystemErrorContext) {
+    inner!(log::info, error, ctx);
+}
+
+/// Error handler that logs the system error at the `debug` level.
+#[track_caller]
+#[inline]
+pub fn debug(error: Error, ctx: SystemErrorContext) {
+    inner!(log::debug, error, ctx);
+}
+
+/// Error handler that logs the system error at the `trace` level.
+#[track_caller]
+#[inline]
+pub fn trace(error: Error, ctx: SystemErrorContext) {
+    inner!(log::trace, error, ctx);
+}
+
+/// Error handler that ignores the system error.
+#[track_caller]
+#[inline]
+pub fn ignore(_: Error, _: SystemErrorContext) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::Event,
+        observer::{Observer, Trigger},
+        schedule::{ExecutorKind, Schedule},
+        system::Commands,
+        world::World,
+    };
+    use alloc::string::String;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Event)]
+    struct TestEvent;
+
+    fn failing_system() -> Result {
+        Err("system failed".into())
+    }
+
+    #[test]
+    #[should_panic(expected = "Encountered an error in system")]
+    fn errors_panic_by_default() {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        schedule.add_systems(failing_system);
+        schedule.run(&mut world);
+    }
+
+    #[test]
+    fn schedule_error_handler() {
+        static ERRORS: AtomicUsize = AtomicUsize::new(0);
+        fn count(_: Error, ctx: SystemErrorContext) {
+            assert!(ctx.name.ends_with("failing_system"));
+            ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        for kind in [
+            ExecutorKind::Simple,
+            ExecutorKind::SingleThreaded,
+            #[cfg(feature = "multi_threaded")]
+            ExecutorKind::MultiThreaded,
+        ] {
+            let mut world = World::new();
+            let mut schedule = Schedule::default();
+            schedule.set_executor_kind(kind);
+            schedule.add_systems((failing_system, || {}));
+            schedule.set_error_handler(count);
+            schedule.run(&mut world);
+        }
+
+        assert_eq!(
+            ERRORS.load(Ordering::Relaxed),
+            if cfg!(feature = "multi_threaded") {
+                3
+            } else {
+                2
+            }
+        );
+    }
+
+    #[test]
+    fn default_error_handler_resource() {
+        static ERRORS: AtomicUsize = AtomicUsize::new(0);
+        fn count(_: Error, _: SystemErrorContext) {
+            ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::new();
+        world.insert_resource(DefaultSystemErrorHandler(count));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(failing_system);
+        schedule.run(&mut world);
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 1);
+
+        // A schedule's own handler takes precedence.
+        schedule.set_error_handler(ignore);
+        schedule.run(&mut world);
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn observer_error_handler() {
+        static ERRORS: AtomicUsize = AtomicUsize::new(0);
+        fn count(_: Error, _: SystemErrorContext) {
+            ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+        static OVERRIDE_ERRORS: AtomicUsize = AtomicUsize::new(0);
+        fn count_override(_: Error, _: SystemErrorContext) {
+            OVERRIDE_ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::new();
+        world.insert_resource(DefaultSystemErrorHandler(count));
+        world.add_observer(|_: Trigger<TestEvent>| -> Result { Err("observer failed".into()) });
+        world.spawn(
+            Observer::new(|_: Trigger<TestEvent>| -> Result { Err("observer failed".into()) })
+                .with_error_handler(count_override),
+        );
+        world.add_observer(|_: Trigger<TestEvent>| {});
+        world.flush();
+
+        world.trigger(TestEvent);
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 1);
+        assert_eq!(OVERRIDE_ERRORS.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn command_errors_use_default_error_handler() {
+        static ERRORS: AtomicUsize = AtomicUsize::new(0);
+        fn count(_: Error, _: SystemErrorContext) {
+            ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::new();
+        world.insert_resource(DefaultSystemErrorHandler(count));
+        let mut schedule = Schedule::default();
+        schedule.add_systems(|mut commands: Commands| {
+            commands.queue(|_: &mut World| -> Result<(), String> { Err("command failed".into()) });
+        });
+        schedule.run(&mut world);
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 1);
+    }
+}
@@ -18,7 +18,7 @@ use crate::{
     component::{ComponentId, Tick},
     prelude::{IntoSystemSet, SystemSet},
     query::Access,
-    result::Result,
+    result::{Error, Result, SystemErrorContext},
     schedule::{BoxedCondition, InternedSystemSet, NodeId, SystemTypeSet},
     system::{ScheduleSystem, System, SystemIn},
     world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World},
@@ -33,6 +33,7 @@ pub(super) trait SystemExecutor: Send + Sync {
         schedule: &mut SystemSchedule,
         world: &mut World,
         skip_systems: Option<&FixedBitSet>,
+        error_handler: fn(Error, SystemErrorContext),
     );
     fn set_apply_final_deferred(&mut self, value: bool);
 }
@@ -160,6 +161,21 @@ pub(super) fn is_apply_deferred(system: &ScheduleSystem) -> bool {
     system.type_id() == TypeId::of::<ApplyDeferred>()
 }
 
+/// Runs `apply`, which applies the buffers of the system with the given [`NodeId`], so that
+/// [`Stepping`](crate::schedule::Stepping) can hold back the commands of the stepped system.
+#[inline]
+pub(super) fn apply_buffers_of<R>(
+    world: &mut World,
+    _node: NodeId,
+    apply: impl FnOnce(&mut World) -> R,
+) -> R {
+    #[cfg(feature = "bevy_debug_stepping")]
+    return crate::schedule::Stepping::apply_system_buffers(world, _node, apply);
+
+    #[cfg(not(feature = "bevy_debug_stepping"))]
+    apply(world)
+}
+
 impl System for ApplyDeferred {
     type In = ();
     type Out = Result<()>;
@@ -1,4 +1,4 @@
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
 use bevy_utils::{default, syncunsafecell::SyncUnsafeCell};
 use concurrent_queue::ConcurrentQueue;
@@ -20,9 +20,15 @@ use alloc::sync::Arc;
 
 use crate::{
     archetype::ArchetypeComponentId,
+    change_detection::DetectChangesMut,
     prelude::Resource,
     query::Access,
-    schedule::{is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
+    result::{Error, SystemErrorContext},
+    schedule::{
+        executor::apply_buffers_of, is_apply_deferred, sync_points::measure_sync_point,
+        trace::RawSystemSpan, BoxedCondition, ExecutorKind, ExecutorTrace, NodeId, SystemExecutor,
+        SystemSchedule,
+    },
     system::ScheduleSystem,
     world::{unsafe_world_cell::UnsafeWorldCell, World},
 };
@@ -35,8 +41,12 @@ use super::__rust_begin_short_backtrace;
 struct Environment<'env, 'sys> {
     executor: &'env MultiThreadedExecutor,
     systems: &'sys [SyncUnsafeCell<ScheduleSystem>],
+    system_ids: &'sys [NodeId],
     conditions: SyncUnsafeCell<Conditions<'sys>>,
     world_cell: UnsafeWorldCell<'env>,
+    error_handler: fn(Error, SystemErrorContext),
+    /// Whether the spans of systems are recorded for the [`ExecutorTrace`].
+    trace: bool,
 }
 
 struct Conditions<'a> {
@@ -51,10 +61,13 @@ impl<'env, 'sys> Environment<'env, 'sys> {
         executor: &'env MultiThreadedExecutor,
         schedule: &'sys mut SystemSchedule,
         world: &'env mut World,
+        error_handler: fn(Error, SystemErrorContext),
     ) -> Self {
+        let trace = world.contains_resource::<ExecutorTrace>();
         Environment {
             executor,
             systems: SyncUnsafeCell::from_mut(schedule.systems.as_mut_slice()).as_slice_of_cells(),
+            system_ids: &schedule.system_ids,
             conditions: SyncUnsafeCell::new(Conditions {
                 system_conditions: &mut schedule.system_conditions,
                 set_conditions: &mut schedule.set_conditions,
@@ -62,6 +75,8 @@ impl<'env, 'sys> Environment<'env, 'sys> {
                 systems_in_sets_with_conditions: &schedule.systems_in_sets_with_conditions,
             }),
             world_cell: world.as_unsafe_world_cell(),
+            error_handler,
+            trace,
         }
     }
 }
@@ -95,6 +110,10 @@ pub struct MultiThreadedExecutor {
     /// When set, tells the executor that a thread has panicked.
     panic_payload: Mutex<Option<Box<dyn Any + Send>>>,
     starting_systems: FixedBitSet,
+    /// Names of the systems, used for the spans of the [`ExecutorTrace`].
+    system_names: Vec<Cow<'static, str>>,
+    /// Spans of the systems that ran, if the world contains an [`ExecutorTrace`].
+    trace_spans: Mutex<Vec<RawSystemSpan>>,
     /// Cached tracing span
     #[cfg(feature = "trace")]
     executor_span: Span,
@@ -167,6 +186,12 @@ impl SystemExecutor for MultiThreadedExecutor {
         state.skipped_systems = FixedBitSet::with_capacity(sys_count);
         state.unapplied_systems = FixedBitSet::with_capacity(sys_count);
 
+        self.system_names = schedule
+            .systems
+            .iter()
+            .map(|system| system.name())
+            .collect();
+
         state.system_task_metadata = Vec::with_capacity(sys_count);
         for index in 0..sys_count {
             state.system_task_metadata.push(SystemTaskMetadata {
@@ -188,6 +213,7 @@ impl SystemExecutor for MultiThreadedExecutor {
         schedule: &mut SystemSchedule,
         world: &mut World,
         _skip_systems: Option<&FixedBitSet>,
+        error_handler: fn(Error, SystemErrorContext),
     ) {
         let state = self.state.get_mut().unwrap();
         // reset counts
@@ -221,7 +247,7 @@ impl SystemExecutor for MultiThreadedExecutor {
             .map(|e| e.0.clone());
         let thread_executor = thread_executor.as_deref();
 
-        let environment = &Environment::new(self, schedule, world);
+        let environment = &Environment::new(self, schedule, world, error_handler);
 
         ComputeTaskPool::get_or_init(TaskPool::default).scope_with_executor(
             false,
@@ -237,12 +263,22 @@ impl SystemExecutor for MultiThreadedExecutor {
 
         // End the borrows of self and world in environment by copying out the reference to systems.
         let systems = environment.systems;
+        let system_ids = environment.system_ids;
+
+        let spans = self.trace_spans.get_mut().unwrap();
+        if !spans.is_empty() {
+            if let Some(mut trace) = world.get_resource_mut::<ExecutorTrace>() {
+                trace.bypass_change_detection().record(spans.drain(..));
+            } else {
+                spans.clear();
+            }
+        }
 
         let state = self.state.get_mut().unwrap();
         if self.apply_final_deferred {
             // Do one final apply buffers after all systems have completed
             // Commands should be applied while on the scope's thread, not the executor's thread
-            let res = apply_deferred(&state.unapplied_systems, systems, world);
+            let res = apply_deferred(&state.unapplied_systems, systems, system_ids, world);
             if let Err(payload) = res {
                 let panic_payload = self.panic_payload.get_mut().unwrap();
                 *panic_payload = Some(payload);
@@ -293,6 +329,22 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
         self.tick_executor();
     }
 
+    /// Runs `f`, recording it as a span of the system at `system_index` if the executor is tracing.
+    fn traced<R>(&self, system_index: usize, f: impl FnOnce() -> R) -> R {
+        if !self.environment.trace {
+            return f();
+        }
+        let name = self.environment.executor.system_names[system_index].clone();
+        let (result, span) = RawSystemSpan::measure(name, f);
+        self.environment
+            .executor
+            .trace_spans
+            .lock()
+            .unwrap()
+            .push(span);
+        result
+    }
+
     fn try_lock<'a>(&'a self) -> Option<(&'a mut Conditions<'sys>, MutexGuard<'a, ExecutorState>)> {
         let guard = self.environment.executor.state.try_lock().ok()?;
         // SAFETY: This is an exclusive access as no other location fetches conditions mutably, and
@@ -330,6 +382,8 @@ impl MultiThreadedExecutor {
             state: Mutex::new(ExecutorState::new()),
             system_completion: ConcurrentQueue::unbounded(),
             starting_systems: FixedBitSet::new(),
+            system_names: Vec::new(),
+            trace_spans: Mutex::new(Vec::new()),
             apply_final_deferred: true,
             panic_payload: Mutex::new(None),
             #[cfg(feature = "trace")]
@@ -602,25 +656,28 @@ impl ExecutorState {
         let system_meta = &self.system_task_metadata[system_index];
 
         let task = async move {
-            let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
-                // SAFETY:
-                // - The caller ensures that we have permission to
-                // access the world data used by the system.
-                // - `update_archetype_component_access` has been called.
-                unsafe {
-                    // TODO: implement an error-handling API instead of panicking.
-                    if let Err(err) = __rust_begin_short_backtrace::run_unsafe(
-                        system,
-                        context.environment.world_cell,
-                    ) {
-                        panic!(
-                            "Encountered an error in system `{}`: {:?}",
-                            &*system.name(),
-                            err
-                        );
+            let res = context.traced(system_index, || {
+                std::panic::catch_unwind(AssertUnwindSafe(|| {
+                    // SAFETY:
+                    // - The caller ensures that we have permission to
+                    // access the world data used by the system.
+                    // - `update_archetype_component_access` has been called.
+                    unsafe {
+                        if let Err(err) = __rust_begin_short_backtrace::run_unsafe(
+                            system,
+                            context.environment.world_cell,
+                        ) {
+                            (context.environment.error_handler)(
+                                err,
+                                SystemErrorContext {
+                                    name: system.name(),
+                                    last_run: system.get_last_run(),
+                                },
+                            );
+                        };
                     };
-                };
-            }));
+                }))
+            });
             context.system_completed(system_index, res, system);
         };
 
@@ -651,7 +708,14 @@ impl ExecutorState {
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
-                let res = apply_deferred(&unapplied_systems, context.environment.systems, world);
+                let res = context.traced(system_index, || {
+                    apply_deferred(
+                        &unapplied_systems,
+                        context.environment.systems,
+                        context.environment.system_ids,
+                        world,
+                    )
+                });
                 context.system_completed(system_index, res, system);
             };
 
@@ -661,16 +725,24 @@ impl ExecutorState {
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
-                let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
-                    // TODO: implement an error-handling API instead of panicking.
-                    if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
-                        panic!(
-                            "Encountered an error in system `{}`: {:?}",
-                            &*system.name(),
-                            err
-                        );
-                    };
-                }));
+                let res = context.traced(system_index, || {
+                    std::panic::catch_unwind(AssertUnwindSafe(|| {
+                        // `run` also applies the buffers of the system
+                        let node = context.environment.system_ids[system_index];
+                        let result = apply_buffers_of(world, node, |world| {
+                            __rust_begin_short_backtrace::run(system, world)
+                        });
+                        if let Err(err) = result {
+                            (context.environment.error_handler)(
+                                err,
+                                SystemErrorContext {
+                                    name: system.name(),
+                                    last_run: system.get_last_run(),
+                                },
+                            );
+                        };
+                    }))
+                });
                 context.system_completed(system_index, res, system);
             };
 
@@ -730,13 +802,27 @@ impl ExecutorState {
 fn apply_deferred(
     unapplied_systems: &FixedBitSet,
     systems: &[SyncUnsafeCell<ScheduleSystem>],
+    system_ids: &[NodeId],
+    world: &mut World,
+) -> Result<(), Box<dyn Any + Send>> {
+    measure_sync_point(world, |world| {
+        apply_deferred_unmeasured(unapplied_systems, systems, system_ids, world)
+    })
+}
+
+fn apply_deferred_unmeasured(
+    unapplied_systems: &FixedBitSet,
+    systems: &[SyncUnsafeCell<ScheduleSystem>],
+    system_ids: &[NodeId],
     world: &mut World,
 ) -> Result<(), Box<dyn Any + Send>> {
     for system_index in unapplied_systems.ones() {
         // SAFETY: none of these systems are running, no other references exist
         let system = unsafe { &mut *systems[system_index].get() };
         let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
-            system.apply_deferred(world);
+            apply_buffers_of(world, system_ids[system_index], |world| {
+                system.apply_deferred(world);
+            });
         }));
         if let Err(payload) = res {
             eprintln!(
@@ -8,8 +8,10 @@ use tracing::info_span;
 use std::eprintln;
 
 use crate::{
+    result::{Error, SystemErrorContext},
     schedule::{
-        executor::is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule,
+        executor::{apply_buffers_of, is_apply_deferred},
+        BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule,
     },
     world::World,
 };
@@ -43,6 +45,7 @@ impl SystemExecutor for SimpleExecutor {
         schedule: &mut SystemSchedule,
         world: &mut World,
         _skip_systems: Option<&FixedBitSet>,
+        error_handler: fn(Error, SystemErrorContext),
     ) {
         // If stepping is enabled, make sure we skip those systems that should
         // not be run.
@@ -83,6 +86,7 @@ impl SystemExecutor for SimpleExecutor {
 
             should_run &= system_conditions_met;
 
+            let node = schedule.system_ids[system_index];
             let system = &mut schedule.systems[system_index];
             if should_run {
                 let valid_params = system.validate_param(world);
@@ -104,12 +108,17 @@ impl SystemExecutor for SimpleExecutor {
             }
 
             let f = AssertUnwindSafe(|| {
-                // TODO: implement an error-handling API instead of panicking.
-                if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
-                    panic!(
-                        "Encountered an error in system `{}`: {:?}",
-                        &*system.name(),
-                        err
+                // `run` also applies the buffers of the system
+                let result = apply_buffers_of(world, node, |world| {
+                    __rust_begin_short_backtrace::run(system, world)
+                });
+                if let Err(err) = result {
+                    error_handler(
+                        err,
+                        SystemErrorContext {
+                            name: system.name(),
+                            last_run: system.get_last_run(),
+                        },
                     );
                 }
             });
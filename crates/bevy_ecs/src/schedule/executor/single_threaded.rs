@@ -8,7 +8,11 @@ use tracing::info_span;
 use std::eprintln;
 
 use crate::{
-    schedule::{is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
+    result::{Error, SystemErrorContext},
+    schedule::{
+        executor::apply_buffers_of, is_apply_deferred, sync_points::measure_sync_point,
+        BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule,
+    },
     world::World,
 };
 
@@ -49,6 +53,7 @@ impl SystemExecutor for SingleThreadedExecutor {
         schedule: &mut SystemSchedule,
         world: &mut World,
         _skip_systems: Option<&FixedBitSet>,
+        error_handler: fn(Error, SystemErrorContext),
     ) {
         // If stepping is enabled, make sure we skip those systems that should
         // not be run.
@@ -89,6 +94,7 @@ impl SystemExecutor for SingleThreadedExecutor {
 
             should_run &= system_conditions_met;
 
+            let node = schedule.system_ids[system_index];
             let system = &mut schedule.systems[system_index];
             if should_run {
                 let valid_params = system.validate_param(world);
@@ -112,12 +118,17 @@ impl SystemExecutor for SingleThreadedExecutor {
 
             let f = AssertUnwindSafe(|| {
                 if system.is_exclusive() {
-                    // TODO: implement an error-handling API instead of panicking.
-                    if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
-                        panic!(
-                            "Encountered an error in system `{}`: {:?}",
-                            &*system.name(),
-                            err
+                    // `run` also applies the buffers of the system
+                    let result = apply_buffers_of(world, node, |world| {
+                        __rust_begin_short_backtrace::run(system, world)
+                    });
+                    if let Err(err) = result {
+                        error_handler(
+                            err,
+                            SystemErrorContext {
+                                name: system.name(),
+                                last_run: system.get_last_run(),
+                            },
                         );
                     }
                 } else {
@@ -127,12 +138,13 @@ impl SystemExecutor for SingleThreadedExecutor {
                     // SAFETY: We have exclusive, single-threaded access to the world and
                     // update_archetype_component_access is being called immediately before this.
                     unsafe {
-                        // TODO: implement an error-handling API instead of panicking.
                         if let Err(err) = __rust_begin_short_backtrace::run_unsafe(system, world) {
-                            panic!(
-                                "Encountered an error in system `{}`: {:?}",
-                                &*system.name(),
-                                err
+                            error_handler(
+                                err,
+                                SystemErrorContext {
+                                    name: system.name(),
+                                    last_run: system.get_last_run(),
+                                },
                             );
                         }
                     };
@@ -181,10 +193,14 @@ impl SingleThreadedExecutor {
     }
 
     fn apply_deferred(&mut self, schedule: &mut SystemSchedule, world: &mut World) {
-        for system_index in self.unapplied_systems.ones() {
-            let system = &mut schedule.systems[system_index];
-            system.apply_deferred(world);
-        }
+        measure_sync_point(world, |world| {
+            for system_index in self.unapplied_systems.ones() {
+                let system = &mut schedule.systems[system_index];
+                apply_buffers_of(world, schedule.system_ids[system_index], |world| {
+                    system.apply_deferred(world);
+                });
+            }
+        });
 
         self.unapplied_systems.clear();
     }
@@ -0,0 +1,326 @@
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::Write;
+
+use crate::schedule::NodeId;
+
+/// A snapshot of the graph of a [`Schedule`](super::Schedule), which can be rendered
+/// as [DOT](Self::to_dot) or [JSON](Self::to_json) to inspect the order of its systems offline.
+///
+/// Created by [`Schedule::export_graph`](super::Schedule::export_graph).
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+/// struct Update;
+///
+/// #[derive(Resource)]
+/// struct Score(u32);
+///
+/// fn add_score(mut score: ResMut<Score>) {
+///     score.0 += 1;
+/// }
+///
+/// fn double_score(mut score: ResMut<Score>) {
+///     score.0 *= 2;
+/// }
+///
+/// let mut world = World::new();
+/// let mut schedule = Schedule::new(Update);
+/// schedule.add_systems((add_score, double_score));
+/// schedule.initialize(&mut world).unwrap();
+///
+/// let export = schedule.export_graph(world.components());
+/// // The two systems are not ordered, but both mutate `Score`.
+/// assert_eq!(export.conflicts.len(), 1);
+/// assert!(export.conflicts[0].components[0].ends_with("Score"));
+/// assert!(export.to_dot().starts_with("digraph"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScheduleGraphExport {
+    /// The label of the schedule.
+    pub label: String,
+    /// The systems and system sets of the schedule, systems first.
+    pub nodes: Vec<ExportedNode>,
+    /// The edges of the [hierarchy](super::ScheduleGraph::hierarchy), from each set to its members.
+    pub hierarchy: Vec<(NodeId, NodeId)>,
+    /// The edges of the [dependency graph](super::ScheduleGraph::dependency),
+    /// from each system or set to the ones that run after it.
+    ///
+    /// Once the schedule is built, this includes the edges to the automatically inserted sync points.
+    pub dependencies: Vec<(NodeId, NodeId)>,
+    /// The systems in the topological order used by the executor,
+    /// or empty if the schedule has not been built yet.
+    pub order: Vec<NodeId>,
+    /// Pairs of systems whose order is ambiguous while their data access conflicts.
+    pub conflicts: Vec<ExportedConflict>,
+}
+
+/// A system or system set in a [`ScheduleGraphExport`].
+#[derive(Debug, Clone)]
+pub struct ExportedNode {
+    /// The id of the node in the [`ScheduleGraph`](super::ScheduleGraph).
+    pub id: NodeId,
+    /// The name of the system or system set.
+    pub name: String,
+    /// The number of run conditions of the system or system set.
+    pub conditions: usize,
+}
+
+/// Two systems with conflicting data access and an ambiguous order, in a [`ScheduleGraphExport`].
+#[derive(Debug, Clone)]
+pub struct ExportedConflict {
+    /// The two conflicting systems.
+    pub systems: (NodeId, NodeId),
+    /// The names of the components and resources both systems access, at least one of them mutably.
+    ///
+    /// This is empty if one of the systems conflicts with any access, such as an exclusive system.
+    pub components: Vec<String>,
+}
+
+impl ScheduleGraphExport {
+    /// Renders the graph in the [DOT](https://graphviz.org/doc/info/lang.html) language of Graphviz.
+    ///
+    /// Systems are drawn as boxes and system sets as dashed ellipses.
+    /// Dependencies are drawn as solid arrows, set membership as dashed gray arrows,
+    /// and conflicts as dotted red lines labeled with the conflicting data.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph ");
+        write_quoted(&mut dot, &self.label);
+        dot.push_str(" {\n");
+        for node in &self.nodes {
+            dot.push_str("    ");
+            write_quoted(&mut dot, &node_key(node.id));
+            dot.push_str(" [label=");
+            write_quoted(&mut dot, &node.name);
+            if node.id.is_set() {
+                dot.push_str(", shape=ellipse, style=dashed");
+            } else {
+                dot.push_str(", shape=box");
+            }
+            dot.push_str("];\n");
+        }
+        for (set, member) in &self.hierarchy {
+            write_dot_edge(&mut dot, *set, *member);
+            dot.push_str(" [style=dashed, color=gray];\n");
+        }
+        for (before, after) in &self.dependencies {
+            write_dot_edge(&mut dot, *before, *after);
+            dot.push_str(";\n");
+        }
+        for conflict in &self.conflicts {
+            write_dot_edge(&mut dot, conflict.systems.0, conflict.systems.1);
+            dot.push_str(" [dir=none, style=dotted, color=red, constraint=false, label=");
+            write_quoted(&mut dot, &conflict.components.join(", "));
+            dot.push_str("];\n");
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders the graph as a JSON object.
+    ///
+    /// Nodes are identified by keys such as `"system-3"` and `"set-1"`:
+    ///
+    /// ```json
+    /// {
+    ///   "label": "Update",
+    ///   "nodes": [{ "id": "system-0", "kind": "system", "name": "add_score", "conditions": 0 }],
+    ///   "hierarchy": [["set-0", "system-0"]],
+    ///   "dependencies": [["system-0", "system-1"]],
+    ///   "order": ["system-0", "system-1"],
+    ///   "conflicts": [{ "systems": ["system-0", "system-1"], "components": ["Score"] }]
+    /// }
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\"label\":");
+        write_json_string(&mut json, &self.label);
+        json.push_str(",\"nodes\":[");
+        for (i, node) in self.nodes.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"id\":");
+            write_json_string(&mut json, &node_key(node.id));
+            json.push_str(",\"kind\":");
+            json.push_str(if node.id.is_set() {
+                "\"set\""
+            } else {
+                "\"system\""
+            });
+            json.push_str(",\"name\":");
+            write_json_string(&mut json, &node.name);
+            let _ = write!(json, ",\"conditions\":{}}}", node.conditions);
+        }
+        json.push_str("],\"hierarchy\":");
+        write_json_edges(&mut json, &self.hierarchy);
+        json.push_str(",\"dependencies\":");
+        write_json_edges(&mut json, &self.dependencies);
+        json.push_str(",\"order\":[");
+        for (i, id) in self.order.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            write_json_string(&mut json, &node_key(*id));
+        }
+        json.push_str("],\"conflicts\":[");
+        for (i, conflict) in self.conflicts.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"systems\":");
+            write_json_pair(&mut json, conflict.systems);
+            json.push_str(",\"components\":[");
+            for (j, component) in conflict.components.iter().enumerate() {
+                if j > 0 {
+                    json.push(',');
+                }
+                write_json_string(&mut json, component);
+            }
+            json.push_str("]}");
+        }
+        json.push_str("]}");
+        json
+    }
+}
+
+fn node_key(id: NodeId) -> String {
+    match id {
+        NodeId::System(index) => format!("system-{index}"),
+        NodeId::Set(index) => format!("set-{index}"),
+    }
+}
+
+fn write_quoted(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        if matches!(c, '"' | '\\') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out.push('"');
+}
+
+fn write_dot_edge(dot: &mut String, from: NodeId, to: NodeId) {
+    dot.push_str("    ");
+    write_quoted(dot, &node_key(from));
+    dot.push_str(" -> ");
+    write_quoted(dot, &node_key(to));
+}
+
+fn write_json_edges(json: &mut String, edges: &[(NodeId, NodeId)]) {
+    json.push('[');
+    for (i, &edge) in edges.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        write_json_pair(json, edge);
+    }
+    json.push(']');
+}
+
+fn write_json_pair(json: &mut String, (a, b): (NodeId, NodeId)) {
+    json.push('[');
+    write_json_string(json, &node_key(a));
+    json.push(',');
+    write_json_string(json, &node_key(b));
+    json.push(']');
+}
+
+/// Writes `value` to `out` as a JSON string literal.
+pub(super) fn write_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        prelude::*,
+        schedule::{NodeId, ScheduleLabel},
+    };
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Update;
+
+    #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Physics;
+
+    #[derive(Resource)]
+    struct Counter;
+
+    fn a(_: ResMut<Counter>) {}
+    fn b() {}
+    fn c(_: ResMut<Counter>) {}
+
+    #[test]
+    fn exports_nodes_edges_and_conflicts() {
+        let mut world = World::new();
+        let mut schedule = Schedule::new(Update);
+        schedule.add_systems(((a, b).chain().in_set(Physics), c));
+
+        // Before the schedule is built, only the nodes and edges are known.
+        let export = schedule.export_graph(world.components());
+        assert!(export.order.is_empty());
+        assert!(export.conflicts.is_empty());
+
+        schedule.initialize(&mut world).unwrap();
+        let export = schedule.export_graph(world.components());
+        let id = |name: &str| {
+            export
+                .nodes
+                .iter()
+                .find(|node| node.name == name)
+                .unwrap()
+                .id
+        };
+        let (a, b, c, physics) = (id("a"), id("b"), id("c"), id("Physics"));
+        assert!(a.is_system() && physics.is_set());
+
+        assert!(export.hierarchy.contains(&(physics, a)));
+        assert!(export.hierarchy.contains(&(physics, b)));
+        assert!(export.dependencies.contains(&(a, b)));
+        assert_eq!(export.order.len(), 3);
+        assert!(
+            export.order.iter().position(|id| *id == a)
+                < export.order.iter().position(|id| *id == b)
+        );
+
+        assert_eq!(export.conflicts.len(), 1);
+        let conflict = &export.conflicts[0];
+        assert!(conflict.systems == (a, c) || conflict.systems == (c, a));
+        assert!(conflict.components[0].ends_with("Counter"));
+
+        let key = |id: NodeId| match id {
+            NodeId::System(index) => alloc::format!("system-{index}"),
+            NodeId::Set(index) => alloc::format!("set-{index}"),
+        };
+        let dot = export.to_dot();
+        assert!(dot.contains(&alloc::format!("\"{}\" -> \"{}\";", key(a), key(b))));
+        let json = export.to_json();
+        assert!(json.starts_with("{\"label\":\"Update\""));
+        assert!(json.contains(&alloc::format!("[\"{}\",\"{}\"]", key(a), key(b))));
+    }
+
+    #[test]
+    fn escapes_json_strings() {
+        let mut json = alloc::string::String::new();
+        super::write_json_string(&mut json, "a\"b\\c\nd\u{1}");
+        assert_eq!(json, "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+}
@@ -3,15 +3,23 @@
 mod condition;
 mod config;
 mod executor;
+mod export;
 mod graph;
 mod schedule;
 mod set;
 mod stepping;
+mod sync_points;
+#[cfg(feature = "std")]
+mod trace;
 
 use self::graph::*;
-pub use self::{condition::*, config::*, executor::*, schedule::*, set::*};
+pub use self::{
+    condition::*, config::*, executor::*, export::*, schedule::*, set::*, sync_points::*,
+};
 
 pub use self::graph::NodeId;
+#[cfg(feature = "std")]
+pub use self::trace::{ExecutorTrace, SystemSpan};
 
 #[cfg(test)]
 mod tests {
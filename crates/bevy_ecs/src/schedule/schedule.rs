@@ -21,9 +21,10 @@ use tracing::info_span;
 
 use crate::{
     self as bevy_ecs,
+    change_detection::DetectChangesMut,
     component::{ComponentId, Components, Tick},
     prelude::Component,
-    result::Result,
+    result::{self, DefaultSystemErrorHandler, Error, Result, SystemErrorContext},
     schedule::*,
     system::{IntoSystem, Resource, ScheduleSystem},
     world::World,
@@ -277,6 +278,7 @@ pub struct Schedule {
     executable: SystemSchedule,
     executor: Box<dyn SystemExecutor>,
     executor_initialized: bool,
+    error_handler: Option<fn(Error, SystemErrorContext)>,
 }
 
 #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
@@ -301,6 +303,7 @@ impl Schedule {
             executable: SystemSchedule::new(),
             executor: make_executor(ExecutorKind::default()),
             executor_initialized: false,
+            error_handler: None,
         }
     }
 
@@ -387,6 +390,15 @@ impl Schedule {
         self
     }
 
+    /// Sets the error handler used when a system in this schedule returns an [`Err`].
+    ///
+    /// If no handler is set, the handler stored in the world's [`DefaultSystemErrorHandler`]
+    /// resource is used instead. See the [`result`](crate::result) module for the built-in handlers.
+    pub fn set_error_handler(&mut self, error_handler: fn(Error, SystemErrorContext)) -> &mut Self {
+        self.error_handler = Some(error_handler);
+        self
+    }
+
     /// Runs all systems in this schedule on the `world`, using its current execution strategy.
     pub fn run(&mut self, world: &mut World) {
         #[cfg(feature = "trace")]
@@ -396,18 +408,57 @@ impl Schedule {
         self.initialize(world)
             .unwrap_or_else(|e| panic!("Error when initializing schedule {:?}: {e}", self.label));
 
+        let error_handler = self.error_handler.unwrap_or_else(|| {
+            world
+                .get_resource::<DefaultSystemErrorHandler>()
+                .map_or(result::panic, |handler| handler.0)
+        });
+
+        if let Some(mut stats) = world.get_resource_mut::<SyncPointStats>() {
+            stats.bypass_change_detection().begin_schedule(self.label);
+        }
+        #[cfg(feature = "std")]
+        if let Some(mut trace) = world.get_resource_mut::<ExecutorTrace>() {
+            trace.bypass_change_detection().begin_schedule(self.label);
+        }
+
         #[cfg(not(feature = "bevy_debug_stepping"))]
-        self.executor.run(&mut self.executable, world, None);
+        self.executor
+            .run(&mut self.executable, world, None, error_handler);
 
         #[cfg(feature = "bevy_debug_stepping")]
         {
-            let skip_systems = match world.get_resource_mut::<Stepping>() {
-                None => None,
-                Some(mut stepping) => stepping.skipped_systems(self),
+            let (skip_systems, previous_schedule) = match world.get_resource_mut::<Stepping>() {
+                None => (None, None),
+                Some(mut stepping) => {
+                    let skip_systems = stepping.skipped_systems(self);
+                    let previous_schedule = stepping
+                        .bypass_change_detection()
+                        .begin_schedule(self.label);
+                    (skip_systems, previous_schedule)
+                }
             };
 
-            self.executor
-                .run(&mut self.executable, world, skip_systems.as_ref());
+            self.executor.run(
+                &mut self.executable,
+                world,
+                skip_systems.as_ref(),
+                error_handler,
+            );
+
+            if let Some(mut stepping) = world.get_resource_mut::<Stepping>() {
+                stepping
+                    .bypass_change_detection()
+                    .end_schedule(previous_schedule);
+            }
+        }
+
+        if let Some(mut stats) = world.get_resource_mut::<SyncPointStats>() {
+            stats.bypass_change_detection().end_schedule(self.label);
+        }
+        #[cfg(feature = "std")]
+        if let Some(mut trace) = world.get_resource_mut::<ExecutorTrace>() {
+            trace.bypass_change_detection().end_schedule(self.label);
         }
     }
 
@@ -522,6 +573,78 @@ impl Schedule {
             self.executable.systems.len()
         }
     }
+
+    /// Exports the systems, system sets, dependencies and conflicts of this schedule,
+    /// to be rendered as DOT or JSON.
+    ///
+    /// Conflicts and the execution order are only known once the schedule has been
+    /// [initialized](Self::initialize) or run.
+    /// The `components` are used to name the data that conflicting systems access.
+    pub fn export_graph(&self, components: &Components) -> ScheduleGraphExport {
+        let graph = &self.graph;
+        // Initialized systems and conditions are moved out of the graph into the executable schedule.
+        let systems: HashMap<NodeId, (&ScheduleSystem, usize)> = graph
+            .systems()
+            .map(|(id, system, conditions)| (id, (system, conditions.len())))
+            .chain(
+                self.executable
+                    .system_ids
+                    .iter()
+                    .zip(&self.executable.systems)
+                    .zip(&self.executable.system_conditions)
+                    .map(|((id, system), conditions)| (*id, (system, conditions.len()))),
+            )
+            .collect();
+        let set_conditions: HashMap<NodeId, usize> = self
+            .executable
+            .set_ids
+            .iter()
+            .zip(&self.executable.set_conditions)
+            .map(|(id, conditions)| (*id, conditions.len()))
+            .collect();
+
+        let mut nodes: Vec<_> = systems
+            .iter()
+            .map(|(&id, &(_, conditions))| ExportedNode {
+                id,
+                name: graph.export_node_name(id, &systems),
+                conditions,
+            })
+            .chain(graph.system_sets().map(|(id, _, conditions)| ExportedNode {
+                id,
+                name: graph.export_node_name(id, &systems),
+                conditions: set_conditions.get(&id).copied().unwrap_or(conditions.len()),
+            }))
+            .collect();
+        nodes.sort_unstable_by_key(|node| node.id);
+
+        let mut hierarchy: Vec<_> = graph.hierarchy.graph.all_edges().collect();
+        hierarchy.sort_unstable();
+        let mut dependencies: Vec<_> = graph.dependency.graph.all_edges().collect();
+        dependencies.sort_unstable();
+
+        let conflicts = graph
+            .conflicting_systems
+            .iter()
+            .map(|(a, b, conflicts)| ExportedConflict {
+                systems: (*a, *b),
+                components: conflicts
+                    .iter()
+                    .filter_map(|id| components.get_name(*id))
+                    .map(ToString::to_string)
+                    .collect(),
+            })
+            .collect();
+
+        ScheduleGraphExport {
+            label: format!("{:?}", self.label),
+            nodes,
+            hierarchy,
+            dependencies,
+            order: self.executable.system_ids.clone(),
+            conflicts,
+        }
+    }
 }
 
 /// A directed acyclic graph structure.
@@ -1639,6 +1762,39 @@ impl ScheduleGraph {
         )
     }
 
+    /// Like [`get_node_name`](Self::get_node_name), but also works for systems that were
+    /// moved into the executable schedule, and never reports sets.
+    fn export_node_name(
+        &self,
+        id: NodeId,
+        systems: &HashMap<NodeId, (&ScheduleSystem, usize)>,
+    ) -> String {
+        let name = match id {
+            NodeId::System(_) => systems
+                .get(&id)
+                .map(|(system, _)| system.name().to_string())
+                .unwrap_or_default(),
+            NodeId::Set(_) => {
+                let set = &self.system_sets[id.index()];
+                if set.is_anonymous() {
+                    let members: Vec<_> = self
+                        .hierarchy
+                        .graph
+                        .edges_directed(id, Outgoing)
+                        .map(|(_, member)| self.export_node_name(member, systems))
+                        .collect();
+                    return format!("({})", members.join(", "));
+                }
+                set.name()
+            }
+        };
+        if self.settings.use_shortnames {
+            ShortName(&name).to_string()
+        } else {
+            name
+        }
+    }
+
     fn get_node_kind(&self, id: &NodeId) -> &'static str {
         match id {
             NodeId::System(_) => "system",
@@ -1,6 +1,7 @@
 use crate::{
     schedule::{InternedScheduleLabel, NodeId, Schedule, ScheduleLabel},
     system::{IntoSystem, ResMut, Resource},
+    world::{CommandQueue, World},
 };
 use alloc::vec::Vec;
 use bevy_utils::{HashMap, TypeIdMap};
@@ -85,6 +86,19 @@ enum Update {
     SetBehavior(InternedScheduleLabel, SystemIdentifier, SystemBehavior),
     /// Clear any system-specific behavior for this schedule & system
     ClearBehavior(InternedScheduleLabel, SystemIdentifier),
+    /// Enable or disable stepping through the commands of stepped systems
+    StepCommands(bool),
+}
+
+/// Pending commands to apply in [`Stepping::apply_pending_commands`]
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+enum ApplyCommands {
+    #[default]
+    None,
+    /// Apply the next pending command; this consumes the step
+    Next,
+    /// Apply all pending commands
+    All,
 }
 
 #[derive(Error, Debug)]
@@ -111,6 +125,27 @@ pub struct Stepping {
 
     // Updates apply at the start of the next render frame
     updates: Vec<Update>,
+
+    // hold the commands of stepped systems, and apply them one per step
+    step_commands: bool,
+
+    // schedule and node of the system stepped during this render frame
+    stepped_system: Option<(InternedScheduleLabel, NodeId)>,
+
+    // schedule that is currently running
+    #[cfg(feature = "bevy_debug_stepping")]
+    running_schedule: Option<InternedScheduleLabel>,
+
+    // node of the system whose buffers are currently applied in the running
+    // schedule
+    #[cfg(feature = "bevy_debug_stepping")]
+    applying_system: Option<NodeId>,
+
+    // commands queued by stepped systems that have not been applied yet
+    pending_commands: CommandQueue,
+
+    // pending commands to apply during this render frame
+    apply_commands: ApplyCommands,
 }
 
 impl core::fmt::Debug for Stepping {
@@ -148,6 +183,39 @@ impl Stepping {
         }
     }
 
+    /// System to call after [`Stepping::begin_frame`] to apply the commands
+    /// held back by [`Stepping::step_commands`].
+    ///
+    /// When stepping a frame, this applies the next pending command instead of
+    /// running the next system. When continuing or disabling stepping, all
+    /// pending commands are applied.
+    ///
+    /// Note: This system is automatically added to the default `MainSchedule`.
+    pub fn apply_pending_commands(world: &mut World) {
+        let Some(mut stepping) = world.get_resource_mut::<Self>() else {
+            return;
+        };
+        let apply = core::mem::take(&mut stepping.apply_commands);
+        if apply == ApplyCommands::None {
+            return;
+        }
+        let mut queue = core::mem::take(&mut stepping.pending_commands);
+        match apply {
+            ApplyCommands::Next => {
+                queue.apply_next(world);
+            }
+            ApplyCommands::All => queue.apply(world),
+            ApplyCommands::None => unreachable!(),
+        }
+
+        // keep any commands that were captured while applying, after the
+        // remaining ones
+        if let Some(mut stepping) = world.get_resource_mut::<Self>() {
+            queue.append(&mut stepping.pending_commands);
+            stepping.pending_commands = queue;
+        }
+    }
+
     /// Return the list of schedules with stepping enabled in the order
     /// they are executed in.
     pub fn schedules(&self) -> Result<&Vec<InternedScheduleLabel>, NotReady> {
@@ -237,6 +305,102 @@ impl Stepping {
         self
     }
 
+    /// Hold back the commands queued by each stepped system, and apply them
+    /// one at a time during the following steps, before stepping to the next
+    /// system.
+    ///
+    /// Observers triggered by a command run when that command is applied, so
+    /// every step pauses after one command and the observers it ran. The held
+    /// commands can be inspected using [`Stepping::pending_commands`].
+    ///
+    /// NOTE: Observers can't be stepped individually; all observers triggered
+    /// by a command run within the same step. Consecutive batched commands
+    /// (see [`Commands::queue_batched`](crate::system::Commands::queue_batched)) are also applied within one step.
+    ///
+    /// NOTE: This will have no impact unless stepping has been enabled
+    pub fn step_commands(&mut self, enabled: bool) -> &mut Self {
+        self.updates.push(Update::StepCommands(enabled));
+        self
+    }
+
+    /// Check if the commands of stepped systems are stepped
+    pub fn is_stepping_commands(&self) -> bool {
+        self.step_commands
+    }
+
+    /// Return the commands held back by [`Stepping::step_commands`] that will
+    /// be applied during the next steps.
+    ///
+    /// With the `bevy_debug_stepping` feature, the queued commands can be
+    /// listed using [`CommandQueue::command_names`].
+    pub fn pending_commands(&self) -> &CommandQueue {
+        &self.pending_commands
+    }
+
+    /// Hold back the commands of the system that is being stepped, if its
+    /// buffers are being applied.
+    ///
+    /// Returns `true` if the commands were moved out of `queue`.
+    #[cfg(feature = "bevy_debug_stepping")]
+    pub(crate) fn capture_commands(&mut self, queue: &mut CommandQueue) -> bool {
+        let Some((schedule, node)) = self.stepped_system else {
+            return false;
+        };
+        if !self.step_commands
+            || self.running_schedule != Some(schedule)
+            || self.applying_system != Some(node)
+        {
+            return false;
+        }
+        self.pending_commands.append(queue);
+        true
+    }
+
+    /// Record that the schedule with the given label started running.
+    ///
+    /// Returns the schedule that was running before, to pass to
+    /// [`Stepping::end_schedule`].
+    #[cfg(feature = "bevy_debug_stepping")]
+    pub(crate) fn begin_schedule(
+        &mut self,
+        label: InternedScheduleLabel,
+    ) -> Option<InternedScheduleLabel> {
+        self.running_schedule.replace(label)
+    }
+
+    /// Record that the running schedule finished, resuming the `previous`
+    /// schedule returned by [`Stepping::begin_schedule`].
+    #[cfg(feature = "bevy_debug_stepping")]
+    pub(crate) fn end_schedule(&mut self, previous: Option<InternedScheduleLabel>) {
+        self.running_schedule = previous;
+    }
+
+    /// Apply the buffers of the system with the given node in the running
+    /// schedule with `apply`, so that the commands of the stepped system can be
+    /// held back.
+    #[cfg(feature = "bevy_debug_stepping")]
+    pub(crate) fn apply_system_buffers<R>(
+        world: &mut World,
+        node: NodeId,
+        apply: impl FnOnce(&mut World) -> R,
+    ) -> R {
+        use crate::change_detection::DetectChangesMut;
+
+        let Some(previous) = world.get_resource_mut::<Self>().map(|mut stepping| {
+            stepping
+                .bypass_change_detection()
+                .applying_system
+                .replace(node)
+        }) else {
+            return apply(world);
+        };
+        let result = apply(world);
+        if let Some(mut stepping) = world.get_resource_mut::<Self>() {
+            stepping.bypass_change_detection().applying_system = previous;
+        }
+        result
+    }
+
     /// Ensure this system always runs when stepping is enabled
     ///
     /// Note: if the system is run multiple times in the [`Schedule`], this
@@ -386,6 +550,8 @@ impl Stepping {
 
     /// Advance schedule states for the next render frame
     fn next_frame(&mut self) {
+        self.stepped_system = None;
+
         // if stepping is enabled; reset our internal state for the start of
         // the next frame
         if self.action != Action::RunAll {
@@ -398,10 +564,25 @@ impl Stepping {
             }
         }
 
-        if self.updates.is_empty() {
-            return;
+        if !self.updates.is_empty() {
+            self.apply_updates();
         }
 
+        // a step applies the next pending command before running any more
+        // systems
+        self.apply_commands = match self.action {
+            _ if self.pending_commands.is_empty() => ApplyCommands::None,
+            Action::Waiting => ApplyCommands::None,
+            Action::Step if self.step_commands => {
+                self.action = Action::Waiting;
+                ApplyCommands::Next
+            }
+            Action::Step | Action::Continue | Action::RunAll => ApplyCommands::All,
+        };
+    }
+
+    /// Apply the updates made since the last render frame
+    fn apply_updates(&mut self) {
         let mut reset_cursor = false;
         for update in self.updates.drain(..) {
             match update {
@@ -505,6 +686,7 @@ impl Stepping {
                         }
                     }
                 }
+                Update::StepCommands(enabled) => self.step_commands = enabled,
             }
         }
 
@@ -566,6 +748,24 @@ impl Stepping {
             // to be waiting
             if self.action == Action::Step {
                 self.action = Action::Waiting;
+
+                // remember which system is stepped, so its commands can be held
+                // back
+                self.stepped_system = schedule
+                    .systems()
+                    .ok()
+                    .into_iter()
+                    .flatten()
+                    .enumerate()
+                    .find(|(i, _)| {
+                        *i >= cursor.system
+                            && !skip_list.contains(*i)
+                            && !matches!(
+                                state.behaviors.get(&state.node_ids[*i]),
+                                Some(SystemBehavior::AlwaysRun)
+                            )
+                    })
+                    .map(|(_, (node, _))| (label, node));
             }
             (skip_list, next_system)
         } else {
@@ -825,7 +1025,10 @@ impl ScheduleState {
 #[cfg(all(test, feature = "bevy_debug_stepping"))]
 mod tests {
     use super::*;
-    use crate::{prelude::*, schedule::ScheduleLabel};
+    use crate::{
+        prelude::*,
+        schedule::{ExecutorKind, ScheduleLabel},
+    };
     use alloc::{format, vec};
     use std::println;
 
@@ -1414,6 +1617,112 @@ mod tests {
         assert!(stepping.schedules().unwrap().is_empty());
     }
 
+    #[test]
+    fn step_commands() {
+        #[derive(Resource, Default)]
+        struct Log(Vec<&'static str>);
+
+        #[derive(Event)]
+        struct Ping;
+
+        let mut world = World::new();
+        world.init_resource::<Log>();
+        world.add_observer(|_: Trigger<Ping>, mut log: ResMut<Log>| log.0.push("observer"));
+
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule
+            .set_executor_kind(ExecutorKind::SingleThreaded)
+            .add_systems(
+                (
+                    |mut commands: Commands| {
+                        commands.queue(|world: &mut World| {
+                            world.resource_mut::<Log>().0.push("command");
+                        });
+                        commands.trigger(Ping);
+                    },
+                    |mut log: ResMut<Log>| log.0.push("system"),
+                )
+                    .chain_ignore_deferred(),
+            );
+
+        let mut stepping = Stepping::new();
+        stepping
+            .add_schedule(TestSchedule)
+            .enable()
+            .step_commands(true);
+        world.insert_resource(stepping);
+
+        let mut step = |world: &mut World| {
+            let mut stepping = world.resource_mut::<Stepping>();
+            stepping.step_frame().next_frame();
+            Stepping::apply_pending_commands(world);
+            schedule.run(world);
+            (
+                world.resource::<Log>().0.clone(),
+                world
+                    .resource::<Stepping>()
+                    .pending_commands()
+                    .command_names()
+                    .count(),
+            )
+        };
+
+        // the first system runs, and its commands are held back
+        assert_eq!(step(&mut world), (vec![], 2));
+        // each step applies a single command, and the observers it triggers
+        assert_eq!(step(&mut world), (vec!["command"], 1));
+        assert_eq!(step(&mut world), (vec!["command", "observer"], 0));
+        // then the next system runs
+        assert_eq!(step(&mut world), (vec!["command", "observer", "system"], 0));
+    }
+
+    #[test]
+    fn step_commands_of_stepped_instance_only() {
+        #[derive(Resource, Default)]
+        struct Log(Vec<&'static str>);
+
+        fn log_command(mut commands: Commands) {
+            commands.queue(|world: &mut World| {
+                world.resource_mut::<Log>().0.push("command");
+            });
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Log>();
+
+        let mut schedule_a = Schedule::new(TestScheduleA);
+        schedule_a
+            .set_executor_kind(ExecutorKind::SingleThreaded)
+            .add_systems(log_command);
+        // the same system in a schedule that isn't stepped
+        let mut schedule_b = Schedule::new(TestScheduleB);
+        schedule_b
+            .set_executor_kind(ExecutorKind::SingleThreaded)
+            .add_systems(log_command);
+
+        let mut stepping = Stepping::new();
+        stepping
+            .add_schedule(TestScheduleA)
+            .enable()
+            .step_commands(true);
+        world.insert_resource(stepping);
+        world.resource_mut::<Stepping>().step_frame().next_frame();
+
+        schedule_a.run(&mut world);
+        schedule_b.run(&mut world);
+
+        // only the commands of the stepped system are held back
+        assert_eq!(world.resource::<Log>().0, vec!["command"]);
+        assert_eq!(
+            world
+                .resource::<Stepping>()
+                .pending_commands()
+                .command_names()
+                .count(),
+            1
+        );
+    }
+
     // verify that Stepping can construct an ordered list of schedules
     #[test]
     fn schedules() {
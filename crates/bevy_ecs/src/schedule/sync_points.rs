@@ -0,0 +1,214 @@
+use alloc::vec::Vec;
+use bevy_utils::HashMap;
+use core::time::Duration;
+
+use crate::{
+    self as bevy_ecs,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+    system::Resource,
+    world::World,
+};
+
+/// Resource that records how long the sync points of each [`Schedule`](super::Schedule) take.
+///
+/// A sync point is where the deferred buffers of systems, such as their [`Commands`](crate::system::Commands),
+/// are applied to the world: every [`ApplyDeferred`](super::ApplyDeferred) system, including the ones
+/// inserted automatically, and the final application at the end of a schedule run.
+///
+/// Timings are only recorded while this resource exists, so insert it to opt in.
+/// Only the [`SingleThreaded`](super::ExecutorKind::SingleThreaded) and
+/// [`MultiThreaded`](super::ExecutorKind::MultiThreaded) executors record sync points:
+/// the [`Simple`](super::ExecutorKind::Simple) executor applies buffers after every system instead.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::{ScheduleLabel, SyncPointStats};
+/// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+/// struct Update;
+///
+/// fn spawn_things(mut commands: Commands) {
+///     commands.spawn_batch((0..100).map(|_| ()));
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<SyncPointStats>();
+///
+/// let mut schedule = Schedule::new(Update);
+/// schedule.add_systems(spawn_things);
+/// schedule.run(&mut world);
+///
+/// let stats = world.resource::<SyncPointStats>();
+/// let update = stats.get(Update).unwrap();
+/// // The commands were applied at the end of the schedule.
+/// assert_eq!(update.last_run().len(), 1);
+/// assert_eq!(update.count(), 1);
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct SyncPointStats {
+    schedules: HashMap<InternedScheduleLabel, ScheduleSyncPoints>,
+    /// The schedules that are currently running, innermost last.
+    running: Vec<InternedScheduleLabel>,
+}
+
+/// Sync point timings of a single [`Schedule`](super::Schedule), stored in [`SyncPointStats`].
+#[derive(Default, Debug, Clone)]
+pub struct ScheduleSyncPoints {
+    last_run: Vec<Duration>,
+    elapsed: Duration,
+    count: u64,
+}
+
+impl ScheduleSyncPoints {
+    /// Returns the duration of each sync point during the most recent run of the schedule, in the order they ran.
+    pub fn last_run(&self) -> &[Duration] {
+        &self.last_run
+    }
+
+    /// Returns the total time spent in sync points since the last [`SyncPointStats::reset`].
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the number of sync points that ran since the last [`SyncPointStats::reset`].
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+impl SyncPointStats {
+    /// Returns the sync point timings of the schedule with the given `label`,
+    /// if it has run since this resource was added.
+    pub fn get(&self, label: impl ScheduleLabel) -> Option<&ScheduleSyncPoints> {
+        self.schedules.get(&label.intern())
+    }
+
+    /// Returns an iterator over the sync point timings of all schedules that have run.
+    pub fn iter(&self) -> impl Iterator<Item = (&InternedScheduleLabel, &ScheduleSyncPoints)> {
+        self.schedules.iter()
+    }
+
+    /// Returns the total time spent in sync points of all schedules since the last [`reset`](Self::reset).
+    pub fn elapsed(&self) -> Duration {
+        self.schedules
+            .values()
+            .map(ScheduleSyncPoints::elapsed)
+            .sum()
+    }
+
+    /// Returns the number of sync points of all schedules that ran since the last [`reset`](Self::reset).
+    pub fn count(&self) -> u64 {
+        self.schedules.values().map(ScheduleSyncPoints::count).sum()
+    }
+
+    /// Resets the accumulated [`elapsed`](ScheduleSyncPoints::elapsed) time and
+    /// [`count`](ScheduleSyncPoints::count) of every schedule.
+    ///
+    /// The timings of the most recent run of each schedule are kept.
+    pub fn reset(&mut self) {
+        for schedule in self.schedules.values_mut() {
+            schedule.elapsed = Duration::ZERO;
+            schedule.count = 0;
+        }
+    }
+
+    pub(crate) fn begin_schedule(&mut self, label: InternedScheduleLabel) {
+        self.schedules.entry(label).or_default().last_run.clear();
+        self.running.push(label);
+    }
+
+    pub(crate) fn end_schedule(&mut self, label: InternedScheduleLabel) {
+        // A schedule that panicked may not have been ended, so search from the innermost schedule.
+        if let Some(index) = self.running.iter().rposition(|running| *running == label) {
+            self.running.truncate(index);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn record(&mut self, duration: Duration) {
+        let Some(label) = self.running.last() else {
+            return;
+        };
+        let schedule = self.schedules.entry(*label).or_default();
+        schedule.last_run.push(duration);
+        schedule.elapsed += duration;
+        schedule.count += 1;
+    }
+}
+
+/// Runs `apply` as a sync point of the innermost running schedule,
+/// recording its duration if the world contains [`SyncPointStats`].
+#[inline]
+pub(super) fn measure_sync_point<R>(world: &mut World, apply: impl FnOnce(&mut World) -> R) -> R {
+    #[cfg(feature = "std")]
+    if world.contains_resource::<SyncPointStats>() {
+        use crate::change_detection::DetectChangesMut;
+
+        let start = bevy_utils::Instant::now();
+        let result = apply(world);
+        let duration = start.elapsed();
+        // The resource may have been removed by the applied commands.
+        if let Some(mut stats) = world.get_resource_mut::<SyncPointStats>() {
+            stats.bypass_change_detection().record(duration);
+        }
+        return result;
+    }
+    apply(world)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::*,
+        schedule::{ApplyDeferred, ExecutorKind},
+    };
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Outer;
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Inner;
+
+    fn spawn(mut commands: Commands) {
+        commands.spawn_empty();
+    }
+
+    #[test]
+    fn records_sync_points_per_schedule() {
+        for executor in [ExecutorKind::SingleThreaded, ExecutorKind::MultiThreaded] {
+            let mut world = World::new();
+            world.init_resource::<SyncPointStats>();
+
+            let mut inner = Schedule::new(Inner);
+            inner.set_executor_kind(executor);
+            inner.add_systems(spawn);
+            world.add_schedule(inner);
+
+            let mut outer = Schedule::new(Outer);
+            outer.set_executor_kind(executor);
+            outer.add_systems(
+                (spawn, ApplyDeferred, |world: &mut World| {
+                    world.run_schedule(Inner);
+                })
+                    .chain(),
+            );
+
+            outer.run(&mut world);
+            outer.run(&mut world);
+
+            let stats = world.resource::<SyncPointStats>();
+            // The explicit `ApplyDeferred` and the final application.
+            assert_eq!(stats.get(Outer).unwrap().last_run().len(), 2);
+            assert_eq!(stats.get(Outer).unwrap().count(), 4);
+            assert_eq!(stats.get(Inner).unwrap().last_run().len(), 1);
+            assert_eq!(stats.get(Inner).unwrap().count(), 2);
+            assert_eq!(stats.count(), 6);
+
+            world.resource_mut::<SyncPointStats>().reset();
+            let stats = world.resource::<SyncPointStats>();
+            assert_eq!(stats.count(), 0);
+            assert_eq!(stats.elapsed(), Duration::ZERO);
+            assert_eq!(stats.get(Outer).unwrap().last_run().len(), 2);
+        }
+    }
+}
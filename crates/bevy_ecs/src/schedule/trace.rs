@@ -0,0 +1,227 @@
+use alloc::{borrow::Cow, format, string::String, vec::Vec};
+use bevy_utils::{HashMap, Instant};
+use core::{fmt::Write, time::Duration};
+use std::thread::ThreadId;
+
+use crate::{
+    self as bevy_ecs,
+    schedule::{export::write_json_string, InternedScheduleLabel},
+    system::Resource,
+};
+
+/// Resource that records when each system of a [`MultiThreaded`](super::ExecutorKind::MultiThreaded)
+/// schedule starts and ends, and on which thread, to render timelines of the executor.
+///
+/// Spans are only recorded while this resource exists, so insert it to opt in.
+/// Sync points are recorded as spans of the [`ApplyDeferred`](super::ApplyDeferred) systems.
+/// The other executors don't record spans, as they run one system at a time.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::{ExecutorTrace, ExecutorKind, ScheduleLabel};
+/// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+/// struct Update;
+///
+/// fn physics() {}
+/// fn ai() {}
+///
+/// let mut world = World::new();
+/// world.init_resource::<ExecutorTrace>();
+///
+/// let mut schedule = Schedule::new(Update);
+/// schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+/// schedule.add_systems((physics, ai));
+/// schedule.run(&mut world);
+///
+/// let trace = world.resource::<ExecutorTrace>();
+/// assert_eq!(trace.spans().len(), 2);
+/// // Open this in `chrome://tracing` or https://ui.perfetto.dev.
+/// let json = trace.to_chrome_trace();
+/// ```
+#[derive(Resource, Debug)]
+pub struct ExecutorTrace {
+    origin: Instant,
+    spans: Vec<SystemSpan>,
+    /// The schedules that are currently running, innermost last.
+    running: Vec<InternedScheduleLabel>,
+}
+
+/// The run of a single system recorded in an [`ExecutorTrace`].
+#[derive(Debug, Clone)]
+pub struct SystemSpan {
+    /// The schedule that ran the system.
+    pub schedule: Option<InternedScheduleLabel>,
+    /// The name of the system.
+    pub system: Cow<'static, str>,
+    /// The thread that ran the system.
+    pub thread: ThreadId,
+    /// When the system started, relative to the creation of the [`ExecutorTrace`].
+    pub start: Duration,
+    /// When the system ended, relative to the creation of the [`ExecutorTrace`].
+    pub end: Duration,
+}
+
+impl Default for ExecutorTrace {
+    fn default() -> Self {
+        Self {
+            origin: Instant::now(),
+            spans: Vec::new(),
+            running: Vec::new(),
+        }
+    }
+}
+
+impl ExecutorTrace {
+    /// Returns the recorded spans, in the order the systems ended.
+    pub fn spans(&self) -> &[SystemSpan] {
+        &self.spans
+    }
+
+    /// Removes all recorded spans.
+    pub fn clear(&mut self) {
+        self.spans.clear();
+    }
+
+    /// Renders the recorded spans in the [Trace Event Format] of Chrome,
+    /// which can be opened with `chrome://tracing` or [Perfetto](https://ui.perfetto.dev).
+    ///
+    /// Threads are numbered in the order they first appear in the trace.
+    ///
+    /// [Trace Event Format]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+    pub fn to_chrome_trace(&self) -> String {
+        let mut threads = HashMap::<ThreadId, usize>::default();
+        let mut json = String::from("{\"traceEvents\":[");
+        for (i, span) in self.spans.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            let thread_count = threads.len();
+            let thread = *threads.entry(span.thread).or_insert(thread_count);
+            json.push_str("{\"name\":");
+            write_json_string(&mut json, &span.system);
+            if let Some(schedule) = span.schedule {
+                json.push_str(",\"cat\":");
+                write_json_string(&mut json, &format!("{schedule:?}"));
+            }
+            let _ = write!(
+                json,
+                ",\"ph\":\"X\",\"pid\":0,\"tid\":{thread},\"ts\":{},\"dur\":{}}}",
+                span.start.as_secs_f64() * 1e6,
+                span.end.saturating_sub(span.start).as_secs_f64() * 1e6,
+            );
+        }
+        json.push_str("],\"displayTimeUnit\":\"ms\"}");
+        json
+    }
+
+    pub(crate) fn begin_schedule(&mut self, label: InternedScheduleLabel) {
+        self.running.push(label);
+    }
+
+    pub(crate) fn end_schedule(&mut self, label: InternedScheduleLabel) {
+        // A schedule that panicked may not have been ended, so search from the innermost schedule.
+        if let Some(index) = self.running.iter().rposition(|running| *running == label) {
+            self.running.truncate(index);
+        }
+    }
+
+    /// Records spans captured by an executor, in the innermost running schedule.
+    pub(super) fn record(&mut self, spans: impl IntoIterator<Item = RawSystemSpan>) {
+        let schedule = self.running.last().copied();
+        let origin = self.origin;
+        self.spans.extend(spans.into_iter().map(|span| SystemSpan {
+            schedule,
+            system: span.system,
+            thread: span.thread,
+            start: span.start.saturating_duration_since(origin),
+            end: span.end.saturating_duration_since(origin),
+        }));
+    }
+}
+
+/// A span captured by an executor while systems are running, before it is added to the [`ExecutorTrace`].
+pub(super) struct RawSystemSpan {
+    pub system: Cow<'static, str>,
+    pub thread: ThreadId,
+    pub start: Instant,
+    pub end: Instant,
+}
+
+impl RawSystemSpan {
+    /// Runs `f`, returning its result and the span of its execution on the current thread.
+    #[inline]
+    pub fn measure<R>(system: Cow<'static, str>, f: impl FnOnce() -> R) -> (R, Self) {
+        let start = Instant::now();
+        let result = f();
+        let end = Instant::now();
+        let span = Self {
+            system,
+            thread: std::thread::current().id(),
+            start,
+            end,
+        };
+        (result, span)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::*,
+        schedule::{ExecutorKind, ScheduleLabel},
+    };
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Outer;
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Inner;
+
+    fn spawn(mut commands: Commands) {
+        commands.spawn_empty();
+    }
+
+    #[test]
+    fn records_spans_per_schedule() {
+        let mut world = World::new();
+        world.init_resource::<ExecutorTrace>();
+
+        let mut inner = Schedule::new(Inner);
+        inner.set_executor_kind(ExecutorKind::MultiThreaded);
+        inner.add_systems(spawn);
+        world.add_schedule(inner);
+
+        let mut outer = Schedule::new(Outer);
+        outer.set_executor_kind(ExecutorKind::MultiThreaded);
+        outer.add_systems((spawn, |world: &mut World| world.run_schedule(Inner)).chain());
+        outer.run(&mut world);
+
+        let trace = world.resource::<ExecutorTrace>();
+        let spans_of = |label: InternedScheduleLabel| {
+            trace
+                .spans()
+                .iter()
+                .filter(|span| span.schedule == Some(label))
+                .count()
+        };
+        assert_eq!(spans_of(Inner.intern()), 1);
+        // `spawn`, the automatically inserted sync point and the exclusive system.
+        assert_eq!(spans_of(Outer.intern()), 3);
+        for span in trace.spans() {
+            assert!(span.start <= span.end);
+        }
+
+        let json = trace.to_chrome_trace();
+        assert!(json.starts_with("{\"traceEvents\":[{\"name\":"));
+        assert_eq!(json.matches("\"ph\":\"X\"").count(), 4);
+
+        world.resource_mut::<ExecutorTrace>().clear();
+        outer.set_executor_kind(ExecutorKind::SingleThreaded);
+        outer.run(&mut world);
+        // Only the multi-threaded inner schedule recorded spans.
+        let trace = world.resource::<ExecutorTrace>();
+        assert_eq!(trace.spans().len(), 1);
+        assert_eq!(trace.spans()[0].schedule, Some(Inner.intern()));
+    }
+}
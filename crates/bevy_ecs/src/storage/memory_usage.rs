@@ -0,0 +1,460 @@
+//! Introspection of the memory used by the archetypes and storages of a [`World`].
+
+use alloc::vec::Vec;
+
+use crate::{
+    archetype::{Archetype, ArchetypeEntity, ArchetypeId},
+    component::ComponentId,
+    entity::Entity,
+    storage::{ComponentSparseSet, Table, TableId},
+    world::World,
+};
+
+/// A report of the memory used to store the entities and components of a [`World`],
+/// returned by [`World::memory_usage`].
+///
+/// Only the memory owned directly by the storages is counted. Memory allocated by the components
+/// themselves, like the contents of a `Vec`, isn't included, and neither are resources.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryUsage {
+    /// The memory used by each archetype, indexed by [`ArchetypeId`].
+    pub archetypes: Vec<ArchetypeMemoryUsage>,
+    /// The memory used by each table, indexed by [`TableId`].
+    pub tables: Vec<TableMemoryUsage>,
+    /// The memory used by the sparse set of each component that has one.
+    pub sparse_sets: Vec<SparseSetMemoryUsage>,
+}
+
+impl MemoryUsage {
+    /// Returns the number of bytes allocated by the archetypes, tables and sparse sets.
+    pub fn allocated_bytes(&self) -> usize {
+        self.archetypes
+            .iter()
+            .map(ArchetypeMemoryUsage::allocated_bytes)
+            .chain(self.tables.iter().map(TableMemoryUsage::allocated_bytes))
+            .chain(self.sparse_sets.iter().map(|set| set.allocated_bytes))
+            .sum()
+    }
+
+    /// Returns the number of bytes that hold the data of an entity.
+    pub fn used_bytes(&self) -> usize {
+        self.archetypes
+            .iter()
+            .map(ArchetypeMemoryUsage::used_bytes)
+            .chain(self.tables.iter().map(TableMemoryUsage::used_bytes))
+            .chain(self.sparse_sets.iter().map(|set| set.used_bytes))
+            .sum()
+    }
+
+    /// Returns the fraction of the allocated memory that doesn't hold the data of an entity,
+    /// between `0.0` and `1.0`.
+    ///
+    /// This memory is either spare capacity kept around to avoid reallocating when entities
+    /// are added, or the index of a sparse set.
+    /// It can be freed with [`World::compact_storage`].
+    pub fn fragmentation(&self) -> f64 {
+        fragmentation(self.used_bytes(), self.allocated_bytes())
+    }
+
+    /// Iterates over the archetypes that don't contain any entities.
+    ///
+    /// These archetypes are dropped by [`World::compact_storage`], except the empty archetype.
+    pub fn empty_archetypes(&self) -> impl Iterator<Item = &ArchetypeMemoryUsage> {
+        self.archetypes
+            .iter()
+            .filter(|archetype| archetype.entity_count == 0)
+    }
+
+    /// Iterates over the tables that don't contain any entities.
+    pub fn empty_tables(&self) -> impl Iterator<Item = &TableMemoryUsage> {
+        self.tables.iter().filter(|table| table.entity_count == 0)
+    }
+}
+
+/// The memory used by an [`Archetype`] to keep track of its entities.
+#[derive(Debug, Clone)]
+pub struct ArchetypeMemoryUsage {
+    /// The id of the archetype.
+    pub id: ArchetypeId,
+    /// The table storing the table components of the archetype.
+    pub table_id: TableId,
+    /// The number of components in the archetype.
+    pub component_count: usize,
+    /// The number of entities in the archetype.
+    pub entity_count: usize,
+    /// The number of entities the archetype can store without reallocating.
+    pub entity_capacity: usize,
+}
+
+impl ArchetypeMemoryUsage {
+    fn new(archetype: &Archetype) -> Self {
+        Self {
+            id: archetype.id(),
+            table_id: archetype.table_id(),
+            component_count: archetype.component_count(),
+            entity_count: archetype.len(),
+            entity_capacity: archetype.entity_capacity(),
+        }
+    }
+
+    /// Returns the number of bytes allocated to store the entities of the archetype.
+    pub fn allocated_bytes(&self) -> usize {
+        self.entity_capacity * size_of::<ArchetypeEntity>()
+    }
+
+    /// Returns the number of bytes used by the entities of the archetype.
+    pub fn used_bytes(&self) -> usize {
+        self.entity_count * size_of::<ArchetypeEntity>()
+    }
+}
+
+/// The memory used by a [`Table`] and its columns.
+#[derive(Debug, Clone)]
+pub struct TableMemoryUsage {
+    /// The id of the table.
+    pub id: TableId,
+    /// The number of entities in the table.
+    pub entity_count: usize,
+    /// The number of entities the table can store without reallocating.
+    pub entity_capacity: usize,
+    /// The memory used by each column of the table.
+    pub columns: Vec<ColumnMemoryUsage>,
+}
+
+impl TableMemoryUsage {
+    fn new(id: TableId, table: &Table) -> Self {
+        let entity_count = table.entity_count();
+        let entity_capacity = table.entity_capacity();
+        let columns = table
+            .iter_columns_with_ids()
+            .map(|(component_id, column)| {
+                let row_size = column.row_size();
+                ColumnMemoryUsage {
+                    component_id,
+                    row_size,
+                    used_bytes: row_size * entity_count,
+                    allocated_bytes: row_size * entity_capacity,
+                }
+            })
+            .collect();
+        Self {
+            id,
+            entity_count,
+            entity_capacity,
+            columns,
+        }
+    }
+
+    /// Returns the number of bytes allocated by the table, including its list of entities.
+    pub fn allocated_bytes(&self) -> usize {
+        self.entity_capacity * size_of::<Entity>()
+            + self
+                .columns
+                .iter()
+                .map(|column| column.allocated_bytes)
+                .sum::<usize>()
+    }
+
+    /// Returns the number of bytes used by the entities in the table.
+    pub fn used_bytes(&self) -> usize {
+        self.entity_count * size_of::<Entity>()
+            + self
+                .columns
+                .iter()
+                .map(|column| column.used_bytes)
+                .sum::<usize>()
+    }
+
+    /// Returns the fraction of the memory allocated by the table that isn't used by its
+    /// entities, between `0.0` and `1.0`.
+    pub fn fragmentation(&self) -> f64 {
+        fragmentation(self.used_bytes(), self.allocated_bytes())
+    }
+}
+
+/// The memory used by a column of a [`Table`].
+#[derive(Debug, Clone)]
+pub struct ColumnMemoryUsage {
+    /// The component stored in the column.
+    pub component_id: ComponentId,
+    /// The number of bytes each entity takes up in the column, including change detection
+    /// metadata.
+    pub row_size: usize,
+    /// The number of bytes used by the entities in the table.
+    pub used_bytes: usize,
+    /// The number of bytes allocated for the column.
+    pub allocated_bytes: usize,
+}
+
+/// The memory used by a [`ComponentSparseSet`].
+#[derive(Debug, Clone)]
+pub struct SparseSetMemoryUsage {
+    /// The component stored in the sparse set.
+    pub component_id: ComponentId,
+    /// The number of entities with the component.
+    pub entity_count: usize,
+    /// The number of bytes used by the components of the entities, including change
+    /// detection metadata.
+    pub used_bytes: usize,
+    /// The number of bytes allocated for the sparse set, including the index from entities to
+    /// their component.
+    pub allocated_bytes: usize,
+}
+
+impl SparseSetMemoryUsage {
+    fn new(component_id: ComponentId, sparse_set: &ComponentSparseSet) -> Self {
+        Self {
+            component_id,
+            entity_count: sparse_set.len(),
+            used_bytes: sparse_set.used_bytes(),
+            allocated_bytes: sparse_set.allocated_bytes(),
+        }
+    }
+}
+
+fn fragmentation(used_bytes: usize, allocated_bytes: usize) -> f64 {
+    if allocated_bytes == 0 {
+        return 0.0;
+    }
+    1.0 - used_bytes as f64 / allocated_bytes as f64
+}
+
+impl World {
+    /// Returns a report of the memory used by the archetypes, tables and sparse sets of this
+    /// [`World`].
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Health(f32);
+    ///
+    /// let mut world = World::new();
+    /// world.spawn_batch((0..100).map(|_| Health(10.0)));
+    ///
+    /// let usage = world.memory_usage();
+    /// let table = usage.tables.iter().max_by_key(|table| table.entity_count).unwrap();
+    /// assert_eq!(table.entity_count, 100);
+    /// assert!(table.used_bytes() >= 100 * size_of::<Health>());
+    /// ```
+    pub fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage {
+            archetypes: self
+                .archetypes
+                .iter()
+                .map(ArchetypeMemoryUsage::new)
+                .collect(),
+            tables: self
+                .storages
+                .tables
+                .iter()
+                .enumerate()
+                .map(|(index, table)| TableMemoryUsage::new(TableId::from_usize(index), table))
+                .collect(),
+            sparse_sets: self
+                .storages
+                .sparse_sets
+                .iter()
+                .map(|(component_id, sparse_set)| {
+                    SparseSetMemoryUsage::new(component_id, sparse_set)
+                })
+                .collect(),
+        }
+    }
+
+    /// Drops the archetypes that don't contain any entities and frees the spare capacity of every
+    /// archetype, table and sparse set, as reported by [`World::memory_usage`].
+    ///
+    /// This is best used after a large number of entities was despawned, like when unloading a
+    /// level, as entities added afterwards will have to allocate again.
+    ///
+    /// # Empty archetypes
+    ///
+    /// Dropping archetypes gives new [`ArchetypeId`]s to the remaining ones, and the archetypes
+    /// matched by queries and systems have to be found again: [`QueryState`]s and
+    /// [`SystemState`]s must be updated with [`QueryState::update_archetypes`] and
+    /// [`SystemState::update_archetypes`] before using their `_manual` methods, which panic
+    /// otherwise. Systems and the other methods update them on their own.
+    ///
+    /// Empty tables are only shrunk, so that they no longer hold on to any memory, and keep their
+    /// [`TableId`].
+    ///
+    /// [`QueryState`]: crate::query::QueryState
+    /// [`QueryState::update_archetypes`]: crate::query::QueryState::update_archetypes
+    /// [`SystemState`]: crate::system::SystemState
+    /// [`SystemState::update_archetypes`]: crate::system::SystemState::update_archetypes
+    pub fn compact_storage(&mut self) {
+        self.flush();
+        if self.archetypes.drop_empty() {
+            for archetype in self.archetypes.iter() {
+                for entity in archetype.entities() {
+                    let mut location = self.entities.get(entity.id()).unwrap();
+                    location.archetype_id = archetype.id();
+                    // SAFETY: The entity is alive, as it's in an archetype.
+                    unsafe { self.entities.set(entity.id().index(), location) };
+                }
+            }
+        }
+        self.archetypes.shrink_to_fit();
+        self.storages.tables.shrink_to_fit();
+        self.storages.sparse_sets.shrink_to_fit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as bevy_ecs, component::StorageType, prelude::*};
+    use alloc::vec::Vec;
+
+    #[derive(Component)]
+    struct A(#[expect(dead_code, reason = "only used for its size")] [u64; 4]);
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct B(#[expect(dead_code, reason = "only used for its size")] u32);
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn memory_usage() {
+        let mut world = World::new();
+        let entities = world
+            .spawn_batch((0..10).map(|_| (A([0; 4]), B(0))))
+            .collect::<Vec<_>>();
+        world.spawn(Marker);
+
+        let a = world.component_id::<A>().unwrap();
+        let b = world.component_id::<B>().unwrap();
+        let usage = world.memory_usage();
+
+        let entity = world.entity(entities[0]);
+        let archetype = entity.archetype();
+        let archetype_usage = &usage.archetypes[archetype.id().index()];
+        assert_eq!(archetype_usage.entity_count, 10);
+        assert_eq!(archetype_usage.component_count, 2);
+
+        let table_usage = &usage.tables[archetype.table_id().as_usize()];
+        assert_eq!(table_usage.entity_count, 10);
+        assert!(table_usage.entity_capacity >= 10);
+        let [column] = &table_usage.columns[..] else {
+            panic!("expected a single column");
+        };
+        assert_eq!(column.component_id, a);
+        assert!(column.row_size >= size_of::<A>());
+        assert_eq!(column.used_bytes, 10 * column.row_size);
+        assert!(column.allocated_bytes >= column.used_bytes);
+
+        let sparse_set = usage
+            .sparse_sets
+            .iter()
+            .find(|set| set.component_id == b)
+            .unwrap();
+        assert_eq!(sparse_set.entity_count, 10);
+        assert!(sparse_set.used_bytes >= 10 * size_of::<B>());
+        assert!(sparse_set.allocated_bytes >= sparse_set.used_bytes);
+
+        assert!(usage.used_bytes() <= usage.allocated_bytes());
+        assert!((0.0..=1.0).contains(&usage.fragmentation()));
+        assert!(usage
+            .empty_archetypes()
+            .all(|archetype| archetype.id != archetype_usage.id));
+    }
+
+    #[test]
+    fn compact_storage() {
+        let mut world = World::new();
+        let entities = world
+            .spawn_batch((0..100).map(|_| (A([0; 4]), B(0))))
+            .collect::<Vec<_>>();
+        let kept = world.spawn((A([1; 4]), B(1))).id();
+        let archetype_id = world.entity(kept).archetype().id();
+        let table_id = world.entity(kept).archetype().table_id();
+        for entity in entities {
+            world.despawn(entity);
+        }
+
+        let before = world.memory_usage();
+        world.compact_storage();
+        let after = world.memory_usage();
+        assert!(after.allocated_bytes() < before.allocated_bytes());
+        assert_eq!(after.used_bytes(), before.used_bytes());
+        assert_eq!(after.tables[table_id.as_usize()].entity_capacity, 1);
+        assert_eq!(after.archetypes[archetype_id.index()].entity_capacity, 1);
+
+        // Empty archetypes are dropped, empty tables free all of their memory, and both can be
+        // created again afterwards.
+        world.despawn(kept);
+        world.compact_storage();
+        let usage = world.memory_usage();
+        assert_eq!(usage.allocated_bytes(), 0);
+        assert_eq!(usage.fragmentation(), 0.0);
+        assert_eq!(world.archetypes().len(), 1);
+        assert_eq!(usage.empty_archetypes().count(), 1);
+
+        let entity = world.spawn((A([2; 4]), B(2))).id();
+        assert_eq!(world.entity(entity).archetype().table_id(), table_id);
+        assert!(world.get::<A>(entity).is_some());
+        assert!(world.get::<B>(entity).is_some());
+        let b = world.component_id::<B>().unwrap();
+        assert_eq!(
+            world.entity(entity).archetype().get_storage_type(b),
+            Some(StorageType::SparseSet)
+        );
+    }
+
+    #[test]
+    fn compact_storage_remaps_archetypes() {
+        #[derive(Resource, Default)]
+        struct Seen(usize);
+
+        let mut world = World::new();
+        world.init_resource::<Seen>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(|query: Query<&A>, mut seen: ResMut<Seen>| {
+            seen.0 = query.iter().count();
+        });
+        let mut query = world.query::<(Entity, &A)>();
+
+        let dropped = world.spawn((A([0; 4]), Marker)).id();
+        let kept = world.spawn((A([1; 4]), B(1))).id();
+        let moved = world.spawn(B(2)).id();
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Seen>().0, 2);
+        assert_eq!(query.iter(&world).count(), 2);
+
+        world.despawn(dropped);
+        let archetype_count = world.archetypes().len();
+        world.compact_storage();
+        assert_eq!(world.archetypes().len(), archetype_count - 1);
+        for archetype in world.archetypes().iter() {
+            for entity in archetype.entities() {
+                assert_eq!(world.entity(entity.id()).archetype().id(), archetype.id());
+            }
+        }
+
+        // Queries and systems find the archetypes again with their new ids.
+        assert!(world.entity(kept).contains::<A>());
+        assert!(world.entity(moved).contains::<B>());
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Seen>().0, 1);
+        assert_eq!(query.iter(&world).collect::<Vec<_>>().len(), 1);
+        assert!(query.get(&world, moved).is_err());
+        assert_eq!(query.get(&world, kept).unwrap().0, kept);
+
+        // Moving entities into new archetypes goes through the archetype graph again.
+        world.entity_mut(moved).insert(A([3; 4]));
+        world.entity_mut(kept).remove::<B>();
+        assert_eq!(query.iter(&world).count(), 2);
+    }
+
+    #[test]
+    #[should_panic = "compacted"]
+    fn compact_storage_invalidates_manual_queries() {
+        let mut world = World::new();
+        let mut query = world.query::<&A>();
+        world.spawn((A([0; 4]), Marker)).despawn();
+        query.update_archetypes(&world);
+        world.compact_storage();
+        let _ = query.iter_manual(&world).count();
+    }
+}
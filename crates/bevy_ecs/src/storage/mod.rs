@@ -13,20 +13,28 @@
 //!    lookup and regular insertion/removal of components.
 //!  - [`Resources`] - singleton storage for the resources in the world
 //!
+//! # Memory Usage
+//! [`World::memory_usage`] reports how much memory each archetype, table and sparse set uses, and
+//! [`World::compact_storage`] frees the memory that is no longer needed by them.
+//!
 //! # Safety
 //! To avoid trivially unsound use of the APIs in this module, it is explicitly impossible to get a mutable
 //! reference to [`Storages`] from [`World`], and none of the types publicly expose a mutable interface.
 //!
 //! [`World`]: crate::world::World
 //! [`World::storages`]: crate::world::World::storages
+//! [`World::memory_usage`]: crate::world::World::memory_usage
+//! [`World::compact_storage`]: crate::world::World::compact_storage
 
 mod blob_array;
 mod blob_vec;
+mod memory_usage;
 mod resource;
 mod sparse_set;
 mod table;
 mod thin_array_ptr;
 
+pub use memory_usage::*;
 pub use resource::*;
 pub use sparse_set::*;
 pub use table::*;
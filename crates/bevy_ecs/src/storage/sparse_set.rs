@@ -1,11 +1,11 @@
 use crate::{
     change_detection::MaybeUnsafeCellLocation,
-    component::{ComponentId, ComponentInfo, ComponentTicks, Tick, TickCells},
+    component::{ComponentId, ComponentInfo, ComponentTicks, Components, Tick, TickCells},
     entity::Entity,
     storage::{Column, TableRow},
 };
 use alloc::{boxed::Box, vec::Vec};
-use bevy_ptr::{OwningPtr, Ptr};
+use bevy_ptr::{OwningPtr, Ptr, PtrMut};
 #[cfg(feature = "track_location")]
 use core::panic::Location;
 use core::{cell::UnsafeCell, hash::Hash, marker::PhantomData};
@@ -13,6 +13,11 @@ use nonmax::NonMaxUsize;
 
 type EntityIndex = u32;
 
+/// Returns the number of bytes allocated for the elements of `vec`.
+fn allocated_bytes<T>(vec: &Vec<T>) -> usize {
+    size_of::<T>() * vec.capacity()
+}
+
 #[derive(Debug)]
 pub(crate) struct SparseArray<I, V = I> {
     values: Vec<Option<V>>,
@@ -104,6 +109,22 @@ impl<I: SparseSetIndex, V> SparseArray<I, V> {
         self.values.clear();
     }
 
+    /// Returns the number of bytes allocated for the values.
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        allocated_bytes(&self.values)
+    }
+
+    /// Shrinks the capacity of the array as much as possible, dropping trailing empty slots.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        let len = self
+            .values
+            .iter()
+            .rposition(Option::is_some)
+            .map_or(0, |index| index + 1);
+        self.values.truncate(len);
+        self.values.shrink_to_fit();
+    }
+
     /// Converts the [`SparseArray`] into an immutable variant.
     pub(crate) fn into_immutable(self) -> ImmutableSparseArray<I, V> {
         ImmutableSparseArray {
@@ -153,6 +174,26 @@ impl ComponentSparseSet {
         self.dense.len()
     }
 
+    /// Returns the number of bytes allocated for this sparse set.
+    pub fn allocated_bytes(&self) -> usize {
+        self.dense.allocated_bytes()
+            + allocated_bytes(&self.entities)
+            + self.sparse.allocated_bytes()
+    }
+
+    /// Returns the number of bytes used by the component values in this sparse set, including
+    /// their change detection metadata and the entities they belong to.
+    pub fn used_bytes(&self) -> usize {
+        self.dense.used_bytes() + size_of_val(self.entities.as_slice())
+    }
+
+    /// Shrinks the capacity of the sparse set as much as possible.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.dense.shrink_to_fit();
+        self.entities.shrink_to_fit();
+        self.sparse.shrink_to_fit();
+    }
+
     /// Returns `true` if the sparse set contains no component values.
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -360,8 +401,15 @@ impl ComponentSparseSet {
         }
     }
 
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
-        self.dense.check_change_ticks(change_tick);
+    /// # Safety
+    /// `check_value` is the [`ComponentInfo::check_change_ticks_fn`] of the stored component
+    pub(crate) unsafe fn check_change_ticks(
+        &mut self,
+        change_tick: Tick,
+        check_value: Option<for<'a> unsafe fn(PtrMut<'a>, Tick)>,
+    ) {
+        // SAFETY: Upheld by the caller.
+        unsafe { self.dense.check_change_ticks(change_tick, check_value) };
     }
 }
 
@@ -650,9 +698,20 @@ impl SparseSets {
         }
     }
 
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
+    pub(crate) fn check_change_ticks(&mut self, components: &Components, change_tick: Tick) {
+        for (component_id, set) in self.sets.iter_mut() {
+            let check_value = components
+                .get_info(*component_id)
+                .and_then(ComponentInfo::check_change_ticks_fn);
+            // SAFETY: `check_value` belongs to the component stored in the sparse set.
+            unsafe { set.check_change_ticks(change_tick, check_value) };
+        }
+    }
+
+    /// Shrinks the capacity of every [`ComponentSparseSet`] as much as possible.
+    pub(crate) fn shrink_to_fit(&mut self) {
         for set in self.sets.values_mut() {
-            set.check_change_ticks(change_tick);
+            set.shrink_to_fit();
         }
     }
 }
@@ -22,6 +22,22 @@ pub struct ThinColumn {
 }
 
 impl ThinColumn {
+    /// Fetches the [`Layout`] for the underlying type.
+    #[inline]
+    pub fn item_layout(&self) -> Layout {
+        self.data.layout()
+    }
+
+    /// Returns the number of bytes each row of this column takes up, including the change
+    /// detection metadata.
+    #[inline]
+    pub(crate) fn row_size(&self) -> usize {
+        let size = self.item_layout().size() + 2 * size_of::<UnsafeCell<Tick>>();
+        #[cfg(feature = "track_location")]
+        let size = size + size_of::<UnsafeCell<&'static Location<'static>>>();
+        size
+    }
+
     /// Create a new [`ThinColumn`] with the given `capacity`.
     pub fn with_capacity(component_info: &ComponentInfo, capacity: usize) -> Self {
         Self {
@@ -121,6 +137,30 @@ impl ThinColumn {
         self.changed_by.realloc(current_capacity, new_capacity);
     }
 
+    /// Frees the memory allocated for this empty [`ThinColumn`].
+    ///
+    /// # Safety
+    /// - `current_capacity` must be the current capacity of this column
+    /// - The column must not contain any elements
+    /// - The caller should make sure their saved `capacity` value is updated to 0 after this operation.
+    pub(crate) unsafe fn dealloc(&mut self, current_capacity: NonZeroUsize) {
+        // The arrays are replaced with unallocated ones before dropping them, as a dropped array
+        // must not be used again.
+        // SAFETY: `drop` is the drop function of the components stored in this column.
+        let empty_data = unsafe { BlobArray::with_capacity(self.data.layout(), self.data.drop, 0) };
+        let mut data = core::mem::replace(&mut self.data, empty_data);
+        let mut added_ticks = core::mem::replace(&mut self.added_ticks, ThinArrayPtr::empty());
+        let mut changed_ticks = core::mem::replace(&mut self.changed_ticks, ThinArrayPtr::empty());
+        #[cfg(feature = "track_location")]
+        let mut changed_by = core::mem::replace(&mut self.changed_by, ThinArrayPtr::empty());
+
+        data.drop(current_capacity.get(), 0);
+        added_ticks.drop(current_capacity.get(), 0);
+        changed_ticks.drop(current_capacity.get(), 0);
+        #[cfg(feature = "track_location")]
+        changed_by.drop(current_capacity.get(), 0);
+    }
+
     /// Call [`alloc`](std::alloc::alloc) to allocate memory for this [`ThinColumn`]
     /// The caller should make sure their saved `capacity` value is updated to `new_capacity` after this operation.
     pub(crate) fn alloc(&mut self, new_capacity: NonZeroUsize) {
@@ -227,12 +267,27 @@ impl ThinColumn {
             .initialize_unchecked(dst_row.as_usize(), changed_by);
     }
 
-    /// Call [`Tick::check_tick`] on all of the ticks stored in this column.
+    /// Call [`Tick::check_tick`] on all of the ticks stored in this column, and `check_value` on
+    /// all of its values.
     ///
     /// # Safety
-    /// `len` is the actual length of this column
+    /// - `len` is the actual length of this column
+    /// - `check_value` is the [`ComponentInfo::check_change_ticks_fn`] of the stored component
     #[inline]
-    pub(crate) unsafe fn check_change_ticks(&mut self, len: usize, change_tick: Tick) {
+    pub(crate) unsafe fn check_change_ticks(
+        &mut self,
+        len: usize,
+        change_tick: Tick,
+        check_value: Option<for<'a> unsafe fn(PtrMut<'a>, Tick)>,
+    ) {
+        if let Some(check_value) = check_value {
+            for i in 0..len {
+                // SAFETY:
+                // - `i` < `len`
+                // - `check_value` belongs to the stored component
+                unsafe { check_value(self.data.get_unchecked_mut(i), change_tick) };
+            }
+        }
         for i in 0..len {
             // SAFETY:
             // - `i` < `len`
@@ -637,6 +692,35 @@ impl Column {
         }
     }
 
+    /// Returns the number of bytes allocated for this column.
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        let size = self.data.layout().size() * self.data.capacity()
+            + size_of::<UnsafeCell<Tick>>()
+                * (self.added_ticks.capacity() + self.changed_ticks.capacity());
+        #[cfg(feature = "track_location")]
+        let size =
+            size + size_of::<UnsafeCell<&'static Location<'static>>>() * self.changed_by.capacity();
+        size
+    }
+
+    /// Returns the number of bytes used by the components in this column, including the change
+    /// detection metadata.
+    pub(crate) fn used_bytes(&self) -> usize {
+        let size = self.data.layout().size() + 2 * size_of::<UnsafeCell<Tick>>();
+        #[cfg(feature = "track_location")]
+        let size = size + size_of::<UnsafeCell<&'static Location<'static>>>();
+        size * self.len()
+    }
+
+    /// Shrinks the capacity of the column as much as possible.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+        self.added_ticks.shrink_to_fit();
+        self.changed_ticks.shrink_to_fit();
+        #[cfg(feature = "track_location")]
+        self.changed_by.shrink_to_fit();
+    }
+
     /// Clears the column, removing all values.
     ///
     /// Note that this function has no effect on the allocated capacity of the [`Column`]>
@@ -648,8 +732,25 @@ impl Column {
         self.changed_by.clear();
     }
 
+    /// Call [`Tick::check_tick`] on all of the ticks stored in this column, and `check_value` on
+    /// all of its values.
+    ///
+    /// # Safety
+    /// `check_value` is the [`ComponentInfo::check_change_ticks_fn`] of the stored component
     #[inline]
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
+    pub(crate) unsafe fn check_change_ticks(
+        &mut self,
+        change_tick: Tick,
+        check_value: Option<for<'a> unsafe fn(PtrMut<'a>, Tick)>,
+    ) {
+        if let Some(check_value) = check_value {
+            for i in 0..self.data.len() {
+                // SAFETY:
+                // - `i` < `len`
+                // - `check_value` belongs to the stored component
+                unsafe { check_value(self.data.get_unchecked_mut(i), change_tick) };
+            }
+        }
         for component_ticks in &mut self.added_ticks {
             component_ticks.get_mut().check_tick(change_tick);
         }
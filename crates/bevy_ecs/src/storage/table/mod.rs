@@ -611,12 +611,18 @@ impl Table {
         self.entities.is_empty()
     }
 
-    /// Call [`Tick::check_tick`] on all of the ticks in the [`Table`]
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
+    /// Call [`Tick::check_tick`] on all of the ticks in the [`Table`], including the ones stored
+    /// in component values.
+    pub(crate) fn check_change_ticks(&mut self, components: &Components, change_tick: Tick) {
         let len = self.entity_count();
-        for col in self.columns.values_mut() {
-            // SAFETY: `len` is the actual length of the column
-            unsafe { col.check_change_ticks(len, change_tick) };
+        for (component_id, col) in self.columns.iter_mut() {
+            let check_value = components
+                .get_info(*component_id)
+                .and_then(ComponentInfo::check_change_ticks_fn);
+            // SAFETY:
+            // - `len` is the actual length of the column
+            // - `check_value` belongs to the component stored in the column
+            unsafe { col.check_change_ticks(len, change_tick, check_value) };
         }
     }
 
@@ -625,6 +631,38 @@ impl Table {
         self.columns.values()
     }
 
+    /// Iterates over the [`ComponentId`]s and [`ThinColumn`]s of the [`Table`].
+    pub fn iter_columns_with_ids(&self) -> impl Iterator<Item = (ComponentId, &ThinColumn)> {
+        self.columns.iter().map(|(id, column)| (*id, column))
+    }
+
+    /// Shrinks the capacity of the [`Table`] as much as possible, freeing all of its memory if
+    /// it is empty.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        let current_capacity = self.capacity();
+        self.entities.shrink_to_fit();
+        // use entities vector capacity as driving capacity for all related allocations
+        let new_capacity = self.capacity();
+        let Some(current_capacity) = NonZeroUsize::new(current_capacity) else {
+            return;
+        };
+        match NonZeroUsize::new(new_capacity) {
+            Some(new_capacity) if new_capacity != current_capacity => {
+                // SAFETY: `current_capacity` is indeed the columns' capacity
+                unsafe { self.realloc_columns(current_capacity, new_capacity) };
+            }
+            Some(_) => {}
+            None => {
+                for col in self.columns.values_mut() {
+                    // SAFETY:
+                    // - `current_capacity` is indeed the columns' capacity
+                    // - The table is empty, so its columns are too
+                    unsafe { col.dealloc(current_capacity) };
+                }
+            }
+        }
+    }
+
     /// Clears all of the stored components in the [`Table`].
     pub(crate) fn clear(&mut self) {
         let len = self.entity_count();
@@ -769,6 +807,13 @@ impl Tables {
         self.tables.iter()
     }
 
+    /// Shrinks the capacity of every [`Table`] as much as possible.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        for table in &mut self.tables {
+            table.shrink_to_fit();
+        }
+    }
+
     /// Clears all data from all [`Table`]s stored within.
     pub(crate) fn clear(&mut self) {
         for table in &mut self.tables {
@@ -776,9 +821,9 @@ impl Tables {
         }
     }
 
-    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
+    pub(crate) fn check_change_ticks(&mut self, components: &Components, change_tick: Tick) {
         for table in &mut self.tables {
-            table.check_change_ticks(change_tick);
+            table.check_change_ticks(components, change_tick);
         }
     }
 }
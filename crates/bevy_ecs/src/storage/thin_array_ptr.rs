@@ -23,7 +23,8 @@ pub struct ThinArrayPtr<T> {
 }
 
 impl<T> ThinArrayPtr<T> {
-    fn empty() -> Self {
+    /// Create a new [`ThinArrayPtr`] without allocating any memory.
+    pub fn empty() -> Self {
         #[cfg(debug_assertions)]
         {
             Self {
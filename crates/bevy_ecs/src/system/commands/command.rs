@@ -9,6 +9,7 @@ use core::panic::Location;
 
 use crate::{
     bundle::{Bundle, InsertMode},
+    component::Component,
     entity::Entity,
     event::{Event, Events},
     observer::TriggerTargets,
@@ -17,6 +18,7 @@ use crate::{
     system::{error_handler, IntoSystem, Resource, SystemId, SystemInput},
     world::{FromWorld, SpawnBatchIter, World},
 };
+use alloc::vec::Vec;
 
 /// A [`World`] mutation.
 ///
@@ -64,6 +66,54 @@ where
     }
 }
 
+/// A [`Command`] that can be applied together with other commands of the same type.
+///
+/// When a command queue is applied, consecutive commands that were queued with
+/// [`Commands::queue_batched`](crate::system::Commands::queue_batched) and share the same type
+/// are collected and passed to [`apply_batch`](Self::apply_batch) in the order they were queued.
+/// This lets commands coalesce their work, for example by moving many entities
+/// between the same archetypes at once.
+///
+/// Commands queued by hooks and observers while a batch is applied run after the whole batch,
+/// rather than after each individual command.
+///
+/// # Usage
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::BatchedCommand;
+/// #[derive(Resource, Default)]
+/// struct Counter(u64);
+///
+/// struct AddToCounter(u64);
+///
+/// impl Command for AddToCounter {
+///     fn apply(self, world: &mut World) {
+///         world.get_resource_or_insert_with(Counter::default).0 += self.0;
+///     }
+/// }
+///
+/// impl BatchedCommand for AddToCounter {
+///     fn apply_batch(batch: Vec<Self>, world: &mut World) {
+///         // Look up the resource once for the whole batch.
+///         let mut counter = world.get_resource_or_insert_with(Counter::default);
+///         counter.0 += batch.iter().map(|command| command.0).sum::<u64>();
+///     }
+/// }
+///
+/// fn some_system(mut commands: Commands) {
+///     commands.queue_batched(AddToCounter(1));
+///     commands.queue_batched(AddToCounter(2));
+/// }
+/// # bevy_ecs::system::assert_is_system(some_system);
+/// ```
+pub trait BatchedCommand: Command + Sized {
+    /// Applies a run of consecutively queued commands of this type.
+    ///
+    /// This must behave like applying each command in `batch` in order.
+    fn apply_batch(batch: Vec<Self>, world: &mut World);
+}
+
 /// Takes a [`Command`] that returns a Result and uses a given error handler function to convert it into
 /// a [`Command`] that internally handles an error if it occurs and returns `()`.
 pub trait HandleError<Out = ()> {
@@ -168,6 +218,122 @@ where
     }
 }
 
+/// A [`BatchedCommand`] that adds a [`Bundle`] to `entity`.
+///
+/// Consecutive insertions of the same bundle type are applied together, in the same way as
+/// [`World::insert_batch`], so entities moving between the same archetypes only look up
+/// the archetype transition once.
+/// If `entity` does not exist when the command is applied, the error is passed to `error_handler`.
+#[track_caller]
+pub fn insert_bundle<B: Bundle>(
+    entity: Entity,
+    bundle: B,
+    mode: InsertMode,
+    error_handler: fn(&mut World, Error),
+) -> impl BatchedCommand {
+    InsertBundle {
+        entity,
+        bundle,
+        mode,
+        error_handler,
+        #[cfg(feature = "track_location")]
+        caller: Location::caller(),
+    }
+}
+
+struct InsertBundle<B: Bundle> {
+    entity: Entity,
+    bundle: B,
+    mode: InsertMode,
+    error_handler: fn(&mut World, Error),
+    #[cfg(feature = "track_location")]
+    caller: &'static Location<'static>,
+}
+
+impl<B: Bundle> Command for InsertBundle<B> {
+    fn apply(self, world: &mut World) {
+        Self::apply_batch(alloc::vec![self], world);
+    }
+}
+
+impl<B: Bundle> BatchedCommand for InsertBundle<B> {
+    fn apply_batch(batch: Vec<Self>, world: &mut World) {
+        // Entities in `pending` are checked for existence right before being added, and nothing
+        // can despawn them until the run is inserted. Commands queued by the hooks and observers
+        // of a run are flushed before any further entities are checked.
+        fn insert_run<B: Bundle>(
+            world: &mut World,
+            pending: &mut Vec<(Entity, B)>,
+            mode: InsertMode,
+            #[cfg(feature = "track_location")] caller: &'static Location<'static>,
+        ) {
+            if pending.is_empty() {
+                return;
+            }
+            world.insert_batch_with_caller(
+                pending.drain(..),
+                mode,
+                #[cfg(feature = "track_location")]
+                caller,
+            );
+            world.flush();
+        }
+
+        world.flush();
+        let Some(first) = batch.first() else {
+            return;
+        };
+        let mut mode = first.mode;
+        #[cfg(feature = "track_location")]
+        let mut caller = first.caller;
+        let mut pending = Vec::with_capacity(batch.len());
+
+        for command in batch {
+            #[cfg(feature = "track_location")]
+            let same_run = command.mode == mode && command.caller == caller;
+            #[cfg(not(feature = "track_location"))]
+            let same_run = command.mode == mode;
+            if !same_run {
+                insert_run(
+                    world,
+                    &mut pending,
+                    mode,
+                    #[cfg(feature = "track_location")]
+                    caller,
+                );
+                mode = command.mode;
+                #[cfg(feature = "track_location")]
+                {
+                    caller = command.caller;
+                }
+            }
+
+            match world.get_entity_mut(command.entity) {
+                Ok(_) => pending.push((command.entity, command.bundle)),
+                Err(err) => {
+                    insert_run(
+                        world,
+                        &mut pending,
+                        mode,
+                        #[cfg(feature = "track_location")]
+                        caller,
+                    );
+                    (command.error_handler)(world, err.into());
+                    world.flush();
+                }
+            }
+        }
+
+        insert_run(
+            world,
+            &mut pending,
+            mode,
+            #[cfg(feature = "track_location")]
+            caller,
+        );
+    }
+}
+
 /// A [`Command`] that inserts a [`Resource`] into the world using a value
 /// created with the [`FromWorld`] trait.
 #[track_caller]
@@ -198,6 +364,35 @@ pub fn remove_resource<R: Resource>() -> impl Command {
     }
 }
 
+/// A [`Command`] that inserts a [`Resource`] on its resource entity.
+///
+/// See [`World::insert_resource_entity`] for more details.
+#[track_caller]
+pub fn insert_resource_entity<R: Resource + Component>(resource: R) -> impl Command {
+    move |world: &mut World| {
+        world.insert_resource_entity(resource);
+    }
+}
+
+/// A [`Command`] that spawns a resource entity for a [`Resource`] created with [`FromWorld`].
+///
+/// See [`World::init_resource_entity`] for more details.
+#[track_caller]
+pub fn init_resource_entity<R: Resource + Component + FromWorld>() -> impl Command {
+    move |world: &mut World| {
+        world.init_resource_entity::<R>();
+    }
+}
+
+/// A [`Command`] that removes a [`Resource`] from its resource entity.
+///
+/// See [`World::remove_resource_entity`] for more details.
+pub fn remove_resource_entity<R: Resource + Component>() -> impl Command {
+    move |world: &mut World| {
+        world.remove_resource_entity::<R>();
+    }
+}
+
 /// A [`Command`] that runs the system corresponding to the given [`SystemId`].
 pub fn run_system<O: 'static>(id: SystemId<(), O>) -> impl Command<Result> {
     move |world: &mut World| -> Result {
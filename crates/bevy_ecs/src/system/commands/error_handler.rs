@@ -1,7 +1,11 @@
 //! This module contains convenience functions that return simple error handlers
 //! for use with [`Commands::queue_handled`](super::Commands::queue_handled) and [`EntityCommands::queue_handled`](super::EntityCommands::queue_handled).
 
-use crate::{result::Error, world::World};
+use crate::{
+    result::{DefaultSystemErrorHandler, Error, SystemErrorContext},
+    world::World,
+};
+use alloc::borrow::Cow;
 use log::{error, warn};
 
 /// An error handler that does nothing.
@@ -25,20 +29,40 @@ pub fn panic() -> fn(&mut World, Error) {
     |_, error| panic!("{error}")
 }
 
-/// The default error handler. This defaults to [`panic()`]. If the
-/// `configurable_error_handler` cargo feature is enabled, then
-/// `GLOBAL_ERROR_HANDLER` will be used instead, enabling error handler customization.
+/// An error handler that passes the error to the world's [`DefaultSystemErrorHandler`],
+/// the same handler used by schedules and observers without a handler of their own.
+///
+/// If the resource is not present, the error causes a panic.
+pub fn world_default() -> fn(&mut World, Error) {
+    |world, error| {
+        let handler = world
+            .get_resource::<DefaultSystemErrorHandler>()
+            .copied()
+            .unwrap_or_default();
+        (handler.0)(
+            error,
+            SystemErrorContext {
+                name: Cow::Borrowed("command"),
+                last_run: world.last_change_tick(),
+            },
+        );
+    }
+}
+
+/// The default error handler. This defaults to [`world_default()`]. If the
+/// `configurable_error_handler` cargo feature is enabled and
+/// `GLOBAL_ERROR_HANDLER` has been set, it will be used instead.
 #[cfg(not(feature = "configurable_error_handler"))]
 #[inline]
 pub fn default() -> fn(&mut World, Error) {
-    panic()
+    world_default()
 }
 
 /// A global error handler. This can be set at startup, as long as it is set before
 /// any uses. This should generally be configured _before_ initializing the app.
 ///
 /// If the `configurable_error_handler` cargo feature is enabled, this will be used
-/// by default.
+/// by default once set.
 ///
 /// This should be set in the following way:
 ///
@@ -51,11 +75,13 @@ pub fn default() -> fn(&mut World, Error) {
 pub static GLOBAL_ERROR_HANDLER: std::sync::OnceLock<fn(&mut World, Error)> =
     std::sync::OnceLock::new();
 
-/// The default error handler. This defaults to [`panic()`]. If the
-/// `configurable_error_handler` cargo feature is enabled, then
-/// [`GLOBAL_ERROR_HANDLER`] will be used instead, enabling error handler customization.
+/// The default error handler. This is [`GLOBAL_ERROR_HANDLER`] if it has been set,
+/// and [`world_default()`] otherwise.
 #[cfg(feature = "configurable_error_handler")]
 #[inline]
 pub fn default() -> fn(&mut World, Error) {
-    *GLOBAL_ERROR_HANDLER.get_or_init(|| panic())
+    GLOBAL_ERROR_HANDLER
+        .get()
+        .copied()
+        .unwrap_or_else(world_default)
 }
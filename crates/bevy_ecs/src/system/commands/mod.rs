@@ -5,7 +5,7 @@ pub mod error_handler;
 #[cfg(feature = "std")]
 mod parallel_scope;
 
-pub use command::Command;
+pub use command::{BatchedCommand, Command};
 pub use entity_command::EntityCommand;
 
 #[cfg(feature = "std")]
@@ -95,8 +95,9 @@ use crate::{
 /// an error handler. Error handlers are functions/closures of the form
 /// `fn(&mut World, CommandError)`.
 ///
-/// The default error handler panics. It can be configured by enabling the `configurable_error_handler`
-/// cargo feature, then setting the `GLOBAL_ERROR_HANDLER`.
+/// The default error handler passes the error to the world's
+/// [`DefaultSystemErrorHandler`](crate::result::DefaultSystemErrorHandler), which panics unless configured otherwise.
+/// It can also be overridden by enabling the `configurable_error_handler` cargo feature, then setting the `GLOBAL_ERROR_HANDLER`.
 ///
 /// Alternatively, you can customize the error handler for a specific command by calling [`Commands::queue_handled`].
 ///
@@ -642,6 +643,26 @@ impl<'w, 's> Commands<'w, 's> {
         self.queue_internal(command.handle_error_with(error_handler));
     }
 
+    /// Pushes a [`BatchedCommand`] to the command queue.
+    ///
+    /// When the queue is applied, this command is applied together with any directly
+    /// neighboring commands of the same type that were also queued with this method.
+    /// See [`BatchedCommand`] for more details.
+    pub fn queue_batched(&mut self, command: impl BatchedCommand) {
+        match &mut self.queue {
+            InternalQueue::CommandQueue(queue) => {
+                queue.push_batched(command);
+            }
+            InternalQueue::RawCommandQueue(queue) => {
+                // SAFETY: `RawCommandQueue` is only every constructed in `Commands::new_raw_from_entities`
+                // where the caller of that has ensured that `queue` outlives `self`
+                unsafe {
+                    queue.push_batched(command);
+                }
+            }
+        }
+    }
+
     fn queue_internal(&mut self, command: impl Command) {
         match &mut self.queue {
             InternalQueue::CommandQueue(queue) => {
@@ -864,6 +885,32 @@ impl<'w, 's> Commands<'w, 's> {
         self.queue(command::insert_resource(resource));
     }
 
+    /// Pushes a [`Command`] to the queue for inserting a [`Resource`] on its resource entity,
+    /// spawning the entity if it doesn't exist.
+    ///
+    /// See [`World::insert_resource_entity`] and the [`resource_entity`](crate::resource_entity)
+    /// module for more details.
+    #[track_caller]
+    pub fn insert_resource_entity<R: Resource + Component>(&mut self, resource: R) {
+        self.queue(command::insert_resource_entity(resource));
+    }
+
+    /// Pushes a [`Command`] to the queue for spawning a resource entity holding a [`Resource`]
+    /// created with [`FromWorld`], unless one already exists.
+    ///
+    /// See [`World::init_resource_entity`] for more details.
+    #[track_caller]
+    pub fn init_resource_entity<R: Resource + Component + FromWorld>(&mut self) {
+        self.queue(command::init_resource_entity::<R>());
+    }
+
+    /// Pushes a [`Command`] to the queue for removing a [`Resource`] from its resource entity.
+    ///
+    /// See [`World::remove_resource_entity`] for more details.
+    pub fn remove_resource_entity<R: Resource + Component>(&mut self) {
+        self.queue(command::remove_resource_entity::<R>());
+    }
+
     /// Pushes a [`Command`] to the queue for removing a [`Resource`] from the [`World`].
     ///
     /// See [`World::remove_resource`] for more details.
@@ -1083,6 +1130,31 @@ impl<'w, 's> Commands<'w, 's> {
         self
     }
 
+    /// Spawns an async task on the [`AsyncComputeTaskPool`] that can access the world
+    /// through the given [`AsyncWorld`].
+    ///
+    /// The task is spawned when this command is applied. Commands, events and world accesses
+    /// queued by the task are applied at the world's next sync point.
+    /// See the [`task`](crate::task) module for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics when applied if the [`AsyncComputeTaskPool`] has not been initialized.
+    ///
+    /// [`AsyncComputeTaskPool`]: bevy_tasks::AsyncComputeTaskPool
+    /// [`AsyncWorld`]: crate::task::AsyncWorld
+    #[cfg(feature = "std")]
+    pub fn spawn_task<F, Fut>(&mut self, task: F) -> &mut Self
+    where
+        F: FnOnce(crate::task::AsyncWorld) -> Fut + Send + 'static,
+        Fut: core::future::Future<Output = ()> + Send + 'static,
+    {
+        self.queue(move |world: &mut World| {
+            crate::task::AsyncWorld::from_world(world).spawn(task);
+        });
+        self
+    }
+
     /// Runs the schedule corresponding to the given [`ScheduleLabel`].
     ///
     /// Calls [`World::try_run_schedule`](World::try_run_schedule).
@@ -1149,8 +1221,9 @@ impl<'w, 's> Commands<'w, 's> {
 /// an error handler. Error handlers are functions/closures of the form
 /// `fn(&mut World, CommandError)`.
 ///
-/// The default error handler panics. It can be configured by enabling the `configurable_error_handler`
-/// cargo feature, then setting the `GLOBAL_ERROR_HANDLER`.
+/// The default error handler passes the error to the world's
+/// [`DefaultSystemErrorHandler`](crate::result::DefaultSystemErrorHandler), which panics unless configured otherwise.
+/// It can also be overridden by enabling the `configurable_error_handler` cargo feature, then setting the `GLOBAL_ERROR_HANDLER`.
 ///
 /// Alternatively, you can customize the error handler for a specific command by calling [`EntityCommands::queue_handled`].
 ///
@@ -1561,6 +1634,93 @@ impl<'a> EntityCommands<'a> {
         )
     }
 
+    /// Adds a [`Bundle`] of components to the entity, batching the insertion with directly
+    /// neighboring batched insertions of the same bundle type.
+    ///
+    /// When the commands are applied, a run of consecutive batched insertions is applied in the
+    /// same way as [`Commands::insert_batch`], which is faster for many insertions. Unlike
+    /// [`Self::insert`], the hooks and observers of the inserted components run and their commands
+    /// are applied once the whole batch has been inserted, rather than after each insertion.
+    ///
+    /// # Panics
+    ///
+    /// The command will panic when applied if the associated entity does not exist.
+    ///
+    /// To avoid a panic in this case, use the command [`Self::try_insert_batched`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// fn heal_all_system(mut commands: Commands, query: Query<Entity>) {
+    ///     for entity in &query {
+    ///         commands.entity(entity).insert_batched(Health(100));
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(heal_all_system);
+    /// ```
+    #[track_caller]
+    pub fn insert_batched(&mut self, bundle: impl Bundle) -> &mut Self {
+        self.insert_with_mode(bundle, InsertMode::Replace, error_handler::default())
+    }
+
+    /// Adds a [`Bundle`] of components to the entity without overwriting, batching the insertion
+    /// with directly neighboring batched insertions of the same bundle type.
+    ///
+    /// This is the same as [`Self::insert_batched`], but in case of duplicate components will leave
+    /// the old values instead of replacing them with new ones.
+    ///
+    /// # Panics
+    ///
+    /// The command will panic when applied if the associated entity does not exist.
+    ///
+    /// To avoid a panic in this case, use the command [`Self::try_insert_if_new_batched`] instead.
+    #[track_caller]
+    pub fn insert_if_new_batched(&mut self, bundle: impl Bundle) -> &mut Self {
+        self.insert_with_mode(bundle, InsertMode::Keep, error_handler::default())
+    }
+
+    /// Tries to add a [`Bundle`] of components to the entity, batching the insertion with directly
+    /// neighboring batched insertions of the same bundle type.
+    ///
+    /// This is the same as [`Self::insert_batched`], but will not panic if the associated entity
+    /// does not exist.
+    #[track_caller]
+    pub fn try_insert_batched(&mut self, bundle: impl Bundle) -> &mut Self {
+        self.insert_with_mode(bundle, InsertMode::Replace, error_handler::silent())
+    }
+
+    /// Tries to add a [`Bundle`] of components to the entity without overwriting, batching the
+    /// insertion with directly neighboring batched insertions of the same bundle type.
+    ///
+    /// This is the same as [`Self::insert_if_new_batched`], but will not panic if the associated
+    /// entity does not exist.
+    #[track_caller]
+    pub fn try_insert_if_new_batched(&mut self, bundle: impl Bundle) -> &mut Self {
+        self.insert_with_mode(bundle, InsertMode::Keep, error_handler::silent())
+    }
+
+    /// Queues a batched insertion, so that consecutive insertions of the same bundle type
+    /// are applied together.
+    #[track_caller]
+    fn insert_with_mode(
+        &mut self,
+        bundle: impl Bundle,
+        mode: InsertMode,
+        error_handler: fn(&mut World, Error),
+    ) -> &mut Self {
+        self.commands.queue_batched(command::insert_bundle(
+            self.entity,
+            bundle,
+            mode,
+            error_handler,
+        ));
+        self
+    }
+
     /// Removes a [`Bundle`] of components from the entity.
     ///
     /// # Example
@@ -2153,8 +2313,10 @@ mod tests {
     use crate::{
         self as bevy_ecs,
         component::{require, Component},
-        system::{Commands, Resource},
-        world::{CommandQueue, FromWorld, World},
+        entity::Entity,
+        observer::Trigger,
+        system::{Commands, ResMut, Resource},
+        world::{CommandQueue, FromWorld, OnAdd, World},
     };
     use alloc::{string::String, sync::Arc, vec, vec::Vec};
     use core::{
@@ -2287,6 +2449,84 @@ mod tests {
         assert_eq!(results3, vec![(42u32, 0u64), (0u32, 42u64)]);
     }
 
+    #[test]
+    fn batched_inserts() {
+        let mut world = World::default();
+        let mut queue = CommandQueue::default();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(W(0u64)).id();
+        let missing = world.spawn_empty().id();
+
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.entity(missing).despawn();
+        commands.entity(a).insert_batched(W(1u32));
+        commands.entity(missing).try_insert_batched(W(2u32));
+        commands.entity(b).insert_batched(W(3u32));
+        commands.entity(a).insert_if_new_batched(W(4u32));
+        commands.entity(a).insert_batched(W(5u32));
+        commands.entity(b).remove::<W<u32>>();
+        commands.entity(b).try_insert_if_new_batched(W(6u32));
+        commands.entity(b).insert_if_new(W(7u32));
+        queue.apply(&mut world);
+
+        assert_eq!(world.get::<W<u32>>(a).unwrap().0, 5);
+        assert_eq!(world.get::<W<u32>>(b).unwrap().0, 6);
+        assert_eq!(world.get::<W<u64>>(b).unwrap().0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "NoSuchEntity")]
+    fn batched_insert_on_missing_entity_panics() {
+        let mut world = World::default();
+        let mut queue = CommandQueue::default();
+        let a = world.spawn_empty().id();
+        let missing = world.spawn_empty().id();
+
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.entity(missing).despawn();
+        commands.entity(a).insert_batched(W(1u32));
+        commands.entity(missing).insert_batched(W(2u32));
+        queue.apply(&mut world);
+    }
+
+    #[test]
+    fn unbatched_inserts_apply_hook_commands_in_order() {
+        #[derive(Resource, Default)]
+        struct Log(Vec<(&'static str, Entity)>);
+
+        let mut world = World::default();
+        world.init_resource::<Log>();
+        world.add_observer(|trigger: Trigger<OnAdd, W<u32>>, mut commands: Commands| {
+            let entity = trigger.target();
+            commands.queue(move |world: &mut World| {
+                world.resource_mut::<Log>().0.push(("command", entity));
+            });
+        });
+        world.add_observer(|trigger: Trigger<OnAdd, W<u32>>, mut log: ResMut<Log>| {
+            log.0.push(("observer", trigger.target()));
+        });
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.entity(a).insert(W(1u32));
+        commands.entity(b).try_insert(W(2u32));
+        queue.apply(&mut world);
+
+        // The commands queued by the observers of each insertion are applied before the next
+        // insertion, as they are without batching.
+        assert_eq!(
+            world.resource::<Log>().0,
+            vec![
+                ("observer", a),
+                ("command", a),
+                ("observer", b),
+                ("command", b),
+            ]
+        );
+    }
+
     #[test]
     fn insert_components() {
         let mut world = World::default();
@@ -2492,15 +2732,15 @@ mod tests {
 
         fn nothing() {}
 
-        assert!(world.iter_resources().count() == 0);
+        let resources = world.iter_resources().count();
         let id = world.register_system_cached(nothing);
-        assert!(world.iter_resources().count() == 1);
+        assert_eq!(world.iter_resources().count(), resources + 1);
         assert!(world.get_entity(id.entity).is_ok());
 
         let mut commands = Commands::new(&mut queue, &world);
         commands.unregister_system_cached(nothing);
         queue.apply(&mut world);
-        assert!(world.iter_resources().count() == 0);
+        assert_eq!(world.iter_resources().count(), resources);
         assert!(world.get_entity(id.entity).is_err());
     }
 
@@ -0,0 +1,387 @@
+use crate::{
+    self as bevy_ecs,
+    component::{ComponentId, Tick},
+    prelude::World,
+    system::{
+        ReadOnlySystemParam, Res, Resource, SystemMeta, SystemParam, SystemParamItem, SystemState,
+    },
+    world::unsafe_world_cell::UnsafeWorldCell,
+};
+use core::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+/// A [`Resource`] holding the [`World`] that [`Extract`] reads from by default.
+///
+/// It is only present while [`World::run_with_source_world`] runs.
+#[derive(Resource, Default)]
+pub struct SourceWorld(World);
+
+impl Deref for SourceWorld {
+    type Target = World;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for SourceWorld {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// The world swapped into [`SourceWorld`] in place of the source world, so that
+/// [`World::run_with_source_world`] doesn't allocate a new [`World`] every time it runs.
+#[derive(Resource, Default)]
+struct ScratchSourceWorld(World);
+
+impl World {
+    /// Runs `f` with `source` moved into the [`SourceWorld`] resource of this world, so that
+    /// systems run by `f` can read from it through [`Extract`].
+    ///
+    /// This is useful to copy data between worlds that are updated separately, for example a
+    /// simulation world and a presentation world.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::system::Extract;
+    /// #[derive(Component, Clone)]
+    /// struct Position(f32);
+    ///
+    /// fn extract_positions(mut commands: Commands, positions: Extract<Query<&Position>>) {
+    ///     for position in &positions {
+    ///         commands.spawn(position.clone());
+    ///     }
+    /// }
+    ///
+    /// let mut simulation = World::new();
+    /// simulation.spawn(Position(1.0));
+    ///
+    /// let mut presentation = World::new();
+    /// let mut schedule = Schedule::default();
+    /// schedule.add_systems(extract_positions);
+    /// presentation.run_with_source_world(&mut simulation, |world| schedule.run(world));
+    ///
+    /// assert_eq!(presentation.query::<&Position>().iter(&presentation).count(), 1);
+    /// ```
+    ///
+    /// `source` is moved back even if `f` panics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` removes the [`SourceWorld`] resource.
+    pub fn run_with_source_world<R>(
+        &mut self,
+        source: &mut World,
+        f: impl FnOnce(&mut World) -> R,
+    ) -> R {
+        let scratch = self
+            .remove_resource::<ScratchSourceWorld>()
+            .unwrap_or_default();
+        let source_world = core::mem::replace(source, scratch.0);
+        self.insert_resource(SourceWorld(source_world));
+
+        let guard = SourceWorldGuard {
+            world: self,
+            source,
+        };
+        let result = f(guard.world);
+        let restored = guard.world.contains_resource::<SourceWorld>();
+        drop(guard);
+        assert!(
+            restored,
+            "The `SourceWorld` resource was removed while it was in use."
+        );
+        result
+    }
+}
+
+/// Moves the [`SourceWorld`] back into the source world when dropped, so that it is restored
+/// when [`World::run_with_source_world`] unwinds.
+struct SourceWorldGuard<'a> {
+    world: &'a mut World,
+    source: &'a mut World,
+}
+
+impl Drop for SourceWorldGuard<'_> {
+    fn drop(&mut self) {
+        // Panicking here while unwinding would abort, so a missing resource is reported by
+        // `run_with_source_world` instead.
+        if let Some(source_world) = self.world.remove_resource::<SourceWorld>() {
+            let scratch = core::mem::replace(self.source, source_world.0);
+            self.world.insert_resource(ScratchSourceWorld(scratch));
+        }
+    }
+}
+
+/// A [`SystemParam`] adapter which applies the contained [`SystemParam`] to another [`World`],
+/// stored in the resource `W`.
+///
+/// By default, this reads from the [`SourceWorld`] provided by [`World::run_with_source_world`],
+/// but any resource that dereferences to a [`World`] can be used.
+/// This is how the render world reads from the main world during extraction, for instance.
+///
+/// This requires that the contained [`SystemParam`] does not mutate the world, as it
+/// uses a read-only reference to the other world internally.
+/// Validation fails when the resource `W` doesn't exist.
+///
+/// # Panics
+///
+/// Panics if the resource `W` doesn't exist when the system is initialized, or if it holds a
+/// different world than the one the system was initialized with.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::Extract;
+/// #[derive(Resource, Clone)]
+/// struct Settings {
+///     volume: f32,
+/// }
+///
+/// fn extract_settings(mut commands: Commands, settings: Extract<Option<Res<Settings>>>) {
+///     if let Some(settings) = &*settings {
+///         commands.insert_resource(Settings::clone(settings));
+///     }
+/// }
+///
+/// let mut source = World::new();
+/// source.insert_resource(Settings { volume: 0.5 });
+///
+/// let mut world = World::new();
+/// world
+///     .run_with_source_world(&mut source, |world| world.run_system_cached(extract_settings))
+///     .unwrap();
+/// assert_eq!(world.resource::<Settings>().volume, 0.5);
+/// ```
+pub struct Extract<'w, 's, P, W = SourceWorld>
+where
+    P: ReadOnlySystemParam + 'static,
+    W: Resource + DerefMut<Target = World>,
+{
+    item: SystemParamItem<'w, 's, P>,
+    marker: PhantomData<fn() -> W>,
+}
+
+#[doc(hidden)]
+pub struct ExtractState<P: SystemParam + 'static> {
+    state: SystemState<P>,
+    source_world_state: ComponentId,
+}
+
+// SAFETY: The only `World` access (`Res<W>`) is read-only.
+unsafe impl<P, W> ReadOnlySystemParam for Extract<'_, '_, P, W>
+where
+    P: ReadOnlySystemParam,
+    W: Resource + DerefMut<Target = World>,
+{
+}
+
+// SAFETY: The only `World` access is properly registered by `Res<W>::init_state`.
+// This call will also ensure that there are no conflicts with prior params.
+unsafe impl<P, W> SystemParam for Extract<'_, '_, P, W>
+where
+    P: ReadOnlySystemParam,
+    W: Resource + DerefMut<Target = World>,
+{
+    type State = ExtractState<P>;
+    type Item<'w, 's> = Extract<'w, 's, P, W>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        let mut source_world = world.resource_mut::<W>();
+        ExtractState {
+            state: SystemState::new(&mut source_world),
+            source_world_state: Res::<W>::init_state(world, system_meta),
+        }
+    }
+
+    #[inline]
+    unsafe fn validate_param(
+        state: &Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell,
+    ) -> bool {
+        // SAFETY: Read-only access to world data registered in `init_state`.
+        let result = unsafe { world.get_resource_by_id(state.source_world_state) };
+        let Some(source_world) = result else {
+            system_meta.try_warn_param::<&World>();
+            return false;
+        };
+        // SAFETY: Type is guaranteed by `ComponentId`.
+        let source_world: &World = unsafe { source_world.deref::<W>() };
+        // SAFETY: We provide the source world on which this system state was initialized on.
+        unsafe {
+            SystemState::<P>::validate_param(
+                &state.state,
+                source_world.as_unsafe_world_cell_readonly(),
+            )
+        }
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        // SAFETY:
+        // - The caller ensures that `world` is the same one that `init_state` was called with.
+        // - The caller ensures that no other `SystemParam`s will conflict with the accesses we have registered.
+        let source_world = unsafe {
+            Res::<W>::get_param(
+                &mut state.source_world_state,
+                system_meta,
+                world,
+                change_tick,
+            )
+        };
+        let item = state.state.get(source_world.into_inner());
+        Extract {
+            item,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'w, 's, P, W> Deref for Extract<'w, 's, P, W>
+where
+    P: ReadOnlySystemParam,
+    W: Resource + DerefMut<Target = World>,
+{
+    type Target = SystemParamItem<'w, 's, P>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.item
+    }
+}
+
+impl<'w, 's, P, W> DerefMut for Extract<'w, 's, P, W>
+where
+    P: ReadOnlySystemParam,
+    W: Resource + DerefMut<Target = World>,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.item
+    }
+}
+
+impl<'a, 'w, 's, P, W> IntoIterator for &'a Extract<'w, 's, P, W>
+where
+    P: ReadOnlySystemParam,
+    W: Resource + DerefMut<Target = World>,
+    &'a SystemParamItem<'w, 's, P>: IntoIterator,
+{
+    type Item = <&'a SystemParamItem<'w, 's, P> as IntoIterator>::Item;
+    type IntoIter = <&'a SystemParamItem<'w, 's, P> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self.item).into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Extract, SourceWorld};
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        prelude::World,
+        schedule::Schedule,
+        system::{Commands, Query, Resource, RunSystemOnce, WithParamWarnPolicy},
+    };
+    use alloc::vec::Vec;
+    use core::ops::{Deref, DerefMut};
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    struct Position(u32);
+
+    #[derive(Resource)]
+    struct Simulation(World);
+
+    impl Deref for Simulation {
+        type Target = World;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl DerefMut for Simulation {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.0
+        }
+    }
+
+    fn extract_positions<W: Resource + DerefMut<Target = World>>(
+        mut commands: Commands,
+        positions: Extract<Query<&Position>, W>,
+    ) {
+        for position in &positions {
+            commands.spawn(position.clone());
+        }
+    }
+
+    #[test]
+    fn source_world() {
+        let mut source = World::new();
+        source.spawn(Position(1));
+        let mut world = World::new();
+        let source_id = source.id();
+
+        world.run_with_source_world(&mut source, |world| {
+            assert_eq!(world.resource::<SourceWorld>().id(), source_id);
+            world
+                .run_system_once(extract_positions::<SourceWorld>)
+                .unwrap();
+        });
+        assert_eq!(source.id(), source_id);
+        assert!(!world.contains_resource::<SourceWorld>());
+        assert_eq!(world.query::<&Position>().iter(&world).count(), 1);
+
+        // The same world is moved in and out every time.
+        world.run_with_source_world(&mut source, |world| {
+            assert_eq!(world.resource::<SourceWorld>().id(), source_id);
+        });
+    }
+
+    #[test]
+    fn source_world_restored_on_panic() {
+        let mut source = World::new();
+        let mut world = World::new();
+        let source_id = source.id();
+
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            world.run_with_source_world(&mut source, |_| panic!("system panicked"));
+        }));
+        assert!(result.is_err());
+        assert_eq!(source.id(), source_id);
+        assert!(!world.contains_resource::<SourceWorld>());
+    }
+
+    #[test]
+    fn custom_source_world() {
+        let mut source = World::new();
+        source.spawn(Position(1));
+        let mut world = World::new();
+        world.insert_resource(Simulation(source));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(extract_positions::<Simulation>.ignore_param_missing());
+        schedule.run(&mut world);
+        assert_eq!(
+            world.query::<&Position>().iter(&world).collect::<Vec<_>>(),
+            [&Position(1)]
+        );
+
+        // Validation fails without the source world.
+        world.remove_resource::<Simulation>();
+        schedule.run(&mut world);
+        assert_eq!(world.query::<&Position>().iter(&world).count(), 1);
+    }
+}
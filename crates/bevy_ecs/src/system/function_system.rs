@@ -545,6 +545,19 @@ impl<Param: SystemParam> SystemState<Param> {
         }
     }
 
+    /// Panics if the archetypes seen by the parameters were invalidated by
+    /// [`World::compact_storage`] since the state was last updated.
+    #[inline]
+    #[track_caller]
+    fn validate_archetypes(&self, world: &World) {
+        assert_eq!(
+            self.archetype_generation.compactions,
+            world.archetypes().generation().compactions,
+            "The archetypes of the World were compacted since this SystemState was last updated. \
+            Call `SystemState::update_archetypes` before this method."
+        );
+    }
+
     /// Updates the state's internal view of the [`World`]'s archetypes. If this is not called before fetching the parameters,
     /// the results may not accurately reflect what is in the `world`.
     ///
@@ -594,6 +607,7 @@ impl<Param: SystemParam> SystemState<Param> {
         Param: ReadOnlySystemParam,
     {
         self.validate_world(world.id());
+        self.validate_archetypes(world);
         let change_tick = world.read_change_tick();
         // SAFETY: Param is read-only and doesn't allow mutable access to World.
         // It also matches the World this SystemState was created with.
@@ -613,6 +627,7 @@ impl<Param: SystemParam> SystemState<Param> {
         world: &'w mut World,
     ) -> SystemParamItem<'w, 's, Param> {
         self.validate_world(world.id());
+        self.validate_archetypes(world);
         let change_tick = world.change_tick();
         // SAFETY: World is uniquely borrowed and matches the World this SystemState was created with.
         unsafe { self.fetch(world.as_unsafe_world_cell(), change_tick) }
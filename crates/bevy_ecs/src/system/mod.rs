@@ -125,6 +125,7 @@ mod combinator;
 mod commands;
 mod exclusive_function_system;
 mod exclusive_system_param;
+mod extract;
 mod function_system;
 mod input;
 mod observer_system;
@@ -143,6 +144,7 @@ pub use combinator::*;
 pub use commands::*;
 pub use exclusive_function_system::*;
 pub use exclusive_system_param::*;
+pub use extract::*;
 pub use function_system::*;
 pub use input::*;
 pub use observer_system::*;
@@ -1,6 +1,8 @@
 use crate::{
     prelude::{Bundle, Trigger},
-    system::System,
+    result::Result,
+    schedule::{Fallible, Infallible},
+    system::{InfallibleSystemWrapper, System},
 };
 
 use super::IntoSystem;
@@ -21,12 +23,15 @@ impl<
 }
 
 /// Implemented for systems that convert into [`ObserverSystem`].
+///
+/// Observer systems may either return `()` or a [`Result`]; systems returning `()`
+/// are wrapped so that they always return `Ok(())`.
 #[diagnostic::on_unimplemented(
     message = "`{Self}` cannot become an `ObserverSystem`",
     label = "the trait `IntoObserverSystem` is not implemented",
     note = "for function `ObserverSystem`s, ensure the first argument is a `Trigger<T>` and any subsequent ones are `SystemParam`"
 )]
-pub trait IntoObserverSystem<E: 'static, B: Bundle, M, Out = ()>: Send + 'static {
+pub trait IntoObserverSystem<E: 'static, B: Bundle, M, Out = Result>: Send + 'static {
     /// The type of [`System`] that this instance converts into.
     type System: ObserverSystem<E, B, Out>;
 
@@ -40,7 +45,7 @@ impl<
         Out,
         E: 'static,
         B: Bundle,
-    > IntoObserverSystem<E, B, M, Out> for S
+    > IntoObserverSystem<E, B, (Fallible, M), Out> for S
 where
     S::System: ObserverSystem<E, B, Out>,
 {
@@ -51,6 +56,18 @@ where
     }
 }
 
+impl<S: IntoSystem<Trigger<'static, E, B>, (), M> + Send + 'static, M, E: 'static, B: Bundle>
+    IntoObserverSystem<E, B, (Infallible, M), Result> for S
+where
+    S::System: ObserverSystem<E, B, ()>,
+{
+    type System = InfallibleSystemWrapper<S::System>;
+
+    fn into_system(this: Self) -> Self::System {
+        InfallibleSystemWrapper::new(IntoSystem::into_system(this))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -11,18 +11,21 @@ use crate::{
 
 use super::IntoSystem;
 
-/// A wrapper system to change a system that returns `()` to return `Ok(())` to make it into a [`ScheduleSystem`]
-pub struct InfallibleSystemWrapper<S: System<In = (), Out = ()>>(S);
+/// A wrapper system to change a system that returns `()` to return `Ok(())`.
+///
+/// This is used to turn infallible systems into a [`ScheduleSystem`], or into a fallible
+/// [`ObserverSystem`](crate::system::ObserverSystem).
+pub struct InfallibleSystemWrapper<S: System<Out = ()>>(S);
 
-impl<S: System<In = (), Out = ()>> InfallibleSystemWrapper<S> {
+impl<S: System<Out = ()>> InfallibleSystemWrapper<S> {
     /// Create a new `OkWrapperSystem`
     pub fn new(system: S) -> Self {
         Self(IntoSystem::into_system(system))
     }
 }
 
-impl<S: System<In = (), Out = ()>> System for InfallibleSystemWrapper<S> {
-    type In = ();
+impl<S: System<Out = ()>> System for InfallibleSystemWrapper<S> {
+    type In = S::In;
     type Out = Result;
 
     #[inline]
@@ -0,0 +1,436 @@
+//! Async tasks that can access the [`World`].
+//!
+//! [`Commands::spawn_task`] spawns a future on the [`AsyncComputeTaskPool`] and hands it an
+//! [`AsyncWorld`]. The task can use it to queue [commands](Command), send [events](Event), or
+//! `await` a closure that runs with exclusive access to the world:
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::task::AsyncWorld;
+//! #[derive(Resource)]
+//! struct LevelName(String);
+//!
+//! #[derive(Event)]
+//! struct LevelLoaded(usize);
+//!
+//! fn load_level(mut commands: Commands) {
+//!     commands.spawn_task(|world: AsyncWorld| async move {
+//!         let name = world
+//!             .run(|world: &mut World| world.resource::<LevelName>().0.clone())
+//!             .await
+//!             .unwrap();
+//!
+//!         // Do some expensive work without blocking the schedule.
+//!         let size = name.len();
+//!
+//!         world.send_event(LevelLoaded(size));
+//!     });
+//! }
+//! ```
+//!
+//! Everything a task queues is applied to the world at its next sync point: whenever the world
+//! flushes its own commands, such as when [`ApplyDeferred`] applies system buffers or before an
+//! exclusive system runs. [`apply_task_commands`] applies them explicitly, and `bevy_app` also runs
+//! it at the start of every frame.
+//!
+//! [`AsyncWorld`] is also a [`SystemParam`], so systems can build a future themselves and return
+//! it. Such systems are [piped](crate::system::IntoSystem::pipe) into [`spawn_task`] to spawn it.
+//!
+//! [`AsyncComputeTaskPool`]: bevy_tasks::AsyncComputeTaskPool
+//! [`ApplyDeferred`]: crate::schedule::ApplyDeferred
+//! [`Commands::spawn_task`]: crate::system::Commands::spawn_task
+
+use crate::{
+    component::Tick,
+    event::Event,
+    system::{command, Command, In, ReadOnlySystemParam, SystemMeta, SystemParam},
+    world::{unsafe_world_cell::UnsafeWorldCell, CommandQueue, FromWorld, World},
+};
+use bevy_tasks::AsyncComputeTaskPool;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use spin::Mutex;
+use thiserror::Error;
+
+#[cfg(feature = "portable-atomic")]
+use {
+    portable_atomic::{AtomicBool, Ordering},
+    portable_atomic_util::{Arc, Weak},
+};
+
+#[cfg(not(feature = "portable-atomic"))]
+use {
+    alloc::sync::{Arc, Weak},
+    core::sync::atomic::{AtomicBool, Ordering},
+};
+
+/// The commands queued by tasks, shared between a [`World`] and its [`AsyncWorld`] handles.
+pub(crate) type SharedTaskCommands = Arc<TaskCommands>;
+
+/// The commands queued by tasks, waiting to be applied at the world's next sync point.
+#[derive(Default)]
+pub(crate) struct TaskCommands {
+    /// Set whenever a command is queued, so flushing the world doesn't need to take the lock.
+    pending: AtomicBool,
+    queue: Mutex<CommandQueue>,
+}
+
+impl TaskCommands {
+    fn push<C: Command>(&self, command: C) {
+        let mut queue = self.queue.lock();
+        queue.push(command);
+        self.pending.store(true, Ordering::Release);
+    }
+
+    /// Takes the queued commands, if any were queued since the last call.
+    pub(crate) fn take(&self) -> Option<CommandQueue> {
+        if !self.pending.swap(false, Ordering::Acquire) {
+            return None;
+        }
+        Some(core::mem::take(&mut *self.queue.lock()))
+    }
+}
+
+/// A handle that lets an async task access the [`World`].
+///
+/// Everything queued through this handle is applied at the world's next sync point, see the
+/// [module-level documentation](self).
+/// Tasks spawned with [`Commands::spawn_task`] are given one. It can also be used as a
+/// [`SystemParam`], or created with [`FromWorld`] to hand to tasks spawned on other pools.
+///
+/// The handle doesn't keep the world alive: once the world is dropped, everything queued through
+/// it is discarded.
+///
+/// See the [module-level documentation](self) for an example.
+///
+/// [`Commands::spawn_task`]: crate::system::Commands::spawn_task
+#[derive(Clone)]
+pub struct AsyncWorld {
+    queue: Weak<TaskCommands>,
+}
+
+impl FromWorld for AsyncWorld {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            queue: Arc::downgrade(world.task_commands.get_or_insert_with(Default::default)),
+        }
+    }
+}
+
+impl AsyncWorld {
+    /// Queues a [`Command`] to be applied to the world.
+    ///
+    /// The command is dropped if the world has been dropped.
+    pub fn queue<C: Command>(&self, command: C) {
+        if let Some(queue) = self.queue.upgrade() {
+            queue.push(command);
+        }
+    }
+
+    /// Returns `true` if the world this handle was created from has been dropped.
+    pub fn is_closed(&self) -> bool {
+        self.queue.strong_count() == 0
+    }
+
+    /// Queues an [`Event`] to be sent.
+    #[track_caller]
+    pub fn send_event<E: Event>(&self, event: E) {
+        self.queue(command::send_event(event));
+    }
+
+    /// Runs `f` with exclusive access to the world, returning a future that resolves to its result.
+    ///
+    /// The future resolves to a [`WorldDroppedError`] if the world is dropped, or `f` panics,
+    /// before the result is available.
+    pub fn run<F, R>(&self, f: F) -> WorldAccess<R>
+    where
+        F: FnOnce(&mut World) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let slot = Arc::new(Mutex::new(Slot {
+            value: None,
+            waker: None,
+            closed: false,
+        }));
+        let sender = Sender(slot.clone());
+        self.queue(move |world: &mut World| sender.send(f(world)));
+        WorldAccess { slot }
+    }
+
+    /// Spawns another task on the [`AsyncComputeTaskPool`] that has access to the same world.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`AsyncComputeTaskPool`] has not been initialized.
+    pub fn spawn<F, Fut>(&self, task: F)
+    where
+        F: FnOnce(AsyncWorld) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        AsyncComputeTaskPool::get()
+            .spawn(task(self.clone()))
+            .detach();
+    }
+}
+
+// SAFETY: no world access
+unsafe impl SystemParam for AsyncWorld {
+    type State = AsyncWorld;
+    type Item<'w, 's> = AsyncWorld;
+
+    fn init_state(world: &mut World, _system_meta: &mut SystemMeta) -> Self::State {
+        AsyncWorld::from_world(world)
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        _system_meta: &SystemMeta,
+        _world: UnsafeWorldCell<'w>,
+        _change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        state.clone()
+    }
+}
+
+// SAFETY: no world access
+unsafe impl ReadOnlySystemParam for AsyncWorld {}
+
+/// The state shared between a [`WorldAccess`] and the command that fulfills it.
+struct Slot<R> {
+    value: Option<R>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+/// Fulfills a [`WorldAccess`], closing it when dropped.
+struct Sender<R>(Arc<Mutex<Slot<R>>>);
+
+impl<R> Sender<R> {
+    fn send(self, value: R) {
+        self.0.lock().value = Some(value);
+    }
+}
+
+impl<R> Drop for Sender<R> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut slot = self.0.lock();
+            slot.closed = true;
+            slot.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// A future that resolves to the result of [`AsyncWorld::run`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WorldAccess<R> {
+    slot: Arc<Mutex<Slot<R>>>,
+}
+
+impl<R> Future for WorldAccess<R> {
+    type Output = Result<R, WorldDroppedError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.slot.lock();
+        if let Some(value) = slot.value.take() {
+            Poll::Ready(Ok(value))
+        } else if slot.closed {
+            Poll::Ready(Err(WorldDroppedError))
+        } else {
+            slot.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// An error returned by [`WorldAccess`] when the world access it was waiting for never ran.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("The world access was dropped before it could run")]
+pub struct WorldDroppedError;
+
+/// Applies the commands queued by tasks spawned with [`Commands::spawn_task`].
+///
+/// Task commands are also applied whenever the world flushes its commands, so this is only needed
+/// to apply them at a point without other sync points. It is added to the `First` schedule by
+/// `bevy_app`'s `TaskPoolPlugin`.
+///
+/// [`Commands::spawn_task`]: crate::system::Commands::spawn_task
+pub fn apply_task_commands(world: &mut World) {
+    world.flush();
+}
+
+/// Spawns the future returned by the piped system on the [`AsyncComputeTaskPool`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::task::{spawn_task, AsyncWorld};
+/// # use core::future::Future;
+/// #[derive(Resource)]
+/// struct Seed(u64);
+///
+/// fn generate(seed: Res<Seed>, world: AsyncWorld) -> impl Future<Output = ()> + Send {
+///     let seed = seed.0;
+///     async move {
+///         let value = seed.wrapping_mul(31);
+///         world.queue(move |world: &mut World| world.insert_resource(Seed(value)));
+///     }
+/// }
+///
+/// # let mut schedule = Schedule::default();
+/// schedule.add_systems(generate.pipe(spawn_task));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the [`AsyncComputeTaskPool`] has not been initialized.
+pub fn spawn_task<Fut>(In(task): In<Fut>)
+where
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    AsyncComputeTaskPool::get().spawn(task).detach();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_task_commands, spawn_task, AsyncWorld, WorldAccess, WorldDroppedError};
+    use crate::{
+        self as bevy_ecs,
+        event::{Event, Events},
+        schedule::{ApplyDeferred, IntoSystemConfigs, Schedule},
+        system::{Commands, IntoSystem, Res, ResMut, Resource, RunSystemOnce},
+        world::{FromWorld, World},
+    };
+    use alloc::vec::Vec;
+    use bevy_tasks::{block_on, AsyncComputeTaskPool, TaskPool};
+    use core::future::Future;
+
+    #[derive(Resource, Clone, Copy, PartialEq, Eq, Debug)]
+    struct Value(u32);
+
+    #[derive(Event, PartialEq, Eq, Debug)]
+    struct Done(u32);
+
+    /// Ticks the task pool and applies task commands until `done` returns `true`.
+    fn run_until(world: &mut World, done: impl Fn(&World) -> bool) {
+        let pool = AsyncComputeTaskPool::get_or_init(TaskPool::default);
+        for _ in 0..10_000 {
+            pool.with_local_executor(|executor| while executor.try_tick() {});
+            apply_task_commands(world);
+            if done(world) {
+                return;
+            }
+            std::thread::yield_now();
+        }
+        panic!("tasks did not finish");
+    }
+
+    #[test]
+    fn spawn_task_accesses_world() {
+        AsyncComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        world.init_resource::<Events<Done>>();
+        world.insert_resource(Value(2));
+
+        world.commands().spawn_task(|world: AsyncWorld| async move {
+            let Value(value) = world
+                .run(|world: &mut World| *world.resource::<Value>())
+                .await
+                .unwrap();
+            let doubled = world
+                .run(move |world: &mut World| {
+                    world.insert_resource(Value(value * 2));
+                    value * 2
+                })
+                .await
+                .unwrap();
+            world.send_event(Done(doubled));
+        });
+        world.flush();
+
+        run_until(&mut world, |world| {
+            !world.resource::<Events<Done>>().is_empty()
+        });
+
+        assert_eq!(*world.resource::<Value>(), Value(4));
+        let events = world.resource::<Events<Done>>();
+        assert_eq!(
+            events.iter_current_update_events().collect::<Vec<_>>(),
+            [&Done(4)]
+        );
+    }
+
+    #[test]
+    fn task_commands_are_applied_at_sync_points() {
+        #[derive(Resource)]
+        struct Pending(WorldAccess<u32>);
+
+        fn queue_access(mut commands: Commands, world: AsyncWorld) {
+            let access = world.run(|world: &mut World| {
+                world.insert_resource(Value(5));
+                5
+            });
+            commands.insert_resource(Pending(access));
+        }
+
+        fn resolve_access(mut pending: ResMut<Pending>, value: Option<Res<Value>>) {
+            assert_eq!(value.as_deref(), Some(&Value(5)));
+            assert_eq!(block_on(&mut pending.0), Ok(5));
+        }
+
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((queue_access, ApplyDeferred, resolve_access).chain());
+        schedule.run(&mut world);
+    }
+
+    #[test]
+    fn world_access_fails_when_world_is_dropped() {
+        let mut world = World::new();
+        let async_world = AsyncWorld::from_world(&mut world);
+        let access = async_world.run(|_: &mut World| 1);
+        assert!(!async_world.is_closed());
+
+        drop(world);
+        assert!(async_world.is_closed());
+        assert_eq!(block_on(access), Err(WorldDroppedError));
+        assert_eq!(
+            block_on(async_world.run(|_: &mut World| 2)),
+            Err(WorldDroppedError)
+        );
+    }
+
+    #[test]
+    fn piped_task() {
+        AsyncComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        world.insert_resource(Value(1));
+
+        fn task(value: Res<Value>, world: AsyncWorld) -> impl Future<Output = ()> + Send {
+            let next = Value(value.0 + 1);
+            async move {
+                world.queue(move |world: &mut World| world.insert_resource(next));
+            }
+        }
+
+        world.run_system_once(task.pipe(spawn_task)).unwrap();
+        run_until(&mut world, |world| *world.resource::<Value>() == Value(2));
+
+        world
+            .run_system_once(|mut commands: Commands| {
+                commands.spawn_task(|world: AsyncWorld| async move {
+                    world.spawn(|world: AsyncWorld| async move {
+                        world.queue(|world: &mut World| world.insert_resource(Value(10)));
+                    });
+                });
+            })
+            .unwrap();
+        run_until(&mut world, |world| *world.resource::<Value>() == Value(10));
+    }
+}
@@ -1,10 +1,11 @@
 use crate::{
-    system::{Command, SystemBuffer, SystemMeta},
+    system::{BatchedCommand, Command, SystemBuffer, SystemMeta},
     world::{DeferredWorld, World},
 };
 use alloc::{boxed::Box, vec::Vec};
 use bevy_ptr::{OwningPtr, Unaligned};
 use core::{
+    any::TypeId,
     fmt::Debug,
     mem::{size_of, MaybeUninit},
     panic::AssertUnwindSafe,
@@ -21,6 +22,32 @@ struct CommandMeta {
     /// Advances `cursor` by the size of `T` in bytes.
     consume_command_and_get_size:
         unsafe fn(value: OwningPtr<Unaligned>, world: Option<NonNull<World>>, cursor: &mut usize),
+    /// Present for commands pushed with [`RawCommandQueue::push_batched`].
+    batch: Option<BatchMeta>,
+    /// The type name of the command, used to inspect queued commands while stepping.
+    #[cfg(feature = "bevy_debug_stepping")]
+    type_name: fn() -> &'static str,
+    /// The size of the command in bytes, used to skip over commands while inspecting the queue.
+    #[cfg(feature = "bevy_debug_stepping")]
+    size: usize,
+}
+
+#[derive(Clone, Copy)]
+struct BatchMeta {
+    /// Identifies the [`BatchedCommand`] type `T`. Consecutive commands are only
+    /// consumed together if their types are identical.
+    type_id: fn() -> TypeId,
+    /// SAFETY: `cursor` must point to a value of type `T: BatchedCommand`, directly after its
+    /// [`CommandMeta`], and `stop` must be the end of the commands that are being applied.
+    ///
+    /// Reads that command and every directly following command of type `T` before `stop`,
+    /// advancing `cursor` past all of them, then applies them as one batch.
+    consume_batch: unsafe fn(
+        bytes: NonNull<Vec<MaybeUninit<u8>>>,
+        world: NonNull<World>,
+        cursor: &mut usize,
+        stop: usize,
+    ),
 }
 
 /// Densely and efficiently stores a queue of heterogenous types implementing [`Command`].
@@ -79,6 +106,18 @@ impl CommandQueue {
         }
     }
 
+    /// Push a [`BatchedCommand`] onto the queue.
+    ///
+    /// When the queue is applied, runs of consecutive commands of the same type
+    /// are passed to [`BatchedCommand::apply_batch`] together.
+    #[inline]
+    pub fn push_batched(&mut self, command: impl BatchedCommand) {
+        // SAFETY: self is guaranteed to live for the lifetime of this method
+        unsafe {
+            self.get_raw().push_batched(command);
+        }
+    }
+
     /// Execute the queued [`Command`]s in the world after applying any commands in the world's internal queue.
     /// This clears the queue.
     #[inline]
@@ -95,6 +134,79 @@ impl CommandQueue {
         }
     }
 
+    /// Execute the next queued [`Command`] in the world after applying any commands in the world's internal queue.
+    /// Returns `false` if the queue is empty.
+    ///
+    /// Like [`CommandQueue::apply`], a [`BatchedCommand`] is applied together with every directly
+    /// following command of the same type, so this may consume several commands at once.
+    pub(crate) fn apply_next(&mut self, world: &mut World) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        world.flush_entities();
+        world.flush_commands();
+
+        // SAFETY: The queue is not empty, so `cursor` points at the metadata of the next command,
+        // which is immediately followed by the command itself.
+        unsafe {
+            let meta = self
+                .bytes
+                .as_mut_ptr()
+                .add(self.cursor)
+                .cast::<CommandMeta>()
+                .read_unaligned();
+            self.cursor += size_of::<CommandMeta>();
+            if let Some(batch) = meta.batch {
+                let stop = self.bytes.len();
+                // `batch` was created for the type of this command by `.push_batched()`, and the
+                // cursor points to the command. The whole batch is read, advancing the cursor past
+                // it, before it is applied, so none of its commands are dropped again on panic.
+                (batch.consume_batch)(
+                    NonNull::from(&mut self.bytes),
+                    world.into(),
+                    &mut self.cursor,
+                    stop,
+                );
+            } else {
+                let command = OwningPtr::<Unaligned>::new(NonNull::new_unchecked(
+                    self.bytes.as_mut_ptr().add(self.cursor).cast(),
+                ));
+                // The cursor is advanced past the command before it is applied, so it won't be
+                // dropped again if applying it panics.
+                (meta.consume_command_and_get_size)(command, Some(world.into()), &mut self.cursor);
+            }
+        }
+
+        if self.is_empty() {
+            // SAFETY: All commands have been consumed.
+            unsafe { self.bytes.set_len(0) };
+            self.cursor = 0;
+        }
+        true
+    }
+
+    /// Returns the type names of the queued commands, in the order they will be applied.
+    #[cfg(feature = "bevy_debug_stepping")]
+    pub fn command_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        let mut cursor = self.cursor;
+        core::iter::from_fn(move || {
+            if cursor >= self.bytes.len() {
+                return None;
+            }
+            // SAFETY: `cursor` points at the metadata of a queued command.
+            let meta = unsafe {
+                self.bytes
+                    .as_ptr()
+                    .add(cursor)
+                    .cast::<CommandMeta>()
+                    .read_unaligned()
+            };
+            cursor += size_of::<CommandMeta>() + meta.size;
+            Some((meta.type_name)())
+        })
+    }
+
     /// Take all commands from `other` and append them to `self`, leaving `other` empty
     pub fn append(&mut self, other: &mut CommandQueue) {
         self.bytes.append(&mut other.bytes);
@@ -149,6 +261,31 @@ impl RawCommandQueue {
     /// * Caller ensures that `self` has not outlived the underlying queue
     #[inline]
     pub unsafe fn push<C: Command>(&mut self, command: C) {
+        // SAFETY: Caller ensures that `self` has not outlived the underlying queue
+        unsafe { self.push_with_batch(command, None) }
+    }
+
+    /// Push a [`BatchedCommand`] onto the queue.
+    ///
+    /// # Safety
+    ///
+    /// * Caller ensures that `self` has not outlived the underlying queue
+    #[inline]
+    pub unsafe fn push_batched<C: BatchedCommand>(&mut self, command: C) {
+        let batch = BatchMeta {
+            type_id: TypeId::of::<C>,
+            consume_batch: consume_batch::<C>,
+        };
+        // SAFETY: Caller ensures that `self` has not outlived the underlying queue
+        unsafe { self.push_with_batch(command, Some(batch)) }
+    }
+
+    /// # Safety
+    ///
+    /// * Caller ensures that `self` has not outlived the underlying queue
+    /// * If `batch` is [`Some`], it must have been created for the type `C`
+    #[inline]
+    unsafe fn push_with_batch<C: Command>(&mut self, command: C, batch: Option<BatchMeta>) {
         // Stores a command alongside its metadata.
         // `repr(C)` prevents the compiler from reordering the fields,
         // while `repr(packed)` prevents the compiler from inserting padding bytes.
@@ -180,6 +317,11 @@ impl RawCommandQueue {
                     None => drop(command),
                 }
             },
+            batch,
+            #[cfg(feature = "bevy_debug_stepping")]
+            type_name: core::any::type_name::<C>,
+            #[cfg(feature = "bevy_debug_stepping")]
+            size: size_of::<C>(),
         };
 
         // SAFETY: There are no outstanding references to self.bytes
@@ -253,14 +395,21 @@ impl RawCommandQueue {
                     self.bytes.as_mut().as_mut_ptr().add(local_cursor).cast(),
                 ))
             };
-            let f = AssertUnwindSafe(|| {
+            let f = AssertUnwindSafe(|| match (meta.batch, world) {
+                // SAFETY: `batch` was created for the type of this command by `.push_batched()`,
+                // and `local_cursor` points to the command, directly after its metadata.
+                // This advances the cursor past every command in the batch, which never extends past `stop`,
+                // so commands queued while applying the batch are left alone.
+                (Some(batch), Some(world)) => unsafe {
+                    (batch.consume_batch)(self.bytes, world, &mut local_cursor, stop);
+                },
                 // SAFETY: The data underneath the cursor must correspond to the type erased in metadata,
                 // since they were stored next to each other by `.push()`.
                 // For ZSTs, the type doesn't matter as long as the pointer is non-null.
                 // This also advances the cursor past the command. For ZSTs, the cursor will not move.
                 // At this point, it will either point to the next `CommandMeta`,
                 // or the cursor will be out of bounds and the loop will end.
-                unsafe { (meta.consume_command_and_get_size)(cmd, world, &mut local_cursor) };
+                _ => unsafe { (meta.consume_command_and_get_size)(cmd, world, &mut local_cursor) },
             });
 
             #[cfg(feature = "std")]
@@ -308,6 +457,62 @@ impl RawCommandQueue {
     }
 }
 
+/// Consumes a run of consecutive commands of type `C` and applies them with [`BatchedCommand::apply_batch`].
+///
+/// # Safety
+///
+/// See [`BatchMeta::consume_batch`].
+unsafe fn consume_batch<C: BatchedCommand>(
+    bytes: NonNull<Vec<MaybeUninit<u8>>>,
+    mut world: NonNull<World>,
+    cursor: &mut usize,
+    stop: usize,
+) {
+    let mut batch = Vec::new();
+    loop {
+        // SAFETY: The caller ensures that `cursor` points to a command of type `C` on the first iteration.
+        // On later iterations, the type id stored in the preceding metadata matched `C`.
+        // Advancing the cursor below guarantees that the command is never read again.
+        let command: C = unsafe {
+            bytes
+                .as_ref()
+                .as_ptr()
+                .add(*cursor)
+                .cast::<C>()
+                .read_unaligned()
+        };
+        *cursor += size_of::<C>();
+        batch.push(command);
+
+        if *cursor >= stop {
+            break;
+        }
+        // SAFETY: The cursor is in bounds and just after the previous command,
+        // so it must point to the start of a new command.
+        let meta = unsafe {
+            bytes
+                .as_ref()
+                .as_ptr()
+                .add(*cursor)
+                .cast::<CommandMeta>()
+                .read_unaligned()
+        };
+        if meta
+            .batch
+            .is_none_or(|batch| (batch.type_id)() != TypeId::of::<C>())
+        {
+            break;
+        }
+        *cursor += size_of::<CommandMeta>();
+    }
+
+    // SAFETY: Caller ensures pointer is not null
+    let world = unsafe { world.as_mut() };
+    C::apply_batch(batch, world);
+    // Like single commands, the batch may have queued up world commands, which are flushed here.
+    world.flush();
+}
+
 impl Drop for CommandQueue {
     fn drop(&mut self) {
         if !self.bytes.is_empty() {
@@ -323,6 +528,17 @@ impl SystemBuffer for CommandQueue {
     fn apply(&mut self, _system_meta: &SystemMeta, world: &mut World) {
         #[cfg(feature = "trace")]
         let _span_guard = _system_meta.commands_span.enter();
+        #[cfg(feature = "bevy_debug_stepping")]
+        {
+            use crate::{change_detection::DetectChangesMut, schedule::Stepping};
+
+            // Commands of a stepped system are applied by `Stepping` during the next steps.
+            if let Some(mut stepping) = world.get_resource_mut::<Stepping>() {
+                if stepping.bypass_change_detection().capture_commands(self) {
+                    return;
+                }
+            }
+        }
         self.apply(world);
     }
 
@@ -336,7 +552,7 @@ impl SystemBuffer for CommandQueue {
 mod test {
     use super::*;
     use crate::{self as bevy_ecs, system::Resource};
-    use alloc::{borrow::ToOwned, string::String, sync::Arc};
+    use alloc::{borrow::ToOwned, string::String, sync::Arc, vec};
     use core::{
         panic::AssertUnwindSafe,
         sync::atomic::{AtomicU32, Ordering},
@@ -496,6 +712,146 @@ mod test {
         assert_eq!(&world.resource::<Order>().0, &[1, 2, 3, 4, 5]);
     }
 
+    #[derive(Resource, Default)]
+    struct Batches(Vec<Vec<u32>>);
+
+    struct Record(u32);
+
+    impl Command for Record {
+        fn apply(self, world: &mut World) {
+            world.resource_mut::<Batches>().0.push(vec![self.0]);
+        }
+    }
+
+    impl BatchedCommand for Record {
+        fn apply_batch(batch: Vec<Self>, world: &mut World) {
+            let values = batch.into_iter().map(|record| record.0).collect();
+            world.resource_mut::<Batches>().0.push(values);
+        }
+    }
+
+    struct RecordOther(u32);
+
+    impl Command for RecordOther {
+        fn apply(self, world: &mut World) {
+            world.resource_mut::<Batches>().0.push(vec![self.0]);
+        }
+    }
+
+    impl BatchedCommand for RecordOther {
+        fn apply_batch(batch: Vec<Self>, world: &mut World) {
+            let values = batch.into_iter().map(|record| record.0).collect();
+            world.resource_mut::<Batches>().0.push(values);
+        }
+    }
+
+    #[test]
+    fn test_command_queue_batches_consecutive_commands() {
+        let mut world = World::new();
+        world.init_resource::<Batches>();
+
+        let mut queue = CommandQueue::default();
+        queue.push_batched(Record(1));
+        queue.push_batched(Record(2));
+        queue.push(Record(3));
+        queue.push_batched(Record(4));
+        queue.push_batched(RecordOther(5));
+        queue.push_batched(RecordOther(6));
+        queue.push_batched(Record(7));
+        queue.apply(&mut world);
+
+        assert_eq!(
+            world.resource::<Batches>().0,
+            vec![vec![1, 2], vec![3], vec![4], vec![5, 6], vec![7]]
+        );
+    }
+
+    #[test]
+    fn test_command_queue_apply_next_applies_batches() {
+        let mut world = World::new();
+        world.init_resource::<Batches>();
+
+        let mut queue = CommandQueue::default();
+        queue.push_batched(Record(1));
+        queue.push_batched(Record(2));
+        queue.push(Record(3));
+        queue.push_batched(RecordOther(4));
+
+        assert!(queue.apply_next(&mut world));
+        assert_eq!(world.resource::<Batches>().0, vec![vec![1, 2]]);
+        assert!(queue.apply_next(&mut world));
+        assert!(queue.apply_next(&mut world));
+        assert!(!queue.apply_next(&mut world));
+        assert_eq!(
+            world.resource::<Batches>().0,
+            vec![vec![1, 2], vec![3], vec![4]]
+        );
+    }
+
+    #[test]
+    fn test_command_queue_batch_excludes_commands_queued_while_applying() {
+        let mut world = World::new();
+        world.init_resource::<Batches>();
+
+        world.commands().queue_batched(Record(1));
+        world.commands().queue(|world: &mut World| {
+            world.commands().queue_batched(Record(2));
+            world.commands().queue_batched(Record(3));
+        });
+        world.commands().queue_batched(Record(4));
+        world.flush_commands();
+
+        assert_eq!(
+            world.resource::<Batches>().0,
+            vec![vec![1], vec![2, 3], vec![4]]
+        );
+    }
+
+    #[expect(
+        dead_code,
+        reason = "The inner `DropCheck` is only used to count how often the command is dropped."
+    )]
+    struct PanicBatch(DropCheck);
+
+    impl Command for PanicBatch {
+        fn apply(self, _: &mut World) {
+            panic!("command is panicking");
+        }
+    }
+
+    impl BatchedCommand for PanicBatch {
+        fn apply_batch(_: Vec<Self>, _: &mut World) {
+            panic!("batch is panicking");
+        }
+    }
+
+    #[test]
+    fn test_command_queue_batch_panic_safe() {
+        std::panic::set_hook(Box::new(|_| {}));
+
+        let mut queue = CommandQueue::default();
+
+        let (dropcheck_a, drops_a) = DropCheck::new();
+        let (dropcheck_b, drops_b) = DropCheck::new();
+        queue.push_batched(PanicBatch(dropcheck_a));
+        queue.push_batched(PanicBatch(dropcheck_b));
+        queue.push(SpawnCommand);
+
+        let mut world = World::new();
+
+        let _ = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            queue.apply(&mut world);
+        }));
+
+        // The whole batch was consumed and dropped exactly once.
+        assert_eq!(drops_a.load(Ordering::Relaxed), 1);
+        assert_eq!(drops_b.load(Ordering::Relaxed), 1);
+
+        queue.push(SpawnCommand);
+        queue.apply(&mut world);
+        assert_eq!(world.entities().len(), 2);
+    }
+
     // NOTE: `CommandQueue` is `Send` because `Command` is send.
     // If the `Command` trait gets reworked to be non-send, `CommandQueue`
     // should be reworked.
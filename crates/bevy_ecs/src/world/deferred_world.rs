@@ -6,7 +6,7 @@ use crate::{
     component::{ComponentId, Mutable},
     entity::Entity,
     event::{Event, EventId, Events, SendBatchIds},
-    observer::{Observers, TriggerTargets},
+    observer::{Observers, Propagation, TriggerTargets},
     prelude::{Component, QueryState},
     query::{QueryData, QueryFilter},
     system::{Commands, Query, Resource},
@@ -597,7 +597,7 @@ impl<'w> DeferredWorld<'w> {
             target,
             components,
             &mut (),
-            &mut false,
+            &mut Propagation::default(),
         );
     }
 
@@ -612,10 +612,14 @@ impl<'w> DeferredWorld<'w> {
         mut target: Entity,
         components: &[ComponentId],
         data: &mut E,
-        mut propagate: bool,
+        propagate: bool,
     ) where
         T: Traversal<E>,
     {
+        let mut propagation = Propagation {
+            propagate,
+            stopped: false,
+        };
         loop {
             Observers::invoke::<_>(
                 self.reborrow(),
@@ -623,9 +627,9 @@ impl<'w> DeferredWorld<'w> {
                 target,
                 components.iter().copied(),
                 data,
-                &mut propagate,
+                &mut propagation,
             );
-            if !propagate {
+            if !propagation.propagate || propagation.stopped {
                 break;
             }
             if let Some(traverse_to) = self
@@ -1755,6 +1755,7 @@ impl<'w> EntityWorldMut<'w> {
                 )
             })
         };
+        components.record_archetype_moves(bundle_info.iter_explicit_components());
 
         #[expect(
             clippy::undocumented_unsafe_blocks,
@@ -1912,6 +1913,11 @@ impl<'w> EntityWorldMut<'w> {
         }
 
         let old_archetype = &world.archetypes[location.archetype_id];
+        world.components.record_archetype_moves(
+            bundle_info
+                .iter_explicit_components()
+                .filter(|component_id| old_archetype.contains(*component_id)),
+        );
         for component_id in bundle_info.iter_explicit_components() {
             if old_archetype.contains(component_id) {
                 world.removed_components.send(component_id, entity);
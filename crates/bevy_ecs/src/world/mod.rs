@@ -13,6 +13,10 @@ pub mod unsafe_world_cell;
 
 #[cfg(feature = "bevy_reflect")]
 pub mod reflect;
+#[cfg(feature = "bevy_reflect")]
+mod snapshot;
+mod storage_migration;
+mod transfer;
 
 pub use crate::{
     change_detection::{Mut, Ref, CHECK_TICK_THRESHOLD},
@@ -27,7 +31,10 @@ pub use entity_ref::{
 };
 pub use filtered_resource::*;
 pub use identifier::WorldId;
+#[cfg(feature = "bevy_reflect")]
+pub use snapshot::{SnapshotError, SnapshotFilter, WorldSnapshot};
 pub use spawn_batch::*;
+pub use transfer::EntityTransfer;
 
 use crate::{
     archetype::{ArchetypeId, ArchetypeRow, Archetypes},
@@ -39,6 +46,7 @@ use crate::{
         RequiredComponentsError, Tick,
     },
     entity::{AllocAtWithoutReplacement, Entities, Entity, EntityLocation},
+    entity_disabling::DefaultQueryFilters,
     event::{Event, EventId, Events, SendBatchIds},
     observer::Observers,
     query::{DebugCheckedUnwrap, QueryData, QueryFilter, QueryState},
@@ -103,6 +111,8 @@ pub struct World {
     pub(crate) last_check_tick: Tick,
     pub(crate) last_trigger_id: u32,
     pub(crate) command_queue: RawCommandQueue,
+    #[cfg(feature = "std")]
+    pub(crate) task_commands: Option<crate::task::SharedTaskCommands>,
 }
 
 impl Default for World {
@@ -123,6 +133,8 @@ impl Default for World {
             last_check_tick: Tick::new(0),
             last_trigger_id: 0,
             command_queue: RawCommandQueue::new(),
+            #[cfg(feature = "std")]
+            task_commands: None,
         };
         world.bootstrap();
         world
@@ -159,6 +171,9 @@ impl World {
 
         let on_remove = OnRemove::register_component_id(self);
         assert_eq!(ON_REMOVE, on_remove);
+
+        // This sets up `Disabled` as a disabling component, via the FromWorld impl
+        self.init_resource::<DefaultQueryFilters>();
     }
     /// Creates a new empty [`World`].
     ///
@@ -1097,8 +1112,23 @@ impl World {
         self.entities
             .set_spawned_or_despawned_by(entity.index(), Location::caller());
 
+        // SAFETY: command_queue is not referenced anywhere else
+        let has_queued_commands = !unsafe { self.command_queue.is_empty() };
+
         // SAFETY: entity and location are valid, as they were just created above
-        unsafe { EntityWorldMut::new(self, entity, entity_location) }
+        let mut entity = unsafe { EntityWorldMut::new(self, entity, entity_location) };
+
+        // Hooks and observers triggered by the spawn may have queued commands, e.g. to keep
+        // relationships in sync. The queue was flushed above, so only those commands are applied.
+        // They may move or despawn the entity, which `world_scope` accounts for, see
+        // `EntityWorldMut::is_despawned`.
+        if has_queued_commands {
+            entity.world_scope(|world| {
+                world.flush_entities();
+                world.apply_command_queue();
+            });
+        }
+        entity
     }
 
     /// # Safety
@@ -2861,13 +2891,25 @@ impl World {
         }
     }
 
-    /// Applies any commands in the world's internal [`CommandQueue`].
+    /// Applies any commands in the world's internal [`CommandQueue`], followed by those queued by
+    /// [async tasks](crate::task).
     /// This does not apply commands from any systems, only those stored in the world.
     ///
     /// # Panics
     /// This will panic if any of the queued commands are [`spawn`](Commands::spawn).
     /// If this is possible, you should instead use [`flush`](Self::flush).
     pub(crate) fn flush_commands(&mut self) {
+        self.apply_command_queue();
+        // Commands queued by async tasks are applied at the same sync points.
+        #[cfg(feature = "std")]
+        if let Some(mut commands) = self.task_commands.as_ref().and_then(|queue| queue.take()) {
+            commands.apply(self);
+        }
+    }
+
+    /// Applies the commands in the world's internal [`CommandQueue`], without the ones queued by
+    /// async tasks.
+    fn apply_command_queue(&mut self) {
         // SAFETY: `self.command_queue` is only de-allocated in `World`'s `Drop`
         if !unsafe { self.command_queue.is_empty() } {
             // SAFETY: `self.command_queue` is only de-allocated in `World`'s `Drop`
@@ -3072,8 +3114,8 @@ impl World {
 
         #[cfg(feature = "trace")]
         let _span = tracing::info_span!("check component ticks").entered();
-        tables.check_change_ticks(change_tick);
-        sparse_sets.check_change_ticks(change_tick);
+        tables.check_change_ticks(&self.components, change_tick);
+        sparse_sets.check_change_ticks(&self.components, change_tick);
         resources.check_change_ticks(change_tick);
         non_send_resources.check_change_ticks(change_tick);
 
@@ -3207,6 +3249,7 @@ impl World {
     /// # struct B(u32);
     /// #
     /// # let mut world = World::new();
+    /// # world.remove_resource::<bevy_ecs::entity_disabling::DefaultQueryFilters>();
     /// # world.insert_resource(A(1));
     /// # world.insert_resource(B(2));
     /// let mut total = 0;
@@ -3675,6 +3718,7 @@ mod tests {
         change_detection::DetectChangesMut,
         component::{ComponentDescriptor, ComponentInfo, StorageType},
         entity::EntityHashSet,
+        entity_disabling::DefaultQueryFilters,
         ptr::OwningPtr,
         system::Resource,
         world::error::EntityFetchError,
@@ -3864,6 +3908,8 @@ mod tests {
     #[test]
     fn iter_resources() {
         let mut world = World::new();
+        // Remove the default resources so only the test resources are iterated.
+        world.remove_resource::<DefaultQueryFilters>();
         world.insert_resource(TestResource(42));
         world.insert_resource(TestResource2("Hello, world!".to_string()));
         world.insert_resource(TestResource3);
@@ -3890,6 +3936,8 @@ mod tests {
     #[test]
     fn iter_resources_mut() {
         let mut world = World::new();
+        // Remove the default resources so only the test resources are iterated.
+        world.remove_resource::<DefaultQueryFilters>();
         world.insert_resource(TestResource(42));
         world.insert_resource(TestResource2("Hello, world!".to_string()));
         world.insert_resource(TestResource3);
@@ -171,6 +171,18 @@ impl World {
             .get_name(component_id)
             .map(ToString::to_string);
 
+        if self
+            .components()
+            .get_info(component_id)
+            .is_some_and(|info| !info.mutable())
+        {
+            return Err(GetComponentReflectError::ComponentIsImmutable {
+                type_id,
+                component_id,
+                component_name,
+            });
+        }
+
         let Some(comp_mut_untyped) = self.get_mut_by_id(entity, component_id) else {
             return Err(GetComponentReflectError::EntityDoesNotHaveComponent {
                 entity,
@@ -226,6 +238,19 @@ pub enum GetComponentReflectError {
         component_name: Option<String>,
     },
 
+    /// The [`Component`] corresponding to the given [`TypeId`] is [immutable](crate::component::Immutable),
+    /// so it cannot be borrowed mutably.
+    #[error("The `{component_name:?}` component ({component_id:?}, which corresponds to {type_id:?}) is immutable")]
+    ComponentIsImmutable {
+        /// The given [`TypeId`].
+        type_id: TypeId,
+        /// The [`ComponentId`] corresponding to the given [`TypeId`].
+        component_id: ComponentId,
+        /// The name corresponding to the [`Component`] with the given [`TypeId`], or `None`
+        /// if not available.
+        component_name: Option<String>,
+    },
+
     /// The [`World`] was missing the [`AppTypeRegistry`] resource.
     #[error("The `World` was missing the `AppTypeRegistry` resource")]
     MissingAppTypeRegistry,
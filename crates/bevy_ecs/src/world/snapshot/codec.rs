@@ -0,0 +1,425 @@
+//! The compact binary encoding of reflected values used by [`WorldSnapshot`](super::WorldSnapshot).
+//!
+//! Values are written without type paths or field names, in the order of their [`TypeInfo`],
+//! so decoding relies on the [`TypeRegistry`] of the world the snapshot is restored into.
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::any::TypeId;
+
+use bevy_reflect::{
+    DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct, DynamicTuple,
+    DynamicTupleStruct, DynamicVariant, Map, PartialReflect, ReflectRef, Set, TypeInfo,
+    TypeRegistry, VariantInfo,
+};
+
+use super::SnapshotError;
+use crate::entity::Entity;
+
+/// Appends `value` to `buffer` as a LEB128 variable-length integer.
+pub(super) fn write_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+/// Reads the values written by [`write_varint`] and [`encode`] from a byte slice.
+pub(super) struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn varint(&mut self) -> Result<u64, SnapshotError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let [byte, rest @ ..] = self.bytes else {
+                return Err(SnapshotError::MalformedData);
+            };
+            self.bytes = rest;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(SnapshotError::MalformedData)
+    }
+
+    pub fn length(&mut self) -> Result<usize, SnapshotError> {
+        usize::try_from(self.varint()?).map_err(|_| SnapshotError::MalformedData)
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], SnapshotError> {
+        let (bytes, rest) = self
+            .bytes
+            .split_first_chunk::<N>()
+            .ok_or(SnapshotError::MalformedData)?;
+        self.bytes = rest;
+        Ok(*bytes)
+    }
+
+    fn slice(&mut self, len: usize) -> Result<&'a [u8], SnapshotError> {
+        if len > self.bytes.len() {
+            return Err(SnapshotError::MalformedData);
+        }
+        let (bytes, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(bytes)
+    }
+}
+
+/// Implements the encoding of the opaque types that snapshots support,
+/// each written as its little-endian bytes.
+///
+/// `usize` and `isize` are always written as 64-bit integers, so snapshots don't depend on the
+/// pointer width of the platform that took them.
+macro_rules! opaque_types {
+    ($($ty:ty),*) => {
+        fn encode_opaque(buffer: &mut Vec<u8>, value: &dyn PartialReflect) -> Result<(), SnapshotError> {
+            $(
+                if let Some(value) = value.try_downcast_ref::<$ty>() {
+                    buffer.extend_from_slice(&value.to_le_bytes());
+                    return Ok(());
+                }
+            )*
+            if let Some(value) = value.try_downcast_ref::<usize>() {
+                buffer.extend_from_slice(&(*value as u64).to_le_bytes());
+            } else if let Some(value) = value.try_downcast_ref::<isize>() {
+                buffer.extend_from_slice(&(*value as i64).to_le_bytes());
+            } else if let Some(value) = value.try_downcast_ref::<bool>() {
+                buffer.push(u8::from(*value));
+            } else if let Some(value) = value.try_downcast_ref::<char>() {
+                buffer.extend_from_slice(&u32::from(*value).to_le_bytes());
+            } else if let Some(value) = value.try_downcast_ref::<String>() {
+                write_varint(buffer, value.len() as u64);
+                buffer.extend_from_slice(value.as_bytes());
+            } else if let Some(value) = value.try_downcast_ref::<Entity>() {
+                buffer.extend_from_slice(&value.to_bits().to_le_bytes());
+            } else {
+                return Err(SnapshotError::UnsupportedType {
+                    type_path: value.reflect_type_path().into(),
+                });
+            }
+            Ok(())
+        }
+
+        fn decode_opaque(
+            reader: &mut Reader,
+            type_info: &'static TypeInfo,
+        ) -> Result<Box<dyn PartialReflect>, SnapshotError> {
+            let type_id = type_info.type_id();
+            $(
+                if type_id == TypeId::of::<$ty>() {
+                    return Ok(Box::new(<$ty>::from_le_bytes(reader.take()?)));
+                }
+            )*
+            if type_id == TypeId::of::<usize>() {
+                let value = usize::try_from(u64::from_le_bytes(reader.take()?))
+                    .map_err(|_| integer_overflow(type_info))?;
+                Ok(Box::new(value))
+            } else if type_id == TypeId::of::<isize>() {
+                let value = isize::try_from(i64::from_le_bytes(reader.take()?))
+                    .map_err(|_| integer_overflow(type_info))?;
+                Ok(Box::new(value))
+            } else if type_id == TypeId::of::<bool>() {
+                let [byte] = reader.take()?;
+                Ok(Box::new(byte != 0))
+            } else if type_id == TypeId::of::<char>() {
+                let value = char::from_u32(u32::from_le_bytes(reader.take()?))
+                    .ok_or(SnapshotError::MalformedData)?;
+                Ok(Box::new(value))
+            } else if type_id == TypeId::of::<String>() {
+                let len = reader.length()?;
+                let value = core::str::from_utf8(reader.slice(len)?)
+                    .map_err(|_| SnapshotError::MalformedData)?;
+                Ok(Box::new(String::from(value)))
+            } else if type_id == TypeId::of::<Entity>() {
+                let value = Entity::try_from_bits(u64::from_le_bytes(reader.take()?))
+                    .map_err(|_| SnapshotError::MalformedData)?;
+                Ok(Box::new(value))
+            } else {
+                Err(SnapshotError::UnsupportedType {
+                    type_path: type_info.type_path().into(),
+                })
+            }
+        }
+    };
+}
+
+opaque_types!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+fn integer_overflow(type_info: &TypeInfo) -> SnapshotError {
+    SnapshotError::IntegerOverflow {
+        type_path: type_info.type_path().into(),
+    }
+}
+
+/// Appends the fields of `value` to `buffer`.
+///
+/// Fields are written in the order they are declared in, without their names,
+/// and the lengths of lists, maps and sets are written before their elements.
+pub(super) fn encode(
+    buffer: &mut Vec<u8>,
+    value: &dyn PartialReflect,
+) -> Result<(), SnapshotError> {
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            for i in 0..value.field_len() {
+                encode(buffer, value.field_at(i).unwrap())?;
+            }
+        }
+        ReflectRef::TupleStruct(value) => {
+            for field in value.iter_fields() {
+                encode(buffer, field)?;
+            }
+        }
+        ReflectRef::Tuple(value) => {
+            for field in value.iter_fields() {
+                encode(buffer, field)?;
+            }
+        }
+        ReflectRef::List(value) => {
+            write_varint(buffer, value.len() as u64);
+            for item in value.iter() {
+                encode(buffer, item)?;
+            }
+        }
+        ReflectRef::Array(value) => {
+            for item in value.iter() {
+                encode(buffer, item)?;
+            }
+        }
+        ReflectRef::Map(value) => {
+            write_varint(buffer, value.len() as u64);
+            for (key, value) in value.iter() {
+                encode(buffer, key)?;
+                encode(buffer, value)?;
+            }
+        }
+        ReflectRef::Set(value) => {
+            write_varint(buffer, value.len() as u64);
+            for item in value.iter() {
+                encode(buffer, item)?;
+            }
+        }
+        ReflectRef::Enum(value) => {
+            write_varint(buffer, value.variant_index() as u64);
+            for field in value.iter_fields() {
+                encode(buffer, field.value())?;
+            }
+        }
+        ReflectRef::Opaque(value) => encode_opaque(buffer, value)?,
+        // Functions can't be captured, and are only reflected when `bevy_reflect/functions` is enabled.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "`unreachable_patterns` may not always lint"
+        )]
+        #[allow(
+            unreachable_patterns,
+            reason = "The wildcard pattern is only reachable if `bevy_reflect/functions` is enabled"
+        )]
+        _ => {
+            return Err(SnapshotError::UnsupportedType {
+                type_path: value.reflect_type_path().into(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Reads a value of the type described by `type_info`, as written by [`encode`].
+///
+/// Values other than the supported opaque types are returned as dynamic types
+/// representing `type_info`.
+pub(super) fn decode(
+    reader: &mut Reader,
+    type_info: &'static TypeInfo,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn PartialReflect>, SnapshotError> {
+    let type_info_of = |type_id: TypeId, type_path: &'static str| {
+        registry
+            .get_type_info(type_id)
+            .ok_or_else(|| SnapshotError::UnregisteredType {
+                type_path: type_path.into(),
+            })
+    };
+
+    Ok(match type_info {
+        TypeInfo::Struct(info) => {
+            let mut value = DynamicStruct::default();
+            value.set_represented_type(Some(type_info));
+            for field in info.iter() {
+                let field_info = type_info_of(field.type_id(), field.type_path())?;
+                value.insert_boxed(field.name(), decode(reader, field_info, registry)?);
+            }
+            Box::new(value)
+        }
+        TypeInfo::TupleStruct(info) => {
+            let mut value = DynamicTupleStruct::default();
+            value.set_represented_type(Some(type_info));
+            for field in info.iter() {
+                let field_info = type_info_of(field.type_id(), field.type_path())?;
+                value.insert_boxed(decode(reader, field_info, registry)?);
+            }
+            Box::new(value)
+        }
+        TypeInfo::Tuple(info) => {
+            let mut value = DynamicTuple::default();
+            value.set_represented_type(Some(type_info));
+            for field in info.iter() {
+                let field_info = type_info_of(field.type_id(), field.type_path())?;
+                value.insert_boxed(decode(reader, field_info, registry)?);
+            }
+            Box::new(value)
+        }
+        TypeInfo::List(info) => {
+            let item_info = type_info_of(info.item_ty().id(), info.item_ty().path())?;
+            let mut value = DynamicList::default();
+            value.set_represented_type(Some(type_info));
+            for _ in 0..reader.length()? {
+                value.push_box(decode(reader, item_info, registry)?);
+            }
+            Box::new(value)
+        }
+        TypeInfo::Array(info) => {
+            let item_info = type_info_of(info.item_ty().id(), info.item_ty().path())?;
+            let items = (0..info.capacity())
+                .map(|_| decode(reader, item_info, registry))
+                .collect::<Result<Vec<_>, _>>()?;
+            let mut value = DynamicArray::new(items.into_boxed_slice());
+            value.set_represented_type(Some(type_info));
+            Box::new(value)
+        }
+        TypeInfo::Map(info) => {
+            let key_info = type_info_of(info.key_ty().id(), info.key_ty().path())?;
+            let value_info = type_info_of(info.value_ty().id(), info.value_ty().path())?;
+            let mut value = DynamicMap::default();
+            value.set_represented_type(Some(type_info));
+            for _ in 0..reader.length()? {
+                let key = decode(reader, key_info, registry)?;
+                value.insert_boxed(key, decode(reader, value_info, registry)?);
+            }
+            Box::new(value)
+        }
+        TypeInfo::Set(info) => {
+            let item_info = type_info_of(info.value_ty().id(), info.value_ty().path())?;
+            let mut value = DynamicSet::default();
+            value.set_represented_type(Some(type_info));
+            for _ in 0..reader.length()? {
+                value.insert_boxed(decode(reader, item_info, registry)?);
+            }
+            Box::new(value)
+        }
+        TypeInfo::Enum(info) => {
+            let index = reader.length()?;
+            let variant_info = info.variant_at(index).ok_or(SnapshotError::MalformedData)?;
+            let variant = match variant_info {
+                VariantInfo::Unit(_) => DynamicVariant::Unit,
+                VariantInfo::Tuple(variant_info) => {
+                    let mut fields = DynamicTuple::default();
+                    for field in variant_info.iter() {
+                        let field_info = type_info_of(field.type_id(), field.type_path())?;
+                        fields.insert_boxed(decode(reader, field_info, registry)?);
+                    }
+                    DynamicVariant::Tuple(fields)
+                }
+                VariantInfo::Struct(variant_info) => {
+                    let mut fields = DynamicStruct::default();
+                    for field in variant_info.iter() {
+                        let field_info = type_info_of(field.type_id(), field.type_path())?;
+                        fields.insert_boxed(field.name(), decode(reader, field_info, registry)?);
+                    }
+                    DynamicVariant::Struct(fields)
+                }
+            };
+            let mut value = DynamicEnum::new_with_index(index, variant_info.name(), variant);
+            value.set_represented_type(Some(type_info));
+            Box::new(value)
+        }
+        TypeInfo::Opaque(_) => decode_opaque(reader, type_info)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use bevy_reflect::{FromReflect, Reflect, Typed};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Shape {
+        Point,
+        Circle(f32),
+        Rect { width: u16, height: u16 },
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Sample {
+        name: String,
+        target: Entity,
+        shapes: Vec<Shape>,
+        lookup: HashMap<u8, (i64, bool)>,
+        grid: [char; 2],
+        #[reflect(ignore)]
+        ignored: u32,
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Sample>();
+
+        let value = Sample {
+            name: String::from("snapshot"),
+            target: Entity::from_raw(7),
+            shapes: vec![
+                Shape::Point,
+                Shape::Circle(1.5),
+                Shape::Rect {
+                    width: 3,
+                    height: 4,
+                },
+            ],
+            lookup: HashMap::from_iter([(1, (-3, true))]),
+            grid: ['a', 'ß'],
+            ignored: 5,
+        };
+
+        let mut buffer = Vec::new();
+        encode(&mut buffer, &value).unwrap();
+        let mut reader = Reader::new(&buffer);
+        let decoded = decode(&mut reader, Sample::type_info(), &registry).unwrap();
+        assert!(reader.is_empty());
+
+        let decoded = Sample::from_reflect(&*decoded).unwrap();
+        assert_eq!(decoded.name, value.name);
+        assert_eq!(decoded.target, value.target);
+        assert_eq!(decoded.shapes, value.shapes);
+        assert_eq!(decoded.lookup, value.lookup);
+        assert_eq!(decoded.grid, value.grid);
+        assert_eq!(decoded.ignored, 0);
+    }
+
+    #[test]
+    fn varint() {
+        let mut buffer = Vec::new();
+        for value in [0, 127, 128, 300, u64::MAX] {
+            write_varint(&mut buffer, value);
+        }
+        let mut reader = Reader::new(&buffer);
+        for value in [0, 127, 128, 300, u64::MAX] {
+            assert_eq!(reader.varint().unwrap(), value);
+        }
+        assert!(reader.is_empty());
+        assert!(matches!(reader.varint(), Err(SnapshotError::MalformedData)));
+    }
+}
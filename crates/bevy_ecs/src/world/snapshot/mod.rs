@@ -0,0 +1,786 @@
+//! Snapshots of the reflected state of a [`World`], to roll it back in place.
+
+mod codec;
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::any::{Any, TypeId};
+#[cfg(feature = "track_location")]
+use core::panic::Location;
+
+use bevy_reflect::{PartialReflect, TypeRegistry};
+use bevy_utils::HashSet;
+use fixedbitset::FixedBitSet;
+use thiserror::Error;
+
+use crate::{
+    archetype::ArchetypeEntity,
+    component::ComponentId,
+    entity::{AllocAtWithoutReplacement, AllocatorSnapshot, Entity},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    world::World,
+};
+use codec::{decode, encode, write_varint, Reader};
+
+/// A filter that selects the components and resources captured by a [`WorldSnapshot`].
+///
+/// Only types registered with [`ReflectComponent`] or [`ReflectResource`] in the [`TypeRegistry`]
+/// can be captured, so the filter is applied to these.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotFilter {
+    /// Contains the set of captured types by their [`TypeId`].
+    Allowlist(HashSet<TypeId>),
+    /// Contains the set of types that aren't captured by their [`TypeId`].
+    Denylist(HashSet<TypeId>),
+}
+
+impl Default for SnapshotFilter {
+    fn default() -> Self {
+        Self::allow_all()
+    }
+}
+
+impl SnapshotFilter {
+    /// Creates a filter where all types are allowed.
+    pub fn allow_all() -> Self {
+        Self::Denylist(HashSet::default())
+    }
+
+    /// Creates a filter where all types are denied.
+    pub fn deny_all() -> Self {
+        Self::Allowlist(HashSet::default())
+    }
+
+    /// Allow the given type, `T`.
+    #[must_use]
+    pub fn allow<T: Any>(self) -> Self {
+        self.allow_by_id(TypeId::of::<T>())
+    }
+
+    /// Allow the given type.
+    #[must_use]
+    pub fn allow_by_id(mut self, type_id: TypeId) -> Self {
+        match &mut self {
+            Self::Allowlist(list) => {
+                list.insert(type_id);
+            }
+            Self::Denylist(list) => {
+                list.remove(&type_id);
+            }
+        }
+        self
+    }
+
+    /// Deny the given type, `T`.
+    #[must_use]
+    pub fn deny<T: Any>(self) -> Self {
+        self.deny_by_id(TypeId::of::<T>())
+    }
+
+    /// Deny the given type.
+    #[must_use]
+    pub fn deny_by_id(mut self, type_id: TypeId) -> Self {
+        match &mut self {
+            Self::Allowlist(list) => {
+                list.remove(&type_id);
+            }
+            Self::Denylist(list) => {
+                list.insert(type_id);
+            }
+        }
+        self
+    }
+
+    /// Returns true if the given type is allowed by the filter.
+    pub fn is_allowed_by_id(&self, type_id: TypeId) -> bool {
+        match self {
+            Self::Allowlist(list) => list.contains(&type_id),
+            Self::Denylist(list) => !list.contains(&type_id),
+        }
+    }
+}
+
+/// The state of the entities, components and resources of a [`World`] at some point,
+/// which can be restored in place to roll the world back to that point.
+///
+/// Snapshots capture the components and resources selected by a [`SnapshotFilter`] among the types
+/// registered in the [`TypeRegistry`], and the state of the entity allocator.
+/// Restoring a snapshot despawns the entities spawned after it was taken that carry captured
+/// components, and respawns the entities despawned since then with the same [`Entity`] ids, so
+/// components referencing entities stay valid. The entities spawned after restoring it get the same
+/// ids as the ones spawned after taking it, which keeps simulations that are replayed from a
+/// snapshot deterministic.
+///
+/// Entities spawned after the snapshot was taken without any captured component, such as observers
+/// or the entities of systems, are kept alive. If one of them uses the index of an entity that is
+/// alive in the snapshot, restoring it fails with [`SnapshotError::EntityIndexInUse`].
+///
+/// The captured values are encoded in a compact binary buffer, without type paths or field names.
+/// Besides reflected structs, enums and collections, values can contain the primitive types,
+/// [`String`] and [`Entity`]. Other opaque types cause [`SnapshotError::UnsupportedType`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::SnapshotFilter;
+/// # use bevy_reflect::Reflect;
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct Health(u32);
+///
+/// let mut world = World::new();
+/// world.init_resource::<AppTypeRegistry>();
+/// world.resource::<AppTypeRegistry>().write().register::<Health>();
+///
+/// let player = world.spawn(Health(10)).id();
+/// let snapshot = world.take_snapshot(&SnapshotFilter::allow_all()).unwrap();
+///
+/// world.entity_mut(player).get_mut::<Health>().unwrap().0 = 0;
+/// let projectile = world.spawn(Health(1)).id();
+///
+/// world.restore_snapshot(&snapshot).unwrap();
+/// assert_eq!(world.entity(player).get::<Health>().unwrap().0, 10);
+/// assert!(world.get_entity(projectile).is_err());
+/// // The next spawned entity gets the same id again.
+/// assert_eq!(world.spawn_empty().id(), projectile);
+/// ```
+#[derive(Debug, Clone)]
+pub struct WorldSnapshot {
+    types: Vec<SnapshotType>,
+    allocator: AllocatorSnapshot,
+    data: Vec<u8>,
+}
+
+/// A component or resource type captured by a [`WorldSnapshot`].
+#[derive(Debug, Clone)]
+struct SnapshotType {
+    type_id: TypeId,
+    type_path: &'static str,
+    is_resource: bool,
+}
+
+impl WorldSnapshot {
+    /// Captures the components and resources of `world` that are allowed by `filter`.
+    ///
+    /// See [`World::take_snapshot`] to use the [`AppTypeRegistry`] of the world.
+    pub fn take(
+        world: &World,
+        filter: &SnapshotFilter,
+        registry: &TypeRegistry,
+    ) -> Result<Self, SnapshotError> {
+        let mut types = Vec::new();
+        let mut components = Vec::new();
+        let mut resources = Vec::new();
+        for registration in registry.iter() {
+            let type_id = registration.type_id();
+            if !filter.is_allowed_by_id(type_id) {
+                continue;
+            }
+            let is_resource =
+                if let Some(reflect_component) = registration.data::<ReflectComponent>() {
+                    if let Some(component_id) = world.components().get_id(type_id) {
+                        components.push((types.len(), component_id, reflect_component));
+                    }
+                    false
+                } else if let Some(reflect_resource) = registration.data::<ReflectResource>() {
+                    resources.push((types.len(), reflect_resource));
+                    true
+                } else {
+                    continue;
+                };
+            types.push(SnapshotType {
+                type_id,
+                type_path: registration.type_info().type_path(),
+                is_resource,
+            });
+        }
+
+        let mut data = Vec::new();
+        let archetypes = world
+            .archetypes()
+            .iter()
+            .filter(|archetype| !archetype.is_empty())
+            .map(|archetype| {
+                let captured = components
+                    .iter()
+                    .filter(|(_, component_id, _)| archetype.contains(*component_id))
+                    .map(|&(index, _, reflect_component)| (index, reflect_component))
+                    .collect::<Vec<_>>();
+                (archetype.entities(), captured)
+            })
+            .filter(|(_, captured)| !captured.is_empty())
+            .collect::<Vec<_>>();
+        let entity_count = archetypes
+            .iter()
+            .map(|(entities, _)| entities.len())
+            .sum::<usize>();
+        write_varint(&mut data, entity_count as u64);
+        for (entities, captured) in &archetypes {
+            for entity in entities.iter() {
+                let entity = world.entity(entity.id());
+                write_varint(&mut data, u64::from(entity.id().index()));
+                write_varint(&mut data, captured.len() as u64);
+                for &(index, reflect_component) in captured {
+                    write_varint(&mut data, index as u64);
+                    // The archetype of the entity contains the component.
+                    let value = reflect_component.reflect(entity).unwrap();
+                    encode(&mut data, value.as_partial_reflect())?;
+                }
+            }
+        }
+
+        let resources = resources
+            .into_iter()
+            .filter_map(|(index, reflect_resource)| Some((index, reflect_resource.reflect(world)?)))
+            .collect::<Vec<_>>();
+        write_varint(&mut data, resources.len() as u64);
+        for (index, value) in resources {
+            write_varint(&mut data, index as u64);
+            encode(&mut data, value.as_partial_reflect())?;
+        }
+
+        Ok(Self {
+            types,
+            allocator: world.entities().snapshot_allocator(),
+            data,
+        })
+    }
+
+    /// Restores the entities, components and resources captured in the snapshot into `world`.
+    ///
+    /// Components and resources of the captured types are inserted, updated or removed to match the snapshot,
+    /// while other components and resources are left untouched. See [`WorldSnapshot`] for which
+    /// entities are despawned.
+    ///
+    /// See [`World::restore_snapshot`] to use the [`AppTypeRegistry`] of the world.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error without modifying `world` if a captured type isn't registered in `registry`,
+    /// or if an entity without captured components uses the index of an entity alive in the snapshot.
+    #[track_caller]
+    pub fn restore(&self, world: &mut World, registry: &TypeRegistry) -> Result<(), SnapshotError> {
+        let mut resolved = Vec::with_capacity(self.types.len());
+        for ty in &self.types {
+            let registration =
+                registry
+                    .get(ty.type_id)
+                    .ok_or_else(|| SnapshotError::UnregisteredType {
+                        type_path: ty.type_path.into(),
+                    })?;
+            let reflect = if ty.is_resource {
+                registration
+                    .data::<ReflectResource>()
+                    .cloned()
+                    .map(SnapshotReflect::Resource)
+            } else {
+                registration
+                    .data::<ReflectComponent>()
+                    .cloned()
+                    .map(SnapshotReflect::Component)
+            };
+            let reflect = reflect.ok_or_else(|| SnapshotError::UnregisteredType {
+                type_path: ty.type_path.into(),
+            })?;
+            resolved.push((registration.type_info(), reflect));
+        }
+
+        let mut reader = Reader::new(&self.data);
+        let decode_value = |reader: &mut Reader| {
+            let index = reader.length()?;
+            let (type_info, _) = resolved.get(index).ok_or(SnapshotError::MalformedData)?;
+            Ok::<_, SnapshotError>((index, decode(reader, type_info, registry)?))
+        };
+        let entity_count = reader.length()?;
+        let mut entities = Vec::with_capacity(entity_count);
+        for _ in 0..entity_count {
+            let index =
+                u32::try_from(reader.varint()?).map_err(|_| SnapshotError::MalformedData)?;
+            let entity = self
+                .allocator
+                .entity_at(index)
+                .ok_or(SnapshotError::MalformedData)?;
+            let values = (0..reader.length()?)
+                .map(|_| decode_value(&mut reader))
+                .collect::<Result<Vec<_>, _>>()?;
+            entities.push((entity, values));
+        }
+        let resources = (0..reader.length()?)
+            .map(|_| decode_value(&mut reader))
+            .collect::<Result<Vec<_>, _>>()?;
+        if !reader.is_empty() {
+            return Err(SnapshotError::MalformedData);
+        }
+
+        let components = resolved
+            .iter()
+            .zip(&self.types)
+            .enumerate()
+            .filter_map(|(index, ((_, reflect), ty))| match reflect {
+                SnapshotReflect::Component(reflect_component) => {
+                    let component_id = world.components().get_id(ty.type_id)?;
+                    Some((index, component_id, reflect_component))
+                }
+                SnapshotReflect::Resource(_) => None,
+            })
+            .collect::<Vec<_>>();
+        let captured = components
+            .iter()
+            .map(|&(_, component_id, _)| component_id)
+            .collect::<Vec<_>>();
+        world.flush();
+        let mut alive = FixedBitSet::new();
+        for entity in self.allocator.alive() {
+            alive.grow_and_insert(entity.index() as usize);
+        }
+        self.check_entity_indices(world, &captured, &alive)?;
+        self.restore_entities(world, &captured, &alive);
+
+        let remove_missing =
+            |world: &mut World, entity: Entity, values: &[(usize, Box<dyn PartialReflect>)]| {
+                let mut entity = world.entity_mut(entity);
+                for &(index, component_id, reflect_component) in &components {
+                    if entity.contains_id(component_id) && !values.iter().any(|(i, _)| *i == index)
+                    {
+                        reflect_component.remove(&mut entity);
+                    }
+                }
+            };
+
+        let mut restored = FixedBitSet::new();
+        for (entity, values) in &entities {
+            restored.grow_and_insert(entity.index() as usize);
+            let mut entity_mut = world.entity_mut(*entity);
+            for (index, value) in values {
+                if let SnapshotReflect::Component(reflect_component) = &resolved[*index].1 {
+                    reflect_component.apply_or_insert(&mut entity_mut, value.as_ref(), registry);
+                }
+            }
+            remove_missing(world, *entity, values);
+        }
+        for entity in self.allocator.alive() {
+            if !restored.contains(entity.index() as usize) {
+                remove_missing(world, entity, &[]);
+            }
+        }
+
+        for (index, (_, reflect)) in resolved.iter().enumerate() {
+            if let SnapshotReflect::Resource(reflect_resource) = reflect {
+                match resources.iter().find(|(i, _)| *i == index) {
+                    Some((_, value)) => {
+                        reflect_resource.apply_or_insert(world, value.as_ref(), registry);
+                    }
+                    None => reflect_resource.remove(world),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if an entity of `world` without any of the `captured` components uses the
+    /// index of another entity that is `alive` in the snapshot.
+    fn check_entity_indices(
+        &self,
+        world: &World,
+        captured: &[ComponentId],
+        alive: &FixedBitSet,
+    ) -> Result<(), SnapshotError> {
+        for archetype in world.archetypes().iter() {
+            if captured
+                .iter()
+                .any(|&component_id| archetype.contains(component_id))
+            {
+                continue;
+            }
+            for entity in archetype.entities().iter().map(ArchetypeEntity::id) {
+                if !alive.contains(entity.index() as usize) {
+                    continue;
+                }
+                match self.allocator.entity_at(entity.index()) {
+                    Some(snapshot_entity) if snapshot_entity != entity => {
+                        return Err(SnapshotError::EntityIndexInUse {
+                            entity,
+                            snapshot_entity,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Despawns the entities that aren't alive in the snapshot and carry one of the `captured`
+    /// components or use the index of an entity `alive` in the snapshot, respawns the ones that
+    /// were despawned since it was taken, and restores the state of the entity allocator.
+    ///
+    /// The entities without captured components that use such an index were rejected by
+    /// [`Self::check_entity_indices`], so the only ones despawned here were spawned by the hooks
+    /// and observers of the despawned entities.
+    #[track_caller]
+    fn restore_entities(&self, world: &mut World, captured: &[ComponentId], alive: &FixedBitSet) {
+        // Despawning entities can run hooks and observers that spawn other entities.
+        loop {
+            let stale = world
+                .archetypes()
+                .iter()
+                .flat_map(|archetype| {
+                    let is_captured = captured
+                        .iter()
+                        .any(|&component_id| archetype.contains(component_id));
+                    archetype
+                        .entities()
+                        .iter()
+                        .map(ArchetypeEntity::id)
+                        .filter(move |entity| {
+                            if alive.contains(entity.index() as usize) {
+                                self.allocator.entity_at(entity.index()) != Some(*entity)
+                            } else {
+                                is_captured
+                            }
+                        })
+                })
+                .collect::<Vec<_>>();
+            if stale.is_empty() {
+                break;
+            }
+            for entity in stale {
+                world.despawn(entity);
+            }
+            world.flush();
+        }
+
+        for entity in self.allocator.alive() {
+            if let AllocAtWithoutReplacement::DidNotExist =
+                world.entities.alloc_at_without_replacement(entity)
+            {
+                // SAFETY: the entity was just allocated
+                unsafe {
+                    world.spawn_at_empty_internal(
+                        entity,
+                        #[cfg(feature = "track_location")]
+                        Location::caller(),
+                    );
+                }
+            }
+        }
+        world.entities.restore_allocator(&self.allocator);
+    }
+
+    /// Returns the encoded components and resources of the snapshot.
+    ///
+    /// The buffer can only be decoded with the [`TypeRegistry`] it was encoded with.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// The reflected operations used to restore a type captured in a [`WorldSnapshot`].
+enum SnapshotReflect {
+    Component(ReflectComponent),
+    Resource(ReflectResource),
+}
+
+impl World {
+    /// Captures the components and resources of this world allowed by `filter` among the types
+    /// registered in the [`AppTypeRegistry`], to [restore](World::restore_snapshot) them later.
+    ///
+    /// See [`WorldSnapshot`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// See [`SnapshotError`] for the possible errors.
+    pub fn take_snapshot(&self, filter: &SnapshotFilter) -> Result<WorldSnapshot, SnapshotError> {
+        let registry = self
+            .get_resource::<AppTypeRegistry>()
+            .ok_or(SnapshotError::MissingAppTypeRegistry)?;
+        WorldSnapshot::take(self, filter, &registry.read())
+    }
+
+    /// Restores the entities, components and resources captured in `snapshot`, using the [`AppTypeRegistry`].
+    ///
+    /// See [`WorldSnapshot::restore`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// See [`SnapshotError`] for the possible errors.
+    #[track_caller]
+    pub fn restore_snapshot(&mut self, snapshot: &WorldSnapshot) -> Result<(), SnapshotError> {
+        let registry = self
+            .get_resource::<AppTypeRegistry>()
+            .ok_or(SnapshotError::MissingAppTypeRegistry)?
+            .clone();
+        let registry = registry.read();
+        snapshot.restore(self, &registry)
+    }
+}
+
+/// The error type returned when taking or restoring a [`WorldSnapshot`].
+#[derive(Error, Debug)]
+pub enum SnapshotError {
+    /// A captured value contains an opaque type that snapshots can't encode.
+    #[error("The opaque type `{type_path}` is not supported in snapshots")]
+    UnsupportedType {
+        /// The type path of the opaque type.
+        type_path: String,
+    },
+
+    /// A captured type, or the type of one of its fields, isn't registered in the [`TypeRegistry`].
+    #[error("The type `{type_path}` is not registered in the type registry")]
+    UnregisteredType {
+        /// The type path of the unregistered type.
+        type_path: String,
+    },
+
+    /// The [`World`] was missing the [`AppTypeRegistry`] resource.
+    #[error("The `World` was missing the `AppTypeRegistry` resource")]
+    MissingAppTypeRegistry,
+
+    /// The snapshot data doesn't match the types registered in the [`TypeRegistry`].
+    #[error("The snapshot data doesn't match the registered types")]
+    MalformedData,
+
+    /// A captured integer doesn't fit in its type on this platform, like a `usize` captured on a
+    /// 64-bit platform restored on a 32-bit one.
+    #[error("A captured `{type_path}` doesn't fit in its type on this platform")]
+    IntegerOverflow {
+        /// The type path of the integer type.
+        type_path: String,
+    },
+
+    /// An entity without captured components uses the index of an entity alive in the snapshot,
+    /// so that entity can't be restored.
+    #[error("The entity {entity} uses the index of the entity {snapshot_entity} alive in the snapshot, but has no captured component")]
+    EntityIndexInUse {
+        /// The entity alive in the world.
+        entity: Entity,
+        /// The entity alive in the snapshot.
+        snapshot_entity: Entity,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_ecs, prelude::*};
+    use alloc::{string::ToString, vec};
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Position(i32, i32);
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Target(Option<Entity>);
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Inventory {
+        items: Vec<String>,
+    }
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Unregistered(u32);
+
+    #[derive(Resource, Reflect, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Tick(u64);
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Position>();
+            registry.register::<Target>();
+            registry.register::<Inventory>();
+            registry.register::<Tick>();
+        }
+        world
+    }
+
+    #[test]
+    fn restores_components_and_resources() {
+        let mut world = world();
+        world.insert_resource(Tick(1));
+        let a = world.spawn((Position(1, 2), Unregistered(1))).id();
+        let b = world
+            .spawn((
+                Target(Some(a)),
+                Inventory {
+                    items: vec!["sword".to_string()],
+                },
+            ))
+            .id();
+        let snapshot = world.take_snapshot(&SnapshotFilter::allow_all()).unwrap();
+
+        world.resource_mut::<Tick>().0 = 2;
+        world
+            .entity_mut(a)
+            .insert(Target(None))
+            .remove::<Position>();
+        world.entity_mut(a).get_mut::<Unregistered>().unwrap().0 = 2;
+        world
+            .entity_mut(b)
+            .get_mut::<Inventory>()
+            .unwrap()
+            .items
+            .clear();
+
+        world.restore_snapshot(&snapshot).unwrap();
+        assert_eq!(world.resource::<Tick>(), &Tick(1));
+        assert_eq!(world.get::<Position>(a), Some(&Position(1, 2)));
+        assert_eq!(world.get::<Target>(a), None);
+        // Types that aren't captured are left untouched.
+        assert_eq!(world.get::<Unregistered>(a).unwrap().0, 2);
+        assert_eq!(world.get::<Target>(b), Some(&Target(Some(a))));
+        assert_eq!(world.get::<Inventory>(b).unwrap().items, vec!["sword"]);
+
+        world.remove_resource::<Tick>();
+        world.restore_snapshot(&snapshot).unwrap();
+        assert_eq!(world.resource::<Tick>(), &Tick(1));
+    }
+
+    #[test]
+    fn restores_entity_ids() {
+        let mut world = world();
+        let a = world.spawn(Position(0, 0)).id();
+        let b = world.spawn(Position(1, 1)).id();
+        let c = world.spawn_empty().id();
+        world.despawn(b);
+        let snapshot = world.take_snapshot(&SnapshotFilter::allow_all()).unwrap();
+
+        let simulate = |world: &mut World| {
+            world.despawn(a);
+            world.despawn(c);
+            let spawned = [
+                world.spawn(Position(2, 2)).id(),
+                world.spawn(Target(None)).id(),
+                world.spawn(Target(None)).id(),
+            ];
+            world
+                .entity_mut(spawned[0])
+                .insert(Target(Some(spawned[1])));
+            spawned
+        };
+        let spawned = simulate(&mut world);
+
+        world.restore_snapshot(&snapshot).unwrap();
+        assert_eq!(world.entities().len(), 2);
+        assert_eq!(world.get::<Position>(a), Some(&Position(0, 0)));
+        assert!(world.get_entity(c).is_ok());
+        for entity in spawned {
+            assert!(world.get_entity(entity).is_err());
+        }
+
+        // Replaying the same operations after a rollback allocates the same ids.
+        assert_eq!(simulate(&mut world), spawned);
+    }
+
+    #[test]
+    fn keeps_entities_without_captured_components() {
+        let mut world = world();
+        let a = world.spawn(Position(0, 0)).id();
+        let snapshot = world.take_snapshot(&SnapshotFilter::allow_all()).unwrap();
+
+        let projectile = world.spawn(Position(1, 1)).id();
+        let observer = world.add_observer(|_: Trigger<OnAdd, Target>| {}).id();
+        let marker = world.spawn(Unregistered(1)).id();
+
+        world.restore_snapshot(&snapshot).unwrap();
+        assert!(world.get_entity(projectile).is_err());
+        assert!(world.get_entity(observer).is_ok());
+        assert_eq!(world.get::<Unregistered>(marker), Some(&Unregistered(1)));
+        assert_eq!(world.get::<Position>(a), Some(&Position(0, 0)));
+
+        // The kept entities stay allocated, so new entities don't reuse their ids.
+        let spawned = world.spawn_batch([Target(None), Target(None), Target(None)]);
+        for entity in spawned.collect::<Vec<_>>() {
+            assert!(![a, observer, marker].contains(&entity));
+        }
+        assert_eq!(world.entities().len(), 6);
+    }
+
+    #[test]
+    fn entity_without_captured_components_using_restored_index() {
+        let mut world = world();
+        let a = world.spawn(Position(0, 0)).id();
+        let snapshot = world.take_snapshot(&SnapshotFilter::allow_all()).unwrap();
+
+        world.despawn(a);
+        let marker = world.spawn(Unregistered(1)).id();
+        assert_eq!(marker.index(), a.index());
+
+        assert!(matches!(
+            world.restore_snapshot(&snapshot),
+            Err(SnapshotError::EntityIndexInUse { entity, snapshot_entity })
+                if entity == marker && snapshot_entity == a
+        ));
+        // The world is left untouched.
+        assert!(world.get_entity(a).is_err());
+        assert_eq!(world.get::<Unregistered>(marker), Some(&Unregistered(1)));
+    }
+
+    #[test]
+    fn pointer_sized_integers() {
+        #[derive(Component, Reflect, Debug, PartialEq)]
+        #[reflect(Component)]
+        struct Sizes(usize, isize);
+
+        let mut world = world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Sizes>();
+        let entity = world.spawn(Sizes(usize::MAX, isize::MIN)).id();
+        let snapshot = world.take_snapshot(&SnapshotFilter::allow_all()).unwrap();
+        // Both are written as 64-bit integers, after the entity and component headers.
+        assert!(snapshot.as_bytes().windows(16).any(|bytes| {
+            bytes[..8] == (usize::MAX as u64).to_le_bytes()
+                && bytes[8..] == (isize::MIN as i64).to_le_bytes()
+        }));
+
+        *world.get_mut::<Sizes>(entity).unwrap() = Sizes(0, 0);
+        world.restore_snapshot(&snapshot).unwrap();
+        assert_eq!(
+            world.get::<Sizes>(entity),
+            Some(&Sizes(usize::MAX, isize::MIN))
+        );
+    }
+
+    #[test]
+    fn filters_types() {
+        let mut world = world();
+        world.insert_resource(Tick(1));
+        let entity = world.spawn((Position(1, 1), Target(None))).id();
+        let filter = SnapshotFilter::deny_all().allow::<Position>();
+        let snapshot = world.take_snapshot(&filter).unwrap();
+
+        world.resource_mut::<Tick>().0 = 2;
+        world
+            .entity_mut(entity)
+            .insert((Position(2, 2), Target(Some(entity))));
+
+        world.restore_snapshot(&snapshot).unwrap();
+        assert_eq!(world.get::<Position>(entity), Some(&Position(1, 1)));
+        assert_eq!(world.get::<Target>(entity), Some(&Target(Some(entity))));
+        assert_eq!(world.resource::<Tick>(), &Tick(2));
+    }
+
+    #[test]
+    fn unsupported_type() {
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        struct Cooldown(core::time::Duration);
+
+        let mut world = world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Cooldown>();
+        world.spawn(Cooldown(core::time::Duration::ZERO));
+        assert!(matches!(
+            world.take_snapshot(&SnapshotFilter::allow_all()),
+            Err(SnapshotError::UnsupportedType { .. })
+        ));
+    }
+}
@@ -0,0 +1,535 @@
+//! Moving components with migratable storage between tables and sparse sets at runtime.
+
+use alloc::vec::Vec;
+#[cfg(feature = "track_location")]
+use core::panic::Location;
+
+use crate::{
+    archetype::{Archetype, ArchetypeId, Edges},
+    component::{Component, ComponentId, ComponentTicks, StorageType},
+    entity::Entity,
+    query::DebugCheckedUnwrap,
+    storage::TableRow,
+    world::World,
+};
+
+impl World {
+    /// Moves the data of the component `T` on every entity to the given [`StorageType`].
+    ///
+    /// Entities with the component are moved to archetypes that store it in its new storage,
+    /// keeping their change ticks. No hooks or observers are triggered.
+    ///
+    /// See [`ComponentInfo::archetype_moves`](crate::component::ComponentInfo::archetype_moves)
+    /// to decide which storage a component should use, or [`World::adapt_component_storage`]
+    /// to let the world decide.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` does not have [migratable storage](Component::MIGRATABLE_STORAGE).
+    pub fn migrate_component_storage<T: Component>(&mut self, storage_type: StorageType) {
+        let component_id = self.register_component::<T>();
+        self.migrate_component_storage_by_id(component_id, storage_type);
+    }
+
+    /// Moves the data of the component with the given [`ComponentId`] on every entity to the given
+    /// [`StorageType`].
+    ///
+    /// **You should prefer to use the typed API [`World::migrate_component_storage`] where possible.**
+    ///
+    /// # Panics
+    ///
+    /// Panics if the component does not exist in this world, or does not have
+    /// [migratable storage](Component::MIGRATABLE_STORAGE).
+    pub fn migrate_component_storage_by_id(
+        &mut self,
+        component_id: ComponentId,
+        storage_type: StorageType,
+    ) {
+        let Some(info) = self.components.get_info(component_id) else {
+            panic!("Component {component_id:?} does not exist in this world");
+        };
+        assert!(
+            info.migratable_storage(),
+            "Component {} does not have migratable storage, mark it with `#[component(migratable_storage)]`",
+            info.name()
+        );
+        if info.storage_type() == storage_type {
+            return;
+        }
+
+        self.flush();
+        self.components.set_storage_type(component_id, storage_type);
+        self.bundles.set_storage_type(component_id, storage_type);
+
+        // The cached archetype graph leads to archetypes that use the old storage.
+        for archetype in &mut self.archetypes.archetypes {
+            *archetype.edges_mut() = Edges::default();
+        }
+
+        // Archetypes using the old storage are left empty. They are only reused if the component
+        // is migrated back, at which point their layout is correct again.
+        let archetype_ids = self
+            .archetypes
+            .iter()
+            .filter(|archetype| archetype.contains(component_id) && !archetype.is_empty())
+            .map(Archetype::id)
+            .collect::<Vec<_>>();
+        for archetype_id in archetype_ids {
+            self.migrate_archetype(archetype_id, component_id, storage_type);
+        }
+    }
+
+    /// Moves every component with [migratable storage](Component::MIGRATABLE_STORAGE) to the
+    /// storage that suits how it was used since the [archetype moves were last reset](World::reset_archetype_moves).
+    ///
+    /// Components that caused more than `max_table_moves` archetype moves are moved to a
+    /// [`StorageType::SparseSet`], the others to a [`StorageType::Table`].
+    /// The archetype moves are reset afterwards, so this can be called periodically, or whenever
+    /// the usage of components is expected to have changed, like after loading a level.
+    pub fn adapt_component_storage(&mut self, max_table_moves: u64) {
+        let migrations = self
+            .components
+            .iter()
+            .filter(|info| info.migratable_storage())
+            .map(|info| {
+                let storage_type = if info.archetype_moves() > max_table_moves {
+                    StorageType::SparseSet
+                } else {
+                    StorageType::Table
+                };
+                (info.id(), storage_type)
+            })
+            .collect::<Vec<_>>();
+        for (component_id, storage_type) in migrations {
+            self.migrate_component_storage_by_id(component_id, storage_type);
+        }
+        self.reset_archetype_moves();
+    }
+
+    /// Resets the [archetype moves](crate::component::ComponentInfo::archetype_moves) counted
+    /// for every component.
+    pub fn reset_archetype_moves(&mut self) {
+        self.components.reset_archetype_moves();
+    }
+
+    /// Moves all entities of `old_archetype_id` to the archetype that stores `component_id` in
+    /// `storage_type` instead.
+    fn migrate_archetype(
+        &mut self,
+        old_archetype_id: ArchetypeId,
+        component_id: ComponentId,
+        storage_type: StorageType,
+    ) {
+        let old_archetype = &self.archetypes[old_archetype_id];
+        let old_table_id = old_archetype.table_id();
+        let mut table_components = old_archetype.table_components().collect::<Vec<_>>();
+        let mut sparse_set_components = old_archetype.sparse_set_components().collect::<Vec<_>>();
+        match storage_type {
+            StorageType::Table => {
+                sparse_set_components.retain(|&id| id != component_id);
+                table_components.push(component_id);
+                table_components.sort_unstable();
+            }
+            StorageType::SparseSet => {
+                table_components.retain(|&id| id != component_id);
+                sparse_set_components.push(component_id);
+                sparse_set_components.sort_unstable();
+            }
+        }
+
+        // SAFETY: The components of an existing archetype are valid and have their storage initialized.
+        let new_table_id = unsafe {
+            self.storages
+                .tables
+                .get_id_or_insert(&table_components, &self.components)
+        };
+        // SAFETY: `new_table_id` is the table for `table_components`.
+        let new_archetype_id = unsafe {
+            self.archetypes.get_id_or_insert(
+                &self.components,
+                &self.observers,
+                new_table_id,
+                table_components,
+                sparse_set_components,
+            )
+        };
+
+        while let Some(archetype_entity) = self.archetypes[old_archetype_id].entities().last() {
+            let entity = archetype_entity.id();
+            // SAFETY: Entities in an archetype have a valid location.
+            let location = unsafe { self.entities.get(entity).debug_checked_unwrap() };
+
+            let (old_archetype, new_archetype) = self
+                .archetypes
+                .get_2_mut(old_archetype_id, new_archetype_id);
+            // The last entity is removed, so no other entity is swapped into its archetype row.
+            let remove_result = old_archetype.swap_remove(location.archetype_row);
+            let old_table_row = remove_result.table_row;
+
+            let (old_table, new_table) = self.storages.tables.get_2_mut(old_table_id, new_table_id);
+            // SAFETY: A sparse set is created for every component with migratable storage.
+            let sparse_set = unsafe {
+                self.storages
+                    .sparse_sets
+                    .get_mut(component_id)
+                    .debug_checked_unwrap()
+            };
+            let move_result = match storage_type {
+                StorageType::Table => {
+                    // SAFETY: `old_table_row` is in bounds, and `new_table` has all columns of `old_table`.
+                    let move_result =
+                        unsafe { old_table.move_to_superset_unchecked(old_table_row, new_table) };
+                    // SAFETY: The entity has the component, which is stored in the sparse set.
+                    let ComponentTicks { added, changed } =
+                        unsafe { sparse_set.get_ticks(entity).debug_checked_unwrap() };
+                    #[cfg(feature = "track_location")]
+                    // SAFETY: See above.
+                    let caller: &'static Location<'static> = unsafe {
+                        *sparse_set
+                            .get_changed_by(entity)
+                            .debug_checked_unwrap()
+                            .get()
+                    };
+                    // SAFETY: See above.
+                    let value =
+                        unsafe { sparse_set.remove_and_forget(entity).debug_checked_unwrap() };
+                    // SAFETY:
+                    // - `new_table` stores the component, and its slot in the new row is uninitialized.
+                    // - `value` points to a value of the component.
+                    unsafe {
+                        let column = new_table
+                            .get_column_mut(component_id)
+                            .debug_checked_unwrap();
+                        column.initialize(
+                            move_result.new_row,
+                            value,
+                            changed,
+                            #[cfg(feature = "track_location")]
+                            caller,
+                        );
+                        *new_table
+                            .get_added_tick(component_id, move_result.new_row)
+                            .debug_checked_unwrap()
+                            .get() = added;
+                    }
+                    move_result
+                }
+                StorageType::SparseSet => {
+                    // SAFETY: `old_table` stores the component and `old_table_row` is in bounds.
+                    let ComponentTicks { added, changed } = unsafe {
+                        old_table
+                            .get_ticks_unchecked(component_id, old_table_row)
+                            .debug_checked_unwrap()
+                    };
+                    #[cfg(feature = "track_location")]
+                    // SAFETY: See above.
+                    let caller: &'static Location<'static> = unsafe {
+                        *old_table
+                            .get_changed_by(component_id, old_table_row)
+                            .debug_checked_unwrap()
+                            .get()
+                    };
+                    // SAFETY:
+                    // - `old_table` stores the component and `old_table_row` is in bounds.
+                    // - The taken value is forgotten by `move_to_and_forget_missing_unchecked` below.
+                    // - The sparse set stores values of the component.
+                    unsafe {
+                        let value = old_table.take_component(component_id, old_table_row);
+                        sparse_set.insert(
+                            entity,
+                            value,
+                            changed,
+                            #[cfg(feature = "track_location")]
+                            caller,
+                        );
+                        *sparse_set
+                            .get_added_tick(entity)
+                            .debug_checked_unwrap()
+                            .get() = added;
+                    }
+                    // SAFETY: `old_table_row` is in bounds.
+                    unsafe {
+                        old_table.move_to_and_forget_missing_unchecked(old_table_row, new_table)
+                    }
+                }
+            };
+
+            // SAFETY: `move_result.new_row` is the entity's row in the archetype's table.
+            let new_location = unsafe { new_archetype.allocate(entity, move_result.new_row) };
+            // SAFETY: The entity was just moved to `new_location`.
+            unsafe { self.entities.set(entity.index(), new_location) };
+
+            // If an entity was moved into this entity's table row, update its table row.
+            if let Some(swapped_entity) = move_result.swapped_entity {
+                self.set_table_row(swapped_entity, old_table_row);
+            }
+        }
+    }
+
+    /// Updates the table row of an `entity` whose data was moved within its table.
+    fn set_table_row(&mut self, entity: Entity, table_row: TableRow) {
+        // SAFETY: Entities in a table have a valid location.
+        let mut location = unsafe { self.entities.get(entity).debug_checked_unwrap() };
+        location.table_row = table_row;
+        self.archetypes.archetypes[location.archetype_id.index()]
+            .set_entity_table_row(location.archetype_row, table_row);
+        // SAFETY: Only the table row of the location changed, which matches the new table row.
+        unsafe { self.entities.set(entity.index(), location) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        component::{ComponentTicks, StorageType, Tick},
+        prelude::*,
+    };
+    use alloc::{sync::Arc, vec::Vec};
+
+    #[derive(Component, Debug, PartialEq)]
+    #[component(migratable_storage)]
+    struct A(u32);
+
+    #[derive(Component, Debug, PartialEq)]
+    struct B(u32);
+
+    #[derive(Component)]
+    #[component(migratable_storage)]
+    struct Counted(#[expect(dead_code, reason = "only held for its drop")] Arc<()>);
+
+    #[derive(Component, Default)]
+    #[require(A(|| A(7)))]
+    struct RequiresA;
+
+    #[derive(Component)]
+    struct NotMigratable;
+
+    fn storage_type_of(world: &World, entity: Entity) -> Option<StorageType> {
+        let id = world.component_id::<A>().unwrap();
+        world.entity(entity).archetype().get_storage_type(id)
+    }
+
+    fn values(world: &mut World) -> Vec<(u32, u32)> {
+        let mut values = world
+            .query::<(&A, &B)>()
+            .iter(world)
+            .map(|(a, b)| (a.0, b.0))
+            .collect::<Vec<_>>();
+        values.sort_unstable();
+        values
+    }
+
+    #[test]
+    fn migrate_table_to_sparse_set_and_back() {
+        let mut world = World::new();
+        let entities = (0..4)
+            .map(|i| world.spawn((A(i), B(i * 10))).id())
+            .collect::<Vec<_>>();
+        let only_a = world.spawn(A(100)).id();
+        let only_b = world.spawn(B(200)).id();
+        let ticks = |world: &World| -> Vec<(Tick, Tick)> {
+            entities
+                .iter()
+                .map(|&e| {
+                    let ComponentTicks { added, changed } =
+                        world.entity(e).get_change_ticks::<A>().unwrap();
+                    (added, changed)
+                })
+                .collect()
+        };
+        world.increment_change_tick();
+        world.get_mut::<A>(entities[1]).unwrap().0 = 11;
+        let before = ticks(&world);
+
+        world.migrate_component_storage::<A>(StorageType::SparseSet);
+        let id = world.component_id::<A>().unwrap();
+        assert_eq!(
+            world.components().get_info(id).unwrap().storage_type(),
+            StorageType::SparseSet
+        );
+        for &entity in entities.iter().chain([&only_a]) {
+            assert_eq!(
+                storage_type_of(&world, entity),
+                Some(StorageType::SparseSet)
+            );
+        }
+        assert_eq!(storage_type_of(&world, only_b), None);
+        assert_eq!(values(&mut world), [(0, 0), (2, 20), (3, 30), (11, 10)]);
+        assert_eq!(world.get::<A>(only_a), Some(&A(100)));
+        assert_eq!(world.get::<B>(only_b), Some(&B(200)));
+        assert_eq!(ticks(&world), before);
+
+        world.migrate_component_storage::<A>(StorageType::Table);
+        for &entity in entities.iter().chain([&only_a]) {
+            assert_eq!(storage_type_of(&world, entity), Some(StorageType::Table));
+        }
+        assert_eq!(values(&mut world), [(0, 0), (2, 20), (3, 30), (11, 10)]);
+        assert_eq!(world.get::<A>(only_a), Some(&A(100)));
+        assert_eq!(ticks(&world), before);
+    }
+
+    #[test]
+    fn queries_after_migration() {
+        let mut world = World::new();
+        let e1 = world.spawn((A(1), B(1))).id();
+        let e2 = world.spawn(B(2)).id();
+        let mut changed = world.query_filtered::<Entity, Changed<A>>();
+        let mut with = world.query_filtered::<Entity, With<A>>();
+        let mut without = world.query_filtered::<Entity, Without<A>>();
+        let mut has = world.query::<(Entity, Has<A>)>();
+        assert_eq!(changed.iter(&world).collect::<Vec<_>>(), [e1]);
+        world.clear_trackers();
+
+        for storage_type in [StorageType::SparseSet, StorageType::Table] {
+            world.migrate_component_storage::<A>(storage_type);
+            assert!(changed.iter(&world).next().is_none());
+            assert_eq!(with.iter(&world).collect::<Vec<_>>(), [e1]);
+            assert_eq!(without.iter(&world).collect::<Vec<_>>(), [e2]);
+            let mut has = has.iter(&world).collect::<Vec<_>>();
+            has.sort_unstable();
+            assert_eq!(has, [(e1, true), (e2, false)]);
+
+            for mut a in world.query::<&mut A>().iter_mut(&mut world) {
+                a.0 += 1;
+            }
+            assert_eq!(changed.iter(&world).collect::<Vec<_>>(), [e1]);
+            world.clear_trackers();
+        }
+        assert_eq!(world.get::<A>(e1), Some(&A(3)));
+    }
+
+    #[test]
+    fn insert_and_remove_after_migration() {
+        let mut world = World::new();
+        let e1 = world.spawn((A(1), B(1))).id();
+        let e2 = world.spawn(B(2)).id();
+        world.migrate_component_storage::<A>(StorageType::SparseSet);
+
+        world.entity_mut(e2).insert(A(2));
+        let e3 = world.spawn((A(3), B(3))).id();
+        let e4 = world.spawn(RequiresA).id();
+        for entity in [e2, e3, e4] {
+            assert_eq!(
+                storage_type_of(&world, entity),
+                Some(StorageType::SparseSet)
+            );
+        }
+        assert_eq!(world.get::<A>(e4), Some(&A(7)));
+        assert_eq!(values(&mut world), [(1, 1), (2, 2), (3, 3)]);
+
+        assert_eq!(world.entity_mut(e1).take::<A>(), Some(A(1)));
+        world.entity_mut(e2).remove::<A>();
+        assert_eq!(values(&mut world), [(3, 3)]);
+
+        world.migrate_component_storage::<A>(StorageType::Table);
+        world.entity_mut(e1).insert(A(1));
+        let e5 = world.spawn(RequiresA).id();
+        for entity in [e1, e3, e4, e5] {
+            assert_eq!(storage_type_of(&world, entity), Some(StorageType::Table));
+        }
+        assert_eq!(values(&mut world), [(1, 1), (3, 3)]);
+        assert_eq!(world.get::<A>(e5), Some(&A(7)));
+    }
+
+    #[test]
+    fn migration_drops_each_value_once() {
+        let mut world = World::new();
+        let counter = Arc::new(());
+        let entities = (0..3)
+            .map(|i| world.spawn((Counted(counter.clone()), B(i))).id())
+            .collect::<Vec<_>>();
+        world.migrate_component_storage::<Counted>(StorageType::SparseSet);
+        world.migrate_component_storage::<Counted>(StorageType::Table);
+        world.migrate_component_storage::<Counted>(StorageType::SparseSet);
+        assert_eq!(Arc::strong_count(&counter), 4);
+
+        world.despawn(entities[0]);
+        assert_eq!(Arc::strong_count(&counter), 3);
+        drop(world);
+        assert_eq!(Arc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn archetype_moves() {
+        let mut world = World::new();
+        let id = world.register_component::<A>();
+        let moves = |world: &World| world.components().get_info(id).unwrap().archetype_moves();
+
+        let e1 = world.spawn(A(1)).id();
+        let e2 = world.spawn(B(2)).id();
+        assert_eq!(moves(&world), 0);
+
+        world.entity_mut(e2).insert(A(2));
+        assert_eq!(moves(&world), 1);
+        world.entity_mut(e2).insert(A(3));
+        assert_eq!(moves(&world), 1);
+        world.entity_mut(e2).remove::<A>();
+        assert_eq!(moves(&world), 2);
+        assert_eq!(world.entity_mut(e1).take::<A>(), Some(A(1)));
+        assert_eq!(moves(&world), 3);
+        world.entity_mut(e1).insert(B(1));
+        assert_eq!(moves(&world), 3);
+
+        world.reset_archetype_moves();
+        assert_eq!(moves(&world), 0);
+
+        // Only moves caused by components with migratable storage are counted.
+        let not_migratable = world.register_component::<NotMigratable>();
+        world.entity_mut(e1).insert(NotMigratable);
+        assert_eq!(
+            world
+                .components()
+                .get_info(not_migratable)
+                .unwrap()
+                .archetype_moves(),
+            0
+        );
+    }
+
+    #[test]
+    fn adapt_component_storage() {
+        let mut world = World::new();
+        let id = world.register_component::<A>();
+        let entity = world.spawn(B(0)).id();
+        for i in 0..5 {
+            world.entity_mut(entity).insert(A(i));
+            world.entity_mut(entity).remove::<A>();
+        }
+
+        world.adapt_component_storage(10);
+        assert_eq!(
+            world.components().get_info(id).unwrap().storage_type(),
+            StorageType::Table
+        );
+
+        for i in 0..6 {
+            world.entity_mut(entity).insert(A(i));
+            world.entity_mut(entity).remove::<A>();
+        }
+        world.entity_mut(entity).insert(A(6));
+        world.adapt_component_storage(10);
+        assert_eq!(
+            world.components().get_info(id).unwrap().storage_type(),
+            StorageType::SparseSet
+        );
+        assert_eq!(
+            storage_type_of(&world, entity),
+            Some(StorageType::SparseSet)
+        );
+        assert_eq!(
+            world.components().get_info(id).unwrap().archetype_moves(),
+            0
+        );
+
+        world.adapt_component_storage(10);
+        assert_eq!(storage_type_of(&world, entity), Some(StorageType::Table));
+    }
+
+    #[test]
+    #[should_panic]
+    fn migrate_non_migratable_component() {
+        let mut world = World::new();
+        world.migrate_component_storage::<NotMigratable>(StorageType::SparseSet);
+    }
+}
@@ -0,0 +1,439 @@
+//! Copying and moving entities from one [`World`] to another.
+
+use alloc::vec::Vec;
+use core::any::TypeId;
+
+use crate::{
+    component::Component,
+    entity::{Entity, EntityHashMap, EntityMapper, MapEntities},
+    world::{error::EntityFetchError, EntityRef, EntityWorldMut, World},
+};
+
+type CopyComponentFn = fn(&EntityRef, &mut EntityWorldMut, &mut dyn EntityMapper);
+type MoveComponentFn = fn(&mut EntityWorldMut, &mut EntityWorldMut, &mut dyn EntityMapper);
+
+#[derive(Debug, Clone)]
+struct TransferredComponent {
+    type_id: TypeId,
+    /// `None` for components that can only be moved.
+    copy: Option<CopyComponentFn>,
+    take: MoveComponentFn,
+}
+
+/// Copies or moves entities, along with a selection of their components, from one [`World`] to
+/// another.
+///
+/// Components are selected by type, as component ids differ between worlds. Components that
+/// store [`Entity`] references can be [mapped](Self::mapped_component), so that references to
+/// transferred entities point at their counterparts in the destination world. Components that
+/// don't implement [`Clone`] can still be [moved](Self::move_only_component).
+///
+/// The correspondence between source and destination entities is kept in an [`EntityHashMap`]
+/// that can be reused across transfers: entities that were already transferred are updated in
+/// place instead of being spawned again, which keeps two worlds in sync.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::entity::{EntityHashMap, MapEntities};
+/// # use bevy_ecs::world::EntityTransfer;
+/// #[derive(Component, Clone)]
+/// struct Health(u32);
+///
+/// #[derive(Component, Clone)]
+/// struct Target(Entity);
+///
+/// impl MapEntities for Target {
+///     fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+///         self.0 = entity_mapper.map_entity(self.0);
+///     }
+/// }
+///
+/// let mut simulation = World::new();
+/// let enemy = simulation.spawn(Health(10)).id();
+/// let player = simulation.spawn((Health(20), Target(enemy))).id();
+///
+/// let mut presentation = World::new();
+/// let mut entity_map = EntityHashMap::default();
+/// let transfer = EntityTransfer::new()
+///     .component::<Health>()
+///     .mapped_component::<Target>();
+/// transfer
+///     .copy(&simulation, &mut presentation, [enemy, player], &mut entity_map)
+///     .unwrap();
+///
+/// let target = presentation.get::<Target>(entity_map[&player]).unwrap();
+/// assert_eq!(target.0, entity_map[&enemy]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EntityTransfer {
+    components: Vec<TransferredComponent>,
+}
+
+impl EntityTransfer {
+    /// Creates an [`EntityTransfer`] that doesn't transfer any component.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Transfers the component `C` as is.
+    #[must_use]
+    pub fn component<C: Component + Clone>(self) -> Self {
+        self.with(TransferredComponent {
+            type_id: TypeId::of::<C>(),
+            copy: Some(copy_component::<C>),
+            take: move_component::<C>,
+        })
+    }
+
+    /// Transfers the component `C` as is when moving entities, and skips it when copying them.
+    ///
+    /// Unlike [`component`](Self::component), this doesn't require `C` to implement [`Clone`].
+    #[must_use]
+    pub fn move_only_component<C: Component>(self) -> Self {
+        self.with(TransferredComponent {
+            type_id: TypeId::of::<C>(),
+            copy: None,
+            take: move_component::<C>,
+        })
+    }
+
+    /// Transfers the component `C`, remapping the [`Entity`] references it stores.
+    ///
+    /// References to transferred entities are mapped to their counterparts in the destination
+    /// world. Other references are mapped to [`Entity::PLACEHOLDER`], as they would be
+    /// meaningless there.
+    #[must_use]
+    pub fn mapped_component<C: Component + Clone + MapEntities>(self) -> Self {
+        self.with(TransferredComponent {
+            type_id: TypeId::of::<C>(),
+            copy: Some(copy_mapped_component::<C>),
+            take: move_mapped_component::<C>,
+        })
+    }
+
+    /// Transfers the component `C` when moving entities, remapping the [`Entity`] references it
+    /// stores like [`mapped_component`](Self::mapped_component), and skips it when copying them.
+    ///
+    /// Unlike [`mapped_component`](Self::mapped_component), this doesn't require `C` to implement
+    /// [`Clone`].
+    #[must_use]
+    pub fn move_only_mapped_component<C: Component + MapEntities>(self) -> Self {
+        self.with(TransferredComponent {
+            type_id: TypeId::of::<C>(),
+            copy: None,
+            take: move_mapped_component::<C>,
+        })
+    }
+
+    fn with(mut self, component: TransferredComponent) -> Self {
+        self.components
+            .retain(|transferred| transferred.type_id != component.type_id);
+        self.components.push(component);
+        self
+    }
+
+    /// Copies `entities` from `source` into `destination`, with the selected components.
+    ///
+    /// Entities that `entity_map` already maps to an entity of `destination` are updated,
+    /// others are spawned and added to `entity_map`.
+    /// Selected components are inserted, triggering their hooks and observers in `destination`,
+    /// and selected components that the source entity doesn't have are removed from its
+    /// counterpart. [Move-only](Self::move_only_component) components are left untouched.
+    ///
+    /// Returns an error without changing anything if one of the entities doesn't exist.
+    pub fn copy(
+        &self,
+        source: &World,
+        destination: &mut World,
+        entities: impl IntoIterator<Item = Entity>,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<(), EntityFetchError> {
+        let entities = prepare(source, destination, entities, entity_map)?;
+        let mut mapper = TransferEntityMapper { map: entity_map };
+        for (source_entity, target) in entities {
+            let source_entity = source.entity(source_entity);
+            let mut target = destination.entity_mut(target);
+            for copy in self
+                .components
+                .iter()
+                .filter_map(|component| component.copy)
+            {
+                copy(&source_entity, &mut target, &mut mapper);
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves `entities` from `source` into `destination`, with the selected components.
+    ///
+    /// This works like [`copy`](Self::copy), except that the selected components are taken
+    /// rather than cloned, including [move-only](Self::move_only_component) ones, and that the
+    /// entities are then despawned from `source`, dropping their other components.
+    pub fn move_entities(
+        &self,
+        source: &mut World,
+        destination: &mut World,
+        entities: impl IntoIterator<Item = Entity>,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<(), EntityFetchError> {
+        let entities = prepare(source, destination, entities, entity_map)?;
+        let mut mapper = TransferEntityMapper { map: entity_map };
+        for &(source_entity, target) in &entities {
+            // Hooks of previously moved components may have despawned the entity.
+            let Ok(mut source_entity) = source.get_entity_mut(source_entity) else {
+                continue;
+            };
+            let mut target = destination.entity_mut(target);
+            for component in &self.components {
+                (component.take)(&mut source_entity, &mut target, &mut mapper);
+            }
+        }
+        for (source_entity, _) in entities {
+            source.try_despawn(source_entity);
+        }
+        Ok(())
+    }
+}
+
+/// Checks that `entities` exist in `source`, and finds or spawns their counterparts in
+/// `destination`.
+fn prepare(
+    source: &World,
+    destination: &mut World,
+    entities: impl IntoIterator<Item = Entity>,
+    entity_map: &mut EntityHashMap<Entity>,
+) -> Result<Vec<(Entity, Entity)>, EntityFetchError> {
+    let entities: Vec<Entity> = entities.into_iter().collect();
+    if let Some(&entity) = entities
+        .iter()
+        .find(|&&entity| source.get_entity(entity).is_err())
+    {
+        return Err(EntityFetchError::NoSuchEntity(
+            entity,
+            source
+                .entities()
+                .entity_does_not_exist_error_details(entity),
+        ));
+    }
+    Ok(entities
+        .into_iter()
+        .map(|entity| {
+            let target = match entity_map.get(&entity) {
+                Some(&target) if destination.get_entity(target).is_ok() => target,
+                _ => {
+                    let target = destination.spawn_empty().id();
+                    entity_map.insert(entity, target);
+                    target
+                }
+            };
+            (entity, target)
+        })
+        .collect())
+}
+
+/// Maps transferred entities to their counterparts, and other entities to [`Entity::PLACEHOLDER`].
+struct TransferEntityMapper<'m> {
+    map: &'m EntityHashMap<Entity>,
+}
+
+impl EntityMapper for TransferEntityMapper<'_> {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.map
+            .get(&entity)
+            .copied()
+            .unwrap_or(Entity::PLACEHOLDER)
+    }
+}
+
+fn copy_component<C: Component + Clone>(
+    source: &EntityRef,
+    target: &mut EntityWorldMut,
+    _mapper: &mut dyn EntityMapper,
+) {
+    if let Some(component) = source.get::<C>() {
+        target.insert(component.clone());
+    } else if target.contains::<C>() {
+        target.remove::<C>();
+    }
+}
+
+fn copy_mapped_component<C: Component + Clone + MapEntities>(
+    source: &EntityRef,
+    target: &mut EntityWorldMut,
+    mut mapper: &mut dyn EntityMapper,
+) {
+    if let Some(component) = source.get::<C>() {
+        let mut component = component.clone();
+        component.map_entities(&mut mapper);
+        target.insert(component);
+    } else if target.contains::<C>() {
+        target.remove::<C>();
+    }
+}
+
+fn move_component<C: Component>(
+    source: &mut EntityWorldMut,
+    target: &mut EntityWorldMut,
+    _mapper: &mut dyn EntityMapper,
+) {
+    if let Some(component) = source.take::<C>() {
+        target.insert(component);
+    } else if target.contains::<C>() {
+        target.remove::<C>();
+    }
+}
+
+fn move_mapped_component<C: Component + MapEntities>(
+    source: &mut EntityWorldMut,
+    target: &mut EntityWorldMut,
+    mut mapper: &mut dyn EntityMapper,
+) {
+    if let Some(mut component) = source.take::<C>() {
+        component.map_entities(&mut mapper);
+        target.insert(component);
+    } else if target.contains::<C>() {
+        target.remove::<C>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntityTransfer;
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        entity::{Entity, EntityHashMap, EntityMapper, MapEntities},
+        world::{error::EntityFetchError, World},
+    };
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    struct Health(u32);
+
+    #[derive(Component, Clone, PartialEq, Debug)]
+    struct Target(Entity);
+
+    impl MapEntities for Target {
+        fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+            self.0 = entity_mapper.map_entity(self.0);
+        }
+    }
+
+    #[derive(Component)]
+    struct Local;
+
+    #[derive(Component, PartialEq, Debug)]
+    struct Handle(u32);
+
+    fn transfer() -> EntityTransfer {
+        EntityTransfer::new()
+            .component::<Health>()
+            .mapped_component::<Target>()
+    }
+
+    #[test]
+    fn copy_keeps_worlds_in_sync() {
+        let mut source = World::new();
+        let outside = source.spawn_empty().id();
+        let a = source.spawn((Health(1), Local)).id();
+        let b = source.spawn((Health(2), Target(a))).id();
+        let c = source.spawn(Target(outside)).id();
+
+        let mut destination = World::new();
+        destination.spawn_empty();
+        let mut entity_map = EntityHashMap::default();
+        transfer()
+            .copy(&source, &mut destination, [a, b, c], &mut entity_map)
+            .unwrap();
+
+        assert_eq!(entity_map.len(), 3);
+        let (a2, b2, c2) = (entity_map[&a], entity_map[&b], entity_map[&c]);
+        assert_eq!(destination.get::<Health>(a2), Some(&Health(1)));
+        assert!(!destination.entity(a2).contains::<Local>());
+        assert_eq!(destination.get::<Target>(b2), Some(&Target(a2)));
+        assert_eq!(
+            destination.get::<Target>(c2),
+            Some(&Target(Entity::PLACEHOLDER))
+        );
+
+        // Copying again updates the same entities.
+        source.get_mut::<Health>(a).unwrap().0 = 5;
+        transfer()
+            .copy(&source, &mut destination, [a], &mut entity_map)
+            .unwrap();
+        assert_eq!(entity_map[&a], a2);
+        assert_eq!(destination.get::<Health>(a2), Some(&Health(5)));
+        assert_eq!(destination.entities().len(), 4);
+        assert!(source.get_entity(a).is_ok());
+
+        // Components removed from the source are removed from the copies.
+        source.entity_mut(b).remove::<Target>();
+        transfer()
+            .copy(&source, &mut destination, [b], &mut entity_map)
+            .unwrap();
+        assert_eq!(destination.get::<Health>(b2), Some(&Health(2)));
+        assert!(!destination.entity(b2).contains::<Target>());
+    }
+
+    #[test]
+    fn move_only_components() {
+        let mut source = World::new();
+        let a = source.spawn((Health(1), Handle(7))).id();
+        let b = source.spawn(Handle(8)).id();
+
+        let transfer = transfer().move_only_component::<Handle>();
+        let mut destination = World::new();
+        let mut entity_map = EntityHashMap::default();
+        transfer
+            .copy(&source, &mut destination, [a], &mut entity_map)
+            .unwrap();
+        let a2 = entity_map[&a];
+        assert_eq!(destination.get::<Health>(a2), Some(&Health(1)));
+        assert!(!destination.entity(a2).contains::<Handle>());
+
+        transfer
+            .move_entities(&mut source, &mut destination, [a, b], &mut entity_map)
+            .unwrap();
+        assert_eq!(destination.get::<Handle>(a2), Some(&Handle(7)));
+        assert_eq!(destination.get::<Handle>(entity_map[&b]), Some(&Handle(8)));
+        assert!(source.get_entity(a).is_err());
+    }
+
+    #[test]
+    fn move_despawns_source_entities() {
+        let mut source = World::new();
+        let a = source.spawn((Health(1), Local)).id();
+        let b = source.spawn((Health(2), Target(a))).id();
+
+        let mut destination = World::new();
+        let mut entity_map = EntityHashMap::default();
+        transfer()
+            .move_entities(&mut source, &mut destination, [a, b], &mut entity_map)
+            .unwrap();
+
+        assert!(source.get_entity(a).is_err());
+        assert!(source.get_entity(b).is_err());
+        let (a2, b2) = (entity_map[&a], entity_map[&b]);
+        assert_eq!(destination.get::<Health>(a2), Some(&Health(1)));
+        assert_eq!(destination.get::<Health>(b2), Some(&Health(2)));
+        assert_eq!(destination.get::<Target>(b2), Some(&Target(a2)));
+    }
+
+    #[test]
+    fn missing_entity() {
+        let mut source = World::new();
+        let a = source.spawn(Health(1)).id();
+        let missing = source.spawn_empty().id();
+        source.despawn(missing);
+
+        let mut destination = World::new();
+        let mut entity_map = EntityHashMap::default();
+        let result =
+            transfer().move_entities(&mut source, &mut destination, [a, missing], &mut entity_map);
+        assert!(
+            matches!(result, Err(EntityFetchError::NoSuchEntity(entity, _)) if entity == missing)
+        );
+        assert!(entity_map.is_empty());
+        assert_eq!(source.get::<Health>(a), Some(&Health(1)));
+        assert_eq!(destination.entities().len(), 0);
+    }
+}
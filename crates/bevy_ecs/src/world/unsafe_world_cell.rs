@@ -740,14 +740,14 @@ impl<'w> UnsafeEntityCell<'w> {
     pub unsafe fn get<T: Component>(self) -> Option<&'w T> {
         let component_id = self.world.components().get_id(TypeId::of::<T>())?;
         // SAFETY:
-        // - `storage_type` is correct (T component_id + T::STORAGE_TYPE)
+        // - `storage_type` is correct (T component_id + storage_type_of::<T>)
         // - `location` is valid
         // - proper aliasing is promised by caller
         unsafe {
             get_component(
                 self.world,
                 component_id,
-                T::STORAGE_TYPE,
+                self.world.components().storage_type_of::<T>(component_id),
                 self.entity,
                 self.location,
             )
@@ -767,14 +767,14 @@ impl<'w> UnsafeEntityCell<'w> {
         let component_id = self.world.components().get_id(TypeId::of::<T>())?;
 
         // SAFETY:
-        // - `storage_type` is correct (T component_id + T::STORAGE_TYPE)
+        // - `storage_type` is correct (T component_id + storage_type_of::<T>)
         // - `location` is valid
         // - proper aliasing is promised by caller
         unsafe {
             get_component_and_ticks(
                 self.world,
                 component_id,
-                T::STORAGE_TYPE,
+                self.world.components().storage_type_of::<T>(component_id),
                 self.entity,
                 self.location,
             )
@@ -806,7 +806,7 @@ impl<'w> UnsafeEntityCell<'w> {
             get_ticks(
                 self.world,
                 component_id,
-                T::STORAGE_TYPE,
+                self.world.components().storage_type_of::<T>(component_id),
                 self.entity,
                 self.location,
             )
@@ -894,7 +894,7 @@ impl<'w> UnsafeEntityCell<'w> {
             get_component_and_ticks(
                 self.world,
                 component_id,
-                T::STORAGE_TYPE,
+                self.world.components().storage_type_of::<T>(component_id),
                 self.entity,
                 self.location,
             )
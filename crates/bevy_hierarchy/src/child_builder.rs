@@ -3,10 +3,11 @@ use bevy_ecs::{
     bundle::Bundle,
     entity::Entity,
     event::Events,
+    relationship::RelationshipTarget,
     system::{Command, Commands, EntityCommands},
     world::{EntityWorldMut, World},
 };
-use smallvec::{smallvec, SmallVec};
+use smallvec::SmallVec;
 
 // Do not use `world.send_event_batch` as it prints error message when the Events are not available in the world,
 // even though it's a valid use case to execute commands on a world without events. Loading a GLTF file for example
@@ -16,105 +17,47 @@ fn push_events(world: &mut World, events: impl IntoIterator<Item = HierarchyEven
     }
 }
 
-/// Adds `child` to `parent`'s [`Children`], without checking if it is already present there.
+/// Sets the [`Parent`] of each of the `children` to `parent`.
 ///
-/// This might cause unexpected results when removing duplicate children.
-fn add_child_unchecked(world: &mut World, parent: Entity, child: Entity) {
-    let mut parent = world.entity_mut(parent);
-    if let Some(mut children) = parent.get_mut::<Children>() {
-        children.0.push(child);
-    } else {
-        parent.insert(Children(smallvec![child]));
-    }
-}
-
-/// Sets [`Parent`] of the `child` to `new_parent`. Inserts [`Parent`] if `child` doesn't have one.
-fn update_parent(world: &mut World, child: Entity, new_parent: Entity) -> Option<Entity> {
-    let mut child = world.entity_mut(child);
-    if let Some(mut parent) = child.get_mut::<Parent>() {
-        let previous = parent.0;
-        *parent = Parent(new_parent);
-        Some(previous)
-    } else {
-        child.insert(Parent(new_parent));
-        None
-    }
-}
-
-/// Remove child from the parent's [`Children`] component.
-///
-/// Removes the [`Children`] component from the parent if it's empty.
-fn remove_from_children(world: &mut World, parent: Entity, child: Entity) {
-    let Ok(mut parent) = world.get_entity_mut(parent) else {
-        return;
-    };
-    let Some(mut children) = parent.get_mut::<Children>() else {
-        return;
-    };
-    children.0.retain(|x| *x != child);
-    if children.is_empty() {
-        parent.remove::<Children>();
-    }
-}
-
-/// Update the [`Parent`] component of the `child`.
-/// Removes the `child` from the previous parent's [`Children`].
-///
-/// Does not update the new parents [`Children`] component.
-///
-/// Does nothing if `child` was already a child of `parent`.
+/// The [`Children`] of both the new and the previous parents are kept in sync by the
+/// [`Relationship`] hooks of [`Parent`]. Children that already belong to `parent` are left untouched.
 ///
 /// Sends [`HierarchyEvent`]'s.
-fn update_old_parent(world: &mut World, child: Entity, parent: Entity) {
-    let previous = update_parent(world, child, parent);
-    if let Some(previous_parent) = previous {
-        // Do nothing if the child was already parented to this entity.
-        if previous_parent == parent {
-            return;
+///
+/// [`Relationship`]: bevy_ecs::relationship::Relationship
+fn update_parents(world: &mut World, parent: Entity, children: &[Entity]) {
+    let mut events: SmallVec<[HierarchyEvent; 8]> = SmallVec::with_capacity(children.len());
+    for &child in children {
+        let previous = world.get::<Parent>(child).map(Parent::get);
+        // Do nothing if the entity already has the correct parent.
+        if previous == Some(parent) {
+            continue;
         }
-        remove_from_children(world, previous_parent, child);
-
-        push_events(
-            world,
-            [HierarchyEvent::ChildMoved {
+        world.entity_mut(child).insert(Parent(parent));
+        events.push(match previous {
+            Some(previous_parent) => HierarchyEvent::ChildMoved {
                 child,
                 previous_parent,
                 new_parent: parent,
-            }],
-        );
-    } else {
-        push_events(world, [HierarchyEvent::ChildAdded { child, parent }]);
+            },
+            None => HierarchyEvent::ChildAdded { child, parent },
+        });
     }
+    push_events(world, events);
 }
 
-/// Update the [`Parent`] components of the `children`.
-/// Removes the `children` from their previous parent's [`Children`].
-///
-/// Does not update the new parents [`Children`] component.
-///
-/// Does nothing for a child if it was already a child of `parent`.
-///
-/// Sends [`HierarchyEvent`]'s.
-fn update_old_parents(world: &mut World, parent: Entity, children: &[Entity]) {
-    let mut events: SmallVec<[HierarchyEvent; 8]> = SmallVec::with_capacity(children.len());
-    for &child in children {
-        if let Some(previous) = update_parent(world, child, parent) {
-            // Do nothing if the entity already has the correct parent.
-            if parent == previous {
-                continue;
-            }
-
-            remove_from_children(world, previous, child);
-            events.push(HierarchyEvent::ChildMoved {
-                child,
-                previous_parent: previous,
-                new_parent: parent,
-            });
-        } else {
-            events.push(HierarchyEvent::ChildAdded { child, parent });
-        }
+/// Moves the given `children` of `parent` so that they are stored contiguously at `index`
+/// (or at the end, if `index` is `None`) of its [`Children`], preserving their relative order.
+fn reorder_children(world: &mut World, parent: Entity, children: &[Entity], index: Option<usize>) {
+    let Some(mut parent_children) = world.get_mut::<Children>(parent) else {
+        return;
+    };
+    let collection = parent_children.collection_mut_risky();
+    collection.retain(|value| !children.contains(value));
+    match index {
+        Some(index) => collection.insert_from_slice(index, children),
+        None => collection.extend_from_slice(children),
     }
-    push_events(world, events);
 }
 
 /// Removes entities in `children` from `parent`'s [`Children`], removing the component if it ends up empty.
@@ -136,17 +79,6 @@ fn remove_children(parent: Entity, children: &[Entity], world: &mut World) {
         }
     }
     push_events(world, events);
-
-    let mut parent = world.entity_mut(parent);
-    if let Some(mut parent_children) = parent.get_mut::<Children>() {
-        parent_children
-            .0
-            .retain(|parent_child| !children.contains(parent_child));
-
-        if parent_children.is_empty() {
-            parent.remove::<Children>();
-        }
-    }
 }
 
 /// Struct for building children entities and adding them to a parent entity.
@@ -453,7 +385,6 @@ impl ChildBuild for WorldChildBuilder<'_> {
 
     fn spawn(&mut self, bundle: impl Bundle) -> EntityWorldMut {
         let entity = self.world.spawn((bundle, Parent(self.parent))).id();
-        add_child_unchecked(self.world, self.parent, entity);
         push_events(
             self.world,
             [HierarchyEvent::ChildAdded {
@@ -499,13 +430,9 @@ impl BuildChildren for EntityWorldMut<'_> {
 
     fn with_child<B: Bundle>(&mut self, bundle: B) -> &mut Self {
         let parent = self.id();
-        let child = self.world_scope(|world| world.spawn((bundle, Parent(parent))).id());
-        if let Some(mut children_component) = self.get_mut::<Children>() {
-            children_component.0.retain(|value| child != *value);
-            children_component.0.push(child);
-        } else {
-            self.insert(Children::from_entities(&[child]));
-        }
+        self.world_scope(|world| {
+            world.spawn((bundle, Parent(parent)));
+        });
         self
     }
 
@@ -515,14 +442,9 @@ impl BuildChildren for EntityWorldMut<'_> {
             panic!("Cannot add entity as a child of itself.");
         }
         self.world_scope(|world| {
-            update_old_parent(world, child, parent);
+            update_parents(world, parent, &[child]);
+            reorder_children(world, parent, &[child], None);
         });
-        if let Some(mut children_component) = self.get_mut::<Children>() {
-            children_component.0.retain(|value| child != *value);
-            children_component.0.push(child);
-        } else {
-            self.insert(Children::from_entities(&[child]));
-        }
         self
     }
 
@@ -536,16 +458,9 @@ impl BuildChildren for EntityWorldMut<'_> {
             panic!("Cannot push entity as a child of itself.");
         }
         self.world_scope(|world| {
-            update_old_parents(world, parent, children);
+            update_parents(world, parent, children);
+            reorder_children(world, parent, children, None);
         });
-        if let Some(mut children_component) = self.get_mut::<Children>() {
-            children_component
-                .0
-                .retain(|value| !children.contains(value));
-            children_component.0.extend(children.iter().cloned());
-        } else {
-            self.insert(Children::from_entities(children));
-        }
         self
     }
 
@@ -555,16 +470,9 @@ impl BuildChildren for EntityWorldMut<'_> {
             panic!("Cannot insert entity as a child of itself.");
         }
         self.world_scope(|world| {
-            update_old_parents(world, parent, children);
+            update_parents(world, parent, children);
+            reorder_children(world, parent, children, Some(index));
         });
-        if let Some(mut children_component) = self.get_mut::<Children>() {
-            children_component
-                .0
-                .retain(|value| !children.contains(value));
-            children_component.0.insert_from_slice(index, children);
-        } else {
-            self.insert(Children::from_entities(children));
-        }
         self
     }
 
@@ -588,7 +496,6 @@ impl BuildChildren for EntityWorldMut<'_> {
         let child = self.id();
         if let Some(parent) = self.take::<Parent>().map(|p| p.get()) {
             self.world_scope(|world| {
-                remove_from_children(world, parent, child);
                 push_events(world, [HierarchyEvent::ChildRemoved { child, parent }]);
             });
         }
@@ -596,15 +503,8 @@ impl BuildChildren for EntityWorldMut<'_> {
     }
 
     fn clear_children(&mut self) -> &mut Self {
-        let parent = self.id();
-        self.world_scope(|world| {
-            if let Some(children) = world.entity_mut(parent).take::<Children>() {
-                for &child in &children.0 {
-                    world.entity_mut(child).remove::<Parent>();
-                }
-            }
-        });
-        self
+        // The `Parent` components of the children are removed by the hooks of `Children`.
+        self.remove::<Children>()
     }
 
     fn replace_children(&mut self, children: &[Entity]) -> &mut Self {
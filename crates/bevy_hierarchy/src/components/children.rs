@@ -1,10 +1,11 @@
+use crate::Parent;
 #[cfg(feature = "reflect")]
 use bevy_ecs::reflect::{
     ReflectComponent, ReflectFromWorld, ReflectMapEntities, ReflectVisitEntities,
     ReflectVisitEntitiesMut,
 };
 use bevy_ecs::{
-    component::{Component, ComponentCloneHandler, Mutable, StorageType},
+    component::Component,
     entity::{Entity, VisitEntitiesMut},
     prelude::FromWorld,
     world::World,
@@ -15,7 +16,8 @@ use smallvec::SmallVec;
 /// Contains references to the child entities of this entity.
 ///
 /// Each child must contain a [`Parent`] component that points back to this entity.
-/// This component rarely needs to be created manually,
+/// [`Children`] is the [`RelationshipTarget`] of [`Parent`], and is kept up to date automatically
+/// whenever a [`Parent`] component is inserted or removed. It should never be created manually;
 /// consider using higher level utilities like [`BuildChildren::with_children`]
 /// which are safer and easier to use.
 ///
@@ -25,7 +27,9 @@ use smallvec::SmallVec;
 /// [`Query`]: bevy_ecs::system::Query
 /// [`Parent`]: crate::components::parent::Parent
 /// [`BuildChildren::with_children`]: crate::child_builder::BuildChildren::with_children
-#[derive(Debug, VisitEntitiesMut)]
+/// [`RelationshipTarget`]: bevy_ecs::relationship::RelationshipTarget
+#[derive(Component, Debug, VisitEntitiesMut)]
+#[relationship_target(relationship = Parent)]
 #[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(
     feature = "reflect",
@@ -40,15 +44,6 @@ use smallvec::SmallVec;
 )]
 pub struct Children(pub(crate) SmallVec<[Entity; 8]>);
 
-impl Component for Children {
-    const STORAGE_TYPE: StorageType = StorageType::Table;
-    type Mutability = Mutable;
-
-    fn get_component_clone_handler() -> ComponentCloneHandler {
-        ComponentCloneHandler::ignore()
-    }
-}
-
 // TODO: We need to impl either FromWorld or Default so Children can be registered as Reflect.
 // This is because Reflect deserialize by creating an instance and apply a patch on top.
 // However Children should only ever be set with a real user-defined entities. Its worth looking
@@ -61,12 +56,6 @@ impl FromWorld for Children {
 }
 
 impl Children {
-    /// Constructs a [`Children`] component with the given entities.
-    #[inline]
-    pub(crate) fn from_entities(entities: &[Entity]) -> Self {
-        Self(SmallVec::from_slice(entities))
-    }
-
     /// Swaps the child at `a_index` with the child at `b_index`.
     #[inline]
     pub fn swap(&mut self, a_index: usize, b_index: usize) {
@@ -1,12 +1,12 @@
+use crate::Children;
 #[cfg(feature = "reflect")]
 use bevy_ecs::reflect::{
     ReflectComponent, ReflectFromWorld, ReflectMapEntities, ReflectVisitEntities,
     ReflectVisitEntitiesMut,
 };
 use bevy_ecs::{
-    component::{Component, ComponentCloneHandler, Mutable, StorageType},
+    component::Component,
     entity::{Entity, VisitEntities, VisitEntitiesMut},
-    traversal::Traversal,
     world::{FromWorld, World},
 };
 use core::ops::Deref;
@@ -14,17 +14,20 @@ use core::ops::Deref;
 /// Holds a reference to the parent entity of this entity.
 /// This component should only be present on entities that actually have a parent entity.
 ///
-/// Parent entity must have this entity stored in its [`Children`] component.
-/// It is hard to set up parent/child relationships manually,
-/// consider using higher level utilities like [`BuildChildren::with_children`].
+/// [`Parent`] is a [`Relationship`] whose target is [`Children`]: inserting or removing it
+/// automatically keeps the parent's [`Children`] component in sync.
+/// Consider using higher level utilities like [`BuildChildren::with_children`],
+/// which also send [`HierarchyEvent`]s.
 ///
 /// See [`HierarchyQueryExt`] for hierarchy related methods on [`Query`].
 ///
 /// [`HierarchyQueryExt`]: crate::query_extension::HierarchyQueryExt
 /// [`Query`]: bevy_ecs::system::Query
-/// [`Children`]: super::children::Children
 /// [`BuildChildren::with_children`]: crate::child_builder::BuildChildren::with_children
-#[derive(Debug, Eq, PartialEq, VisitEntities, VisitEntitiesMut)]
+/// [`HierarchyEvent`]: crate::HierarchyEvent
+/// [`Relationship`]: bevy_ecs::relationship::Relationship
+#[derive(Component, Debug, Eq, PartialEq, VisitEntities, VisitEntitiesMut)]
+#[relationship(relationship_target = Children)]
 #[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(
     feature = "reflect",
@@ -40,15 +43,6 @@ use core::ops::Deref;
 )]
 pub struct Parent(pub(crate) Entity);
 
-impl Component for Parent {
-    const STORAGE_TYPE: StorageType = StorageType::Table;
-    type Mutability = Mutable;
-
-    fn get_component_clone_handler() -> ComponentCloneHandler {
-        ComponentCloneHandler::ignore()
-    }
-}
-
 impl Parent {
     /// Gets the [`Entity`] ID of the parent.
     #[inline(always)]
@@ -87,14 +81,3 @@ impl Deref for Parent {
         &self.0
     }
 }
-
-/// This provides generalized hierarchy traversal for use in [event propagation].
-///
-/// `Parent::traverse` will never form loops in properly-constructed hierarchies.
-///
-/// [event propagation]: bevy_ecs::observer::Trigger::propagate
-impl<D> Traversal<D> for &Parent {
-    fn traverse(item: Self::Item<'_>, _data: &D) -> Option<Entity> {
-        Some(item.0)
-    }
-}
@@ -12,14 +12,7 @@ use log::debug;
 
 /// Function for despawning an entity and all its children
 pub fn despawn_with_children_recursive(world: &mut World, entity: Entity, warn: bool) {
-    // first, make the entity's own parent forget about it
-    if let Some(parent) = world.get::<Parent>(entity).map(|parent| parent.0) {
-        if let Some(mut children) = world.get_mut::<Children>(parent) {
-            children.0.retain(|c| *c != entity);
-        }
-    }
-
-    // then despawn the entity and all of its children
+    // The entity's own parent forgets about it through the hooks of `Parent`.
     despawn_with_children_recursive_inner(world, entity, warn);
 }
 
@@ -214,7 +207,8 @@ impl<'w> DespawnRecursiveExt for EntityWorldMut<'w> {
 /// Trait that holds functions for cloning entities recursively down the hierarchy
 pub trait CloneEntityHierarchyExt {
     /// Sets the option to recursively clone entities.
-    /// When set to true all children will be cloned with the same options as the parent.
+    /// When set to true all children will be cloned with the same options as the parent,
+    /// and entity references between the cloned entities will point at the clones.
     fn recursive(&mut self, recursive: bool) -> &mut Self;
     /// Sets the option to add cloned entity as a child to the parent entity.
     fn as_child(&mut self, as_child: bool) -> &mut Self;
@@ -223,11 +217,9 @@ pub trait CloneEntityHierarchyExt {
 impl CloneEntityHierarchyExt for EntityCloneBuilder<'_> {
     fn recursive(&mut self, recursive: bool) -> &mut Self {
         if recursive {
-            self.override_component_clone_handler::<Children>(
-                ComponentCloneHandler::custom_handler(component_clone_children),
-            )
+            self.follow_relationship::<Children>()
         } else {
-            self.remove_component_clone_handler_override::<Children>()
+            self.unfollow::<Children>()
         }
     }
     fn as_child(&mut self, as_child: bool) -> &mut Self {
@@ -241,32 +233,18 @@ impl CloneEntityHierarchyExt for EntityCloneBuilder<'_> {
     }
 }
 
-/// Clone handler for the [`Children`] component. Allows to clone the entity recursively.
-fn component_clone_children(world: &mut DeferredWorld, ctx: &mut ComponentCloneCtx) {
-    let children = ctx
-        .read_source_component::<Children>()
-        .expect("Source entity must have Children component")
-        .iter();
-    let parent = ctx.target();
-    for child in children {
-        let child_clone = world.commands().spawn_empty().id();
-        let mut clone_entity = ctx
-            .entity_cloner()
-            .with_source_and_target(*child, child_clone);
-        world.commands().queue(move |world: &mut World| {
-            clone_entity.clone_entity(world);
-            world.entity_mut(child_clone).set_parent(parent);
-        });
-    }
-}
-
 /// Clone handler for the [`Parent`] component. Allows to add clone as a child to the parent entity.
 fn component_clone_parent(world: &mut DeferredWorld, ctx: &mut ComponentCloneCtx) {
     let parent = ctx
         .read_source_component::<Parent>()
         .map(|p| p.0)
         .expect("Source entity must have Parent component");
-    world.commands().entity(ctx.target()).set_parent(parent);
+    // When cloning recursively, descendants are added as children to the clone of their parent.
+    if let Some(parent_clone) = ctx.cloned_entity(parent) {
+        ctx.write_target_component(Parent(parent_clone));
+    } else {
+        world.commands().entity(ctx.target()).set_parent(parent);
+    }
 }
 
 #[cfg(test)]
@@ -274,6 +252,7 @@ mod tests {
     use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
     use bevy_ecs::{
         component::Component,
+        entity::{Entity, VisitEntities, VisitEntitiesMut},
         system::Commands,
         world::{CommandQueue, World},
     };
@@ -281,7 +260,7 @@ mod tests {
     use super::DespawnRecursiveExt;
     use crate::{
         child_builder::{BuildChildren, ChildBuild},
-        components::Children,
+        components::{Children, Parent},
         CloneEntityHierarchyExt,
     };
 
@@ -348,9 +327,9 @@ mod tests {
         results.sort_unstable_by_key(|(_, index)| *index);
 
         {
-            let children = world.get::<Children>(grandparent_entity).unwrap();
+            let children = world.get::<Children>(grandparent_entity);
             assert!(
-                !children.iter().any(|&i| i == parent_entity),
+                children.is_none(),
                 "grandparent should no longer know about its child which has been removed"
             );
         }
@@ -479,6 +458,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn clone_entity_recursive_remaps_entities() {
+        #[derive(Component, Clone, VisitEntities, VisitEntitiesMut)]
+        struct Target(Entity);
+
+        let mut world = World::default();
+        let root = world.spawn_empty().id();
+        let child_1 = world.spawn_empty().set_parent(root).id();
+        let child_2 = world.spawn(Target(child_1)).set_parent(root).id();
+
+        let root_clone = world.spawn_empty().id();
+        world.entity_mut(root).clone_with(root_clone, |builder| {
+            builder.recursive(true).map_entities::<Target>();
+        });
+
+        let children = world.get::<Children>(root_clone).unwrap();
+        let (child_1_clone, child_2_clone) = (children[0], children[1]);
+        assert_ne!(child_1_clone, child_1);
+        assert_ne!(child_2_clone, child_2);
+        assert_eq!(
+            world.get::<Parent>(child_1_clone).unwrap().get(),
+            root_clone
+        );
+        assert_eq!(world.get::<Target>(child_2_clone).unwrap().0, child_1_clone);
+    }
+
     #[test]
     fn clone_entity_as_child() {
         let mut world = World::default();
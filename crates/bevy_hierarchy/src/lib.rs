@@ -27,9 +27,11 @@
 //!
 //! # Hierarchy management
 //!
-//! The methods defined in this crate fully manage
-//! the components responsible for defining the entity hierarchy.
-//! Mutating these components manually may result in hierarchy invalidation.
+//! [`Parent`] and [`Children`] are a [relationship] provided by `bevy_ecs`:
+//! [`Parent`] is the source of truth, and [`Children`] is kept in sync
+//! automatically whenever a [`Parent`] component is inserted or removed.
+//! Mutating these components in place bypasses this bookkeeping
+//! and may result in hierarchy invalidation.
 //!
 //! Hierarchical relationships are always managed symmetrically.
 //! For example, assigning a child to an entity
@@ -42,8 +44,8 @@
 //!
 //! The commands and methods provided by `bevy_ecs` to despawn entities
 //! are not capable of automatically despawning hierarchies of entities.
-//! In most cases, these operations will invalidate the hierarchy.
-//! Instead, you should use the provided [hierarchical despawn extension methods].
+//! Despawning a parent this way orphans its children instead.
+//! To despawn a whole hierarchy, you should use the provided [hierarchical despawn extension methods].
 //!
 //! [command and world]: BuildChildren
 //! [diagnostic plugin]: ValidParentCheckPlugin
@@ -51,6 +53,7 @@
 //! [hierarchical despawn extension methods]: DespawnRecursiveExt
 //! [plugin]: HierarchyPlugin
 //! [query extension methods]: HierarchyQueryExt
+//! [relationship]: bevy_ecs::relationship
 
 #[cfg(feature = "std")]
 extern crate std;
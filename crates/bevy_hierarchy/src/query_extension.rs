@@ -1,15 +1,20 @@
-use alloc::collections::VecDeque;
-
 use bevy_ecs::{
     entity::Entity,
     query::{QueryData, QueryFilter, WorldQuery},
+    relationship,
     system::Query,
 };
-use smallvec::SmallVec;
 
 use crate::{Children, Parent};
 
 /// An extension trait for [`Query`] that adds hierarchy related methods.
+///
+/// The traversals are also available as inherent methods on every [`Query`] of a [`Relationship`]
+/// or [`RelationshipTarget`], such as [`Query::iter_descendants`], which take precedence in method
+/// call syntax. The methods of this trait behave the same for [`Parent`] and [`Children`].
+///
+/// [`Relationship`]: bevy_ecs::relationship::Relationship
+/// [`RelationshipTarget`]: bevy_ecs::relationship::RelationshipTarget
 pub trait HierarchyQueryExt<'w, 's, D: QueryData, F: QueryFilter> {
     /// Returns the parent [`Entity`] of the given `entity`, if any.
     fn parent(&'w self, entity: Entity) -> Option<Entity>
@@ -126,38 +131,21 @@ impl<'w, 's, D: QueryData, F: QueryFilter> HierarchyQueryExt<'w, 's, D, F> for Q
     where
         <D as QueryData>::ReadOnly: WorldQuery<Item<'w> = &'w Parent>,
     {
-        // Recursively search up the tree until we're out of parents
-        match self.get(entity) {
-            Ok(parent) => self.root_ancestor(parent.get()),
-            Err(_) => entity,
-        }
+        Query::root_ancestor::<Parent>(self, entity)
     }
 
-    fn iter_leaves(&'w self, entity: Entity) -> impl Iterator<Item = Entity>
+    fn iter_leaves(&'w self, entity: Entity) -> impl Iterator<Item = Entity> + 'w
     where
         <D as QueryData>::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
     {
-        self.iter_descendants_depth_first(entity).filter(|entity| {
-            self.get(*entity)
-                .ok()
-                // These are leaf nodes if they have the `Children` component but it's empty
-                // Or if they don't have the `Children` component at all
-                .is_none_or(|children| children.is_empty())
-        })
+        Query::iter_leaves::<Children>(self, entity)
     }
 
     fn iter_siblings(&'w self, entity: Entity) -> impl Iterator<Item = Entity>
     where
         D::ReadOnly: WorldQuery<Item<'w> = (Option<&'w Parent>, Option<&'w Children>)>,
     {
-        self.get(entity)
-            .ok()
-            .and_then(|(maybe_parent, _)| maybe_parent.map(Parent::get))
-            .and_then(|parent| self.get(parent).ok())
-            .and_then(|(_, maybe_children)| maybe_children)
-            .into_iter()
-            .flat_map(move |children| children.iter().filter(move |child| **child != entity))
-            .copied()
+        Query::iter_siblings::<Parent>(self, entity)
     }
 
     fn iter_descendants(&'w self, entity: Entity) -> DescendantIter<'w, 's, D, F>
@@ -188,127 +176,16 @@ impl<'w, 's, D: QueryData, F: QueryFilter> HierarchyQueryExt<'w, 's, D, F> for Q
 /// An [`Iterator`] of [`Entity`]s over the descendants of an [`Entity`].
 ///
 /// Traverses the hierarchy breadth-first.
-pub struct DescendantIter<'w, 's, D: QueryData, F: QueryFilter>
-where
-    D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
-{
-    children_query: &'w Query<'w, 's, D, F>,
-    vecdeque: VecDeque<Entity>,
-}
-
-impl<'w, 's, D: QueryData, F: QueryFilter> DescendantIter<'w, 's, D, F>
-where
-    D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
-{
-    /// Returns a new [`DescendantIter`].
-    pub fn new(children_query: &'w Query<'w, 's, D, F>, entity: Entity) -> Self {
-        DescendantIter {
-            children_query,
-            vecdeque: children_query
-                .get(entity)
-                .into_iter()
-                .flatten()
-                .copied()
-                .collect(),
-        }
-    }
-}
-
-impl<'w, 's, D: QueryData, F: QueryFilter> Iterator for DescendantIter<'w, 's, D, F>
-where
-    D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
-{
-    type Item = Entity;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let entity = self.vecdeque.pop_front()?;
-
-        if let Ok(children) = self.children_query.get(entity) {
-            self.vecdeque.extend(children);
-        }
-
-        Some(entity)
-    }
-}
+pub type DescendantIter<'w, 's, D, F> = relationship::DescendantIter<'w, 's, D, F, Children>;
 
 /// An [`Iterator`] of [`Entity`]s over the descendants of an [`Entity`].
 ///
 /// Traverses the hierarchy depth-first.
-pub struct DescendantDepthFirstIter<'w, 's, D: QueryData, F: QueryFilter>
-where
-    D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
-{
-    children_query: &'w Query<'w, 's, D, F>,
-    stack: SmallVec<[Entity; 8]>,
-}
-
-impl<'w, 's, D: QueryData, F: QueryFilter> DescendantDepthFirstIter<'w, 's, D, F>
-where
-    D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
-{
-    /// Returns a new [`DescendantDepthFirstIter`].
-    pub fn new(children_query: &'w Query<'w, 's, D, F>, entity: Entity) -> Self {
-        DescendantDepthFirstIter {
-            children_query,
-            stack: children_query
-                .get(entity)
-                .map_or(SmallVec::new(), |children| {
-                    children.iter().rev().copied().collect()
-                }),
-        }
-    }
-}
-
-impl<'w, 's, D: QueryData, F: QueryFilter> Iterator for DescendantDepthFirstIter<'w, 's, D, F>
-where
-    D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
-{
-    type Item = Entity;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let entity = self.stack.pop()?;
-
-        if let Ok(children) = self.children_query.get(entity) {
-            self.stack.extend(children.iter().rev().copied());
-        }
-
-        Some(entity)
-    }
-}
+pub type DescendantDepthFirstIter<'w, 's, D, F> =
+    relationship::DescendantDepthFirstIter<'w, 's, D, F, Children>;
 
 /// An [`Iterator`] of [`Entity`]s over the ancestors of an [`Entity`].
-pub struct AncestorIter<'w, 's, D: QueryData, F: QueryFilter>
-where
-    D::ReadOnly: WorldQuery<Item<'w> = &'w Parent>,
-{
-    parent_query: &'w Query<'w, 's, D, F>,
-    next: Option<Entity>,
-}
-
-impl<'w, 's, D: QueryData, F: QueryFilter> AncestorIter<'w, 's, D, F>
-where
-    D::ReadOnly: WorldQuery<Item<'w> = &'w Parent>,
-{
-    /// Returns a new [`AncestorIter`].
-    pub fn new(parent_query: &'w Query<'w, 's, D, F>, entity: Entity) -> Self {
-        AncestorIter {
-            parent_query,
-            next: Some(entity),
-        }
-    }
-}
-
-impl<'w, 's, D: QueryData, F: QueryFilter> Iterator for AncestorIter<'w, 's, D, F>
-where
-    D::ReadOnly: WorldQuery<Item<'w> = &'w Parent>,
-{
-    type Item = Entity;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.next = self.parent_query.get(self.next?).ok().map(Parent::get);
-        self.next
-    }
-}
+pub type AncestorIter<'w, 's, D, F> = relationship::AncestorIter<'w, 's, D, F, Parent>;
 
 #[cfg(test)]
 mod tests {
@@ -432,4 +309,39 @@ mod tests {
 
         assert_eq!([&A(2), &A(3)], result.as_slice());
     }
+
+    #[test]
+    fn hierarchy_query_ext() {
+        let world = &mut World::new();
+
+        let [a0, a1, a2, a3] = core::array::from_fn(|i| world.spawn(A(i)).id());
+
+        world.entity_mut(a0).add_children(&[a1, a2]);
+        world.entity_mut(a1).add_children(&[a3]);
+
+        let mut system_state = SystemState::<(
+            Query<&Children>,
+            Query<&Parent>,
+            Query<(Option<&Parent>, Option<&Children>)>,
+        )>::new(world);
+        let (children_query, parent_query, hierarchy_query) = system_state.get(world);
+
+        let descendants: Vec<_> =
+            HierarchyQueryExt::iter_descendants(&children_query, a0).collect();
+        assert_eq!([a1, a2, a3], descendants.as_slice());
+
+        let descendants: Vec<_> =
+            HierarchyQueryExt::iter_descendants_depth_first(&children_query, a0).collect();
+        assert_eq!([a1, a3, a2], descendants.as_slice());
+
+        let leaves: Vec<_> = HierarchyQueryExt::iter_leaves(&children_query, a0).collect();
+        assert_eq!([a3, a2], leaves.as_slice());
+
+        let ancestors: Vec<_> = HierarchyQueryExt::iter_ancestors(&parent_query, a3).collect();
+        assert_eq!([a1, a0], ancestors.as_slice());
+        assert_eq!(a0, HierarchyQueryExt::root_ancestor(&parent_query, a3));
+
+        let siblings: Vec<_> = HierarchyQueryExt::iter_siblings(&hierarchy_query, a1).collect();
+        assert_eq!([a2], siblings.as_slice());
+    }
 }
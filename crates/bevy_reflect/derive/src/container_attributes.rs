@@ -11,10 +11,10 @@ use crate::{
 };
 use bevy_macro_utils::fq_std::{FQAny, FQOption};
 use proc_macro2::{Ident, Span};
-use quote::quote_spanned;
+use quote::{quote, quote_spanned};
 use syn::{
     ext::IdentExt, parenthesized, parse::ParseStream, spanned::Spanned, token, Expr, LitBool,
-    MetaList, MetaNameValue, Path, Token, WhereClause,
+    LitInt, MetaList, MetaNameValue, Path, PathArguments, PathSegment, Token, WhereClause,
 };
 
 mod kw {
@@ -25,6 +25,8 @@ mod kw {
     syn::custom_keyword!(Hash);
     syn::custom_keyword!(no_field_bounds);
     syn::custom_keyword!(opaque);
+    syn::custom_keyword!(version);
+    syn::custom_keyword!(methods);
 }
 
 // The "special" trait idents that are used internally for reflection.
@@ -43,6 +45,12 @@ const FROM_REFLECT_ATTR: &str = "from_reflect";
 // Attributes for `TypePath` implementation
 const TYPE_PATH_ATTR: &str = "type_path";
 
+// Attribute for the serialization version
+const VERSION_ATTR: &str = "version";
+
+// Attribute for registering reflected methods
+const METHODS_ATTR: &str = "methods";
+
 // The error message to show when a trait/type is specified multiple times
 const CONFLICTING_TYPE_DATA_MESSAGE: &str = "conflicting type data registration";
 
@@ -80,6 +88,31 @@ impl TraitImpl {
     }
 }
 
+/// A method registered via `#[reflect(methods(...))]`.
+#[derive(Clone)]
+pub(crate) struct ReflectMethod {
+    /// The trait the method belongs to, or `None` for inherent methods.
+    trait_path: Option<Path>,
+    /// The method itself, along with any generic arguments.
+    segment: PathSegment,
+}
+
+impl ReflectMethod {
+    /// The name the method is registered with.
+    pub fn name(&self) -> String {
+        self.segment.ident.to_string()
+    }
+
+    /// Returns an expression evaluating to the method of `Self`.
+    pub fn to_expr(&self) -> proc_macro2::TokenStream {
+        let segment = &self.segment;
+        match &self.trait_path {
+            Some(trait_path) => quote!(<Self as #trait_path>::#segment),
+            None => quote!(Self::#segment),
+        }
+    }
+}
+
 /// A collection of attributes used for deriving `FromReflect`.
 #[derive(Clone, Default)]
 pub(crate) struct FromReflectAttrs {
@@ -184,6 +217,8 @@ pub(crate) struct ContainerAttributes {
     no_field_bounds: bool,
     custom_attributes: CustomAttributes,
     is_opaque: bool,
+    version: Option<LitInt>,
+    methods: Vec<ReflectMethod>,
     idents: Vec<Ident>,
 }
 
@@ -236,6 +271,10 @@ impl ContainerAttributes {
             self.parse_opaque(input)
         } else if lookahead.peek(kw::no_field_bounds) {
             self.parse_no_field_bounds(input)
+        } else if lookahead.peek(kw::version) {
+            self.parse_version(input)
+        } else if lookahead.peek(kw::methods) {
+            self.parse_methods(input)
         } else if lookahead.peek(kw::Debug) {
             self.parse_debug(input)
         } else if lookahead.peek(kw::PartialEq) {
@@ -354,6 +393,92 @@ impl ContainerAttributes {
         Ok(())
     }
 
+    /// Parse `version` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(version = 2)]`
+    fn parse_version(&mut self, input: ParseStream) -> syn::Result<()> {
+        let pair = input.parse::<MetaNameValue>()?;
+        let version = match &pair.value {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(lit),
+                ..
+            }) => {
+                // Make sure the version fits the `u32` it is registered as.
+                lit.base10_parse::<u32>()?;
+                lit.clone()
+            }
+            value => return Err(syn::Error::new(value.span(), "Expected an integer value")),
+        };
+
+        if let Some(existing) = &self.version {
+            if existing.base10_digits() != version.base10_digits() {
+                return Err(syn::Error::new(
+                    version.span(),
+                    format!("`{VERSION_ATTR}` already set to {existing}"),
+                ));
+            }
+        } else {
+            self.version = Some(version);
+        }
+
+        Ok(())
+    }
+
+    /// Parse `methods` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(methods(health, heal))]`
+    /// - `#[reflect(methods(Damageable::damage, convert::<f32>))]`
+    fn parse_methods(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::methods>()?;
+
+        if !cfg!(feature = "functions") {
+            return Err(syn::Error::new(
+                ident.span,
+                format!("`{METHODS_ATTR}` requires the `functions` feature of `bevy_reflect`"),
+            ));
+        }
+
+        let content;
+        parenthesized!(content in input);
+        for path in content.parse_terminated(<Path as syn::parse::Parse>::parse, Token![,])? {
+            let mut segments = path.segments.into_iter().collect::<Vec<_>>();
+            let mut segment = segments.pop().unwrap();
+            // Generic arguments of the method require a turbofish in expression position.
+            if let PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+                arguments.colon2_token.get_or_insert_with(Default::default);
+            }
+
+            let trait_path = match segments.as_slice() {
+                [] => None,
+                [segment] if segment.ident == "Self" && segment.arguments.is_none() => None,
+                _ => Some(Path {
+                    leading_colon: path.leading_colon,
+                    segments: segments.into_iter().collect(),
+                }),
+            };
+
+            let method = ReflectMethod {
+                trait_path,
+                segment,
+            };
+            if self
+                .methods
+                .iter()
+                .any(|existing| existing.name() == method.name())
+            {
+                return Err(syn::Error::new(
+                    method.segment.ident.span(),
+                    format!("method `{}` is already registered", method.name()),
+                ));
+            }
+            self.methods.push(method);
+        }
+
+        Ok(())
+    }
+
     /// Parse `where` attribute.
     ///
     /// Examples:
@@ -537,6 +662,18 @@ impl ContainerAttributes {
         self.no_field_bounds
     }
 
+    /// The serialization version found within `#[reflect(version = ...)]` attributes on this type.
+    ///
+    /// The returned literal is guaranteed to fit in a `u32`.
+    pub fn version(&self) -> Option<&LitInt> {
+        self.version.as_ref()
+    }
+
+    /// The methods registered within `#[reflect(methods(...))]` attributes on this type.
+    pub fn methods(&self) -> &[ReflectMethod] {
+        &self.methods
+    }
+
     /// Returns true if the `opaque` attribute was found on this type.
     pub fn is_opaque(&self) -> bool {
         self.is_opaque
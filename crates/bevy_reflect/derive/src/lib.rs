@@ -286,6 +286,61 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// // {/* ... */}
 /// ```
 ///
+/// ## `#[reflect(version = 2)]`
+///
+/// This attribute sets the serialization version of the type, registering it as `ReflectVersion`.
+///
+/// The reflection serializers write the version along with the data of versioned types,
+/// and the reflection deserializers use it to load data written by older versions of the type
+/// with the migrations registered on the `ReflectVersion` type data.
+///
+/// ### Example
+///
+/// ```ignore (bevy_reflect is not accessible from this crate)
+/// #[derive(Reflect)]
+/// #[reflect(version = 2)]
+/// struct Player {
+///   // Used to be called `hp` in version 1.
+///   health: u32,
+/// }
+/// ```
+///
+/// ## `#[reflect(methods(...))]`
+///
+/// This attribute registers methods of the type as `ReflectMethods` type data,
+/// so that they can be looked up by name and called dynamically.
+/// It requires the `functions` feature.
+///
+/// Each method is given by its name, and trait methods are given by the path to their trait.
+/// Methods are converted using `IntoFunction`,
+/// so they must have one of the signatures listed in the `bevy_reflect::func` module docs.
+///
+/// ### Example
+///
+/// ```ignore (bevy_reflect is not accessible from this crate)
+/// #[derive(Reflect)]
+/// #[reflect(methods(health, heal, Damageable::damage))]
+/// struct Player {
+///   health: u32,
+/// }
+///
+/// impl Player {
+///   fn health(&self) -> u32 {
+///     self.health
+///   }
+///
+///   fn heal(&mut self, amount: u32) {
+///     self.health += amount;
+///   }
+/// }
+///
+/// impl Damageable for Player {
+///   fn damage(&mut self, amount: u32) {
+///     self.health = self.health.saturating_sub(amount);
+///   }
+/// }
+/// ```
+///
 /// ## `#[reflect(@...)]`
 ///
 /// This attribute can be used to register custom attributes to the type's `TypeInfo`.
@@ -45,6 +45,29 @@ pub(crate) fn impl_get_type_registration<'a>(
         }
     });
 
+    let version_data = meta.attrs().version().map(|version| {
+        let version = version.base10_parse::<u32>().unwrap();
+        quote! {
+            registration.insert::<#bevy_reflect_path::serde::ReflectVersion>(#bevy_reflect_path::serde::ReflectVersion::new(#version));
+        }
+    });
+
+    let methods_data = (!meta.attrs().methods().is_empty()).then(|| {
+        let (names, exprs): (Vec<_>, Vec<_>) = meta
+            .attrs()
+            .methods()
+            .iter()
+            .map(|method| (method.name(), method.to_expr()))
+            .unzip();
+        quote! {
+            registration.insert::<#bevy_reflect_path::func::ReflectMethods>({
+                let mut methods = #bevy_reflect_path::func::ReflectMethods::default();
+                #(methods.overwrite_registration(#names, #exprs);)*
+                methods
+            });
+        }
+    });
+
     quote! {
         #[allow(unused_mut)]
         impl #impl_generics #bevy_reflect_path::GetTypeRegistration for #type_path #ty_generics #where_reflect_clause {
@@ -53,6 +76,8 @@ pub(crate) fn impl_get_type_registration<'a>(
                 registration.insert::<#bevy_reflect_path::ReflectFromPtr>(#bevy_reflect_path::FromType::<Self>::from_type());
                 #from_reflect_data
                 #serialization_data
+                #version_data
+                #methods_data
                 #(registration.insert::<#registration_data>(#bevy_reflect_path::FromType::<Self>::from_type());)*
                 registration
             }
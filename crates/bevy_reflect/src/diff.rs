@@ -0,0 +1,709 @@
+use crate::{
+    ApplyError, PartialReflect, ReflectKind, ReflectMut, ReflectRef, TypeInfo, VariantType,
+};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use thiserror::Error;
+
+/// The changes that turn one reflected value into another.
+///
+/// A diff is computed from two values with [`ReflectDiff::new`], and applied to a value with
+/// [`apply_diff`]. It only contains what changed: for example, the diff of two structs lists the
+/// fields that differ between them, recursively.
+///
+/// This is useful to implement undo and redo, or to store and send deltas instead of whole values.
+/// A diff can be serialized with [`ReflectDiffSerializer`] and deserialized with
+/// [`ReflectDiffDeserializer`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{apply_diff, Reflect, ReflectDiff};
+/// #[derive(Reflect, Clone, PartialEq, Debug)]
+/// struct Player {
+///     name: String,
+///     health: u32,
+///     inventory: Vec<String>,
+/// }
+///
+/// let before = Player {
+///     name: "Ferris".to_string(),
+///     health: 10,
+///     inventory: vec!["sword".to_string()],
+/// };
+/// let mut after = before.clone();
+/// after.health = 7;
+/// after.inventory.push("shield".to_string());
+///
+/// let diff = ReflectDiff::new(&before, &after);
+/// let ReflectDiff::Struct(fields) = &diff else {
+///     panic!("expected a struct diff");
+/// };
+/// assert_eq!(fields.len(), 2);
+///
+/// let mut value = before.clone();
+/// apply_diff(&mut value, &diff).unwrap();
+/// assert_eq!(value, after);
+/// ```
+///
+/// [`ReflectDiffSerializer`]: crate::serde::ReflectDiffSerializer
+/// [`ReflectDiffDeserializer`]: crate::serde::ReflectDiffDeserializer
+#[derive(Debug)]
+pub enum ReflectDiff {
+    /// The values are equal.
+    Unchanged,
+    /// The value was replaced by another one.
+    ///
+    /// This is the diff of opaque values that differ, and of values whose type, [kind] or
+    /// enum variant differ.
+    ///
+    /// [kind]: ReflectKind
+    Replaced(Box<dyn PartialReflect>),
+    /// The changed fields of a struct or struct variant, by name.
+    Struct(Vec<(String, ReflectDiff)>),
+    /// The changed fields of a tuple, tuple struct or tuple variant, or the changed elements of
+    /// an array, by index.
+    Tuple(Vec<(usize, ReflectDiff)>),
+    /// The changes to a list.
+    ///
+    /// Elements are compared by index, so inserting an element at the front of a list changes
+    /// every element.
+    List {
+        /// The new length of the list.
+        len: usize,
+        /// The changed elements that existed in both lists, by index.
+        changed: Vec<(usize, ReflectDiff)>,
+        /// The elements added at the end of the list.
+        appended: Vec<Box<dyn PartialReflect>>,
+    },
+    /// The changes to a map.
+    Map {
+        /// The changed values, by key.
+        changed: Vec<(Box<dyn PartialReflect>, ReflectDiff)>,
+        /// The inserted entries.
+        inserted: Vec<(Box<dyn PartialReflect>, Box<dyn PartialReflect>)>,
+        /// The keys of the removed entries.
+        removed: Vec<Box<dyn PartialReflect>>,
+    },
+    /// The changes to a set.
+    Set {
+        /// The inserted values.
+        inserted: Vec<Box<dyn PartialReflect>>,
+        /// The removed values.
+        removed: Vec<Box<dyn PartialReflect>>,
+    },
+}
+
+impl ReflectDiff {
+    /// Computes the changes that turn `old` into `new`.
+    ///
+    /// Opaque values are compared with [`PartialReflect::reflect_partial_eq`], and are
+    /// considered changed when it returns `None`.
+    pub fn new(old: &dyn PartialReflect, new: &dyn PartialReflect) -> Self {
+        if type_path(old) != type_path(new) {
+            return Self::Replaced(new.clone_value());
+        }
+
+        let diff = match (old.reflect_ref(), new.reflect_ref()) {
+            (ReflectRef::Struct(old_struct), ReflectRef::Struct(new_struct)) => {
+                if old_struct.field_len() != new_struct.field_len() {
+                    return Self::Replaced(new.clone_value());
+                }
+                let mut fields = Vec::new();
+                for (index, new_field) in new_struct.iter_fields().enumerate() {
+                    let name = new_struct.name_at(index).unwrap();
+                    let Some(old_field) = old_struct.field(name) else {
+                        return Self::Replaced(new.clone_value());
+                    };
+                    push_changed(&mut fields, name.to_string(), old_field, new_field);
+                }
+                Self::Struct(fields)
+            }
+            (ReflectRef::TupleStruct(old_tuple), ReflectRef::TupleStruct(new_tuple)) => {
+                match diff_indexed(old_tuple.iter_fields(), new_tuple.iter_fields()) {
+                    Some(fields) => Self::Tuple(fields),
+                    None => return Self::Replaced(new.clone_value()),
+                }
+            }
+            (ReflectRef::Tuple(old_tuple), ReflectRef::Tuple(new_tuple)) => {
+                match diff_indexed(old_tuple.iter_fields(), new_tuple.iter_fields()) {
+                    Some(fields) => Self::Tuple(fields),
+                    None => return Self::Replaced(new.clone_value()),
+                }
+            }
+            (ReflectRef::Array(old_array), ReflectRef::Array(new_array)) => {
+                match diff_indexed(old_array.iter(), new_array.iter()) {
+                    Some(elements) => Self::Tuple(elements),
+                    None => return Self::Replaced(new.clone_value()),
+                }
+            }
+            (ReflectRef::Enum(old_enum), ReflectRef::Enum(new_enum)) => {
+                if old_enum.variant_name() != new_enum.variant_name()
+                    || old_enum.variant_type() != new_enum.variant_type()
+                    || old_enum.field_len() != new_enum.field_len()
+                {
+                    return Self::Replaced(new.clone_value());
+                }
+                if new_enum.variant_type() == VariantType::Struct {
+                    let mut fields = Vec::new();
+                    for (index, new_field) in new_enum.iter_fields().enumerate() {
+                        let name = new_enum.name_at(index).unwrap();
+                        let Some(old_field) = old_enum.field(name) else {
+                            return Self::Replaced(new.clone_value());
+                        };
+                        push_changed(&mut fields, name.to_string(), old_field, new_field.value());
+                    }
+                    Self::Struct(fields)
+                } else {
+                    let old_fields = old_enum.iter_fields().map(|field| field.value());
+                    let new_fields = new_enum.iter_fields().map(|field| field.value());
+                    match diff_indexed(old_fields, new_fields) {
+                        Some(fields) => Self::Tuple(fields),
+                        None => return Self::Replaced(new.clone_value()),
+                    }
+                }
+            }
+            (ReflectRef::List(old_list), ReflectRef::List(new_list)) => {
+                let mut changed = Vec::new();
+                for (index, (old_element, new_element)) in
+                    old_list.iter().zip(new_list.iter()).enumerate()
+                {
+                    push_changed(&mut changed, index, old_element, new_element);
+                }
+                let appended: Vec<_> = new_list
+                    .iter()
+                    .skip(old_list.len())
+                    .map(PartialReflect::clone_value)
+                    .collect();
+                if changed.is_empty() && appended.is_empty() && old_list.len() == new_list.len() {
+                    return Self::Unchanged;
+                }
+                return Self::List {
+                    len: new_list.len(),
+                    changed,
+                    appended,
+                };
+            }
+            (ReflectRef::Map(old_map), ReflectRef::Map(new_map)) => {
+                let mut changed = Vec::new();
+                let mut inserted = Vec::new();
+                for (key, new_value) in new_map.iter() {
+                    match old_map.get(key) {
+                        Some(old_value) => {
+                            push_changed(&mut changed, key.clone_value(), old_value, new_value);
+                        }
+                        None => inserted.push((key.clone_value(), new_value.clone_value())),
+                    }
+                }
+                let removed: Vec<_> = old_map
+                    .iter()
+                    .filter(|(key, _)| new_map.get(*key).is_none())
+                    .map(|(key, _)| key.clone_value())
+                    .collect();
+                if changed.is_empty() && inserted.is_empty() && removed.is_empty() {
+                    return Self::Unchanged;
+                }
+                return Self::Map {
+                    changed,
+                    inserted,
+                    removed,
+                };
+            }
+            (ReflectRef::Set(old_set), ReflectRef::Set(new_set)) => {
+                let inserted: Vec<_> = new_set
+                    .iter()
+                    .filter(|value| !old_set.contains(*value))
+                    .map(PartialReflect::clone_value)
+                    .collect();
+                let removed: Vec<_> = old_set
+                    .iter()
+                    .filter(|value| !new_set.contains(*value))
+                    .map(PartialReflect::clone_value)
+                    .collect();
+                if inserted.is_empty() && removed.is_empty() {
+                    return Self::Unchanged;
+                }
+                return Self::Set { inserted, removed };
+            }
+            (ReflectRef::Opaque(_), ReflectRef::Opaque(_)) => {
+                return match old.reflect_partial_eq(new) {
+                    Some(true) => Self::Unchanged,
+                    _ => Self::Replaced(new.clone_value()),
+                };
+            }
+            _ => return Self::Replaced(new.clone_value()),
+        };
+
+        match diff {
+            Self::Struct(fields) if fields.is_empty() => Self::Unchanged,
+            Self::Tuple(fields) if fields.is_empty() => Self::Unchanged,
+            diff => diff,
+        }
+    }
+
+    /// Returns `true` if this diff doesn't change anything.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+}
+
+/// Applies the changes described by `diff` to `target`.
+///
+/// The diff is expected to be applied to a value equal to the `old` value it was computed from,
+/// though it can be applied to any value with the same shape.
+///
+/// If an error occurs, the changes applied so far are not reverted.
+pub fn apply_diff(
+    target: &mut dyn PartialReflect,
+    diff: &ReflectDiff,
+) -> Result<(), ApplyDiffError> {
+    match diff {
+        ReflectDiff::Unchanged => {}
+        ReflectDiff::Replaced(value) => replace(target, value.as_partial_reflect())?,
+        ReflectDiff::Struct(fields) => {
+            for (name, field_diff) in fields {
+                let field = match target.reflect_mut() {
+                    ReflectMut::Struct(target) => target.field_mut(name),
+                    ReflectMut::Enum(target) => target.field_mut(name),
+                    target => return Err(mismatched_kinds(ReflectKind::Struct, target.kind())),
+                };
+                let field =
+                    field.ok_or_else(|| ApplyDiffError::MissingField(name.as_str().into()))?;
+                apply_diff(field, field_diff)?;
+            }
+        }
+        ReflectDiff::Tuple(fields) => {
+            for &(index, ref field_diff) in fields {
+                let field = match target.reflect_mut() {
+                    ReflectMut::TupleStruct(target) => target.field_mut(index),
+                    ReflectMut::Tuple(target) => target.field_mut(index),
+                    ReflectMut::Array(target) => target.get_mut(index),
+                    ReflectMut::Enum(target) => target.field_at_mut(index),
+                    target => return Err(mismatched_kinds(ReflectKind::Tuple, target.kind())),
+                };
+                let field = field.ok_or(ApplyDiffError::MissingIndex(index))?;
+                apply_diff(field, field_diff)?;
+            }
+        }
+        ReflectDiff::List {
+            len,
+            changed,
+            appended,
+        } => {
+            let target = match target.reflect_mut() {
+                ReflectMut::List(target) => target,
+                target => return Err(mismatched_kinds(ReflectKind::List, target.kind())),
+            };
+            for &(index, ref element_diff) in changed {
+                let element = target
+                    .get_mut(index)
+                    .ok_or(ApplyDiffError::MissingIndex(index))?;
+                apply_diff(element, element_diff)?;
+            }
+            while target.len() > *len {
+                target.pop();
+            }
+            for element in appended {
+                target.push(element.clone_value());
+            }
+        }
+        ReflectDiff::Map {
+            changed,
+            inserted,
+            removed,
+        } => {
+            let target = match target.reflect_mut() {
+                ReflectMut::Map(target) => target,
+                target => return Err(mismatched_kinds(ReflectKind::Map, target.kind())),
+            };
+            for (key, value_diff) in changed {
+                let value = target
+                    .get_mut(key.as_partial_reflect())
+                    .ok_or_else(|| ApplyDiffError::MissingKey(format!("{key:?}").into()))?;
+                apply_diff(value, value_diff)?;
+            }
+            for key in removed {
+                target.remove(key.as_partial_reflect());
+            }
+            for (key, value) in inserted {
+                target.insert_boxed(key.clone_value(), value.clone_value());
+            }
+        }
+        ReflectDiff::Set { inserted, removed } => {
+            let target = match target.reflect_mut() {
+                ReflectMut::Set(target) => target,
+                target => return Err(mismatched_kinds(ReflectKind::Set, target.kind())),
+            };
+            for value in removed {
+                target.remove(value.as_partial_reflect());
+            }
+            for value in inserted {
+                target.insert_boxed(value.clone_value());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// An error returned by [`apply_diff`].
+#[derive(Error, Debug)]
+pub enum ApplyDiffError {
+    #[error("attempted to apply a `{diff_kind}` diff to `{to_kind}`")]
+    /// Attempted to apply a diff of the wrong [kind](ReflectKind), e.g. a list diff to a struct.
+    MismatchedKinds {
+        diff_kind: ReflectKind,
+        to_kind: ReflectKind,
+    },
+
+    #[error("the value doesn't have a field named `{0}`")]
+    /// The struct or enum variant that we tried to apply to was missing a changed field.
+    MissingField(Box<str>),
+
+    #[error("the value doesn't have a field or element at index {0}")]
+    /// The value that we tried to apply to was missing a changed field or element.
+    MissingIndex(usize),
+
+    #[error("the map doesn't contain the key `{0}`")]
+    /// The map that we tried to apply to was missing the key of a changed value.
+    MissingKey(Box<str>),
+
+    #[error(transparent)]
+    /// A replaced value could not be applied.
+    Apply(#[from] ApplyError),
+}
+
+fn mismatched_kinds(diff_kind: ReflectKind, to_kind: ReflectKind) -> ApplyDiffError {
+    ApplyDiffError::MismatchedKinds { diff_kind, to_kind }
+}
+
+/// Replaces `target` with `value`.
+///
+/// Concrete values are [set](crate::Reflect::set) directly. Otherwise `value` is applied on top of
+/// an emptied list, map or set, since applying alone would keep the elements and entries that
+/// `value` doesn't have.
+fn replace(target: &mut dyn PartialReflect, value: &dyn PartialReflect) -> Result<(), ApplyError> {
+    if let Some(target) = target.try_as_reflect_mut() {
+        let value = match value.clone_value().try_into_reflect() {
+            Ok(value) => match target.set(value) {
+                Ok(()) => return Ok(()),
+                Err(value) => value.into_partial_reflect(),
+            },
+            Err(value) => value,
+        };
+        return replace_dynamic(target.as_partial_reflect_mut(), &*value);
+    }
+    replace_dynamic(target, value)
+}
+
+fn replace_dynamic(
+    target: &mut dyn PartialReflect,
+    value: &dyn PartialReflect,
+) -> Result<(), ApplyError> {
+    match (target.reflect_mut(), value.reflect_kind()) {
+        (ReflectMut::List(target), ReflectKind::List) => drop(target.drain()),
+        (ReflectMut::Map(target), ReflectKind::Map) => drop(target.drain()),
+        (ReflectMut::Set(target), ReflectKind::Set) => drop(target.drain()),
+        _ => {}
+    }
+    target.try_apply(value)
+}
+
+/// Returns the path of the type `value` represents, falling back to its own type path.
+fn type_path(value: &dyn PartialReflect) -> &str {
+    value
+        .get_represented_type_info()
+        .map(TypeInfo::type_path)
+        .unwrap_or_else(|| value.reflect_type_path())
+}
+
+fn push_changed<K>(
+    diffs: &mut Vec<(K, ReflectDiff)>,
+    key: K,
+    old: &dyn PartialReflect,
+    new: &dyn PartialReflect,
+) {
+    let diff = ReflectDiff::new(old, new);
+    if !diff.is_unchanged() {
+        diffs.push((key, diff));
+    }
+}
+
+/// Diffs two sequences of fields by index, returning `None` if their lengths differ.
+fn diff_indexed<'a>(
+    old: impl Iterator<Item = &'a dyn PartialReflect>,
+    new: impl Iterator<Item = &'a dyn PartialReflect>,
+) -> Option<Vec<(usize, ReflectDiff)>> {
+    let old: Vec<_> = old.collect();
+    let new: Vec<_> = new.collect();
+    if old.len() != new.len() {
+        return None;
+    }
+    let mut diffs = Vec::new();
+    for (index, (old, new)) in old.into_iter().zip(new).enumerate() {
+        push_changed(&mut diffs, index, old, new);
+    }
+    Some(diffs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_diff, ApplyDiffError, ReflectDiff};
+    use crate::{
+        self as bevy_reflect,
+        serde::{ReflectDiffDeserializer, ReflectDiffSerializer},
+        DynamicList, DynamicMap, DynamicSet, DynamicStruct, PartialReflect, Reflect, Struct,
+        TypeRegistry,
+    };
+    use alloc::{
+        boxed::Box,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+    use bevy_utils::{HashMap, HashSet};
+    use bincode::Options;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    enum Shape {
+        Circle { radius: f32 },
+        Rectangle(f32, f32),
+        Point,
+    }
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    struct Scene {
+        name: String,
+        shapes: Vec<Shape>,
+        position: (f32, f32),
+        tags: HashSet<String>,
+        counts: HashMap<String, u32>,
+        grid: [u8; 3],
+    }
+
+    fn scene() -> Scene {
+        Scene {
+            name: "scene".to_string(),
+            shapes: vec![Shape::Circle { radius: 1.0 }, Shape::Rectangle(1.0, 2.0)],
+            position: (0.0, 0.0),
+            tags: ["a".to_string(), "b".to_string()].into_iter().collect(),
+            counts: [("x".to_string(), 1), ("y".to_string(), 2)]
+                .into_iter()
+                .collect(),
+            grid: [0, 1, 2],
+        }
+    }
+
+    fn assert_round_trip(old: &Scene, new: &Scene) -> ReflectDiff {
+        let diff = ReflectDiff::new(old, new);
+        let mut value = old.clone();
+        apply_diff(&mut value, &diff).unwrap();
+        assert_eq!(&value, new);
+        diff
+    }
+
+    #[test]
+    fn unchanged() {
+        let diff = ReflectDiff::new(&scene(), &scene());
+        assert!(diff.is_unchanged());
+    }
+
+    #[test]
+    fn struct_fields() {
+        let old = scene();
+        let mut new = scene();
+        new.position.1 = 3.0;
+        new.grid[2] = 7;
+        let diff = assert_round_trip(&old, &new);
+
+        let ReflectDiff::Struct(fields) = diff else {
+            panic!("expected a struct diff, found {diff:?}");
+        };
+        let names: Vec<_> = fields.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["position", "grid"]);
+        assert!(
+            matches!(&fields[0].1, ReflectDiff::Tuple(fields) if fields.len() == 1 && fields[0].0 == 1)
+        );
+    }
+
+    #[test]
+    fn enums() {
+        let old = scene();
+        let mut new = scene();
+        new.shapes[0] = Shape::Circle { radius: 2.0 };
+        new.shapes[1] = Shape::Point;
+        let diff = assert_round_trip(&old, &new);
+
+        let ReflectDiff::Struct(fields) = diff else {
+            panic!("expected a struct diff, found {diff:?}");
+        };
+        let ReflectDiff::List {
+            len,
+            changed,
+            appended,
+        } = &fields[0].1
+        else {
+            panic!("expected a list diff, found {:?}", fields[0].1);
+        };
+        assert_eq!((*len, changed.len(), appended.len()), (2, 2, 0));
+        // Same variant.
+        assert!(matches!(&changed[0].1, ReflectDiff::Struct(_)));
+        // Different variant.
+        assert!(matches!(&changed[1].1, ReflectDiff::Replaced(_)));
+    }
+
+    #[test]
+    fn lists() {
+        let old = scene();
+        let mut new = scene();
+        new.shapes.push(Shape::Point);
+        new.shapes.push(Shape::Rectangle(3.0, 4.0));
+        assert_round_trip(&old, &new);
+
+        let mut shorter = scene();
+        shorter.shapes.truncate(1);
+        assert_round_trip(&old, &shorter);
+        assert_round_trip(&new, &shorter);
+    }
+
+    #[test]
+    fn maps_and_sets() {
+        let old = scene();
+        let mut new = scene();
+        new.tags.remove("a");
+        new.tags.insert("c".to_string());
+        new.counts.remove("x");
+        new.counts.insert("y".to_string(), 3);
+        new.counts.insert("z".to_string(), 4);
+        let diff = assert_round_trip(&old, &new);
+
+        let ReflectDiff::Struct(fields) = diff else {
+            panic!("expected a struct diff, found {diff:?}");
+        };
+        let ReflectDiff::Map {
+            changed,
+            inserted,
+            removed,
+        } = &fields[1].1
+        else {
+            panic!("expected a map diff, found {:?}", fields[1].1);
+        };
+        assert_eq!((changed.len(), inserted.len(), removed.len()), (1, 1, 1));
+    }
+
+    #[test]
+    fn dynamic_values() {
+        let old = scene();
+        let mut new = scene();
+        new.name = "renamed".to_string();
+
+        // A dynamic value representing the same type can be diffed and patched.
+        let dynamic = Struct::clone_dynamic(&new);
+        let diff = ReflectDiff::new(&old, &dynamic);
+        let mut value = Struct::clone_dynamic(&old);
+        apply_diff(&mut value, &diff).unwrap();
+        assert!(value.reflect_partial_eq(&new).unwrap());
+
+        // Values of different types are replaced.
+        let diff = ReflectDiff::new(&old, &1_u32);
+        assert!(matches!(diff, ReflectDiff::Replaced(_)));
+    }
+
+    #[test]
+    fn replaced_collections() {
+        let old = scene();
+
+        // A dynamic map that doesn't represent a type replaces the whole map,
+        // including the entries it doesn't have.
+        let mut counts = DynamicMap::default();
+        counts.insert("y".to_string(), 3_u32);
+        let diff = ReflectDiff::new(&old.counts, &counts);
+        assert!(matches!(diff, ReflectDiff::Replaced(_)));
+        let mut value = old.counts.clone();
+        apply_diff(&mut value, &diff).unwrap();
+        assert_eq!(value, [("y".to_string(), 3)].into_iter().collect());
+
+        let mut tags = DynamicSet::default();
+        tags.insert("c".to_string());
+        let diff = ReflectDiff::new(&old.tags, &tags);
+        let mut value = old.tags.clone();
+        apply_diff(&mut value, &diff).unwrap();
+        assert_eq!(value, ["c".to_string()].into_iter().collect());
+
+        let mut shapes = DynamicList::default();
+        shapes.push(Shape::Point);
+        let diff = ReflectDiff::new(&old.shapes, &shapes);
+        let mut value = old.shapes.clone();
+        apply_diff(&mut value, &diff).unwrap();
+        assert_eq!(value, vec![Shape::Point]);
+
+        // The entries missing from a concrete map are removed as well.
+        let diff = ReflectDiff::Replaced(Box::new(HashMap::<String, u32>::default()));
+        let mut value = old.counts.clone();
+        apply_diff(&mut value, &diff).unwrap();
+        assert!(value.is_empty());
+    }
+
+    #[test]
+    fn serialization() {
+        let old = scene();
+        let mut new = scene();
+        new.name = "renamed".to_string();
+        new.shapes[0] = Shape::Circle { radius: 2.0 };
+        new.shapes.push(Shape::Point);
+        new.tags.insert("c".to_string());
+        new.counts.remove("x");
+        new.counts.insert("y".to_string(), 3);
+        new.counts.insert("z".to_string(), 4);
+        new.grid[0] = 9;
+        let diff = ReflectDiff::new(&old, &new);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Scene>();
+        let serializer = ReflectDiffSerializer::new(&diff, &registry);
+
+        let serialized = ron::to_string(&serializer).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+        let deserialized = ReflectDiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let mut value = old.clone();
+        apply_diff(&mut value, &deserialized).unwrap();
+        assert_eq!(value, new);
+
+        let serialized = bincode::serialize(&serializer).unwrap();
+        let deserialized = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(ReflectDiffDeserializer::new(&registry), &serialized)
+            .unwrap();
+        let mut value = old.clone();
+        apply_diff(&mut value, &deserialized).unwrap();
+        assert_eq!(value, new);
+    }
+
+    #[test]
+    fn errors() {
+        let old = scene();
+        let mut new = scene();
+        new.shapes[0] = Shape::Circle { radius: 2.0 };
+        let diff = ReflectDiff::new(&old, &new);
+
+        let mut target = old.clone();
+        target.shapes[0] = Shape::Point;
+        assert!(matches!(
+            apply_diff(&mut target, &diff),
+            Err(ApplyDiffError::MissingField(name)) if &*name == "radius"
+        ));
+
+        let mut target = DynamicStruct::default();
+        target.insert("shapes", 1_u32);
+        assert!(matches!(
+            apply_diff(&mut target, &diff),
+            Err(ApplyDiffError::MismatchedKinds { .. })
+        ));
+    }
+}
@@ -52,6 +52,11 @@ impl ArgInfo {
         self.index
     }
 
+    /// Set the index of the argument within its function.
+    pub(crate) fn set_index(&mut self, index: usize) {
+        self.index = index;
+    }
+
     /// The name of the argument, if it was given one.
     ///
     /// Note that this may return `None` even if the argument has a name.
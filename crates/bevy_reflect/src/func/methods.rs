@@ -0,0 +1,443 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use bevy_utils::HashMap;
+
+use crate::{
+    func::{
+        args::ArgValue, ArgList, DynamicFunction, DynamicFunctionMut, FunctionInfo,
+        FunctionRegistrationError, IntoFunction, Return, SignatureInfo,
+    },
+    PartialReflect,
+};
+
+/// Type data holding the reflected methods of a type, mapped by name.
+///
+/// Methods are stored as [`DynamicFunction<'static>`] taking the receiver as their first argument,
+/// such as `fn(&Self, u32) -> u32` for a method taking `&self` and a `u32`.
+/// They can either be called directly, or [bound] to a receiver to be called like a function
+/// taking the remaining arguments.
+///
+/// This is registered by the `Reflect` derive when the type has the
+/// `#[reflect(methods(...))]` attribute, or it can be inserted manually into a [`TypeRegistration`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{prelude::*, func::{ArgList, ReflectMethods}, TypeRegistry};
+/// #[derive(Reflect)]
+/// #[reflect(methods(health, heal))]
+/// struct Player {
+///     health: u32,
+/// }
+///
+/// impl Player {
+///     fn health(&self) -> u32 {
+///         self.health
+///     }
+///
+///     fn heal(&mut self, amount: u32) {
+///         self.health += amount;
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+///
+/// let mut player: Box<dyn Reflect> = Box::new(Player { health: 10 });
+/// let methods = registry
+///     .get_type_data::<ReflectMethods>(player.reflect_type_info().type_id())
+///     .unwrap();
+///
+/// let heal = methods.bind_mut("heal", player.as_partial_reflect_mut()).unwrap();
+/// heal.call_once(ArgList::new().push_owned(5_u32)).unwrap();
+///
+/// let health = methods.bind("health", player.as_partial_reflect()).unwrap();
+/// let value = health.call(ArgList::new()).unwrap().unwrap_owned();
+/// assert_eq!(value.try_take::<u32>().unwrap(), 15);
+/// ```
+///
+/// [bound]: Self::bind
+/// [`TypeRegistration`]: crate::TypeRegistration
+#[derive(Clone, Default)]
+pub struct ReflectMethods {
+    methods: HashMap<Cow<'static, str>, DynamicFunction<'static>>,
+}
+
+impl ReflectMethods {
+    /// Attempts to register the given method with the given name.
+    ///
+    /// This function accepts both functions that satisfy [`IntoFunction`]
+    /// and direct [`DynamicFunction`] instances, whose [name] is set to the given name.
+    ///
+    /// If a method with the same name already exists, an error is returned.
+    /// To register the method anyway, use [`overwrite_registration`] instead.
+    ///
+    /// [name]: DynamicFunction::name
+    /// [`overwrite_registration`]: Self::overwrite_registration
+    pub fn register<F, Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        method: F,
+    ) -> Result<&mut Self, FunctionRegistrationError>
+    where
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        let method = method.into_function().with_name(name);
+        let name = method.name().unwrap().clone();
+        self.methods
+            .try_insert(name, method)
+            .map_err(|err| FunctionRegistrationError::DuplicateName(err.entry.key().clone()))?;
+
+        Ok(self)
+    }
+
+    /// Registers the given method with the given name, overwriting any existing registration.
+    ///
+    /// Returns the previous method with the same name, if any.
+    pub fn overwrite_registration<F, Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        method: F,
+    ) -> Option<DynamicFunction<'static>>
+    where
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        let method = method.into_function().with_name(name);
+        let name = method.name().unwrap().clone();
+        self.methods.insert(name, method)
+    }
+
+    /// Get a reference to the method with the given name.
+    ///
+    /// The method takes its receiver as its first argument.
+    pub fn get(&self, name: &str) -> Option<&DynamicFunction<'static>> {
+        self.methods.get(name)
+    }
+
+    /// Returns true if a method with the given name is registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.methods.contains_key(name)
+    }
+
+    /// Returns an iterator over the registered methods, in an arbitrary order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &DynamicFunction<'static>> {
+        self.methods.values()
+    }
+
+    /// Returns the number of registered methods.
+    pub fn len(&self) -> usize {
+        self.methods.len()
+    }
+
+    /// Returns true if no methods are registered.
+    pub fn is_empty(&self) -> bool {
+        self.methods.is_empty()
+    }
+
+    /// Returns the method with the given name as a [`DynamicFunction`] with its receiver bound to
+    /// `receiver`, so that it only takes the remaining arguments.
+    ///
+    /// The receiver is passed to the method as an [`ArgValue::Ref`],
+    /// so this only works with methods taking `&self`.
+    /// Since the returned function can't borrow from the receiver,
+    /// any reference it returns is cloned into an owned value.
+    /// To get a returned reference, call the method [directly] instead.
+    ///
+    /// Returns `None` if no method with the given name is registered,
+    /// or if it doesn't take any arguments.
+    ///
+    /// [directly]: Self::get
+    pub fn bind<'env>(
+        &self,
+        name: &str,
+        receiver: &'env dyn PartialReflect,
+    ) -> Option<DynamicFunction<'env>> {
+        let method = self.methods.get(name)?;
+        let info = bound_info(method.info())?;
+        let method = method.clone();
+        Some(DynamicFunction::new(
+            move |args| {
+                let args = with_receiver(ArgValue::Ref(receiver), args);
+                method.call(args).map(into_owned)
+            },
+            info,
+        ))
+    }
+
+    /// Returns the method with the given name as a [`DynamicFunctionMut`] with its receiver bound
+    /// to `receiver`, so that it only takes the remaining arguments.
+    ///
+    /// The receiver is passed to the method as an [`ArgValue::Mut`],
+    /// so this only works with methods taking `&mut self`.
+    /// Since the returned function can't borrow from the receiver,
+    /// any reference it returns is cloned into an owned value.
+    ///
+    /// Returns `None` if no method with the given name is registered,
+    /// or if it doesn't take any arguments.
+    pub fn bind_mut<'env>(
+        &self,
+        name: &str,
+        receiver: &'env mut dyn PartialReflect,
+    ) -> Option<DynamicFunctionMut<'env>> {
+        let method = self.methods.get(name)?;
+        let info = bound_info(method.info())?;
+        let method = method.clone();
+        Some(DynamicFunctionMut::new(
+            move |args| {
+                let args = with_receiver(ArgValue::Mut(&mut *receiver), args);
+                method.call(args).map(into_owned)
+            },
+            info,
+        ))
+    }
+}
+
+impl core::fmt::Debug for ReflectMethods {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut names = self.methods.keys().collect::<Vec<_>>();
+        names.sort_unstable();
+        f.debug_struct("ReflectMethods")
+            .field("methods", &names)
+            .finish()
+    }
+}
+
+/// Returns the [`FunctionInfo`] of a method once its receiver is bound,
+/// or `None` if it doesn't have a receiver.
+fn bound_info(info: &FunctionInfo) -> Option<FunctionInfo> {
+    let signatures = info
+        .signatures()
+        .iter()
+        .map(|signature| {
+            let (_, args) = signature.args().split_first()?;
+            let args = args
+                .iter()
+                .cloned()
+                .enumerate()
+                .map(|(index, mut arg)| {
+                    arg.set_index(index);
+                    arg
+                })
+                .collect();
+            let bound = match signature.name() {
+                Some(name) => SignatureInfo::named(name.clone()),
+                None => SignatureInfo::anonymous(),
+            };
+            Some(
+                bound
+                    .with_args(args)
+                    .with_return_info(signature.return_info().clone()),
+            )
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let bound = FunctionInfo::try_from_iter(signatures).ok()?;
+    Some(bound.with_name(info.name().cloned()))
+}
+
+/// Returns the given arguments preceded by the given receiver.
+fn with_receiver<'a>(receiver: ArgValue<'a>, mut args: ArgList<'a>) -> ArgList<'a> {
+    let mut bound = ArgList::new().push_arg(receiver);
+    while let Ok(arg) = args.take_arg() {
+        bound = bound.push_arg(arg.take_value());
+    }
+    bound
+}
+
+/// Clones any reference returned by a bound method, which can't outlive its receiver.
+fn into_owned<'a>(value: Return<'_>) -> Return<'a> {
+    match value {
+        Return::Owned(value) => Return::Owned(value),
+        Return::Ref(value) => Return::Owned(value.clone_value()),
+        Return::Mut(value) => Return::Owned(value.clone_value()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        self as bevy_reflect,
+        func::{args::ArgCount, FunctionError},
+        GetTypeRegistration, Reflect, TypeRegistry,
+    };
+    use alloc::string::{String, ToString};
+
+    trait Damageable {
+        fn damage(&mut self, amount: u32);
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(methods(health, heal, name, Damageable::damage, scaled::<u32>, new))]
+    struct Player {
+        name: String,
+        health: u32,
+    }
+
+    impl Player {
+        fn new(name: String) -> Self {
+            Self { name, health: 10 }
+        }
+
+        fn health(&self) -> u32 {
+            self.health
+        }
+
+        fn heal(&mut self, amount: u32) {
+            self.health += amount;
+        }
+
+        fn name(&self) -> &String {
+            &self.name
+        }
+
+        fn scaled<T: Into<u32>>(&self, factor: T) -> u32 {
+            self.health * factor.into()
+        }
+    }
+
+    impl Damageable for Player {
+        fn damage(&mut self, amount: u32) {
+            self.health = self.health.saturating_sub(amount);
+        }
+    }
+
+    fn create_player() -> Player {
+        Player {
+            name: "Alice".to_string(),
+            health: 10,
+        }
+    }
+
+    #[test]
+    fn should_register_methods() {
+        let registration = Player::get_type_registration();
+        let methods = registration.data::<ReflectMethods>().unwrap();
+
+        assert_eq!(methods.len(), 6);
+        for name in ["health", "heal", "name", "damage", "scaled", "new"] {
+            assert!(methods.contains(name), "missing method {name}");
+            assert_eq!(methods.get(name).unwrap().name().unwrap(), name);
+        }
+    }
+
+    #[test]
+    fn should_call_unbound_methods() {
+        let registration = Player::get_type_registration();
+        let methods = registration.data::<ReflectMethods>().unwrap();
+        let mut player = create_player();
+
+        let name = methods
+            .get("name")
+            .unwrap()
+            .call(ArgList::new().push_ref(&player))
+            .unwrap()
+            .unwrap_ref();
+        assert_eq!(name.try_downcast_ref::<String>().unwrap(), "Alice");
+
+        methods
+            .get("damage")
+            .unwrap()
+            .call(ArgList::new().push_mut(&mut player).push_owned(3_u32))
+            .unwrap();
+        assert_eq!(player.health, 7);
+
+        let new = methods
+            .get("new")
+            .unwrap()
+            .call(ArgList::new().push_owned("Bob".to_string()))
+            .unwrap()
+            .unwrap_owned();
+        assert_eq!(
+            new.try_take::<Player>().unwrap(),
+            Player::new("Bob".to_string())
+        );
+    }
+
+    #[test]
+    fn should_call_bound_methods() {
+        let registration = Player::get_type_registration();
+        let methods = registration.data::<ReflectMethods>().unwrap();
+        let mut player = create_player();
+
+        let mut heal = methods.bind_mut("heal", &mut player).unwrap();
+        assert_eq!(heal.arg_count(), ArgCount::new(1).unwrap());
+        heal.call(ArgList::new().push_owned(5_u32)).unwrap();
+        heal.call(ArgList::new().push_owned(1_u32)).unwrap();
+        drop(heal);
+        assert_eq!(player.health, 16);
+
+        let scaled = methods.bind("scaled", &player).unwrap();
+        let value = scaled
+            .call(ArgList::new().push_owned(2_u32))
+            .unwrap()
+            .unwrap_owned();
+        assert_eq!(value.try_take::<u32>().unwrap(), 32);
+
+        // References are returned as owned values.
+        let name = methods.bind("name", &player).unwrap();
+        let value = name.call(ArgList::new()).unwrap().unwrap_owned();
+        assert_eq!(value.try_take::<String>().unwrap(), "Alice");
+    }
+
+    #[test]
+    fn should_not_bind_methods_without_receiver() {
+        let registration = Player::get_type_registration();
+        let methods = registration.data::<ReflectMethods>().unwrap();
+        let player = create_player();
+
+        assert!(methods.bind("missing", &player).is_none());
+
+        let mut methods = methods.clone();
+        methods.register("max_health", || 100_u32).unwrap();
+        assert!(methods.bind("max_health", &player).is_none());
+    }
+
+    #[test]
+    fn should_error_on_invalid_receiver() {
+        let registration = Player::get_type_registration();
+        let methods = registration.data::<ReflectMethods>().unwrap();
+        let mut player = create_player();
+
+        // `heal` takes `&mut self`.
+        let heal = methods.bind("heal", &player).unwrap();
+        let result = heal.call(ArgList::new().push_owned(5_u32));
+        assert!(matches!(result, Err(FunctionError::ArgError(_))));
+        drop(heal);
+
+        let mut value = 123_u32;
+        let mut damage = methods.bind_mut("damage", &mut value).unwrap();
+        let result = damage.call(ArgList::new().push_owned(5_u32));
+        assert!(matches!(result, Err(FunctionError::ArgError(_))));
+
+        let mut heal = methods.bind_mut("heal", &mut player).unwrap();
+        let result = heal.call(ArgList::new());
+        assert!(matches!(
+            result,
+            Err(FunctionError::ArgCountMismatch { received: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn should_register_methods_manually() {
+        let mut methods = ReflectMethods::default();
+        methods.register("health", Player::health).unwrap();
+
+        let result = methods.register("health", Player::scaled::<u8>);
+        assert!(matches!(
+            result,
+            Err(FunctionRegistrationError::DuplicateName(_))
+        ));
+
+        let previous = methods.overwrite_registration("health", Player::scaled::<u8>);
+        assert!(previous.is_some());
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry
+            .get_mut(core::any::TypeId::of::<Player>())
+            .unwrap()
+            .insert(methods);
+        let methods = registry.get_type_data::<ReflectMethods>(core::any::TypeId::of::<Player>());
+        assert_eq!(methods.unwrap().len(), 1);
+    }
+}
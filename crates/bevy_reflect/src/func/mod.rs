@@ -149,6 +149,34 @@
 //! assert_eq!(value.unwrap_owned().try_downcast_ref::<i32>(), Some(&50));
 //! ```
 //!
+//! # Reflected Methods
+//!
+//! Methods of a type can be registered by name as [`ReflectMethods`] type data,
+//! either manually or with the `#[reflect(methods(...))]` attribute of the `Reflect` derive.
+//! They can then be called dynamically from a reflected value,
+//! with the value bound as their receiver.
+//!
+//! ```
+//! # use bevy_reflect::{prelude::*, func::{ArgList, ReflectMethods}, GetTypeRegistration};
+//! #[derive(Reflect)]
+//! #[reflect(methods(double))]
+//! struct Counter(u32);
+//!
+//! impl Counter {
+//!     fn double(&self) -> u32 {
+//!         self.0 * 2
+//!     }
+//! }
+//!
+//! let registration = Counter::get_type_registration();
+//! let methods = registration.data::<ReflectMethods>().unwrap();
+//!
+//! let counter: &dyn PartialReflect = &Counter(21);
+//! let double = methods.bind("double", counter).unwrap();
+//! let value = double.call(ArgList::new()).unwrap().unwrap_owned();
+//! assert_eq!(value.try_take::<u32>().unwrap(), 42);
+//! ```
+//!
 //! [`PartialReflect`]: crate::PartialReflect
 //! [`Reflect`]: crate::Reflect
 //! [lack of variadic generics]: https://poignardazur.github.io/2024/05/25/report-on-rustnl-variadics/
@@ -166,6 +194,7 @@ pub use function::*;
 pub use info::*;
 pub use into_function::*;
 pub use into_function_mut::*;
+pub use methods::*;
 pub use reflect_fn::*;
 pub use reflect_fn_mut::*;
 pub use registry::*;
@@ -181,6 +210,7 @@ mod info;
 mod into_function;
 mod into_function_mut;
 pub(crate) mod macros;
+mod methods;
 mod reflect_fn;
 mod reflect_fn_mut;
 mod registry;
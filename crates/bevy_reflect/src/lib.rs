@@ -508,6 +508,17 @@
 //! This can be useful for generating documentation for scripting language interop or
 //! for displaying tooltips in an editor.
 //!
+//! ## `schema`
+//!
+//! | Default | Dependencies                                  |
+//! | :-----: | :-------------------------------------------: |
+//! | ❌      | [`serde_json`]                                |
+//!
+//! This feature enables exporting the types of a [type registry] as a [JSON Schema] document
+//! or as [TypeScript] declarations, for use by external tools.
+//!
+//! For more information, read the [`schema`] module docs.
+//!
 //! ## `debug`
 //!
 //! | Default | Dependencies                                  |
@@ -555,6 +566,10 @@
 //! [`DynamicFunction`]: crate::func::DynamicFunction
 //! [`DynamicFunctionMut`]: crate::func::DynamicFunctionMut
 //! [`ArgList`]: crate::func::ArgList
+//! [`serde_json`]: https://docs.rs/serde_json/latest/serde_json/
+//! [JSON Schema]: https://json-schema.org/
+//! [TypeScript]: https://www.typescriptlang.org/
+//! [`schema`]: crate::schema
 //! [derive `Reflect`]: derive@crate::Reflect
 
 #![no_std]
@@ -565,6 +580,7 @@ extern crate std;
 extern crate alloc;
 
 mod array;
+mod diff;
 mod fields;
 mod from_reflect;
 #[cfg(feature = "functions")]
@@ -605,6 +621,8 @@ mod impls {
 pub mod attributes;
 mod enums;
 mod generics;
+#[cfg(feature = "schema")]
+pub mod schema;
 pub mod serde;
 pub mod std_traits;
 #[cfg(feature = "debug_stack")]
@@ -629,6 +647,7 @@ pub mod prelude {
 }
 
 pub use array::*;
+pub use diff::*;
 pub use enums::*;
 pub use fields::*;
 pub use from_reflect::*;
@@ -0,0 +1,591 @@
+use super::{custom_attributes, docs, option_value, Primitive, SchemaExporter};
+use crate::{
+    attributes::CustomAttributes,
+    serde::{ReflectVersion, SerializationData, TypedReflectSerializer},
+    GenericInfo, NamedField, PartialReflect, TypeInfo, TypeRegistration, UnnamedField, VariantInfo,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::any::TypeId;
+use serde_json::{json, Map, Value};
+
+/// The JSON Schema dialect of the documents exported by [`SchemaExporter::json_schema`].
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+impl SchemaExporter<'_> {
+    /// Exports the types as a [JSON Schema] document.
+    ///
+    /// The schema of each exported type is stored under `$defs`, with its type path as key.
+    /// A type can thus be referenced with a URI such as
+    /// `types.schema.json#/$defs/my_crate::Player`, once percent-encoded.
+    ///
+    /// Along with the standard keywords, schemas contain the following annotations:
+    /// - `typePath`, `shortPath`, `crateName` and `modulePath`: the [`TypePath`] of the type.
+    /// - `kind`: the [`ReflectKind`] of the type.
+    /// - `keyType`: the schema of the keys of maps, which are serialized as strings in JSON.
+    /// - `generics`: the [generic parameters] of the type.
+    /// - `customAttributes`: the [custom attributes] of types, fields and variants, serialized
+    ///   with the [`TypeRegistry`]. Attributes that can't be serialized are left out.
+    /// - `version`: the [version] of versioned types.
+    ///
+    /// Doc comments are exported as `description`s with the `documentation` feature.
+    ///
+    /// [JSON Schema]: https://json-schema.org/
+    /// [`TypePath`]: crate::TypePath
+    /// [`ReflectKind`]: crate::ReflectKind
+    /// [generic parameters]: crate::Generics
+    /// [custom attributes]: crate::attributes
+    /// [`TypeRegistry`]: crate::TypeRegistry
+    /// [version]: ReflectVersion
+    pub fn json_schema(&self) -> Value {
+        let defs = self
+            .registrations()
+            .into_iter()
+            .map(|registration| {
+                (
+                    registration.type_info().type_path().to_string(),
+                    self.type_schema(registration),
+                )
+            })
+            .collect::<Map<_, _>>();
+        json!({
+            "$schema": JSON_SCHEMA_DIALECT,
+            "$defs": defs,
+        })
+    }
+
+    /// Returns the JSON Schema of a single type, as stored in the `$defs` of [`json_schema`].
+    ///
+    /// [`json_schema`]: Self::json_schema
+    pub fn type_schema(&self, registration: &TypeRegistration) -> Value {
+        let info = registration.type_info();
+        let mut schema = self.data_schema(registration);
+
+        if let Some(reflect_version) = registration.data::<ReflectVersion>() {
+            schema = json!({
+                "type": "array",
+                "prefixItems": [{ "const": reflect_version.version() }, schema],
+                "items": false,
+                "minItems": 2,
+                "version": reflect_version.version(),
+            });
+        }
+
+        let Value::Object(object) = &mut schema else {
+            unreachable!("type schemas are objects");
+        };
+        let table = info.type_path_table();
+        object.insert("title".into(), table.short_path().into());
+        object.insert("typePath".into(), table.path().into());
+        object.insert("shortPath".into(), table.short_path().into());
+        if let Some(crate_name) = table.crate_name() {
+            object.insert("crateName".into(), crate_name.into());
+        }
+        if let Some(module_path) = table.module_path() {
+            object.insert("modulePath".into(), module_path.into());
+        }
+        object.insert("kind".into(), format!("{:?}", info.kind()).into());
+        if let Some(docs) = docs(info) {
+            object.insert("description".into(), docs.trim().into());
+        }
+        if !info.generics().is_empty() {
+            let generics = info
+                .generics()
+                .iter()
+                .map(|generic| self.generic_schema(generic))
+                .collect();
+            object.insert("generics".into(), Value::Array(generics));
+        }
+        if let Some(attributes) = custom_attributes(info) {
+            self.insert_custom_attributes(object, attributes);
+        }
+        schema
+    }
+
+    /// Returns the schema of the serialized data of the given type, without its version.
+    fn data_schema(&self, registration: &TypeRegistration) -> Value {
+        let serialization_data = registration.data::<SerializationData>();
+        let is_skipped =
+            |index| serialization_data.is_some_and(|data| data.is_field_skipped(index));
+
+        match registration.type_info() {
+            TypeInfo::Struct(info) => self.struct_schema(info.iter().enumerate(), is_skipped),
+            TypeInfo::TupleStruct(info) => {
+                if info.field_len() == 1 && serialization_data.is_none() {
+                    // Single-field tuple structs are serialized as newtypes.
+                    self.unnamed_field_schema(info.field_at(0).unwrap())
+                } else {
+                    self.tuple_schema(
+                        info.iter()
+                            .enumerate()
+                            .filter(|(index, _)| !is_skipped(*index))
+                            .map(|(_, field)| field),
+                    )
+                }
+            }
+            TypeInfo::Tuple(info) => self.tuple_schema(info.iter()),
+            TypeInfo::List(info) => json!({
+                "type": "array",
+                "items": self.type_ref(info.item_ty().id(), info.item_ty().path()),
+            }),
+            TypeInfo::Array(info) => json!({
+                "type": "array",
+                "items": self.type_ref(info.item_ty().id(), info.item_ty().path()),
+                "minItems": info.capacity(),
+                "maxItems": info.capacity(),
+            }),
+            TypeInfo::Set(info) => json!({
+                "type": "array",
+                "items": self.type_ref(info.value_ty().id(), info.value_ty().path()),
+                "uniqueItems": true,
+            }),
+            TypeInfo::Map(info) => json!({
+                "type": "object",
+                "additionalProperties": self.type_ref(info.value_ty().id(), info.value_ty().path()),
+                "keyType": self.type_ref(info.key_ty().id(), info.key_ty().path()),
+            }),
+            TypeInfo::Enum(info) => {
+                if let Some(value) = option_value(info) {
+                    return json!({
+                        "anyOf": [{ "type": "null" }, self.unnamed_field_schema(value)],
+                    });
+                }
+                json!({
+                    "oneOf": info
+                        .iter()
+                        .map(|variant| self.variant_schema(variant))
+                        .collect::<Vec<_>>(),
+                })
+            }
+            TypeInfo::Opaque(info) => match Primitive::of(info.type_path()) {
+                Some(Primitive::Boolean) => json!({ "type": "boolean" }),
+                Some(Primitive::Integer { minimum, maximum }) => {
+                    let mut schema = json!({ "type": "integer" });
+                    if let Some(minimum) = minimum {
+                        schema["minimum"] = minimum.into();
+                    }
+                    if let Some(maximum) = maximum {
+                        schema["maximum"] = maximum.into();
+                    }
+                    schema
+                }
+                Some(Primitive::Number) => json!({ "type": "number" }),
+                Some(Primitive::Char) => json!({
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 1,
+                }),
+                Some(Primitive::String) => json!({ "type": "string" }),
+                // The format of other opaque types is only known to their `Serialize` impl.
+                None => json!({}),
+            },
+        }
+    }
+
+    fn struct_schema<'f>(
+        &self,
+        fields: impl Iterator<Item = (usize, &'f NamedField)>,
+        is_skipped: impl Fn(usize) -> bool,
+    ) -> Value {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        for (index, field) in fields {
+            if is_skipped(index) {
+                continue;
+            }
+            if !is_option(field.type_path()) {
+                required.push(Value::from(field.name()));
+            }
+            properties.insert(field.name().to_string(), self.named_field_schema(field));
+        }
+        json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        })
+    }
+
+    fn tuple_schema<'f>(&self, fields: impl Iterator<Item = &'f UnnamedField>) -> Value {
+        let items = fields
+            .map(|field| self.unnamed_field_schema(field))
+            .collect::<Vec<_>>();
+        json!({
+            "type": "array",
+            "minItems": items.len(),
+            "maxItems": items.len(),
+            "prefixItems": items,
+            "items": false,
+        })
+    }
+
+    fn variant_schema(&self, variant: &VariantInfo) -> Value {
+        let (mut schema, attributes) = match variant {
+            // Unit variants are serialized as their name,
+            // and other variants as a map from their name to their fields.
+            VariantInfo::Unit(variant) => (
+                json!({ "const": variant.name() }),
+                variant.custom_attributes(),
+            ),
+            VariantInfo::Tuple(variant) => {
+                let fields = if variant.field_len() == 1 {
+                    self.unnamed_field_schema(variant.field_at(0).unwrap())
+                } else {
+                    self.tuple_schema(variant.iter())
+                };
+                (
+                    variant_object(variant.name(), fields),
+                    variant.custom_attributes(),
+                )
+            }
+            VariantInfo::Struct(variant) => {
+                let fields = self.struct_schema(variant.iter().enumerate(), |_| false);
+                (
+                    variant_object(variant.name(), fields),
+                    variant.custom_attributes(),
+                )
+            }
+        };
+
+        let Value::Object(object) = &mut schema else {
+            unreachable!("variant schemas are objects");
+        };
+        object.insert("title".into(), variant.name().into());
+        #[cfg(feature = "documentation")]
+        if let Some(docs) = variant.docs() {
+            object.insert("description".into(), docs.trim().into());
+        }
+        self.insert_custom_attributes(object, attributes);
+        schema
+    }
+
+    fn named_field_schema(&self, field: &NamedField) -> Value {
+        let mut schema = self.type_ref(field.type_id(), field.type_path());
+        let Value::Object(object) = &mut schema else {
+            unreachable!("type references are objects");
+        };
+        #[cfg(feature = "documentation")]
+        if let Some(docs) = field.docs() {
+            object.insert("description".into(), docs.trim().into());
+        }
+        self.insert_custom_attributes(object, field.custom_attributes());
+        schema
+    }
+
+    fn unnamed_field_schema(&self, field: &UnnamedField) -> Value {
+        let mut schema = self.type_ref(field.type_id(), field.type_path());
+        let Value::Object(object) = &mut schema else {
+            unreachable!("type references are objects");
+        };
+        #[cfg(feature = "documentation")]
+        if let Some(docs) = field.docs() {
+            object.insert("description".into(), docs.trim().into());
+        }
+        self.insert_custom_attributes(object, field.custom_attributes());
+        schema
+    }
+
+    fn generic_schema(&self, generic: &GenericInfo) -> Value {
+        let mut schema = json!({
+            "name": generic.name(),
+            "typePath": generic.type_path(),
+            "const": generic.is_const(),
+        });
+        match generic {
+            GenericInfo::Type(info) => {
+                if let Some(default) = info.default() {
+                    schema["default"] = default.path().into();
+                }
+            }
+            GenericInfo::Const(info) => {
+                if let Some(default) = info.default().and_then(|value| self.to_json(value)) {
+                    schema["default"] = default;
+                }
+            }
+        }
+        schema
+    }
+
+    /// Returns a reference to the schema of the type, or an empty schema if it isn't exported.
+    fn type_ref(&self, type_id: TypeId, type_path: &str) -> Value {
+        if self.is_exported(type_id) {
+            json!({
+                "$ref": format!("#/$defs/{}", encode_pointer(type_path)),
+            })
+        } else {
+            json!({ "typePath": type_path })
+        }
+    }
+
+    fn insert_custom_attributes(
+        &self,
+        object: &mut Map<String, Value>,
+        attributes: &CustomAttributes,
+    ) {
+        let attributes = attributes
+            .iter()
+            .filter_map(|(_, attribute)| {
+                let value = self.to_json(attribute.as_partial_reflect())?;
+                Some((attribute.reflect_type_path().to_string(), value))
+            })
+            .collect::<Map<_, _>>();
+        if !attributes.is_empty() {
+            object.insert("customAttributes".into(), Value::Object(attributes));
+        }
+    }
+
+    fn to_json(&self, value: &dyn PartialReflect) -> Option<Value> {
+        serde_json::to_value(TypedReflectSerializer::new(value, self.registry)).ok()
+    }
+}
+
+/// Returns the schema of a map from the name of a variant to its fields.
+fn variant_object(name: &str, fields: Value) -> Value {
+    json!({
+        "type": "object",
+        "properties": { name: fields },
+        "required": [name],
+        "additionalProperties": false,
+    })
+}
+
+fn is_option(type_path: &str) -> bool {
+    type_path.starts_with("core::option::Option<")
+}
+
+/// Encodes a type path as a JSON pointer segment within a URI fragment.
+fn encode_pointer(type_path: &str) -> String {
+    let mut encoded = String::with_capacity(type_path.len());
+    for char in type_path.chars() {
+        match char {
+            // JSON pointer escapes.
+            '~' => encoded.push_str("~0"),
+            '/' => encoded.push_str("~1"),
+            // Characters allowed in URI fragments.
+            'a'..='z'
+            | 'A'..='Z'
+            | '0'..='9'
+            | '-'
+            | '.'
+            | '_'
+            | '!'
+            | '$'
+            | '&'
+            | '\''
+            | '('
+            | ')'
+            | '*'
+            | '+'
+            | ','
+            | ';'
+            | '='
+            | ':'
+            | '@'
+            | '?' => encoded.push(char),
+            _ => {
+                let mut buffer = [0; 4];
+                for byte in char.encode_utf8(&mut buffer).bytes() {
+                    encoded.push_str(&format!("%{byte:02X}"));
+                }
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_reflect, Reflect, TypeRegistry};
+    use alloc::vec;
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect)]
+    struct Tooltip(String);
+
+    #[derive(Reflect)]
+    #[reflect(@Tooltip(String::from("The player")))]
+    struct Player {
+        name: String,
+        health: u8,
+        target: Option<u32>,
+        #[reflect(skip_serializing)]
+        cache: Vec<u8>,
+        inventory: HashMap<String, Item>,
+        position: Position,
+    }
+
+    #[derive(Reflect)]
+    struct Position(f32, f32);
+
+    #[derive(Reflect)]
+    enum Item {
+        Empty,
+        Potion(u8),
+        Weapon { damage: u32, range: f32 },
+    }
+
+    #[derive(Reflect)]
+    struct Wrapper<T, const N: usize>([T; N]);
+
+    fn create_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Player>();
+        registry.register::<Tooltip>();
+        registry
+    }
+
+    #[test]
+    fn should_export_dependencies() {
+        let registry = create_registry();
+        let exporter = SchemaExporter::new(&registry)
+            .with_filter(|registration| registration.type_id() == TypeId::of::<Player>());
+        let type_paths = exporter
+            .registrations()
+            .into_iter()
+            .map(|registration| registration.type_info().type_path())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            type_paths,
+            vec![
+                "alloc::string::String",
+                "alloc::vec::Vec<u8>",
+                "bevy_reflect::schema::json_schema::tests::Item",
+                "bevy_reflect::schema::json_schema::tests::Player",
+                "bevy_reflect::schema::json_schema::tests::Position",
+                "bevy_utils::hashbrown::HashMap<alloc::string::String, bevy_reflect::schema::json_schema::tests::Item, bevy_utils::FixedHasher>",
+                "core::option::Option<u32>",
+                "f32",
+                "u32",
+                "u8",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_export_structs() {
+        let registry = create_registry();
+        let schema = SchemaExporter::new(&registry).json_schema();
+        assert_eq!(schema["$schema"], JSON_SCHEMA_DIALECT);
+
+        let player = &schema["$defs"]["bevy_reflect::schema::json_schema::tests::Player"];
+        assert_eq!(player["type"], "object");
+        assert_eq!(player["title"], "Player");
+        assert_eq!(player["kind"], "Struct");
+        assert_eq!(player["crateName"], "bevy_reflect");
+        assert_eq!(
+            player["required"],
+            json!(["name", "health", "inventory", "position"])
+        );
+        assert!(player["properties"].get("cache").is_none());
+        assert_eq!(
+            player["properties"]["name"],
+            json!({ "$ref": "#/$defs/alloc::string::String" })
+        );
+        assert_eq!(
+            player["customAttributes"],
+            json!({ "bevy_reflect::schema::json_schema::tests::Tooltip": "The player" })
+        );
+        assert_eq!(
+            player["properties"]["inventory"]["$ref"],
+            "#/$defs/bevy_utils::hashbrown::HashMap%3Calloc::string::String,%20bevy_reflect::schema::json_schema::tests::Item,%20bevy_utils::FixedHasher%3E"
+        );
+
+        let u8 = &schema["$defs"]["u8"];
+        assert_eq!(u8["type"], "integer");
+        assert_eq!(u8["maximum"], 255);
+
+        let position = &schema["$defs"]["bevy_reflect::schema::json_schema::tests::Position"];
+        assert_eq!(position["type"], "array");
+        assert_eq!(position["prefixItems"].as_array().unwrap().len(), 2);
+
+        let option = &schema["$defs"]["core::option::Option<u32>"];
+        assert_eq!(
+            option["anyOf"],
+            json!([{ "type": "null" }, { "$ref": "#/$defs/u32" }])
+        );
+    }
+
+    #[test]
+    fn should_export_enums() {
+        let registry = create_registry();
+        let schema = SchemaExporter::new(&registry).json_schema();
+        let item = &schema["$defs"]["bevy_reflect::schema::json_schema::tests::Item"];
+        assert_eq!(
+            item["oneOf"],
+            json!([
+                { "const": "Empty", "title": "Empty" },
+                {
+                    "type": "object",
+                    "properties": { "Potion": { "$ref": "#/$defs/u8" } },
+                    "required": ["Potion"],
+                    "additionalProperties": false,
+                    "title": "Potion",
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Weapon": {
+                            "type": "object",
+                            "properties": {
+                                "damage": { "$ref": "#/$defs/u32" },
+                                "range": { "$ref": "#/$defs/f32" },
+                            },
+                            "required": ["damage", "range"],
+                            "additionalProperties": false,
+                        },
+                    },
+                    "required": ["Weapon"],
+                    "additionalProperties": false,
+                    "title": "Weapon",
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn should_export_generics() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Wrapper<u8, 3>>();
+        let schema = SchemaExporter::new(&registry).json_schema();
+        let wrapper = &schema["$defs"]["bevy_reflect::schema::json_schema::tests::Wrapper<u8, 3>"];
+        assert_eq!(
+            wrapper["generics"],
+            json!([
+                { "name": "T", "typePath": "u8", "const": false },
+                { "name": "N", "typePath": "usize", "const": true },
+            ])
+        );
+        // Newtypes are serialized as their field.
+        assert_eq!(wrapper["$ref"], "#/$defs/%5Bu8;%203%5D");
+        let array = &schema["$defs"]["[u8; 3]"];
+        assert_eq!(array["minItems"], 3);
+        assert_eq!(array["maxItems"], 3);
+    }
+
+    #[test]
+    fn should_export_versioned_types() {
+        #[derive(Reflect)]
+        #[reflect(version = 3)]
+        struct Versioned {
+            value: u8,
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Versioned>();
+        let schema = SchemaExporter::new(&registry).json_schema();
+        let versioned = &schema["$defs"]["bevy_reflect::schema::json_schema::tests::Versioned"];
+        assert_eq!(versioned["version"], 3);
+        assert_eq!(versioned["prefixItems"][0], json!({ "const": 3 }));
+        assert_eq!(versioned["prefixItems"][1]["type"], "object");
+        assert_eq!(versioned["kind"], "Struct");
+    }
+
+    #[test]
+    fn should_encode_pointers() {
+        assert_eq!(encode_pointer("a::B<c/d, ~e>"), "a::B%3Cc~1d,%20~0e%3E");
+    }
+}
@@ -0,0 +1,234 @@
+//! Exporting the types of a [`TypeRegistry`] as [JSON Schema] and [TypeScript] declarations.
+//!
+//! The [`SchemaExporter`] describes registered types from their [`TypeInfo`],
+//! including their [generic parameters], [custom attributes] and,
+//! with the `documentation` feature, their doc comments.
+//!
+//! The exported descriptions follow the format used by the [reflection serializers]
+//! with a self-describing format such as JSON, so that tools can validate and
+//! create data meant to be loaded by the [reflection deserializers].
+//!
+//! As this only requires a [`TypeRegistry`], it can be used offline, for example from a build
+//! script or a small binary that registers the types of a project and writes their descriptions
+//! to files consumed by web-based tools:
+//!
+//! ```
+//! # use bevy_reflect::{prelude::*, schema::SchemaExporter, TypeRegistry};
+//! #[derive(Reflect)]
+//! struct Player {
+//!     name: String,
+//!     health: u32,
+//! }
+//!
+//! let mut registry = TypeRegistry::new();
+//! registry.register::<Player>();
+//!
+//! let exporter = SchemaExporter::new(&registry);
+//! let json_schema = serde_json::to_string_pretty(&exporter.json_schema()).unwrap();
+//! let typescript = exporter.typescript();
+//! # let _ = (json_schema, typescript);
+//! // std::fs::write("types.schema.json", json_schema).unwrap();
+//! // std::fs::write("types.d.ts", typescript).unwrap();
+//! ```
+//!
+//! [JSON Schema]: https://json-schema.org/
+//! [TypeScript]: https://www.typescriptlang.org/
+//! [generic parameters]: crate::Generics
+//! [custom attributes]: crate::attributes
+//! [reflection serializers]: crate::serde::ReflectSerializer
+//! [reflection deserializers]: crate::serde::ReflectDeserializer
+
+mod json_schema;
+mod typescript;
+
+pub use json_schema::*;
+
+use crate::{
+    attributes::CustomAttributes, EnumInfo, TypeInfo, TypeRegistration, TypeRegistry, VariantInfo,
+};
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+use core::any::TypeId;
+
+/// Exports the types of a [`TypeRegistry`] as a [JSON Schema] document or as
+/// [TypeScript] declarations.
+///
+/// By default, all registered types are exported.
+/// A [filter] can be used to only export some of them, along with the registered types they
+/// depend on, such as the types of their fields.
+///
+/// See the [module-level documentation] for more information.
+///
+/// [JSON Schema]: https://json-schema.org/
+/// [TypeScript]: https://www.typescriptlang.org/
+/// [filter]: Self::with_filter
+/// [module-level documentation]: crate::schema
+pub struct SchemaExporter<'a> {
+    registry: &'a TypeRegistry,
+    filter: Option<Box<dyn Fn(&TypeRegistration) -> bool + 'a>>,
+}
+
+impl<'a> SchemaExporter<'a> {
+    /// Creates an exporter for all the types of `registry`.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            filter: None,
+        }
+    }
+
+    /// Only exports the types accepted by `filter`, along with the registered types they depend on.
+    ///
+    /// ```
+    /// # use bevy_reflect::{schema::SchemaExporter, TypeRegistry};
+    /// let registry = TypeRegistry::new();
+    /// let exporter = SchemaExporter::new(&registry).with_filter(|registration| {
+    ///     registration.type_info().type_path_table().crate_name() == Some("my_game")
+    /// });
+    /// ```
+    #[must_use]
+    pub fn with_filter(mut self, filter: impl Fn(&TypeRegistration) -> bool + 'a) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Returns the [`TypeRegistry`] whose types are exported.
+    pub fn registry(&self) -> &'a TypeRegistry {
+        self.registry
+    }
+
+    /// Returns the registrations of the exported types, sorted by type path.
+    pub fn registrations(&self) -> Vec<&'a TypeRegistration> {
+        let mut exported = BTreeMap::new();
+        let mut pending: Vec<&TypeRegistration> = self
+            .registry
+            .iter()
+            .filter(|registration| {
+                self.filter
+                    .as_ref()
+                    .map_or(true, |filter| filter(registration))
+            })
+            .collect();
+        while let Some(registration) = pending.pop() {
+            let type_path = registration.type_info().type_path();
+            if exported.insert(type_path, registration).is_some() {
+                continue;
+            }
+            pending.extend(
+                dependencies(registration.type_info())
+                    .into_iter()
+                    .filter_map(|type_id| self.registry.get(type_id)),
+            );
+        }
+        exported.into_values().collect()
+    }
+
+    /// Returns true if values of the type with the given [`TypeId`] are exported.
+    fn is_exported(&self, type_id: TypeId) -> bool {
+        // Dependencies of exported types are exported as well,
+        // so any registered type can be referenced.
+        self.registry.contains(type_id)
+    }
+}
+
+/// Returns the [`TypeId`]s of the types that values of the given type contain.
+fn dependencies(info: &TypeInfo) -> Vec<TypeId> {
+    match info {
+        TypeInfo::Struct(info) => info.iter().map(crate::NamedField::type_id).collect(),
+        TypeInfo::TupleStruct(info) => info.iter().map(crate::UnnamedField::type_id).collect(),
+        TypeInfo::Tuple(info) => info.iter().map(crate::UnnamedField::type_id).collect(),
+        TypeInfo::List(info) => Vec::from([info.item_ty().id()]),
+        TypeInfo::Array(info) => Vec::from([info.item_ty().id()]),
+        TypeInfo::Map(info) => Vec::from([info.key_ty().id(), info.value_ty().id()]),
+        TypeInfo::Set(info) => Vec::from([info.value_ty().id()]),
+        TypeInfo::Enum(info) => info
+            .iter()
+            .flat_map(|variant| match variant {
+                VariantInfo::Struct(variant) => variant
+                    .iter()
+                    .map(crate::NamedField::type_id)
+                    .collect::<Vec<_>>(),
+                VariantInfo::Tuple(variant) => {
+                    variant.iter().map(crate::UnnamedField::type_id).collect()
+                }
+                VariantInfo::Unit(_) => Vec::new(),
+            })
+            .collect(),
+        TypeInfo::Opaque(_) => Vec::new(),
+    }
+}
+
+/// Returns the custom attributes of the given type, if its kind supports them.
+fn custom_attributes(info: &TypeInfo) -> Option<&CustomAttributes> {
+    match info {
+        TypeInfo::Struct(info) => Some(info.custom_attributes()),
+        TypeInfo::TupleStruct(info) => Some(info.custom_attributes()),
+        TypeInfo::Enum(info) => Some(info.custom_attributes()),
+        _ => None,
+    }
+}
+
+/// Returns the docs of the given type, if any.
+fn docs(info: &TypeInfo) -> Option<&str> {
+    #[cfg(feature = "documentation")]
+    return info.docs();
+    #[cfg(not(feature = "documentation"))]
+    {
+        let _ = info;
+        None
+    }
+}
+
+/// Returns the [`TypeId`] of the value of `Option`, if the given enum is an `Option`.
+///
+/// Options are serialized as either `null` or their value.
+fn option_value(info: &EnumInfo) -> Option<&crate::UnnamedField> {
+    let table = info.type_path_table();
+    if table.module_path() != Some("core::option") || table.ident() != Some("Option") {
+        return None;
+    }
+    match info.variant("Some")? {
+        VariantInfo::Tuple(variant) => variant.field_at(0),
+        _ => None,
+    }
+}
+
+/// Primitive values that opaque types are serialized as.
+enum Primitive {
+    Boolean,
+    Integer {
+        minimum: Option<i64>,
+        maximum: Option<u64>,
+    },
+    Number,
+    Char,
+    String,
+}
+
+impl Primitive {
+    /// Returns the primitive values of the opaque type with the given type path are serialized as.
+    fn of(type_path: &str) -> Option<Self> {
+        let integer = |minimum, maximum| Self::Integer { minimum, maximum };
+        Some(match type_path {
+            "bool" => Self::Boolean,
+            "u8" => integer(Some(0), Some(u8::MAX.into())),
+            "u16" => integer(Some(0), Some(u16::MAX.into())),
+            "u32" => integer(Some(0), Some(u32::MAX.into())),
+            "u64" | "usize" => integer(Some(0), Some(u64::MAX)),
+            "u128" => integer(Some(0), None),
+            "i8" => integer(Some(i8::MIN.into()), Some(i8::MAX as u64)),
+            "i16" => integer(Some(i16::MIN.into()), Some(i16::MAX as u64)),
+            "i32" => integer(Some(i32::MIN.into()), Some(i32::MAX as u64)),
+            "i64" | "isize" => integer(Some(i64::MIN), Some(i64::MAX as u64)),
+            "i128" => integer(None, None),
+            "f32" | "f64" => Self::Number,
+            "char" => Self::Char,
+            "str"
+            | "&str"
+            | "alloc::string::String"
+            | "alloc::borrow::Cow<str>"
+            | "std::path::PathBuf"
+            | "smol_str::SmolStr" => Self::String,
+            _ => return None,
+        })
+    }
+}
@@ -0,0 +1,311 @@
+use super::{docs, option_value, Primitive, SchemaExporter};
+use crate::{
+    serde::{ReflectVersion, SerializationData},
+    NamedField, TypeInfo, TypeRegistration, UnnamedField, VariantInfo,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_utils::HashMap;
+use core::any::TypeId;
+
+/// The name of the TypeScript interface mapping type paths to types.
+const TYPES_INTERFACE: &str = "Types";
+
+/// Names that can't be used by type aliases, as they would shadow the types used by the
+/// declarations.
+const RESERVED_NAMES: &[&str] = &[
+    TYPES_INTERFACE,
+    "Array",
+    "Record",
+    "any",
+    "bigint",
+    "boolean",
+    "never",
+    "null",
+    "number",
+    "object",
+    "string",
+    "symbol",
+    "undefined",
+    "unknown",
+    "void",
+];
+
+impl SchemaExporter<'_> {
+    /// Exports the types as [TypeScript] declarations.
+    ///
+    /// Types are declared as the properties of an exported `Types` interface, with their type
+    /// path as key, matching the `$defs` of the [JSON Schema] export.
+    /// A type can thus be referenced as `Types["my_crate::Player"]`.
+    ///
+    /// For convenience, non-generic and non-opaque types whose short type path is unique among
+    /// the exported types are also exported as a type alias, such as `Player`.
+    ///
+    /// Doc comments are exported as [JSDoc] comments with the `documentation` feature.
+    ///
+    /// [TypeScript]: https://www.typescriptlang.org/
+    /// [JSON Schema]: Self::json_schema
+    /// [JSDoc]: https://jsdoc.app/
+    pub fn typescript(&self) -> String {
+        let registrations = self.registrations();
+        let mut output = format!("export interface {TYPES_INTERFACE} {{\n");
+        for registration in &registrations {
+            let info = registration.type_info();
+            if let Some(docs) = docs(info) {
+                output.push_str(&format!("  {}\n", jsdoc(docs)));
+            }
+            output.push_str(&format!(
+                "  {}: {};\n",
+                string_literal(info.type_path()),
+                self.typescript_type(registration)
+            ));
+        }
+        output.push_str("}\n");
+
+        let mut short_paths = HashMap::<&str, usize>::default();
+        for registration in &registrations {
+            *short_paths
+                .entry(registration.type_info().type_path_table().short_path())
+                .or_default() += 1;
+        }
+        for registration in &registrations {
+            let info = registration.type_info();
+            let short_path = info.type_path_table().short_path();
+            if short_paths[short_path] == 1
+                && !matches!(info, TypeInfo::Opaque(_))
+                && !RESERVED_NAMES.contains(&short_path)
+                && is_identifier(short_path)
+            {
+                output.push_str(&format!(
+                    "\nexport type {short_path} = {TYPES_INTERFACE}[{}];\n",
+                    string_literal(info.type_path())
+                ));
+            }
+        }
+        output
+    }
+
+    /// Returns the TypeScript type of the serialized data of the given type.
+    fn typescript_type(&self, registration: &TypeRegistration) -> String {
+        let serialization_data = registration.data::<SerializationData>();
+        let is_skipped =
+            |index| serialization_data.is_some_and(|data| data.is_field_skipped(index));
+
+        let data = match registration.type_info() {
+            TypeInfo::Struct(info) => self.typescript_struct(info.iter().enumerate(), is_skipped),
+            TypeInfo::TupleStruct(info) => {
+                if info.field_len() == 1 && serialization_data.is_none() {
+                    self.typescript_unnamed_field(info.field_at(0).unwrap())
+                } else {
+                    self.typescript_tuple(
+                        info.iter()
+                            .enumerate()
+                            .filter(|(index, _)| !is_skipped(*index))
+                            .map(|(_, field)| field),
+                    )
+                }
+            }
+            TypeInfo::Tuple(info) => self.typescript_tuple(info.iter()),
+            TypeInfo::List(info) => format!(
+                "Array<{}>",
+                self.typescript_ref(info.item_ty().id(), info.item_ty().path())
+            ),
+            TypeInfo::Array(info) => format!(
+                "Array<{}>",
+                self.typescript_ref(info.item_ty().id(), info.item_ty().path())
+            ),
+            TypeInfo::Set(info) => format!(
+                "Array<{}>",
+                self.typescript_ref(info.value_ty().id(), info.value_ty().path())
+            ),
+            TypeInfo::Map(info) => format!(
+                "Record<string, {}>",
+                self.typescript_ref(info.value_ty().id(), info.value_ty().path())
+            ),
+            TypeInfo::Enum(info) => {
+                if let Some(value) = option_value(info) {
+                    format!("{} | null", self.typescript_unnamed_field(value))
+                } else if info.variant_len() == 0 {
+                    String::from("never")
+                } else {
+                    info.iter()
+                        .map(|variant| self.typescript_variant(variant))
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                }
+            }
+            TypeInfo::Opaque(info) => match Primitive::of(info.type_path()) {
+                Some(Primitive::Boolean) => String::from("boolean"),
+                Some(Primitive::Integer { .. } | Primitive::Number) => String::from("number"),
+                Some(Primitive::Char | Primitive::String) => String::from("string"),
+                None => String::from("unknown"),
+            },
+        };
+
+        match registration.data::<ReflectVersion>() {
+            Some(reflect_version) => format!("[{}, {data}]", reflect_version.version()),
+            None => data,
+        }
+    }
+
+    fn typescript_struct<'f>(
+        &self,
+        fields: impl Iterator<Item = (usize, &'f NamedField)>,
+        is_skipped: impl Fn(usize) -> bool,
+    ) -> String {
+        let fields = fields
+            .filter(|(index, _)| !is_skipped(*index))
+            .map(|(_, field)| {
+                let optional = if field.type_path().starts_with("core::option::Option<") {
+                    "?"
+                } else {
+                    ""
+                };
+                let field_type = format!(
+                    "{}{optional}: {}",
+                    field.name(),
+                    self.typescript_ref(field.type_id(), field.type_path())
+                );
+                #[cfg(feature = "documentation")]
+                if let Some(docs) = field.docs() {
+                    return format!("{} {field_type}", jsdoc(docs));
+                }
+                field_type
+            })
+            .collect::<Vec<_>>();
+        if fields.is_empty() {
+            String::from("{}")
+        } else {
+            format!("{{ {} }}", fields.join("; "))
+        }
+    }
+
+    fn typescript_tuple<'f>(&self, fields: impl Iterator<Item = &'f UnnamedField>) -> String {
+        let fields = fields
+            .map(|field| self.typescript_unnamed_field(field))
+            .collect::<Vec<_>>();
+        format!("[{}]", fields.join(", "))
+    }
+
+    fn typescript_variant(&self, variant: &VariantInfo) -> String {
+        let fields = match variant {
+            VariantInfo::Unit(variant) => return string_literal(variant.name()),
+            VariantInfo::Tuple(variant) => {
+                if variant.field_len() == 1 {
+                    self.typescript_unnamed_field(variant.field_at(0).unwrap())
+                } else {
+                    self.typescript_tuple(variant.iter())
+                }
+            }
+            VariantInfo::Struct(variant) => {
+                self.typescript_struct(variant.iter().enumerate(), |_| false)
+            }
+        };
+        format!("{{ {}: {fields} }}", string_literal(variant.name()))
+    }
+
+    fn typescript_unnamed_field(&self, field: &UnnamedField) -> String {
+        self.typescript_ref(field.type_id(), field.type_path())
+    }
+
+    /// Returns a reference to the type, or `unknown` if it isn't exported.
+    fn typescript_ref(&self, type_id: TypeId, type_path: &str) -> String {
+        if self.is_exported(type_id) {
+            format!("{TYPES_INTERFACE}[{}]", string_literal(type_path))
+        } else {
+            String::from("unknown")
+        }
+    }
+}
+
+/// Quotes the given string as a TypeScript string literal.
+fn string_literal(value: &str) -> String {
+    // JSON strings are valid TypeScript string literals.
+    serde_json::Value::from(value).to_string()
+}
+
+/// Formats the given docs as a single-line `JSDoc` comment.
+fn jsdoc(docs: &str) -> String {
+    let docs = docs
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!("/** {} */", docs.replace("*/", "*\\/"))
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|char| char.is_ascii_alphabetic() || char == '_' || char == '$')
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '$')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_reflect, Reflect, TypeRegistry};
+
+    #[derive(Reflect)]
+    struct Player {
+        name: String,
+        target: Option<u32>,
+        #[reflect(skip_serializing)]
+        cache: Vec<u8>,
+        position: Position,
+        item: Item,
+    }
+
+    #[derive(Reflect)]
+    struct Position(f32, f32);
+
+    #[derive(Reflect)]
+    #[reflect(version = 2)]
+    enum Item {
+        Empty,
+        Potion(u8),
+        Weapon { damage: u32 },
+    }
+
+    #[test]
+    fn should_export_typescript() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Player>();
+        let exporter = SchemaExporter::new(&registry)
+            .with_filter(|registration| registration.type_id() == TypeId::of::<Player>());
+
+        let expected = r#"export interface Types {
+  "alloc::string::String": string;
+  "alloc::vec::Vec<u8>": Array<Types["u8"]>;
+  "bevy_reflect::schema::typescript::tests::Item": [2, "Empty" | { "Potion": Types["u8"] } | { "Weapon": { damage: Types["u32"] } }];
+  "bevy_reflect::schema::typescript::tests::Player": { name: Types["alloc::string::String"]; target?: Types["core::option::Option<u32>"]; position: Types["bevy_reflect::schema::typescript::tests::Position"]; item: Types["bevy_reflect::schema::typescript::tests::Item"] };
+  "bevy_reflect::schema::typescript::tests::Position": [Types["f32"], Types["f32"]];
+  "core::option::Option<u32>": Types["u32"] | null;
+  "f32": number;
+  "u32": number;
+  "u8": number;
+}
+
+export type Item = Types["bevy_reflect::schema::typescript::tests::Item"];
+
+export type Player = Types["bevy_reflect::schema::typescript::tests::Player"];
+
+export type Position = Types["bevy_reflect::schema::typescript::tests::Position"];
+"#;
+        assert_eq!(exporter.typescript(), expected);
+    }
+
+    #[test]
+    fn should_check_identifiers() {
+        assert!(is_identifier("Player"));
+        assert!(is_identifier("_private2"));
+        assert!(!is_identifier("Vec<u8>"));
+        assert!(!is_identifier("2d"));
+        assert!(!is_identifier(""));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::TypeRegistry;
+use bevy_utils::HashMap;
+use core::any::TypeId;
+
+/// A table of the numeric ids identifying types in the compact serialization format.
+///
+/// The [`CompactReflectSerializer`] writes these ids in place of the [type paths] written by the
+/// [`ReflectSerializer`], and the [`CompactReflectDeserializer`] uses them to find the
+/// registration of the serialized type.
+/// Both sides must therefore use the same ids for the same types, which can be achieved by
+/// either:
+/// - [hashing] the type paths of the registered types, which requires no coordination, or
+/// - agreeing on a list of type paths, such as one sent by a server to its clients,
+///   and [numbering] the types in that order, which produces smaller ids.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{serde::CompactTypeIds, TypeRegistry};
+/// let registry = TypeRegistry::new();
+///
+/// // Ids that are stable across builds and platforms, as long as type paths don't change.
+/// let hashed = CompactTypeIds::hashed(&registry);
+/// assert_eq!(hashed.id_of(core::any::TypeId::of::<u8>()), Some(CompactTypeIds::hash_type_path("u8")));
+///
+/// // Ids negotiated from a list of type paths shared by both sides.
+/// let type_paths = ["u8", "alloc::string::String"];
+/// let negotiated = CompactTypeIds::from_type_paths(&registry, type_paths);
+/// assert_eq!(negotiated.id_of(core::any::TypeId::of::<u8>()), Some(0));
+/// ```
+///
+/// [`CompactReflectSerializer`]: crate::serde::CompactReflectSerializer
+/// [type paths]: crate::TypePath::type_path
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [`CompactReflectDeserializer`]: crate::serde::CompactReflectDeserializer
+/// [hashing]: Self::hashed
+/// [numbering]: Self::from_type_paths
+#[derive(Clone, Debug, Default)]
+pub struct CompactTypeIds {
+    ids: HashMap<TypeId, u64>,
+    type_ids: HashMap<u64, TypeId>,
+}
+
+impl CompactTypeIds {
+    /// Creates a table identifying each type of `registry` by the [hash] of its type path.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the type paths of two registered types have the same hash.
+    ///
+    /// [hash]: Self::hash_type_path
+    pub fn hashed(registry: &TypeRegistry) -> Self {
+        let mut table = Self::default();
+        for registration in registry.iter() {
+            let type_path = registration.type_info().type_path();
+            let id = Self::hash_type_path(type_path);
+            if let Some(existing) = table.type_ids.insert(id, registration.type_id()) {
+                let existing = registry
+                    .get(existing)
+                    .map(|registration| registration.type_info().type_path())
+                    .unwrap_or_default();
+                panic!("the type paths `{type_path}` and `{existing}` have the same hash {id}");
+            }
+            table.ids.insert(registration.type_id(), id);
+        }
+        table
+    }
+
+    /// Creates a table identifying the types of `registry` by the position of their type path in
+    /// `type_paths`.
+    ///
+    /// Type paths that aren't registered are skipped without affecting the ids of the other types,
+    /// so that peers with different sets of types can still agree on the ids of the types they
+    /// share.
+    pub fn from_type_paths<'p>(
+        registry: &TypeRegistry,
+        type_paths: impl IntoIterator<Item = &'p str>,
+    ) -> Self {
+        let mut table = Self::default();
+        for (id, type_path) in (0..).zip(type_paths) {
+            if let Some(registration) = registry.get_with_type_path(type_path) {
+                table.insert(registration.type_id(), id);
+            }
+        }
+        table
+    }
+
+    /// Returns the stable hash of the given type path, as used by [`hashed`].
+    ///
+    /// This is the 64-bit FNV-1a hash of the type path, so that it can be computed by other
+    /// programs as well.
+    ///
+    /// [`hashed`]: Self::hashed
+    pub const fn hash_type_path(type_path: &str) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let bytes = type_path.as_bytes();
+        let mut hash = OFFSET_BASIS;
+        let mut index = 0;
+        while index < bytes.len() {
+            hash ^= bytes[index] as u64;
+            hash = hash.wrapping_mul(PRIME);
+            index += 1;
+        }
+        hash
+    }
+
+    /// Identifies the type with the given [`TypeId`] by `id`, replacing any previous id of the
+    /// type and any type previously identified by `id`.
+    pub fn insert(&mut self, type_id: TypeId, id: u64) {
+        if let Some(previous_id) = self.ids.insert(type_id, id) {
+            self.type_ids.remove(&previous_id);
+        }
+        if let Some(previous_type_id) = self.type_ids.insert(id, type_id) {
+            if previous_type_id != type_id {
+                self.ids.remove(&previous_type_id);
+            }
+        }
+    }
+
+    /// Returns the id of the type with the given [`TypeId`], if any.
+    pub fn id_of(&self, type_id: TypeId) -> Option<u64> {
+        self.ids.get(&type_id).copied()
+    }
+
+    /// Returns the [`TypeId`] of the type identified by `id`, if any.
+    pub fn type_id_of(&self, id: u64) -> Option<TypeId> {
+        self.type_ids.get(&id).copied()
+    }
+
+    /// Returns the number of identified types.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns true if no types are identified.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    #[test]
+    fn should_hash_type_paths() {
+        // Reference values of the 64-bit FNV-1a hash.
+        assert_eq!(CompactTypeIds::hash_type_path(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(CompactTypeIds::hash_type_path("a"), 0xaf63_dc4c_8601_ec8c);
+
+        let registry = TypeRegistry::new();
+        let table = CompactTypeIds::hashed(&registry);
+        assert_eq!(table.len(), registry.iter().count());
+        let id = CompactTypeIds::hash_type_path("alloc::string::String");
+        assert_eq!(table.id_of(TypeId::of::<String>()), Some(id));
+        assert_eq!(table.type_id_of(id), Some(TypeId::of::<String>()));
+    }
+
+    #[test]
+    fn should_number_type_paths() {
+        let registry = TypeRegistry::new();
+        let table = CompactTypeIds::from_type_paths(
+            &registry,
+            ["u8", "my_crate::Unknown", "alloc::string::String"],
+        );
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.id_of(TypeId::of::<u8>()), Some(0));
+        assert_eq!(table.id_of(TypeId::of::<String>()), Some(2));
+        assert_eq!(table.type_id_of(1), None);
+    }
+
+    #[test]
+    fn should_replace_ids() {
+        let mut table = CompactTypeIds::default();
+        table.insert(TypeId::of::<u8>(), 1);
+        table.insert(TypeId::of::<u16>(), 1);
+        assert_eq!(table.id_of(TypeId::of::<u8>()), None);
+        assert_eq!(table.type_id_of(1), Some(TypeId::of::<u16>()));
+
+        table.insert(TypeId::of::<u16>(), 2);
+        assert_eq!(table.type_id_of(1), None);
+        assert_eq!(table.len(), 1);
+    }
+}
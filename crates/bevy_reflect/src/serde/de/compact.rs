@@ -0,0 +1,158 @@
+use crate::{
+    serde::{de::error_utils::make_custom_error, CompactTypeIds, TypedReflectDeserializer},
+    PartialReflect, TypeRegistry,
+};
+use alloc::boxed::Box;
+use core::{fmt, fmt::Formatter};
+use serde::de::{DeserializeSeed, Error, SeqAccess, Visitor};
+
+use super::ReflectDeserializerProcessor;
+
+/// A general purpose deserializer for reflected types, reading the compact output of the
+/// [`CompactReflectSerializer`].
+///
+/// The type of the value is found from the numeric id written along with it, using the given
+/// [`CompactTypeIds`], which must match the one used for serialization.
+///
+/// As with the [`ReflectDeserializer`], the output is a `Box<dyn PartialReflect>`,
+/// which usually holds a dynamic representation of the value.
+///
+/// If you want to override deserialization for a specific [`TypeRegistration`],
+/// you can pass in a reference to a [`ReflectDeserializerProcessor`] which will
+/// take priority over all other deserialization methods - see [`with_processor`].
+///
+/// # Example
+///
+/// ```
+/// # use bincode::Options;
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::{CompactReflectDeserializer, CompactReflectSerializer, CompactTypeIds}};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// #[type_path = "my_crate"]
+/// struct MyStruct {
+///   value: i32
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<MyStruct>();
+/// let type_ids = CompactTypeIds::from_type_paths(&registry, ["my_crate::MyStruct"]);
+///
+/// let input = MyStruct { value: 123 };
+/// let bytes = bincode::serialize(&CompactReflectSerializer::new(&input, &registry, &type_ids)).unwrap();
+///
+/// let reflect_deserializer = CompactReflectDeserializer::new(&registry, &type_ids);
+/// let output: Box<dyn PartialReflect> = bincode::DefaultOptions::new()
+///     .with_fixint_encoding()
+///     .deserialize_seed(reflect_deserializer, &bytes)
+///     .unwrap();
+///
+/// let output = <MyStruct as FromReflect>::from_reflect(output.as_ref()).unwrap();
+/// assert_eq!(output, MyStruct { value: 123 });
+/// ```
+///
+/// [`CompactReflectSerializer`]: crate::serde::CompactReflectSerializer
+/// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
+/// [`TypeRegistration`]: crate::TypeRegistration
+/// [`with_processor`]: Self::with_processor
+pub struct CompactReflectDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
+    registry: &'a TypeRegistry,
+    type_ids: &'a CompactTypeIds,
+    processor: Option<&'a mut P>,
+}
+
+impl<'a> CompactReflectDeserializer<'a, ()> {
+    /// Creates a deserializer with no processor.
+    ///
+    /// If you want to add custom logic for deserializing certain types, use
+    /// [`with_processor`].
+    ///
+    /// [`with_processor`]: Self::with_processor
+    pub fn new(registry: &'a TypeRegistry, type_ids: &'a CompactTypeIds) -> Self {
+        Self {
+            registry,
+            type_ids,
+            processor: None,
+        }
+    }
+}
+
+impl<'a, P: ReflectDeserializerProcessor> CompactReflectDeserializer<'a, P> {
+    /// Creates a deserializer with a processor.
+    ///
+    /// If you do not need any custom logic for handling certain types, use
+    /// [`new`].
+    ///
+    /// [`new`]: Self::new
+    pub fn with_processor(
+        registry: &'a TypeRegistry,
+        type_ids: &'a CompactTypeIds,
+        processor: &'a mut P,
+    ) -> Self {
+        Self {
+            registry,
+            type_ids,
+            processor: Some(processor),
+        }
+    }
+}
+
+impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
+    for CompactReflectDeserializer<'_, P>
+{
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct CompactReflectDeserializerVisitor<'a, P> {
+            registry: &'a TypeRegistry,
+            type_ids: &'a CompactTypeIds,
+            processor: Option<&'a mut P>,
+        }
+
+        impl<'de, P: ReflectDeserializerProcessor> Visitor<'de>
+            for CompactReflectDeserializerVisitor<'_, P>
+        {
+            type Value = Box<dyn PartialReflect>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("tuple containing the type id and the reflected value")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let id: u64 = seq
+                    .next_element()?
+                    .ok_or_else(|| Error::invalid_length(0, &self))?;
+                let registration = self
+                    .type_ids
+                    .type_id_of(id)
+                    .and_then(|type_id| self.registry.get(type_id))
+                    .ok_or_else(|| {
+                        make_custom_error(format_args!(
+                            "no registration found for compact type id {id}"
+                        ))
+                    })?;
+
+                seq.next_element_seed(TypedReflectDeserializer::new_internal(
+                    registration,
+                    self.registry,
+                    self.processor,
+                ))?
+                .ok_or_else(|| Error::invalid_length(1, &"a type id and a value"))
+            }
+        }
+
+        deserializer.deserialize_tuple(
+            2,
+            CompactReflectDeserializerVisitor {
+                registry: self.registry,
+                type_ids: self.type_ids,
+                processor: self.processor,
+            },
+        )
+    }
+}
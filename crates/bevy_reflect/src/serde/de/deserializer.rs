@@ -1,12 +1,13 @@
 #[cfg(feature = "debug_stack")]
 use crate::serde::de::error_utils::TYPE_INFO_STACK;
-use crate::serde::{ReflectDeserializeWithRegistry, SerializationData};
+use crate::serde::{ReflectDeserializeWithRegistry, ReflectVersion, SerializationData};
 use crate::{
     serde::{
         de::{
             arrays::ArrayVisitor, enums::EnumVisitor, error_utils::make_custom_error,
             lists::ListVisitor, maps::MapVisitor, options::OptionVisitor, sets::SetVisitor,
             structs::StructVisitor, tuple_structs::TupleStructVisitor, tuples::TupleVisitor,
+            versioned::VersionedVisitor,
         },
         TypeRegistrationDeserializer,
     },
@@ -135,6 +136,14 @@ impl<'a, P: ReflectDeserializerProcessor> ReflectDeserializer<'a, P> {
             processor: Some(processor),
         }
     }
+
+    /// An internal constructor for creating a deserializer with an optional processor.
+    pub(super) fn new_internal(registry: &'a TypeRegistry, processor: Option<&'a mut P>) -> Self {
+        Self {
+            registry,
+            processor,
+        }
+    }
 }
 
 impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for ReflectDeserializer<'_, P> {
@@ -335,7 +344,7 @@ impl<'a, P: ReflectDeserializerProcessor> TypedReflectDeserializer<'a, P> {
     }
 
     /// An internal constructor for creating a deserializer without resetting the type info stack.
-    pub(super) fn new_internal(
+    pub(in crate::serde) fn new_internal(
         registration: &'a TypeRegistration,
         registry: &'a TypeRegistry,
         processor: Option<&'a mut P>,
@@ -353,179 +362,221 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
 {
     type Value = Box<dyn PartialReflect>;
 
-    fn deserialize<D>(mut self, deserializer: D) -> Result<Self::Value, D::Error>
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.with_borrow_mut(|stack| stack.push(self.registration.type_info()));
+
+        let output = self.deserialize_internal(deserializer);
+
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.with_borrow_mut(crate::type_info_stack::TypeInfoStack::pop);
+
+        output
+    }
+}
+
+impl<'a, P: ReflectDeserializerProcessor> TypedReflectDeserializer<'a, P> {
+    fn deserialize_internal<'de, D>(
+        mut self,
+        deserializer: D,
+    ) -> Result<Box<dyn PartialReflect>, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let deserialize_internal = || -> Result<Self::Value, D::Error> {
-            // First, check if our processor wants to deserialize this type
-            // This takes priority over any other deserialization operations
-            let deserializer = if let Some(processor) = self.processor.as_deref_mut() {
-                match processor.try_deserialize(self.registration, self.registry, deserializer) {
-                    Ok(Ok(value)) => {
-                        return Ok(value);
-                    }
-                    Err(err) => {
-                        return Err(make_custom_error(err));
-                    }
-                    Ok(Err(deserializer)) => deserializer,
+        // First, check if our processor wants to deserialize this type
+        // This takes priority over any other deserialization operations
+        let deserializer = if let Some(processor) = self.processor.as_deref_mut() {
+            match processor.try_deserialize(self.registration, self.registry, deserializer) {
+                Ok(Ok(value)) => {
+                    return Ok(value);
+                }
+                Err(err) => {
+                    return Err(make_custom_error(err));
                 }
+                Ok(Err(deserializer)) => deserializer,
+            }
+        } else {
+            deserializer
+        };
+
+        // Versioned types are serialized along with their version,
+        // which determines whether the data needs to be migrated
+        if let Some(reflect_version) = self.registration.data::<ReflectVersion>() {
+            let visitor = VersionedVisitor {
+                reflect_version,
+                registration: self.registration,
+                registry: self.registry,
+                deserializer: self,
+            };
+            // Human-readable formats can tell data serialized before the type was versioned
+            // from the versioned tuple
+            return if deserializer.is_human_readable() {
+                deserializer.deserialize_any(visitor)
             } else {
-                deserializer
+                deserializer.deserialize_tuple(2, visitor)
             };
+        }
 
-            let type_path = self.registration.type_info().type_path();
+        self.deserialize_unversioned(deserializer)
+    }
 
-            // Handle both Value case and types that have a custom `ReflectDeserialize`
-            if let Some(deserialize_reflect) = self.registration.data::<ReflectDeserialize>() {
-                let value = deserialize_reflect.deserialize(deserializer)?;
-                return Ok(value.into_partial_reflect());
-            }
+    /// Returns the processor of the deserializer, if any.
+    pub(super) fn into_processor(self) -> Option<&'a mut P> {
+        self.processor
+    }
 
-            if let Some(deserialize_reflect) =
-                self.registration.data::<ReflectDeserializeWithRegistry>()
-            {
-                let value = deserialize_reflect.deserialize(deserializer, self.registry)?;
-                return Ok(value);
-            }
+    /// Deserializes the data of the current version of the type, without its version.
+    pub(super) fn deserialize_unversioned<'de, D>(
+        self,
+        deserializer: D,
+    ) -> Result<Box<dyn PartialReflect>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let type_path = self.registration.type_info().type_path();
+
+        // Handle both Value case and types that have a custom `ReflectDeserialize`
+        if let Some(deserialize_reflect) = self.registration.data::<ReflectDeserialize>() {
+            let value = deserialize_reflect.deserialize(deserializer)?;
+            return Ok(value.into_partial_reflect());
+        }
+
+        if let Some(deserialize_reflect) =
+            self.registration.data::<ReflectDeserializeWithRegistry>()
+        {
+            let value = deserialize_reflect.deserialize(deserializer, self.registry)?;
+            return Ok(value);
+        }
 
-            match self.registration.type_info() {
-                TypeInfo::Struct(struct_info) => {
-                    let mut dynamic_struct = deserializer.deserialize_struct(
-                        struct_info.type_path_table().ident().unwrap(),
-                        struct_info.field_names(),
-                        StructVisitor {
-                            struct_info,
+        match self.registration.type_info() {
+            TypeInfo::Struct(struct_info) => {
+                let mut dynamic_struct = deserializer.deserialize_struct(
+                    struct_info.type_path_table().ident().unwrap(),
+                    struct_info.field_names(),
+                    StructVisitor {
+                        struct_info,
+                        registration: self.registration,
+                        registry: self.registry,
+                        processor: self.processor,
+                    },
+                )?;
+                dynamic_struct.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_struct))
+            }
+            TypeInfo::TupleStruct(tuple_struct_info) => {
+                let mut dynamic_tuple_struct = if tuple_struct_info.field_len() == 1
+                    && self.registration.data::<SerializationData>().is_none()
+                {
+                    deserializer.deserialize_newtype_struct(
+                        tuple_struct_info.type_path_table().ident().unwrap(),
+                        TupleStructVisitor {
+                            tuple_struct_info,
                             registration: self.registration,
                             registry: self.registry,
                             processor: self.processor,
                         },
-                    )?;
-                    dynamic_struct.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_struct))
-                }
-                TypeInfo::TupleStruct(tuple_struct_info) => {
-                    let mut dynamic_tuple_struct = if tuple_struct_info.field_len() == 1
-                        && self.registration.data::<SerializationData>().is_none()
-                    {
-                        deserializer.deserialize_newtype_struct(
-                            tuple_struct_info.type_path_table().ident().unwrap(),
-                            TupleStructVisitor {
-                                tuple_struct_info,
-                                registration: self.registration,
-                                registry: self.registry,
-                                processor: self.processor,
-                            },
-                        )?
-                    } else {
-                        deserializer.deserialize_tuple_struct(
-                            tuple_struct_info.type_path_table().ident().unwrap(),
-                            tuple_struct_info.field_len(),
-                            TupleStructVisitor {
-                                tuple_struct_info,
-                                registration: self.registration,
-                                registry: self.registry,
-                                processor: self.processor,
-                            },
-                        )?
-                    };
-                    dynamic_tuple_struct.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_tuple_struct))
-                }
-                TypeInfo::List(list_info) => {
-                    let mut dynamic_list = deserializer.deserialize_seq(ListVisitor {
-                        list_info,
-                        registry: self.registry,
-                        processor: self.processor,
-                    })?;
-                    dynamic_list.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_list))
-                }
-                TypeInfo::Array(array_info) => {
-                    let mut dynamic_array = deserializer.deserialize_tuple(
-                        array_info.capacity(),
-                        ArrayVisitor {
-                            array_info,
+                    )?
+                } else {
+                    deserializer.deserialize_tuple_struct(
+                        tuple_struct_info.type_path_table().ident().unwrap(),
+                        tuple_struct_info.field_len(),
+                        TupleStructVisitor {
+                            tuple_struct_info,
+                            registration: self.registration,
                             registry: self.registry,
                             processor: self.processor,
                         },
-                    )?;
-                    dynamic_array.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_array))
-                }
-                TypeInfo::Map(map_info) => {
-                    let mut dynamic_map = deserializer.deserialize_map(MapVisitor {
-                        map_info,
+                    )?
+                };
+                dynamic_tuple_struct.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_tuple_struct))
+            }
+            TypeInfo::List(list_info) => {
+                let mut dynamic_list = deserializer.deserialize_seq(ListVisitor {
+                    list_info,
+                    registry: self.registry,
+                    processor: self.processor,
+                })?;
+                dynamic_list.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_list))
+            }
+            TypeInfo::Array(array_info) => {
+                let mut dynamic_array = deserializer.deserialize_tuple(
+                    array_info.capacity(),
+                    ArrayVisitor {
+                        array_info,
                         registry: self.registry,
                         processor: self.processor,
-                    })?;
-                    dynamic_map.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_map))
-                }
-                TypeInfo::Set(set_info) => {
-                    let mut dynamic_set = deserializer.deserialize_seq(SetVisitor {
-                        set_info,
+                    },
+                )?;
+                dynamic_array.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_array))
+            }
+            TypeInfo::Map(map_info) => {
+                let mut dynamic_map = deserializer.deserialize_map(MapVisitor {
+                    map_info,
+                    registry: self.registry,
+                    processor: self.processor,
+                })?;
+                dynamic_map.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_map))
+            }
+            TypeInfo::Set(set_info) => {
+                let mut dynamic_set = deserializer.deserialize_seq(SetVisitor {
+                    set_info,
+                    registry: self.registry,
+                    processor: self.processor,
+                })?;
+                dynamic_set.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_set))
+            }
+            TypeInfo::Tuple(tuple_info) => {
+                let mut dynamic_tuple = deserializer.deserialize_tuple(
+                    tuple_info.field_len(),
+                    TupleVisitor {
+                        tuple_info,
+                        registration: self.registration,
                         registry: self.registry,
                         processor: self.processor,
-                    })?;
-                    dynamic_set.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_set))
-                }
-                TypeInfo::Tuple(tuple_info) => {
-                    let mut dynamic_tuple = deserializer.deserialize_tuple(
-                        tuple_info.field_len(),
-                        TupleVisitor {
-                            tuple_info,
+                    },
+                )?;
+                dynamic_tuple.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_tuple))
+            }
+            TypeInfo::Enum(enum_info) => {
+                let mut dynamic_enum = if enum_info.type_path_table().module_path()
+                    == Some("core::option")
+                    && enum_info.type_path_table().ident() == Some("Option")
+                {
+                    deserializer.deserialize_option(OptionVisitor {
+                        enum_info,
+                        registry: self.registry,
+                        processor: self.processor,
+                    })?
+                } else {
+                    deserializer.deserialize_enum(
+                        enum_info.type_path_table().ident().unwrap(),
+                        enum_info.variant_names(),
+                        EnumVisitor {
+                            enum_info,
                             registration: self.registration,
                             registry: self.registry,
                             processor: self.processor,
                         },
-                    )?;
-                    dynamic_tuple.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_tuple))
-                }
-                TypeInfo::Enum(enum_info) => {
-                    let mut dynamic_enum = if enum_info.type_path_table().module_path()
-                        == Some("core::option")
-                        && enum_info.type_path_table().ident() == Some("Option")
-                    {
-                        deserializer.deserialize_option(OptionVisitor {
-                            enum_info,
-                            registry: self.registry,
-                            processor: self.processor,
-                        })?
-                    } else {
-                        deserializer.deserialize_enum(
-                            enum_info.type_path_table().ident().unwrap(),
-                            enum_info.variant_names(),
-                            EnumVisitor {
-                                enum_info,
-                                registration: self.registration,
-                                registry: self.registry,
-                                processor: self.processor,
-                            },
-                        )?
-                    };
-                    dynamic_enum.set_represented_type(Some(self.registration.type_info()));
-                    Ok(Box::new(dynamic_enum))
-                }
-                TypeInfo::Opaque(_) => {
-                    // This case should already be handled
-                    Err(make_custom_error(format_args!(
-                        "type `{type_path}` did not register the `ReflectDeserialize` type data. For certain types, this may need to be registered manually using `register_type_data`",
-                    )))
-                }
+                    )?
+                };
+                dynamic_enum.set_represented_type(Some(self.registration.type_info()));
+                Ok(Box::new(dynamic_enum))
             }
-        };
-
-        #[cfg(feature = "debug_stack")]
-        TYPE_INFO_STACK.with_borrow_mut(|stack| stack.push(self.registration.type_info()));
-
-        let output = deserialize_internal();
-
-        #[cfg(feature = "debug_stack")]
-        TYPE_INFO_STACK.with_borrow_mut(crate::type_info_stack::TypeInfoStack::pop);
-
-        output
+            TypeInfo::Opaque(_) => {
+                // This case should already be handled
+                Err(make_custom_error(format_args!(
+                    "type `{type_path}` did not register the `ReflectDeserialize` type data. For certain types, this may need to be registered manually using `register_type_data`",
+                )))
+            }
+        }
     }
 }
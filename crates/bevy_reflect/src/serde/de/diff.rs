@@ -0,0 +1,463 @@
+use crate::{
+    serde::{ReflectDeserializer, ReflectDeserializerProcessor},
+    PartialReflect, ReflectDiff, TypeRegistry,
+};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{fmt, fmt::Formatter, marker::PhantomData};
+use serde::de::{
+    Deserialize, DeserializeSeed, Deserializer, EnumAccess, Error, SeqAccess, VariantAccess,
+    Visitor,
+};
+
+/// A deserializer for [`ReflectDiff`].
+///
+/// This is the deserializer counterpart to [`ReflectDiffSerializer`].
+///
+/// # Output
+///
+/// The values contained in the diff, such as replaced values or inserted map entries, are
+/// deserialized with [`ReflectDeserializer`]. This means that they will usually contain the
+/// dynamic equivalent of their type, which [`apply_diff`] handles like the concrete type.
+///
+/// If you want to override deserialization for a specific [`TypeRegistration`],
+/// you can pass in a reference to a [`ReflectDeserializerProcessor`] which will
+/// take priority over all other deserialization methods - see [`with_processor`].
+///
+/// # Example
+///
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{apply_diff, TypeRegistry, serde::ReflectDiffDeserializer};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct MyStruct {
+///   value: i32,
+///   other: i32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<MyStruct>();
+///
+/// let input = r#"Struct([("value", Replaced({"i32": 5}))])"#;
+///
+/// let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+/// let diff = ReflectDiffDeserializer::new(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+///
+/// let mut value = MyStruct { value: 1, other: 2 };
+/// apply_diff(&mut value, &diff).unwrap();
+/// assert_eq!(value, MyStruct { value: 5, other: 2 });
+/// ```
+///
+/// [`ReflectDiffSerializer`]: crate::serde::ReflectDiffSerializer
+/// [`apply_diff`]: crate::apply_diff
+/// [`TypeRegistration`]: crate::TypeRegistration
+/// [`with_processor`]: Self::with_processor
+pub struct ReflectDiffDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+}
+
+impl<'a> ReflectDiffDeserializer<'a, ()> {
+    /// Creates a deserializer with no processor.
+    ///
+    /// If you want to add custom logic for deserializing certain types, use
+    /// [`with_processor`].
+    ///
+    /// [`with_processor`]: Self::with_processor
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            processor: None,
+        }
+    }
+}
+
+impl<'a, P: ReflectDeserializerProcessor> ReflectDiffDeserializer<'a, P> {
+    /// Creates a deserializer with a processor.
+    ///
+    /// If you do not need any custom logic for handling certain types, use
+    /// [`new`].
+    ///
+    /// [`new`]: Self::new
+    pub fn with_processor(registry: &'a TypeRegistry, processor: &'a mut P) -> Self {
+        Self {
+            registry,
+            processor: Some(processor),
+        }
+    }
+
+    fn reborrow(&mut self) -> ReflectDiffDeserializer<'_, P> {
+        ReflectDiffDeserializer {
+            registry: self.registry,
+            processor: self.processor.as_deref_mut(),
+        }
+    }
+
+    fn seed<T: Part<P>>(&mut self) -> PartSeed<'_, P, T> {
+        PartSeed {
+            de: self.reborrow(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for ReflectDiffDeserializer<'_, P> {
+    type Value = ReflectDiff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("ReflectDiff", DiffVariant::NAMES, DiffVisitor(self))
+    }
+}
+
+struct DiffVisitor<'a, P: ReflectDeserializerProcessor>(ReflectDiffDeserializer<'a, P>);
+
+impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for DiffVisitor<'_, P> {
+    type Value = ReflectDiff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected diff")
+    }
+
+    fn visit_enum<A>(mut self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        type Values = Vec<Box<dyn PartialReflect>>;
+        type IndexedDiffs = Vec<(usize, ReflectDiff)>;
+
+        let (variant, access) = data.variant::<DiffVariant>()?;
+        let de = &mut self.0;
+        Ok(match variant {
+            DiffVariant::Unchanged => {
+                access.unit_variant()?;
+                ReflectDiff::Unchanged
+            }
+            DiffVariant::Replaced => ReflectDiff::Replaced(
+                access.newtype_variant_seed(de.seed::<Box<dyn PartialReflect>>())?,
+            ),
+            DiffVariant::Struct => ReflectDiff::Struct(
+                access.newtype_variant_seed(de.seed::<Vec<(String, ReflectDiff)>>())?,
+            ),
+            DiffVariant::Tuple => {
+                ReflectDiff::Tuple(access.newtype_variant_seed(de.seed::<IndexedDiffs>())?)
+            }
+            DiffVariant::List => {
+                let (len, changed, appended) = access.tuple_variant(
+                    3,
+                    TupleVisitor::<P, (usize, IndexedDiffs, Values)>::new(de.reborrow()),
+                )?;
+                ReflectDiff::List {
+                    len,
+                    changed,
+                    appended,
+                }
+            }
+            DiffVariant::Map => {
+                type Changed = Vec<(Box<dyn PartialReflect>, ReflectDiff)>;
+                type Inserted = Vec<(Box<dyn PartialReflect>, Box<dyn PartialReflect>)>;
+                let (changed, inserted, removed) = access.tuple_variant(
+                    3,
+                    TupleVisitor::<P, (Changed, Inserted, Values)>::new(de.reborrow()),
+                )?;
+                ReflectDiff::Map {
+                    changed,
+                    inserted,
+                    removed,
+                }
+            }
+            DiffVariant::Set => {
+                let (inserted, removed) = access
+                    .tuple_variant(2, TupleVisitor::<P, (Values, Values)>::new(de.reborrow()))?;
+                ReflectDiff::Set { inserted, removed }
+            }
+        })
+    }
+}
+
+enum DiffVariant {
+    Unchanged,
+    Replaced,
+    Struct,
+    Tuple,
+    List,
+    Map,
+    Set,
+}
+
+impl DiffVariant {
+    const NAMES: &'static [&'static str] = &[
+        "Unchanged",
+        "Replaced",
+        "Struct",
+        "Tuple",
+        "List",
+        "Map",
+        "Set",
+    ];
+
+    fn from_index(index: u64) -> Option<Self> {
+        Some(match index {
+            0 => Self::Unchanged,
+            1 => Self::Replaced,
+            2 => Self::Struct,
+            3 => Self::Tuple,
+            4 => Self::List,
+            5 => Self::Map,
+            6 => Self::Set,
+            _ => return None,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for DiffVariant {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct DiffVariantVisitor;
+
+        impl<'de> Visitor<'de> for DiffVariantVisitor {
+            type Value = DiffVariant;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("reflected diff variant")
+            }
+
+            fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+                DiffVariant::from_index(value).ok_or_else(|| {
+                    Error::invalid_value(serde::de::Unexpected::Unsigned(value), &self)
+                })
+            }
+
+            fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+                DiffVariant::NAMES
+                    .iter()
+                    .position(|name| *name == value)
+                    .and_then(|index| DiffVariant::from_index(index as u64))
+                    .ok_or_else(|| Error::unknown_variant(value, DiffVariant::NAMES))
+            }
+        }
+
+        deserializer.deserialize_identifier(DiffVariantVisitor)
+    }
+}
+
+/// A part of a serialized [`ReflectDiff`], deserialized into `Self::Value`.
+///
+/// This lets the nested sequences and tuples of a diff share the processor.
+trait Part<P: ReflectDeserializerProcessor> {
+    type Value;
+
+    fn deserialize<'de, D>(
+        de: ReflectDiffDeserializer<'_, P>,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+struct PartSeed<'a, P: ReflectDeserializerProcessor, T: ?Sized> {
+    de: ReflectDiffDeserializer<'a, P>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<'de, P: ReflectDeserializerProcessor, T: Part<P> + ?Sized> DeserializeSeed<'de>
+    for PartSeed<'_, P, T>
+{
+    type Value = T::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(self.de, deserializer)
+    }
+}
+
+impl<P: ReflectDeserializerProcessor> Part<P> for ReflectDiff {
+    type Value = ReflectDiff;
+
+    fn deserialize<'de, D>(
+        de: ReflectDiffDeserializer<'_, P>,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        DeserializeSeed::deserialize(de, deserializer)
+    }
+}
+
+impl<P: ReflectDeserializerProcessor> Part<P> for Box<dyn PartialReflect> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<'de, D>(
+        de: ReflectDiffDeserializer<'_, P>,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ReflectDeserializer::new_internal(de.registry, de.processor).deserialize(deserializer)
+    }
+}
+
+macro_rules! impl_plain_part {
+    ($($ty:ty),*) => {
+        $(
+            impl<P: ReflectDeserializerProcessor> Part<P> for $ty {
+                type Value = $ty;
+
+                fn deserialize<'de, D>(
+                    _de: ReflectDiffDeserializer<'_, P>,
+                    deserializer: D,
+                ) -> Result<Self::Value, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    <$ty as Deserialize>::deserialize(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_plain_part!(usize, String);
+
+impl<P: ReflectDeserializerProcessor, T: Part<P>> Part<P> for Vec<T> {
+    type Value = Vec<T::Value>;
+
+    fn deserialize<'de, D>(
+        de: ReflectDiffDeserializer<'_, P>,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SeqVisitor<'a, P: ReflectDeserializerProcessor, T> {
+            de: ReflectDiffDeserializer<'a, P>,
+            marker: PhantomData<fn() -> T>,
+        }
+
+        impl<'de, P: ReflectDeserializerProcessor, T: Part<P>> Visitor<'de> for SeqVisitor<'_, P, T> {
+            type Value = Vec<T::Value>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("sequence")
+            }
+
+            fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+                while let Some(value) = seq.next_element_seed(self.de.seed::<T>())? {
+                    values.push(value);
+                }
+                Ok(values)
+            }
+        }
+
+        deserializer.deserialize_seq(SeqVisitor::<P, T> {
+            de,
+            marker: PhantomData,
+        })
+    }
+}
+
+/// A tuple of [parts](Part), deserialized from a sequence.
+trait TuplePart<P: ReflectDeserializerProcessor>: Part<P> {
+    const LEN: usize;
+
+    fn visit_seq<'de, A>(
+        de: ReflectDiffDeserializer<'_, P>,
+        seq: A,
+    ) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>;
+}
+
+struct TupleVisitor<'a, P: ReflectDeserializerProcessor, T> {
+    de: ReflectDiffDeserializer<'a, P>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, P: ReflectDeserializerProcessor, T> TupleVisitor<'a, P, T> {
+    fn new(de: ReflectDiffDeserializer<'a, P>) -> Self {
+        Self {
+            de,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, P: ReflectDeserializerProcessor, T: TuplePart<P>> Visitor<'de>
+    for TupleVisitor<'_, P, T>
+{
+    type Value = T::Value;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "tuple of length {}", T::LEN)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        T::visit_seq(self.de, seq)
+    }
+}
+
+fn next_element<'de, P, T, A>(
+    de: &mut ReflectDiffDeserializer<'_, P>,
+    seq: &mut A,
+    index: usize,
+) -> Result<T::Value, A::Error>
+where
+    P: ReflectDeserializerProcessor,
+    T: Part<P>,
+    A: SeqAccess<'de>,
+{
+    seq.next_element_seed(de.seed::<T>())?
+        .ok_or_else(|| Error::invalid_length(index, &"a longer tuple"))
+}
+
+macro_rules! impl_tuple_part {
+    ($len:literal: $($name:ident $index:tt),*) => {
+        impl<P: ReflectDeserializerProcessor, $($name: Part<P>),*> Part<P> for ($($name,)*) {
+            type Value = ($($name::Value,)*);
+
+            fn deserialize<'de, D>(
+                de: ReflectDiffDeserializer<'_, P>,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_tuple($len, TupleVisitor::<P, Self>::new(de))
+            }
+        }
+
+        impl<P: ReflectDeserializerProcessor, $($name: Part<P>),*> TuplePart<P> for ($($name,)*) {
+            const LEN: usize = $len;
+
+            fn visit_seq<'de, A>(
+                mut de: ReflectDiffDeserializer<'_, P>,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(($(next_element::<P, $name, A>(&mut de, &mut seq, $index)?,)*))
+            }
+        }
+    };
+}
+
+impl_tuple_part!(2: T0 0, T1 1);
+impl_tuple_part!(3: T0 0, T1 1, T2 2);
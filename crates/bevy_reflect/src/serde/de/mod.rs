@@ -1,11 +1,15 @@
+pub use compact::*;
 pub use deserialize_with_registry::*;
 pub use deserializer::*;
+pub use diff::*;
 pub use processor::*;
 pub use registrations::*;
 
 mod arrays;
+mod compact;
 mod deserialize_with_registry;
 mod deserializer;
+mod diff;
 mod enums;
 mod error_utils;
 mod helpers;
@@ -21,6 +25,7 @@ mod structs;
 mod tuple_structs;
 mod tuple_utils;
 mod tuples;
+mod versioned;
 
 #[cfg(test)]
 mod tests {
@@ -35,6 +35,18 @@ use alloc::boxed::Box;
 /// [`DeserializeWithRegistry`] implementation, so this is also useful for
 /// overriding deserialization behavior if you need to do something custom.
 ///
+/// # Versioned types
+///
+/// Types registering [`ReflectVersion`] are serialized along with their
+/// version, and data serialized by older versions of such types is loaded
+/// through the migrations registered on their [`ReflectVersion`].
+///
+/// The processor is called before the version is read, so a processor
+/// overriding the deserialization of a versioned type is given the whole
+/// `(version, value)` tuple, and is responsible for migrating older data
+/// itself. Giving the deserializer back lets the default logic read the
+/// version and run the appropriate migration.
+///
 /// # Examples
 ///
 /// Deserializing a reflected value in an asset loader, and replacing asset
@@ -138,6 +150,7 @@ use alloc::boxed::Box;
 /// [`try_deserialize`]: Self::try_deserialize
 /// [`DeserializeWithRegistry`]: crate::serde::DeserializeWithRegistry
 /// [`ReflectSerializerProcessor`]: crate::serde::ReflectSerializerProcessor
+/// [`ReflectVersion`]: crate::serde::ReflectVersion
 pub trait ReflectDeserializerProcessor {
     /// Attempts to deserialize the value which a [`TypedReflectDeserializer`]
     /// is currently looking at, and knows the type of.
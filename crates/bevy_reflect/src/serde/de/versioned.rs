@@ -0,0 +1,192 @@
+use crate::{
+    serde::{de::error_utils::make_custom_error, ReflectVersion, TypedReflectDeserializer},
+    PartialReflect, TypeRegistration, TypeRegistry,
+};
+use alloc::{boxed::Box, string::String};
+use core::{fmt, fmt::Formatter};
+use serde::de::{
+    value::{BorrowedStrDeserializer, EnumAccessDeserializer, MapAccessDeserializer},
+    DeserializeSeed, EnumAccess, Error, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+
+use super::ReflectDeserializerProcessor;
+
+/// A [`Visitor`] for deserializing the `(version, value)` tuple of types registering
+/// [`ReflectVersion`].
+///
+/// In human-readable formats, any other value is data serialized before the type was versioned,
+/// which is loaded as its [base version](ReflectVersion::BASE_VERSION).
+pub(super) struct VersionedVisitor<'a, P: ReflectDeserializerProcessor> {
+    pub reflect_version: &'a ReflectVersion,
+    pub registration: &'a TypeRegistration,
+    pub registry: &'a TypeRegistry,
+    pub deserializer: TypedReflectDeserializer<'a, P>,
+}
+
+impl<'a, P: ReflectDeserializerProcessor> VersionedVisitor<'a, P> {
+    /// Deserializes data serialized by the given version of the type.
+    fn deserialize_version<'de, D>(
+        self,
+        version: u32,
+        deserializer: D,
+    ) -> Result<Box<dyn PartialReflect>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        MigrationDeserializer {
+            reflect_version: self.reflect_version,
+            version,
+            registry: self.registry,
+            deserializer: self.deserializer,
+        }
+        .deserialize(deserializer)
+    }
+
+    /// Deserializes data serialized before the type was versioned.
+    fn deserialize_base<'de, D>(self, deserializer: D) -> Result<Box<dyn PartialReflect>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if self
+            .reflect_version
+            .has_migration(ReflectVersion::BASE_VERSION)
+        {
+            self.deserialize_version(ReflectVersion::BASE_VERSION, deserializer)
+        } else {
+            self.deserializer.deserialize_unversioned(deserializer)
+        }
+    }
+}
+
+impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for VersionedVisitor<'_, P> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("versioned reflected value of type ")?;
+        formatter.write_str(self.registration.type_info().type_path())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let version: u32 = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let current_version = self.reflect_version.version();
+        let type_path = self.registration.type_info().type_path();
+
+        if version > current_version {
+            return Err(make_custom_error(format_args!(
+                "version {version} of type `{type_path}` is newer than its current version {current_version}",
+            )));
+        } else if version < current_version && !self.reflect_version.has_migration(version) {
+            return Err(make_custom_error(format_args!(
+                "no migration registered to load version {version} of type `{type_path}`, whose current version is {current_version}",
+            )));
+        }
+
+        seq.next_element_seed(VersionSeed {
+            visitor: self,
+            version,
+        })?
+        .ok_or_else(|| Error::invalid_length(1, &"a version and a value"))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        self.deserialize_base(MapAccessDeserializer::new(map))
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        self.deserialize_base(EnumAccessDeserializer::new(data))
+    }
+
+    fn visit_bool<E: Error>(self, v: bool) -> Result<Self::Value, E> {
+        self.deserialize_base(v.into_deserializer())
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        self.deserialize_base(v.into_deserializer())
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.deserialize_base(v.into_deserializer())
+    }
+
+    fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+        self.deserialize_base(v.into_deserializer())
+    }
+
+    fn visit_char<E: Error>(self, v: char) -> Result<Self::Value, E> {
+        self.deserialize_base(v.into_deserializer())
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        self.deserialize_base(v.into_deserializer())
+    }
+
+    fn visit_borrowed_str<E: Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        self.deserialize_base(BorrowedStrDeserializer::new(v))
+    }
+
+    fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+        self.deserialize_base(v.into_deserializer())
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        self.deserialize_base(().into_deserializer())
+    }
+}
+
+/// Deserializes the value following the version in the `(version, value)` tuple.
+struct VersionSeed<'a, P: ReflectDeserializerProcessor> {
+    visitor: VersionedVisitor<'a, P>,
+    version: u32,
+}
+
+impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for VersionSeed<'_, P> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if self.version == self.visitor.reflect_version.version() {
+            self.visitor
+                .deserializer
+                .deserialize_unversioned(deserializer)
+        } else {
+            self.visitor.deserialize_version(self.version, deserializer)
+        }
+    }
+}
+
+/// Deserializes the data of an older version of a versioned type with its migration.
+struct MigrationDeserializer<'a, P: ReflectDeserializerProcessor> {
+    reflect_version: &'a ReflectVersion,
+    version: u32,
+    registry: &'a TypeRegistry,
+    deserializer: TypedReflectDeserializer<'a, P>,
+}
+
+impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for MigrationDeserializer<'_, P> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.reflect_version.migrate_internal(
+            self.version,
+            deserializer,
+            self.registry,
+            self.deserializer.into_processor(),
+        )
+    }
+}
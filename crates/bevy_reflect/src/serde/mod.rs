@@ -1,10 +1,14 @@
+mod compact;
 mod de;
 mod ser;
 mod type_data;
+mod version;
 
+pub use compact::*;
 pub use de::*;
 pub use ser::*;
 pub use type_data::*;
+pub use version::*;
 
 #[cfg(test)]
 mod tests {
@@ -456,4 +460,452 @@ mod tests {
             assert_serialize(&nested_tuple_struct_with_skip, &registry);
         }
     }
+
+    mod versioning {
+        use super::*;
+        use crate::serde::ReflectVersion;
+        use alloc::{boxed::Box, string::String};
+        use bincode::Options;
+        use core::any::TypeId;
+        use serde::Deserialize;
+
+        #[derive(Reflect, Debug)]
+        struct PlayerV0(u32);
+
+        #[derive(Reflect, Debug)]
+        struct PlayerV1 {
+            hp: u32,
+        }
+
+        #[derive(Reflect, PartialEq, Debug)]
+        #[reflect(version = 2)]
+        struct Player {
+            health: u32,
+        }
+
+        #[derive(Reflect, PartialEq, Debug)]
+        struct Save {
+            name: String,
+            player: Player,
+        }
+
+        fn create_registry() -> TypeRegistry {
+            let mut registry = TypeRegistry::default();
+            registry.register::<Save>();
+            registry.register::<PlayerV1>();
+            registry
+                .get_type_data_mut::<ReflectVersion>(TypeId::of::<Player>())
+                .unwrap()
+                .register_migration(0, |deserializer, _registry| {
+                    let health = u32::deserialize(deserializer)?;
+                    Ok(Box::new(Player { health }))
+                })
+                .register_migration_from(1, |old: PlayerV1| Player { health: old.hp });
+            registry
+        }
+
+        fn deserialize_save(input: &str, registry: &TypeRegistry) -> Result<Save, ron::Error> {
+            let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            let value =
+                TypedReflectDeserializer::of::<Save>(registry).deserialize(&mut deserializer)?;
+            Ok(Save::from_reflect(&*value).unwrap())
+        }
+
+        #[test]
+        fn should_register_version() {
+            let registry = create_registry();
+            let reflect_version = registry
+                .get_type_data::<ReflectVersion>(TypeId::of::<Player>())
+                .unwrap();
+            assert_eq!(reflect_version.version(), 2);
+            assert!(reflect_version.has_migration(0));
+            assert!(reflect_version.has_migration(1));
+            assert!(registry
+                .get_type_data::<ReflectVersion>(TypeId::of::<Save>())
+                .is_none());
+        }
+
+        #[test]
+        fn should_serialize_versioned_value() {
+            let registry = create_registry();
+            let save = Save {
+                name: String::from("Save 1"),
+                player: Player { health: 10 },
+            };
+
+            let serializer = TypedReflectSerializer::new(&save, &registry);
+            let serialized = ron::ser::to_string(&serializer).unwrap();
+            assert_eq!(serialized, r#"(name:"Save 1",player:(2,(health:10)))"#);
+            assert_eq!(deserialize_save(&serialized, &registry).unwrap(), save);
+
+            let serializer = ReflectSerializer::new(&save.player, &registry);
+            let serialized = bincode::serialize(&serializer).unwrap();
+            let value = bincode::DefaultOptions::new()
+                .with_fixint_encoding()
+                .deserialize_seed(ReflectDeserializer::new(&registry), &serialized)
+                .unwrap();
+            assert_eq!(Player::from_reflect(&*value), Some(Player { health: 10 }));
+        }
+
+        #[test]
+        fn should_migrate_older_versions() {
+            let registry = create_registry();
+            let expected = Save {
+                name: String::from("Save 1"),
+                player: Player { health: 10 },
+            };
+
+            let input = r#"(name:"Save 1",player:(0,10))"#;
+            assert_eq!(deserialize_save(input, &registry).unwrap(), expected);
+
+            let input = r#"(name:"Save 1",player:(1,(hp:10)))"#;
+            assert_eq!(deserialize_save(input, &registry).unwrap(), expected);
+        }
+
+        #[test]
+        fn should_error_on_unknown_version() {
+            let mut registry = create_registry();
+            registry.register::<PlayerV0>();
+
+            let input = r#"(name:"Save 1",player:(3,(health:10)))"#;
+            let error = deserialize_save(input, &registry).unwrap_err();
+            #[cfg(feature = "debug_stack")]
+            assert_eq!(
+                error,
+                ron::Error::Message(String::from(
+                    "version 3 of type `bevy_reflect::serde::tests::versioning::Player` is newer than its current version 2 (stack: `bevy_reflect::serde::tests::versioning::Save` -> `bevy_reflect::serde::tests::versioning::Player`)"
+                ))
+            );
+            #[cfg(not(feature = "debug_stack"))]
+            assert_eq!(
+                error,
+                ron::Error::Message(String::from(
+                    "version 3 of type `bevy_reflect::serde::tests::versioning::Player` is newer than its current version 2"
+                ))
+            );
+
+            // Version 1 can't be loaded anymore without its migration.
+            let mut registry = TypeRegistry::default();
+            registry.register::<Save>();
+            let input = r#"(name:"Save 1",player:(1,(hp:10)))"#;
+            let error = deserialize_save(input, &registry).unwrap_err();
+            #[cfg(feature = "debug_stack")]
+            assert_eq!(
+                error,
+                ron::Error::Message(String::from(
+                    "no migration registered to load version 1 of type `bevy_reflect::serde::tests::versioning::Player`, whose current version is 2 (stack: `bevy_reflect::serde::tests::versioning::Save` -> `bevy_reflect::serde::tests::versioning::Player`)"
+                ))
+            );
+            #[cfg(not(feature = "debug_stack"))]
+            assert_eq!(
+                error,
+                ron::Error::Message(String::from(
+                    "no migration registered to load version 1 of type `bevy_reflect::serde::tests::versioning::Player`, whose current version is 2"
+                ))
+            );
+        }
+
+        #[test]
+        fn should_load_unversioned_data_as_base_version() {
+            #[derive(Reflect, PartialEq, Debug)]
+            #[reflect(version = 1)]
+            struct Npc {
+                health: u32,
+            }
+
+            let mut registry = create_registry();
+            registry.register::<Npc>();
+            let expected = Save {
+                name: String::from("Save 1"),
+                player: Player { health: 10 },
+            };
+
+            // Unversioned data is migrated from the base version.
+            let input = r#"(name:"Save 1",player:10)"#;
+            assert_eq!(deserialize_save(input, &registry).unwrap(), expected);
+
+            // Without a migration, unversioned data is loaded as the current version.
+            let value = TypedReflectDeserializer::of::<Npc>(&registry)
+                .deserialize(&mut ron::de::Deserializer::from_str("(health:5)").unwrap())
+                .unwrap();
+            assert_eq!(Npc::from_reflect(&*value), Some(Npc { health: 5 }));
+            let value = TypedReflectDeserializer::of::<Npc>(&registry)
+                .deserialize(&mut serde_json::Deserializer::from_str(r#"{"health":5}"#))
+                .unwrap();
+            assert_eq!(Npc::from_reflect(&*value), Some(Npc { health: 5 }));
+        }
+
+        #[test]
+        fn should_apply_processor_to_migrated_data() {
+            use crate::{serde::ReflectDeserializerProcessor, TypeRegistration};
+
+            /// Doubles every `u32`.
+            struct Doubler;
+
+            impl ReflectDeserializerProcessor for Doubler {
+                fn try_deserialize<'de, D>(
+                    &mut self,
+                    registration: &TypeRegistration,
+                    _registry: &TypeRegistry,
+                    deserializer: D,
+                ) -> Result<Result<Box<dyn PartialReflect>, D>, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    if registration.type_id() != TypeId::of::<u32>() {
+                        return Ok(Err(deserializer));
+                    }
+                    let value = u32::deserialize(deserializer)? * 2;
+                    Ok(Ok(Box::new(value)))
+                }
+            }
+
+            let registry = create_registry();
+            let mut processor = Doubler;
+            let mut deserializer = ron::de::Deserializer::from_str("(1,(hp:10))").unwrap();
+            let value = TypedReflectDeserializer::with_processor(
+                registry.get(TypeId::of::<Player>()).unwrap(),
+                &registry,
+                &mut processor,
+            )
+            .deserialize(&mut deserializer)
+            .unwrap();
+            assert_eq!(Player::from_reflect(&*value), Some(Player { health: 20 }));
+        }
+
+        #[test]
+        #[should_panic(expected = "isn't older than the current version 2")]
+        fn should_panic_on_migration_from_current_version() {
+            ReflectVersion::new(2)
+                .register_migration_from(2, |old: PlayerV1| Player { health: old.hp });
+        }
+    }
+
+    mod compact {
+        use super::*;
+        use crate::{serde::ReflectDeserializerProcessor, TypeRegistration};
+        use alloc::{
+            boxed::Box,
+            string::{String, ToString},
+            vec,
+            vec::Vec,
+        };
+        use bevy_utils::HashMap;
+        use bincode::Options;
+        use core::any::TypeId;
+        use serde::{Deserialize, Serialize, Serializer};
+
+        #[derive(Reflect, PartialEq, Debug)]
+        struct Player {
+            name: String,
+            health: u32,
+            #[reflect(skip_serializing)]
+            cache: u32,
+            target: Option<u32>,
+            items: Vec<Item>,
+            stats: HashMap<String, f32>,
+        }
+
+        #[derive(Reflect, PartialEq, Debug)]
+        enum Item {
+            Empty,
+            Potion(u8),
+            Weapon { damage: u32, range: f32 },
+        }
+
+        fn create_registry() -> TypeRegistry {
+            let mut registry = TypeRegistry::default();
+            registry.register::<Player>();
+            registry
+        }
+
+        fn create_player() -> Player {
+            Player {
+                name: "Alice".to_string(),
+                health: 10,
+                cache: 0,
+                target: Some(3),
+                items: vec![
+                    Item::Empty,
+                    Item::Potion(2),
+                    Item::Weapon {
+                        damage: 5,
+                        range: 1.5,
+                    },
+                ],
+                stats: HashMap::from_iter([("speed".to_string(), 2.0)]),
+            }
+        }
+
+        fn bincode_options() -> impl Options {
+            bincode::DefaultOptions::new()
+        }
+
+        #[test]
+        fn should_roundtrip_with_hashed_ids() {
+            let registry = create_registry();
+            let type_ids = CompactTypeIds::hashed(&registry);
+            let input = create_player();
+
+            let serializer = CompactReflectSerializer::new(&input, &registry, &type_ids);
+            let bytes = bincode_options().serialize(&serializer).unwrap();
+
+            let deserializer = CompactReflectDeserializer::new(&registry, &type_ids);
+            let output = bincode_options()
+                .deserialize_seed(deserializer, &bytes)
+                .unwrap();
+            assert_eq!(Player::from_reflect(&*output).unwrap(), input);
+
+            let serializer = ReflectSerializer::new(&input, &registry);
+            assert!(bincode_options().serialize(&serializer).unwrap().len() > bytes.len());
+        }
+
+        #[test]
+        fn should_roundtrip_with_negotiated_ids() {
+            let registry = create_registry();
+            let mut type_paths = registry
+                .iter()
+                .map(|registration| registration.type_info().type_path())
+                .collect::<Vec<_>>();
+            type_paths.sort_unstable();
+            let type_ids = CompactTypeIds::from_type_paths(&registry, type_paths);
+            let input = create_player();
+
+            let serializer = CompactReflectSerializer::new(&input, &registry, &type_ids);
+            let bytes = bincode_options().serialize(&serializer).unwrap();
+
+            let deserializer = CompactReflectDeserializer::new(&registry, &type_ids);
+            let output = bincode_options()
+                .deserialize_seed(deserializer, &bytes)
+                .unwrap();
+            assert_eq!(Player::from_reflect(&*output).unwrap(), input);
+
+            // Small ids take less space than hashes.
+            let hashed_ids = CompactTypeIds::hashed(&registry);
+            let serializer = CompactReflectSerializer::new(&input, &registry, &hashed_ids);
+            assert!(bincode_options().serialize(&serializer).unwrap().len() > bytes.len());
+        }
+
+        #[test]
+        fn should_omit_field_names() {
+            let registry = create_registry();
+            let type_ids = CompactTypeIds::hashed(&registry);
+            let input = create_player();
+            let contains =
+                |bytes: &[u8], name: &[u8]| bytes.windows(name.len()).any(|window| window == name);
+
+            let serializer = TypedReflectSerializer::new(&input, &registry);
+            let bytes = rmp_serde::to_vec_named(&serializer).unwrap();
+            assert!(contains(&bytes, b"health"));
+            assert!(contains(&bytes, b"damage"));
+
+            let serializer = TypedReflectSerializer::new(&input, &registry).compact();
+            let bytes = rmp_serde::to_vec_named(&serializer).unwrap();
+            assert!(!contains(&bytes, b"health"));
+            assert!(!contains(&bytes, b"damage"));
+
+            // Compact values can be deserialized as usual.
+            let output = TypedReflectDeserializer::of::<Player>(&registry)
+                .deserialize(&mut rmp_serde::Deserializer::new(bytes.as_slice()))
+                .unwrap();
+            assert_eq!(Player::from_reflect(&*output).unwrap(), input);
+
+            let serializer = CompactReflectSerializer::new(&input, &registry, &type_ids);
+            let bytes = rmp_serde::to_vec_named(&serializer).unwrap();
+            assert!(!contains(&bytes, b"health"));
+            let output = CompactReflectDeserializer::new(&registry, &type_ids)
+                .deserialize(&mut rmp_serde::Deserializer::new(bytes.as_slice()))
+                .unwrap();
+            assert_eq!(Player::from_reflect(&*output).unwrap(), input);
+        }
+
+        #[test]
+        fn should_use_processors() {
+            /// Serializes names in uppercase.
+            struct UppercaseSerializer;
+
+            impl ReflectSerializerProcessor for UppercaseSerializer {
+                fn try_serialize<S>(
+                    &self,
+                    value: &dyn PartialReflect,
+                    _registry: &TypeRegistry,
+                    serializer: S,
+                ) -> Result<Result<S::Ok, S>, S::Error>
+                where
+                    S: Serializer,
+                {
+                    match value.try_downcast_ref::<String>() {
+                        Some(value) => value.to_uppercase().serialize(serializer).map(Ok),
+                        None => Ok(Err(serializer)),
+                    }
+                }
+            }
+
+            /// Deserializes names in lowercase.
+            struct LowercaseDeserializer;
+
+            impl ReflectDeserializerProcessor for LowercaseDeserializer {
+                fn try_deserialize<'de, D>(
+                    &mut self,
+                    registration: &TypeRegistration,
+                    _registry: &TypeRegistry,
+                    deserializer: D,
+                ) -> Result<Result<Box<dyn PartialReflect>, D>, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    if registration.type_id() != TypeId::of::<String>() {
+                        return Ok(Err(deserializer));
+                    }
+                    let value = String::deserialize(deserializer)?.to_lowercase();
+                    Ok(Ok(Box::new(value)))
+                }
+            }
+
+            let registry = create_registry();
+            let type_ids = CompactTypeIds::hashed(&registry);
+            let input = create_player();
+
+            let serializer = CompactReflectSerializer::with_processor(
+                &input,
+                &registry,
+                &type_ids,
+                &UppercaseSerializer,
+            );
+            let bytes = bincode_options().serialize(&serializer).unwrap();
+            assert!(bytes.windows(5).any(|window| window == b"ALICE"));
+
+            let mut processor = LowercaseDeserializer;
+            let deserializer =
+                CompactReflectDeserializer::with_processor(&registry, &type_ids, &mut processor);
+            let output = bincode_options()
+                .deserialize_seed(deserializer, &bytes)
+                .unwrap();
+            let output = Player::from_reflect(&*output).unwrap();
+            assert_eq!(output.name, "alice");
+            assert_eq!(output.stats["speed"], 2.0);
+        }
+
+        #[test]
+        fn should_error_on_unknown_types() {
+            let registry = create_registry();
+            let type_ids = CompactTypeIds::from_type_paths(&registry, ["u32"]);
+            let input = create_player();
+
+            let serializer = CompactReflectSerializer::new(&input, &registry, &type_ids);
+            let error = bincode_options().serialize(&serializer).unwrap_err();
+            assert!(error.to_string().contains(
+                "no compact type id found for type `bevy_reflect::serde::tests::compact::Player`"
+            ));
+
+            let bytes = bincode_options().serialize(&(1_u64, 10_u32)).unwrap();
+            let deserializer = CompactReflectDeserializer::new(&registry, &type_ids);
+            let error = bincode_options()
+                .deserialize_seed(deserializer, &bytes)
+                .unwrap_err();
+            assert!(error
+                .to_string()
+                .contains("no registration found for compact type id 1"));
+        }
+    }
 }
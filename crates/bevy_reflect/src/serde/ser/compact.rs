@@ -0,0 +1,141 @@
+use crate::{
+    serde::{ser::error_utils::make_custom_error, CompactTypeIds, TypedReflectSerializer},
+    PartialReflect, TypeRegistry,
+};
+use serde::{ser::SerializeTuple, Serialize, Serializer};
+
+use super::ReflectSerializerProcessor;
+
+/// A general purpose serializer for reflected types, producing a compact output meant for
+/// binary formats.
+///
+/// This is the serializer counterpart to [`CompactReflectDeserializer`].
+///
+/// # Output
+///
+/// This serializer will output a tuple of two elements, where the first element is the numeric
+/// id of the reflected type in the given [`CompactTypeIds`] and the second element is the
+/// serialized data.
+///
+/// Unlike the [`ReflectSerializer`], the names of fields are omitted, as with
+/// [`TypedReflectSerializer::compact`].
+///
+/// If you want to override serialization for specific values, you can pass in
+/// a reference to a [`ReflectSerializerProcessor`] which will take priority
+/// over all other serialization methods - see [`with_processor`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::{CompactReflectSerializer, CompactTypeIds, ReflectSerializer}};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// #[type_path = "my_crate"]
+/// struct MyStruct {
+///   value: i32
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<MyStruct>();
+/// let type_ids = CompactTypeIds::from_type_paths(&registry, ["my_crate::MyStruct"]);
+///
+/// let input = MyStruct { value: 123 };
+///
+/// let reflect_serializer = CompactReflectSerializer::new(&input, &registry, &type_ids);
+/// let output: Vec<u8> = bincode::serialize(&reflect_serializer).unwrap();
+///
+/// // The id and the value, instead of the type path and the value.
+/// assert_eq!(output.len(), 8 + 4);
+/// let reflect_serializer = ReflectSerializer::new(&input, &registry);
+/// assert!(bincode::serialize(&reflect_serializer).unwrap().len() > output.len());
+/// ```
+///
+/// [`CompactReflectDeserializer`]: crate::serde::CompactReflectDeserializer
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [`with_processor`]: Self::with_processor
+pub struct CompactReflectSerializer<'a, P = ()> {
+    value: &'a dyn PartialReflect,
+    registry: &'a TypeRegistry,
+    type_ids: &'a CompactTypeIds,
+    processor: Option<&'a P>,
+}
+
+impl<'a> CompactReflectSerializer<'a, ()> {
+    /// Creates a serializer with no processor.
+    ///
+    /// If you want to add custom logic for serializing certain values, use
+    /// [`with_processor`].
+    ///
+    /// [`with_processor`]: Self::with_processor
+    pub fn new(
+        value: &'a dyn PartialReflect,
+        registry: &'a TypeRegistry,
+        type_ids: &'a CompactTypeIds,
+    ) -> Self {
+        Self {
+            value,
+            registry,
+            type_ids,
+            processor: None,
+        }
+    }
+}
+
+impl<'a, P: ReflectSerializerProcessor> CompactReflectSerializer<'a, P> {
+    /// Creates a serializer with a processor.
+    ///
+    /// If you do not need any custom logic for handling certain values, use
+    /// [`new`].
+    ///
+    /// [`new`]: Self::new
+    pub fn with_processor(
+        value: &'a dyn PartialReflect,
+        registry: &'a TypeRegistry,
+        type_ids: &'a CompactTypeIds,
+        processor: &'a P,
+    ) -> Self {
+        Self {
+            value,
+            registry,
+            type_ids,
+            processor: Some(processor),
+        }
+    }
+}
+
+impl<P: ReflectSerializerProcessor> Serialize for CompactReflectSerializer<'_, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let type_info = self.value.get_represented_type_info().ok_or_else(|| {
+            if self.value.is_dynamic() {
+                make_custom_error(format_args!(
+                    "cannot serialize dynamic value without represented type: `{}`",
+                    self.value.reflect_type_path()
+                ))
+            } else {
+                make_custom_error(format_args!(
+                    "cannot get type info for `{}`",
+                    self.value.reflect_type_path()
+                ))
+            }
+        })?;
+        let id = self.type_ids.id_of(type_info.type_id()).ok_or_else(|| {
+            make_custom_error(format_args!(
+                "no compact type id found for type `{}`",
+                type_info.type_path()
+            ))
+        })?;
+
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&id)?;
+        state.serialize_element(&TypedReflectSerializer::new_internal(
+            self.value,
+            self.registry,
+            self.processor,
+            true,
+        ))?;
+        state.end()
+    }
+}
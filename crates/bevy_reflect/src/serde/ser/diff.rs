@@ -0,0 +1,196 @@
+use crate::{
+    serde::{ReflectSerializer, ReflectSerializerProcessor},
+    PartialReflect, ReflectDiff, TypeRegistry,
+};
+use alloc::boxed::Box;
+use serde::{
+    ser::{SerializeTupleVariant, Serializer},
+    Serialize,
+};
+
+/// A serializer for [`ReflectDiff`].
+///
+/// This is the serializer counterpart to [`ReflectDiffDeserializer`].
+///
+/// # Output
+///
+/// A diff is serialized as an enum with one variant per [`ReflectDiff`] variant.
+/// The values it contains, such as replaced values or inserted map entries, are serialized
+/// with [`ReflectSerializer`], along with their type path, as they are not always of the type
+/// of the diffed value.
+///
+/// If you want to override serialization for specific values, you can pass in
+/// a reference to a [`ReflectSerializerProcessor`] which will take priority
+/// over all other serialization methods - see [`with_processor`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{ReflectDiff, TypeRegistry, serde::ReflectDiffSerializer};
+/// #[derive(Reflect, Clone)]
+/// struct MyStruct {
+///   value: i32,
+///   other: i32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<MyStruct>();
+///
+/// let old = MyStruct { value: 1, other: 2 };
+/// let new = MyStruct { value: 5, other: 2 };
+/// let diff = ReflectDiff::new(&old, &new);
+///
+/// let diff_serializer = ReflectDiffSerializer::new(&diff, &registry);
+/// let output = ron::to_string(&diff_serializer).unwrap();
+///
+/// assert_eq!(output, r#"Struct([("value",Replaced({"i32":5}))])"#);
+/// ```
+///
+/// [`ReflectDiffDeserializer`]: crate::serde::ReflectDiffDeserializer
+/// [`with_processor`]: Self::with_processor
+pub struct ReflectDiffSerializer<'a, P = ()> {
+    diff: &'a ReflectDiff,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a P>,
+}
+
+impl<'a> ReflectDiffSerializer<'a, ()> {
+    /// Creates a serializer with no processor.
+    ///
+    /// If you want to add custom logic for serializing certain values, use
+    /// [`with_processor`].
+    ///
+    /// [`with_processor`]: Self::with_processor
+    pub fn new(diff: &'a ReflectDiff, registry: &'a TypeRegistry) -> Self {
+        Self {
+            diff,
+            registry,
+            processor: None,
+        }
+    }
+}
+
+impl<'a, P: ReflectSerializerProcessor> ReflectDiffSerializer<'a, P> {
+    /// Creates a serializer with a processor.
+    ///
+    /// If you do not need any custom logic for handling certain values, use
+    /// [`new`].
+    ///
+    /// [`new`]: Self::new
+    pub fn with_processor(
+        diff: &'a ReflectDiff,
+        registry: &'a TypeRegistry,
+        processor: &'a P,
+    ) -> Self {
+        Self {
+            diff,
+            registry,
+            processor: Some(processor),
+        }
+    }
+
+    fn diff(&self, diff: &'a ReflectDiff) -> Self {
+        Self {
+            diff,
+            registry: self.registry,
+            processor: self.processor,
+        }
+    }
+
+    fn value(&self, value: &'a dyn PartialReflect) -> ReflectSerializer<'a, P> {
+        ReflectSerializer::new_internal(value, self.registry, self.processor)
+    }
+
+    fn values(
+        &self,
+        values: &'a [Box<dyn PartialReflect>],
+    ) -> impl Iterator<Item = ReflectSerializer<'a, P>> + Clone + '_ {
+        values
+            .iter()
+            .map(|value| self.value(value.as_partial_reflect()))
+    }
+}
+
+impl<P: ReflectSerializerProcessor> Serialize for ReflectDiffSerializer<'_, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        const NAME: &str = "ReflectDiff";
+        match self.diff {
+            ReflectDiff::Unchanged => serializer.serialize_unit_variant(NAME, 0, "Unchanged"),
+            ReflectDiff::Replaced(value) => serializer.serialize_newtype_variant(
+                NAME,
+                1,
+                "Replaced",
+                &self.value(value.as_partial_reflect()),
+            ),
+            ReflectDiff::Struct(fields) => serializer.serialize_newtype_variant(
+                NAME,
+                2,
+                "Struct",
+                &Seq(fields.iter().map(|(name, diff)| (name, self.diff(diff)))),
+            ),
+            ReflectDiff::Tuple(fields) => serializer.serialize_newtype_variant(
+                NAME,
+                3,
+                "Tuple",
+                &Seq(fields.iter().map(|(index, diff)| (index, self.diff(diff)))),
+            ),
+            ReflectDiff::List {
+                len,
+                changed,
+                appended,
+            } => {
+                let mut state = serializer.serialize_tuple_variant(NAME, 4, "List", 3)?;
+                state.serialize_field(len)?;
+                state.serialize_field(&Seq(changed
+                    .iter()
+                    .map(|(index, diff)| (index, self.diff(diff)))))?;
+                state.serialize_field(&Seq(self.values(appended)))?;
+                state.end()
+            }
+            ReflectDiff::Map {
+                changed,
+                inserted,
+                removed,
+            } => {
+                let mut state = serializer.serialize_tuple_variant(NAME, 5, "Map", 3)?;
+                state.serialize_field(&Seq(changed
+                    .iter()
+                    .map(|(key, diff)| (self.value(key.as_partial_reflect()), self.diff(diff)))))?;
+                state.serialize_field(&Seq(inserted.iter().map(|(key, value)| {
+                    (
+                        self.value(key.as_partial_reflect()),
+                        self.value(value.as_partial_reflect()),
+                    )
+                })))?;
+                state.serialize_field(&Seq(self.values(removed)))?;
+                state.end()
+            }
+            ReflectDiff::Set { inserted, removed } => {
+                let mut state = serializer.serialize_tuple_variant(NAME, 6, "Set", 2)?;
+                state.serialize_field(&Seq(self.values(inserted)))?;
+                state.serialize_field(&Seq(self.values(removed)))?;
+                state.end()
+            }
+        }
+    }
+}
+
+/// Serializes the items of an iterator as a sequence.
+struct Seq<I>(I);
+
+impl<I> Serialize for Seq<I>
+where
+    I: Iterator + Clone,
+    I::Item: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.0.clone())
+    }
+}
@@ -14,6 +14,7 @@ pub(super) struct EnumSerializer<'a, P> {
     pub enum_value: &'a dyn Enum,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub compact: bool,
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for EnumSerializer<'_, P> {
@@ -70,6 +71,24 @@ impl<P: ReflectSerializerProcessor> Serialize for EnumSerializer<'_, P> {
                     }
                 };
 
+                if self.compact {
+                    let mut state = serializer.serialize_tuple_variant(
+                        enum_name,
+                        variant_index,
+                        variant_name,
+                        field_len,
+                    )?;
+                    for field in self.enum_value.iter_fields() {
+                        state.serialize_field(&TypedReflectSerializer::new_internal(
+                            field.value(),
+                            self.registry,
+                            self.processor,
+                            self.compact,
+                        ))?;
+                    }
+                    return state.end();
+                }
+
                 let mut state = serializer.serialize_struct_variant(
                     enum_name,
                     variant_index,
@@ -84,6 +103,7 @@ impl<P: ReflectSerializerProcessor> Serialize for EnumSerializer<'_, P> {
                             field.value(),
                             self.registry,
                             self.processor,
+                            self.compact,
                         ),
                     )?;
                 }
@@ -99,13 +119,19 @@ impl<P: ReflectSerializerProcessor> Serialize for EnumSerializer<'_, P> {
                         field,
                         self.registry,
                         self.processor,
+                        self.compact,
                     ))
                 } else {
                     serializer.serialize_newtype_variant(
                         enum_name,
                         variant_index,
                         variant_name,
-                        &TypedReflectSerializer::new_internal(field, self.registry, self.processor),
+                        &TypedReflectSerializer::new_internal(
+                            field,
+                            self.registry,
+                            self.processor,
+                            self.compact,
+                        ),
                     )
                 }
             }
@@ -121,6 +147,7 @@ impl<P: ReflectSerializerProcessor> Serialize for EnumSerializer<'_, P> {
                         field.value(),
                         self.registry,
                         self.processor,
+                        self.compact,
                     ))?;
                 }
                 state.end()
@@ -1,10 +1,14 @@
+pub use compact::*;
+pub use diff::*;
 pub use processor::*;
 pub use serializable::*;
 pub use serialize_with_registry::*;
 pub use serializer::*;
 
 mod arrays;
+mod compact;
 mod custom_serialization;
+mod diff;
 mod enums;
 mod error_utils;
 mod lists;
@@ -17,6 +21,7 @@ mod sets;
 mod structs;
 mod tuple_structs;
 mod tuples;
+mod versioned;
 
 #[cfg(test)]
 mod tests {
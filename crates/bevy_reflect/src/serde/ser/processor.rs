@@ -36,6 +36,14 @@ use crate::{PartialReflect, TypeRegistry};
 /// [`SerializeWithRegistry`] implementation, so this is also useful for
 /// overriding serialization behavior if you need to do something custom.
 ///
+/// # Versioned types
+///
+/// Types registering [`ReflectVersion`] are serialized as a `(version, value)`
+/// tuple. The processor is called before the version is written, so a
+/// processor overriding the serialization of a versioned type should write
+/// the version as well if its output is meant to be read back by a
+/// [`TypedReflectDeserializer`] without a processor.
+///
 /// # Examples
 ///
 /// Serializing a reflected value when saving an asset to disk, and replacing
@@ -129,6 +137,8 @@ use crate::{PartialReflect, TypeRegistry};
 /// [`try_serialize`]: Self::try_serialize
 /// [`SerializeWithRegistry`]: crate::serde::SerializeWithRegistry
 /// [`ReflectDeserializerProcessor`]: crate::serde::ReflectDeserializerProcessor
+/// [`ReflectVersion`]: crate::serde::ReflectVersion
+/// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
 pub trait ReflectSerializerProcessor {
     /// Attempts to serialize the value which a [`TypedReflectSerializer`] is
     /// currently looking at.
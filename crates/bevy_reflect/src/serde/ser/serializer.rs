@@ -5,8 +5,9 @@ use crate::{
         arrays::ArraySerializer, custom_serialization::try_custom_serialize, enums::EnumSerializer,
         error_utils::make_custom_error, lists::ListSerializer, maps::MapSerializer,
         sets::SetSerializer, structs::StructSerializer, tuple_structs::TupleStructSerializer,
-        tuples::TupleSerializer,
+        tuples::TupleSerializer, versioned::VersionedSerializer,
     },
+    serde::ReflectVersion,
     PartialReflect, ReflectRef, TypeRegistry,
 };
 use serde::{ser::SerializeMap, Serialize, Serializer};
@@ -94,6 +95,19 @@ impl<'a, P: ReflectSerializerProcessor> ReflectSerializer<'a, P> {
             processor: Some(processor),
         }
     }
+
+    /// An internal constructor for creating a serializer with an optional processor.
+    pub(super) fn new_internal(
+        value: &'a dyn PartialReflect,
+        registry: &'a TypeRegistry,
+        processor: Option<&'a P>,
+    ) -> Self {
+        Self {
+            value,
+            registry,
+            processor,
+        }
+    }
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for ReflectSerializer<'_, P> {
@@ -119,7 +133,7 @@ impl<P: ReflectSerializerProcessor> Serialize for ReflectSerializer<'_, P> {
                     }
                 })?
                 .type_path(),
-            &TypedReflectSerializer::new_internal(self.value, self.registry, self.processor),
+            &TypedReflectSerializer::new_internal(self.value, self.registry, self.processor, false),
         )?;
         state.end()
     }
@@ -139,6 +153,10 @@ impl<P: ReflectSerializerProcessor> Serialize for ReflectSerializer<'_, P> {
 ///
 /// Instead, it will output just the serialized data.
 ///
+/// For binary formats, the output can be made more compact by omitting the names of fields
+/// with [`compact`].
+/// It can still be deserialized by a [`TypedReflectDeserializer`].
+///
 /// If you want to override serialization for specific values, you can pass in
 /// a reference to a [`ReflectSerializerProcessor`] which will take priority
 /// over all other serialization methods - see [`with_processor`].
@@ -167,11 +185,13 @@ impl<P: ReflectSerializerProcessor> Serialize for ReflectSerializer<'_, P> {
 ///
 /// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
 /// [type path]: crate::TypePath::type_path
+/// [`compact`]: Self::compact
 /// [`with_processor`]: Self::with_processor
 pub struct TypedReflectSerializer<'a, P = ()> {
     value: &'a dyn PartialReflect,
     registry: &'a TypeRegistry,
     processor: Option<&'a P>,
+    compact: bool,
 }
 
 impl<'a> TypedReflectSerializer<'a, ()> {
@@ -189,6 +209,7 @@ impl<'a> TypedReflectSerializer<'a, ()> {
             value,
             registry,
             processor: None,
+            compact: false,
         }
     }
 }
@@ -212,19 +233,35 @@ impl<'a, P> TypedReflectSerializer<'a, P> {
             value,
             registry,
             processor: Some(processor),
+            compact: false,
         }
     }
 
+    /// Omits the names of fields, serializing structs and struct variants as tuples.
+    ///
+    /// This makes the output of binary formats that would otherwise write field names,
+    /// such as CBOR, more compact.
+    /// Note that fields can then only be deserialized in order,
+    /// so data written this way won't load if the fields of its types are reordered.
+    /// Self-describing formats such as RON may also fail to deserialize structs from tuples.
+    #[must_use]
+    pub fn compact(mut self) -> Self {
+        self.compact = true;
+        self
+    }
+
     /// An internal constructor for creating a serializer without resetting the type info stack.
     pub(super) fn new_internal(
         value: &'a dyn PartialReflect,
         registry: &'a TypeRegistry,
         processor: Option<&'a P>,
+        compact: bool,
     ) -> Self {
         Self {
             value,
             registry,
             processor,
+            compact,
         }
     }
 }
@@ -257,6 +294,28 @@ impl<P: ReflectSerializerProcessor> Serialize for TypedReflectSerializer<'_, P>
             serializer
         };
 
+        // Versioned types are serialized along with their version
+        if let Some(reflect_version) = self.value.get_represented_type_info().and_then(|info| {
+            self.registry
+                .get_type_data::<ReflectVersion>(info.type_id())
+        }) {
+            return VersionedSerializer {
+                version: reflect_version.version(),
+                serializer: self,
+            }
+            .serialize(serializer);
+        }
+
+        self.serialize_unversioned(serializer)
+    }
+}
+
+impl<P: ReflectSerializerProcessor> TypedReflectSerializer<'_, P> {
+    /// Serializes the data of the value, without its version.
+    pub(super) fn serialize_unversioned<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
         // Handle both Value case and types that have a custom `Serialize`
         let (serializer, error) = match try_custom_serialize(self.value, self.registry, serializer)
         {
@@ -269,48 +328,56 @@ impl<P: ReflectSerializerProcessor> Serialize for TypedReflectSerializer<'_, P>
                 struct_value,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::TupleStruct(tuple_struct) => TupleStructSerializer {
                 tuple_struct,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Tuple(tuple) => TupleSerializer {
                 tuple,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::List(list) => ListSerializer {
                 list,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Array(array) => ArraySerializer {
                 array,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Map(map) => MapSerializer {
                 map,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Set(set) => SetSerializer {
                 set,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             ReflectRef::Enum(enum_value) => EnumSerializer {
                 enum_value,
                 registry: self.registry,
                 processor: self.processor,
+                compact: self.compact,
             }
             .serialize(serializer),
             #[cfg(feature = "functions")]
@@ -2,7 +2,10 @@ use crate::{
     serde::{ser::error_utils::make_custom_error, SerializationData, TypedReflectSerializer},
     Struct, TypeInfo, TypeRegistry,
 };
-use serde::{ser::SerializeStruct, Serialize};
+use serde::{
+    ser::{SerializeStruct, SerializeTuple},
+    Serialize,
+};
 
 use super::ReflectSerializerProcessor;
 
@@ -11,6 +14,7 @@ pub(super) struct StructSerializer<'a, P> {
     pub struct_value: &'a dyn Struct,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub compact: bool,
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for StructSerializer<'_, P> {
@@ -42,6 +46,24 @@ impl<P: ReflectSerializerProcessor> Serialize for StructSerializer<'_, P> {
             .get(type_info.type_id())
             .and_then(|registration| registration.data::<SerializationData>());
         let ignored_len = serialization_data.map(SerializationData::len).unwrap_or(0);
+
+        if self.compact {
+            let mut state =
+                serializer.serialize_tuple(self.struct_value.field_len() - ignored_len)?;
+            for (index, value) in self.struct_value.iter_fields().enumerate() {
+                if serialization_data.is_some_and(|data| data.is_field_skipped(index)) {
+                    continue;
+                }
+                state.serialize_element(&TypedReflectSerializer::new_internal(
+                    value,
+                    self.registry,
+                    self.processor,
+                    self.compact,
+                ))?;
+            }
+            return state.end();
+        }
+
         let mut state = serializer.serialize_struct(
             struct_info.type_path_table().ident().unwrap(),
             self.struct_value.field_len() - ignored_len,
@@ -54,7 +76,12 @@ impl<P: ReflectSerializerProcessor> Serialize for StructSerializer<'_, P> {
             let key = struct_info.field_at(index).unwrap().name();
             state.serialize_field(
                 key,
-                &TypedReflectSerializer::new_internal(value, self.registry, self.processor),
+                &TypedReflectSerializer::new_internal(
+                    value,
+                    self.registry,
+                    self.processor,
+                    self.compact,
+                ),
             )?;
         }
         state.end()
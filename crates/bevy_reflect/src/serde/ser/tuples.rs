@@ -8,6 +8,7 @@ pub(super) struct TupleSerializer<'a, P> {
     pub tuple: &'a dyn Tuple,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub compact: bool,
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for TupleSerializer<'_, P> {
@@ -22,6 +23,7 @@ impl<P: ReflectSerializerProcessor> Serialize for TupleSerializer<'_, P> {
                 value,
                 self.registry,
                 self.processor,
+                self.compact,
             ))?;
         }
         state.end()
@@ -0,0 +1,36 @@
+use crate::serde::TypedReflectSerializer;
+use serde::{ser::SerializeTuple, Serialize};
+
+use super::ReflectSerializerProcessor;
+
+/// A serializer for the `(version, value)` tuple of types registering [`ReflectVersion`].
+///
+/// [`ReflectVersion`]: crate::serde::ReflectVersion
+pub(super) struct VersionedSerializer<'a, 'b, P> {
+    pub version: u32,
+    pub serializer: &'b TypedReflectSerializer<'a, P>,
+}
+
+impl<P: ReflectSerializerProcessor> Serialize for VersionedSerializer<'_, '_, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&self.version)?;
+        state.serialize_element(&UnversionedSerializer(self.serializer))?;
+        state.end()
+    }
+}
+
+/// Serializes the data of a versioned value, without its version.
+struct UnversionedSerializer<'a, 'b, P>(&'b TypedReflectSerializer<'a, P>);
+
+impl<P: ReflectSerializerProcessor> Serialize for UnversionedSerializer<'_, '_, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize_unversioned(serializer)
+    }
+}
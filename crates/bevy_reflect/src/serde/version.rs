@@ -0,0 +1,267 @@
+use crate::{
+    serde::{ReflectDeserializerProcessor, TypedReflectDeserializer},
+    FromReflect, PartialReflect, TypePath, TypeRegistry,
+};
+use alloc::{boxed::Box, sync::Arc};
+use bevy_utils::HashMap;
+use core::{any::TypeId, fmt};
+use serde::de::{DeserializeSeed, Error};
+
+type MigrationFn = dyn Fn(
+        &mut dyn erased_serde::Deserializer,
+        &TypeRegistry,
+    ) -> Result<Box<dyn PartialReflect>, erased_serde::Error>
+    + Send
+    + Sync;
+
+type ConvertFn = dyn Fn(&dyn PartialReflect) -> Option<Box<dyn PartialReflect>> + Send + Sync;
+
+/// A way of loading data serialized by an older version of a type.
+#[derive(Clone)]
+enum Migration {
+    /// Deserializes the old data directly.
+    Deserialize(Arc<MigrationFn>),
+    /// Deserializes the old data as another reflected type, then converts it.
+    Convert {
+        type_id: TypeId,
+        type_path: &'static str,
+        convert: Arc<ConvertFn>,
+    },
+}
+
+/// Type data holding the serialization version of a type, along with the migrations used to load
+/// data serialized by its older versions.
+///
+/// This is registered by the `Reflect` derive when the type has the `#[reflect(version = N)]`
+/// attribute, or it can be inserted manually into a [`TypeRegistration`].
+///
+/// # Serialization
+///
+/// The reflection serializers write a versioned value as a `(version, value)` tuple.
+/// When deserializing it, the reflection deserializers read the version first:
+/// data of the current version is deserialized as usual, while data of an older version is
+/// handed to the [migration] registered for that version, which must produce a value of the
+/// current version.
+///
+/// Data serialized before a type was versioned doesn't contain a version, and is loaded as the
+/// [base version] of the type: with the migration registered for that version if any, or as the
+/// current version otherwise.
+/// This way, adding a version to a type doesn't break its existing data.
+/// Since the version can't be told apart from the value otherwise, unversioned data is only
+/// recognized in human-readable formats such as RON or JSON, and only if it isn't a sequence,
+/// as it is for tuple structs, tuples and lists.
+///
+/// # Example
+///
+/// ```
+/// # use core::any::TypeId;
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::{ReflectVersion, TypedReflectDeserializer}};
+/// // The old shape of `Player`, kept around to load old data.
+/// #[derive(Reflect)]
+/// struct PlayerV1 {
+///     hp: u32,
+/// }
+///
+/// #[derive(Reflect, PartialEq, Debug)]
+/// #[reflect(version = 2)]
+/// struct Player {
+///     health: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+/// registry.register::<PlayerV1>();
+/// registry
+///     .get_type_data_mut::<ReflectVersion>(TypeId::of::<Player>())
+///     .unwrap()
+///     .register_migration_from(1, |old: PlayerV1| Player { health: old.hp });
+///
+/// let input = "(1, (hp: 10))";
+/// let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+/// let output = TypedReflectDeserializer::of::<Player>(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert_eq!(Player::from_reflect(&*output), Some(Player { health: 10 }));
+/// ```
+///
+/// [`TypeRegistration`]: crate::TypeRegistration
+/// [base version]: Self::BASE_VERSION
+/// [migration]: Self::register_migration
+#[derive(Clone)]
+pub struct ReflectVersion {
+    version: u32,
+    migrations: HashMap<u32, Migration>,
+}
+
+impl ReflectVersion {
+    /// The version of data serialized before the type was versioned.
+    pub const BASE_VERSION: u32 = 0;
+
+    /// Creates a [`ReflectVersion`] for the given current version, without any migration.
+    pub fn new(version: u32) -> Self {
+        Self {
+            version,
+            migrations: HashMap::default(),
+        }
+    }
+
+    /// Returns the current version of the type.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Registers a migration loading data serialized by the version `from_version` of the type.
+    ///
+    /// The migration is given a deserializer positioned at the old data, and must return a
+    /// value of the current version of the type, either as the concrete type or as its dynamic
+    /// equivalent.
+    /// It replaces any migration previously registered for the same version.
+    ///
+    /// For the common case where the old version of the type is still available as a reflected
+    /// type, prefer [`register_migration_from`], which also applies the
+    /// [`ReflectDeserializerProcessor`] of the deserializer to the old data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from_version` isn't older than the current version.
+    ///
+    /// [`register_migration_from`]: Self::register_migration_from
+    pub fn register_migration(
+        &mut self,
+        from_version: u32,
+        migration: impl Fn(
+                &mut dyn erased_serde::Deserializer,
+                &TypeRegistry,
+            ) -> Result<Box<dyn PartialReflect>, erased_serde::Error>
+            + Send
+            + Sync
+            + 'static,
+    ) -> &mut Self {
+        self.assert_older(from_version);
+        self.migrations
+            .insert(from_version, Migration::Deserialize(Arc::new(migration)));
+        self
+    }
+
+    /// Registers a migration loading data serialized by the version `from_version` of the type,
+    /// where the old data has the shape of the reflected type `Old`.
+    ///
+    /// The old data is deserialized as an `Old` value, which `migrate` converts to the current
+    /// version of the type.
+    /// The [`ReflectDeserializerProcessor`] of the deserializer, if any, is applied to the old data
+    /// as well.
+    /// `Old` must be registered in the [`TypeRegistry`] used for deserialization.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from_version` isn't older than the current version.
+    pub fn register_migration_from<Old, New>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        Old: FromReflect + TypePath,
+        New: PartialReflect,
+    {
+        self.assert_older(from_version);
+        let convert = move |old: &dyn PartialReflect| {
+            let new: Box<dyn PartialReflect> = Box::new(migrate(Old::from_reflect(old)?));
+            Some(new)
+        };
+        self.migrations.insert(
+            from_version,
+            Migration::Convert {
+                type_id: TypeId::of::<Old>(),
+                type_path: Old::type_path(),
+                convert: Arc::new(convert),
+            },
+        );
+        self
+    }
+
+    fn assert_older(&self, from_version: u32) {
+        assert!(
+            from_version < self.version,
+            "cannot register a migration from version {from_version}, as it isn't older than the current version {}",
+            self.version
+        );
+    }
+
+    /// Returns true if a migration is registered for the version `from_version`.
+    pub fn has_migration(&self, from_version: u32) -> bool {
+        self.migrations.contains_key(&from_version)
+    }
+
+    /// Loads data serialized by the version `from_version` of the type, using the migration
+    /// registered for that version.
+    ///
+    /// Returns an error if no migration is registered for `from_version`.
+    pub fn migrate<'de, D>(
+        &self,
+        from_version: u32,
+        deserializer: D,
+        registry: &TypeRegistry,
+    ) -> Result<Box<dyn PartialReflect>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.migrate_internal(from_version, deserializer, registry, None::<&mut ()>)
+    }
+
+    /// Loads data serialized by the version `from_version` of the type, applying the given
+    /// processor to the old data of migrations registered with [`register_migration_from`].
+    ///
+    /// [`register_migration_from`]: Self::register_migration_from
+    pub(crate) fn migrate_internal<'de, D, P>(
+        &self,
+        from_version: u32,
+        deserializer: D,
+        registry: &TypeRegistry,
+        processor: Option<&mut P>,
+    ) -> Result<Box<dyn PartialReflect>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        P: ReflectDeserializerProcessor,
+    {
+        match self.migrations.get(&from_version) {
+            Some(Migration::Deserialize(migration)) => {
+                let mut erased = <dyn erased_serde::Deserializer>::erase(deserializer);
+                migration(&mut erased, registry).map_err(D::Error::custom)
+            }
+            Some(Migration::Convert {
+                type_id,
+                type_path,
+                convert,
+            }) => {
+                let registration = registry.get(*type_id).ok_or_else(|| {
+                    D::Error::custom(format_args!("no registration found for type `{type_path}`"))
+                })?;
+                let old = TypedReflectDeserializer::new_internal(registration, registry, processor)
+                    .deserialize(deserializer)?;
+                convert(&*old).ok_or_else(|| {
+                    D::Error::custom(format_args!(
+                        "failed to convert the deserialized value to `{type_path}`"
+                    ))
+                })
+            }
+            None => Err(D::Error::custom(format_args!(
+                "no migration registered from version {from_version} to version {}",
+                self.version
+            ))),
+        }
+    }
+}
+
+impl fmt::Debug for ReflectVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut migrations = self.migrations.keys().collect::<alloc::vec::Vec<_>>();
+        migrations.sort_unstable();
+        f.debug_struct("ReflectVersion")
+            .field("version", &self.version)
+            .field("migrations", &migrations)
+            .finish()
+    }
+}
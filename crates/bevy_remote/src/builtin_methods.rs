@@ -10,6 +10,7 @@ use bevy_ecs::{
     query::QueryBuilder,
     reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
     removal_detection::RemovedComponentEntity,
+    schedule::{Schedules, Stepping},
     system::{In, Local},
     world::{EntityRef, EntityWorldMut, FilteredEntityRef, World},
 };
@@ -62,6 +63,12 @@ pub const BRP_LIST_AND_WATCH_METHOD: &str = "bevy/list+watch";
 /// The method path for a `bevy/registry/schema` request.
 pub const BRP_REGISTRY_SCHEMA_METHOD: &str = "bevy/registry/schema";
 
+/// The method path for a `bevy/stepping/state` request.
+pub const BRP_STEPPING_STATE_METHOD: &str = "bevy/stepping/state";
+
+/// The method path for a `bevy/stepping/control` request.
+pub const BRP_STEPPING_CONTROL_METHOD: &str = "bevy/stepping/control";
+
 /// `bevy/get`: Retrieves one or more components from the entity with the given
 /// ID.
 ///
@@ -298,6 +305,36 @@ pub struct JsonSchemaTypeLimit {
     pub with: Vec<String>,
 }
 
+/// `bevy/stepping/control`: Changes the state of the [`Stepping`] resource.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BrpSteppingParams {
+    /// The stepping action to perform, if any.
+    #[serde(default)]
+    pub action: Option<BrpSteppingAction>,
+
+    /// Whether stepping should pause after each command queued by the stepped
+    /// system, if this should be changed.
+    #[serde(default)]
+    pub step_commands: Option<bool>,
+}
+
+/// An action that can be sent in a `bevy/stepping/control` request.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BrpSteppingAction {
+    /// Enable stepping; see [`Stepping::enable`].
+    Enable,
+    /// Disable stepping; see [`Stepping::disable`].
+    Disable,
+    /// Run the next system or command; see [`Stepping::step_frame`].
+    StepFrame,
+    /// Run until the end of the frame or the next breakpoint; see
+    /// [`Stepping::continue_frame`].
+    ContinueFrame,
+}
+
 /// A response from the world to the client that specifies a single entity.
 ///
 /// This is sent in response to `bevy/spawn`.
@@ -375,6 +412,32 @@ pub struct BrpQueryRow {
     pub has: HashMap<String, Value>,
 }
 
+/// The response to a `bevy/stepping/state` request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingStateResponse {
+    /// Whether stepping is enabled.
+    pub enabled: bool,
+
+    /// Whether stepping pauses after each command queued by the stepped system.
+    pub step_commands: bool,
+
+    /// The system that will run on the next step, if any.
+    pub cursor: Option<BrpSteppingCursor>,
+
+    /// The type names of the commands waiting to be applied, in order.
+    pub pending_commands: Vec<String>,
+}
+
+/// The position of the [`Stepping`] cursor.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingCursor {
+    /// The debug name of the schedule the cursor is in.
+    pub schedule: String,
+
+    /// The name of the system the cursor points at.
+    pub system: String,
+}
+
 /// A helper function used to parse a `serde_json::Value`.
 fn parse<T: for<'de> Deserialize<'de>>(value: Value) -> Result<T, BrpError> {
     serde_json::from_value(value).map_err(|err| BrpError {
@@ -743,12 +806,18 @@ pub fn process_remote_mutate_component_request(
             BrpError::component_error(anyhow!("Unknown component type: `{}`", component))
         })?;
 
+    let reflect_component = component_type.data::<ReflectComponent>().ok_or_else(|| {
+        BrpError::component_error(anyhow!("Component `{}` isn't registered.", component))
+    })?;
+    if !reflect_component.is_mutable() {
+        return Err(BrpError::component_error(anyhow!(
+            "Component `{}` is immutable and can only be replaced with `bevy/insert`",
+            component
+        )));
+    }
+
     // Get the reflected representation of the component.
-    let mut reflected = component_type
-        .data::<ReflectComponent>()
-        .ok_or_else(|| {
-            BrpError::component_error(anyhow!("Component `{}` isn't registered.", component))
-        })?
+    let mut reflected = reflect_component
         .reflect_mut(world.entity_mut(entity))
         .ok_or_else(|| {
             BrpError::component_error(anyhow!("Cannot reflect component `{}`", component))
@@ -848,6 +917,82 @@ pub fn process_remote_reparent_request(
     Ok(Value::Null)
 }
 
+/// Handles a `bevy/stepping/state` request coming from a client.
+pub fn process_remote_stepping_state_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let stepping = get_stepping(world)?;
+
+    let cursor = stepping.cursor().map(|(label, node)| {
+        let system = world
+            .get_resource::<Schedules>()
+            .and_then(|schedules| schedules.get(label))
+            .and_then(|schedule| schedule.graph().get_system_at(node))
+            .map(|system| system.name().into_owned())
+            .unwrap_or_else(|| format!("{node:?}"));
+        BrpSteppingCursor {
+            schedule: format!("{label:?}"),
+            system,
+        }
+    });
+
+    #[cfg(feature = "bevy_debug_stepping")]
+    let pending_commands = stepping
+        .pending_commands()
+        .command_names()
+        .map(String::from)
+        .collect();
+    #[cfg(not(feature = "bevy_debug_stepping"))]
+    let pending_commands = Vec::new();
+
+    let response = BrpSteppingStateResponse {
+        enabled: stepping.is_enabled(),
+        step_commands: stepping.is_stepping_commands(),
+        cursor,
+        pending_commands,
+    };
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/stepping/control` request coming from a client.
+pub fn process_remote_stepping_control_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSteppingParams {
+        action,
+        step_commands,
+    } = parse_some(params)?;
+
+    let mut stepping = world
+        .get_resource_mut::<Stepping>()
+        .ok_or_else(BrpError::stepping_not_present)?;
+
+    if let Some(enabled) = step_commands {
+        stepping.step_commands(enabled);
+    }
+
+    match action {
+        Some(BrpSteppingAction::Enable) => {
+            stepping.enable();
+        }
+        Some(BrpSteppingAction::Disable) => {
+            stepping.disable();
+        }
+        Some(BrpSteppingAction::StepFrame) => {
+            stepping.step_frame();
+        }
+        Some(BrpSteppingAction::ContinueFrame) => {
+            stepping.continue_frame();
+        }
+        None => {}
+    }
+
+    Ok(Value::Null)
+}
+
 /// Handles a `bevy/list` request (list all components) coming from a client.
 pub fn process_remote_list_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
     let app_type_registry = world.resource::<AppTypeRegistry>();
@@ -1318,6 +1463,14 @@ fn get_entity(world: &World, entity: Entity) -> Result<EntityRef<'_>, BrpError>
         .map_err(|_| BrpError::entity_not_found(entity))
 }
 
+/// Immutably retrieves the [`Stepping`] resource from the [`World`], returning
+/// an error if it isn't present.
+fn get_stepping(world: &World) -> Result<&Stepping, BrpError> {
+    world
+        .get_resource::<Stepping>()
+        .ok_or_else(BrpError::stepping_not_present)
+}
+
 /// Mutably retrieves an entity from the [`World`], returning an error if the
 /// entity isn't present.
 fn get_entity_mut(world: &mut World, entity: Entity) -> Result<EntityWorldMut<'_>, BrpError> {
@@ -1535,6 +1688,19 @@ mod tests {
         test_serialize_deserialize(BrpListParams {
             entity: Entity::from_raw(0),
         });
+        test_serialize_deserialize(BrpSteppingParams {
+            action: Some(BrpSteppingAction::StepFrame),
+            step_commands: Some(true),
+        });
+        test_serialize_deserialize(BrpSteppingStateResponse {
+            enabled: true,
+            step_commands: false,
+            cursor: Some(BrpSteppingCursor {
+                schedule: "Update".to_owned(),
+                system: "my_system".to_owned(),
+            }),
+            pending_commands: vec!["my_command".to_owned()],
+        });
     }
 
     #[test]
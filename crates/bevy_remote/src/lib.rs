@@ -205,6 +205,8 @@
 //!   [`GetPath`](bevy_reflect::GetPath#syntax) for more information on formatting this string.
 //! - `value`: The value to insert at `path`.
 //!
+//! Immutable components cannot be mutated, and must be replaced with `bevy/insert` instead.
+//!
 //! `result`: null.
 //!
 //! ### bevy/reparent
@@ -274,6 +276,30 @@
 //! - `removed`: An array of fully-qualified type names of components removed from the entity
 //!   in the last tick.
 //!
+//! ### bevy/stepping/state
+//!
+//! Report the state of the [`Stepping`](bevy_ecs::schedule::Stepping) resource.
+//!
+//! `params`: None.
+//!
+//! `result`:
+//! - `enabled`: Whether stepping is enabled.
+//! - `step_commands`: Whether stepping pauses after each command queued by the stepped system.
+//! - `cursor`: The `schedule` and `system` that will run on the next step, or null.
+//! - `pending_commands`: An array of the type names of commands waiting to be applied. Only
+//!   populated when the `bevy_debug_stepping` feature is enabled.
+//!
+//! ### bevy/stepping/control
+//!
+//! Drive the [`Stepping`](bevy_ecs::schedule::Stepping) resource.
+//!
+//! `params`:
+//! - `action` (optional): One of `enable`, `disable`, `step_frame` or `continue_frame`.
+//! - `step_commands` (optional): Whether stepping should pause after each command queued by the
+//!   stepped system.
+//!
+//! `result`: null.
+//!
 //!
 //! ## Custom methods
 //!
@@ -438,6 +464,14 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_LIST_AND_WATCH_METHOD,
                 builtin_methods::process_remote_list_watching_request,
             )
+            .with_method(
+                builtin_methods::BRP_STEPPING_STATE_METHOD,
+                builtin_methods::process_remote_stepping_state_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_CONTROL_METHOD,
+                builtin_methods::process_remote_stepping_control_request,
+            )
     }
 }
 
@@ -726,6 +760,16 @@ impl BrpError {
         }
     }
 
+    /// The `Stepping` resource wasn't found.
+    #[must_use]
+    pub fn stepping_not_present() -> Self {
+        Self {
+            code: error_codes::STEPPING_NOT_PRESENT,
+            message: String::from("Stepping resource not present"),
+            data: None,
+        }
+    }
+
     /// Attempt to reparent an entity to itself.
     #[must_use]
     pub fn self_reparent(entity: Entity) -> Self {
@@ -770,6 +814,9 @@ pub mod error_codes {
 
     /// Cannot reparent an entity to itself.
     pub const SELF_REPARENT: i16 = -23404;
+
+    /// The `Stepping` resource is not present in the world.
+    pub const STEPPING_NOT_PRESENT: i16 = -23405;
 }
 
 /// The result of a request.
@@ -2,6 +2,7 @@ use crate::{DynamicScene, SceneSpawnError};
 use bevy_asset::Asset;
 use bevy_ecs::{
     entity::{Entity, EntityHashMap, SceneEntityMapper},
+    entity_disabling::DefaultQueryFilters,
     reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectResource},
     world::World,
 };
@@ -59,9 +60,13 @@ impl Scene {
     ) -> Result<(), SceneSpawnError> {
         let type_registry = type_registry.read();
 
+        // Every world starts with its own `DefaultQueryFilters`, so the scene's copy is not written
+        // to the target world.
+        let default_query_filters_id = self.world.resource_id::<DefaultQueryFilters>();
+
         // Resources archetype
         for (component_id, resource_data) in self.world.storages().resources.iter() {
-            if !resource_data.is_present() {
+            if !resource_data.is_present() || Some(component_id) == default_query_filters_id {
                 continue;
             }
 